@@ -1,10 +1,29 @@
+use crate::app_context;
+use crate::app_error::{self, AppError};
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::apple_intelligence;
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
-use crate::managers::audio::AudioRecordingManager;
-use crate::managers::history::HistoryManager;
+use crate::audio_toolkit::{apply_local_grammar, normalize_numbers, stitch_transcripts};
+use crate::completion_notice::{self, Notice};
+use crate::confirmation::{self, Decision};
+use crate::continuation;
+use crate::input;
+use crate::latency::{self, DictationTimings};
+use crate::llm_client::CompletionOptions;
+use crate::log_redaction;
+use crate::managers::audio::{AudioRecordingManager, SampleEvent};
+use crate::managers::history::{HistoryManager, PostProcessStep};
 use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, AppSettings, APPLE_INTELLIGENCE_PROVIDER_ID};
+use crate::meeting;
+use crate::output_action::{self, OutputContext};
+use crate::power_policy::PowerProfile;
+use crate::profanity::ProfanityFilter;
+use crate::prompt_template;
+use crate::punctuation;
+use crate::settings::{
+    get_settings, AppOutputBehavior, AppSettings, LLMPrompt, PasteMethod, PostProcessProvider,
+    APPLE_INTELLIGENCE_PROVIDER_ID, LOCAL_GRAMMAR_DEFAULT_MODEL_ID, LOCAL_GRAMMAR_PROVIDER_ID,
+};
 use crate::shortcut;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::{
@@ -13,14 +32,22 @@ use crate::utils::{
 use crate::voice_commands::{self, KeyAction, VoiceAction, VoiceCommandResult};
 use crate::TranscriptionCoordinator;
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
-use log::{debug, error, info};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
 use tauri::AppHandle;
 use tauri::Manager;
+use tracing::{debug, error, info, info_span, Instrument};
+
+/// Serializes transcription pipelines so a recording queued while another one
+/// was processing is transcribed and pasted after it, in order.
+static PIPELINE_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// How long a paste waits after text held for confirmation is sent from the
+/// overlay, for it to hide and give focus back. Covers the fade-out.
+const FOCUS_RETURN_DELAY: Duration = Duration::from_millis(400);
 
 /// Drop guard that notifies the [`TranscriptionCoordinator`] when the
 /// transcription pipeline finishes — whether it completes normally or panics.
@@ -42,19 +69,72 @@ pub trait ShortcutAction: Send + Sync {
 // Transcribe Action
 struct TranscribeAction {
     post_process: bool,
-    streaming_active: Arc<AtomicBool>,
-    streaming_handle: Arc<std::sync::Mutex<Option<std::thread::JoinHandle<()>>>>,
-    /// Final text produced by the streaming loop (displayed in overlay only).
-    /// `stop()` uses this to decide whether to skip full re-transcription.
-    streaming_final_text: Arc<std::sync::Mutex<Option<String>>>,
+    /// Private dictation: skip history and keep transcript text out of logs.
+    private: bool,
+    /// Whether the current session is private (binding flag or global `private_mode`).
+    session_private: Arc<AtomicBool>,
+    /// Application that had focus when recording started (for `app_behaviors`).
+    session_app_name: Arc<std::sync::Mutex<Option<String>>>,
+    /// The streaming loop, which returns the text it displayed in the overlay.
+    /// `stop()` uses it to decide whether to skip full re-transcription.
+    streaming_handle: Arc<std::sync::Mutex<Option<StreamingHandle>>>,
+    /// Timings recorded while recording; `stop()` completes them.
+    session_timings: Arc<std::sync::Mutex<DictationTimings>>,
+}
+
+type StreamingHandle = std::thread::JoinHandle<Option<StreamedTranscript>>;
+
+/// Wake-ups for the streaming loop.
+enum StreamSignal {
+    /// Raw samples recorded so far
+    Samples(usize),
+    /// The background model load finished (successfully or not)
+    ModelReady,
+    /// Recording stopped or was cancelled
+    Stop,
+}
+
+/// Result of the streaming loop, handed to `stop()`.
+struct StreamedTranscript {
+    /// Everything shown in the overlay.
+    text: String,
+    /// Text of the finalized chunks, which cover the raw samples before
+    /// `finalized_offset`; only the audio after it needs a final pass.
+    finalized_text: String,
+    finalized_offset: usize,
+}
+
+/// Audio a streaming chunk repeats from the end of the one before (1s), so
+/// a word cut at the boundary is heard whole by one of them.
+const CHUNK_OVERLAP_SAMPLES: usize = WHISPER_SAMPLE_RATE;
+/// Words on each side of a chunk boundary searched for the overlap.
+const STITCH_WINDOW_WORDS: usize = 8;
+
+/// Where the audio of the chunk after `finalized_offset` starts.
+fn chunk_start(finalized_offset: usize) -> usize {
+    finalized_offset.saturating_sub(CHUNK_OVERLAP_SAMPLES)
+}
+
+/// Append a chunk's text to the text before it, dropping the words both
+/// heard in the overlap.
+fn join_transcript(before: &str, after: &str) -> String {
+    stitch_transcripts(before, after, STITCH_WINDOW_WORDS)
 }
 
-async fn post_process_transcription(settings: &AppSettings, transcription: &str) -> Option<String> {
+/// Run the configured prompt chain (or the single selected prompt) over the
+/// transcription. Each stage receives the previous stage's output as `${output}`.
+/// Returns the successful stages in order; an empty list means nothing ran.
+async fn post_process_transcription(
+    app: &AppHandle,
+    settings: &AppSettings,
+    transcription: &str,
+    private: bool,
+) -> Vec<PostProcessStep> {
     let provider = match settings.active_post_process_provider().cloned() {
         Some(provider) => provider,
         None => {
             debug!("Post-processing enabled but no provider is selected");
-            return None;
+            return Vec::new();
         }
     };
 
@@ -64,50 +144,113 @@ async fn post_process_transcription(settings: &AppSettings, transcription: &str)
         .cloned()
         .unwrap_or_default();
 
-    if model.trim().is_empty() {
+    // The offline grammar provider ignores prompts and never leaves the machine
+    if provider.id == LOCAL_GRAMMAR_PROVIDER_ID {
+        let output = apply_local_grammar(transcription, &settings.selected_language);
         debug!(
-            "Post-processing skipped because provider '{}' has no model configured",
-            provider.id
+            "Local grammar post-processing finished. Output length: {} chars",
+            output.len()
         );
-        return None;
+        return vec![PostProcessStep {
+            prompt_id: LOCAL_GRAMMAR_PROVIDER_ID.to_string(),
+            prompt_name: LOCAL_GRAMMAR_DEFAULT_MODEL_ID.to_string(),
+            output,
+        }];
     }
 
-    let selected_prompt_id = match &settings.post_process_selected_prompt_id {
-        Some(id) => id.clone(),
-        None => {
-            debug!("Post-processing skipped because no prompt is selected");
-            return None;
+    let prompts = settings.post_process_prompt_chain();
+    if prompts.is_empty() {
+        debug!("Post-processing skipped because no prompt is selected");
+        return Vec::new();
+    }
+
+    debug!(
+        "Starting LLM post-processing with provider '{}' (model: {}, {} stage(s))",
+        provider.id,
+        model,
+        prompts.len()
+    );
+
+    let mut steps: Vec<PostProcessStep> = Vec::new();
+    let mut current = transcription.to_string();
+    for prompt in prompts {
+        if prompt.prompt.trim().is_empty() {
+            debug!(
+                "Skipping empty prompt '{}' in post-processing chain",
+                prompt.id
+            );
+            continue;
         }
-    };
 
-    let prompt = match settings
-        .post_process_prompts
-        .iter()
-        .find(|prompt| prompt.id == selected_prompt_id)
-    {
-        Some(prompt) => prompt.prompt.clone(),
-        None => {
+        // A prompt can run on its own model instead of the provider's
+        let model = prompt
+            .model
+            .as_deref()
+            .filter(|m| !m.trim().is_empty())
+            .unwrap_or(&model);
+        if model.trim().is_empty() {
             debug!(
-                "Post-processing skipped because prompt '{}' was not found",
-                selected_prompt_id
+                "Post-processing of prompt '{}' skipped because provider '{}' has no model configured",
+                prompt.id, provider.id
             );
-            return None;
+            break;
         }
-    };
 
-    if prompt.trim().is_empty() {
-        debug!("Post-processing skipped because the selected prompt is empty");
-        return None;
+        match run_post_process_prompt(app, settings, &provider, model, prompt, &current, private)
+            .await
+        {
+            Some(output) => {
+                current = output.clone();
+                steps.push(PostProcessStep {
+                    prompt_id: prompt.id.clone(),
+                    prompt_name: prompt.name.clone(),
+                    output,
+                });
+            }
+            None => {
+                // Keep the output of the stages that did succeed
+                debug!(
+                    "Post-processing chain stopped at prompt '{}' after {} stage(s)",
+                    prompt.id,
+                    steps.len()
+                );
+                break;
+            }
+        }
     }
 
-    debug!(
-        "Starting LLM post-processing with provider '{}' (model: {})",
-        provider.id, model
-    );
+    steps
+}
 
-    // Replace ${output} variable in the prompt with the actual text
-    let processed_prompt = prompt.replace("${output}", transcription);
+/// Run a single prompt against the active provider.
+async fn run_post_process_prompt(
+    app: &AppHandle,
+    settings: &AppSettings,
+    provider: &PostProcessProvider,
+    model: &str,
+    prompt: &LLMPrompt,
+    transcription: &str,
+    private: bool,
+) -> Option<String> {
+    // Substitute ${output}, ${language}, ${app_name}, ... in the prompt and
+    // its system message
+    let system_message = prompt.system_message.as_deref().unwrap_or_default();
+    let variables = prompt_template::collect(
+        app,
+        settings,
+        &format!("{}\n{}", system_message, prompt.prompt),
+        transcription,
+    );
+    let processed_prompt = prompt_template::render(&prompt.prompt, &variables);
     debug!("Processed prompt length: {} chars", processed_prompt.len());
+    let options = CompletionOptions {
+        system_message: prompt
+            .system_message
+            .as_deref()
+            .map(|system| prompt_template::render(system, &variables)),
+        temperature: prompt.temperature,
+        max_tokens: prompt.max_tokens,
+    };
 
     if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID {
         #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
@@ -117,7 +260,10 @@ async fn post_process_transcription(settings: &AppSettings, transcription: &str)
                 return None;
             }
 
-            let token_limit = model.trim().parse::<i32>().unwrap_or(0);
+            let token_limit = match prompt.max_tokens {
+                Some(max_tokens) => max_tokens as i32,
+                None => model.trim().parse::<i32>().unwrap_or(0),
+            };
             return match apple_intelligence::process_text(&processed_prompt, token_limit) {
                 Ok(result) => {
                     if result.trim().is_empty() {
@@ -132,7 +278,13 @@ async fn post_process_transcription(settings: &AppSettings, transcription: &str)
                     }
                 }
                 Err(err) => {
-                    error!("Apple Intelligence post-processing failed: {}", err);
+                    app_error::report(
+                        app,
+                        AppError::PostProcessingFailed {
+                            provider_id: provider.id.clone(),
+                            message: err.to_string(),
+                        },
+                    );
                     None
                 }
             };
@@ -145,15 +297,18 @@ async fn post_process_transcription(settings: &AppSettings, transcription: &str)
         }
     }
 
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider.id)
-        .cloned()
-        .unwrap_or_default();
+    let api_key = settings.post_process_api_key(&provider.id);
 
     // Send the chat completion request
-    match crate::llm_client::send_chat_completion(&provider, api_key, &model, processed_prompt)
-        .await
+    match crate::llm_client::send_chat_completion(
+        provider,
+        api_key,
+        model,
+        processed_prompt,
+        &options,
+        private,
+    )
+    .await
     {
         Ok(Some(content)) => {
             // Strip invisible Unicode characters that some LLMs (e.g., Qwen) may insert
@@ -170,14 +325,22 @@ async fn post_process_transcription(settings: &AppSettings, transcription: &str)
             Some(content)
         }
         Ok(None) => {
-            error!("LLM API response has no content");
+            app_error::report(
+                app,
+                AppError::PostProcessingFailed {
+                    provider_id: provider.id.clone(),
+                    message: "response has no content".to_string(),
+                },
+            );
             None
         }
         Err(e) => {
-            error!(
-                "LLM post-processing failed for provider '{}': {}. Falling back to original transcription.",
-                provider.id,
-                e
+            app_error::report(
+                app,
+                AppError::PostProcessingFailed {
+                    provider_id: provider.id.clone(),
+                    message: e.to_string(),
+                },
             );
             None
         }
@@ -343,75 +506,87 @@ fn release_all_modifiers(app: &AppHandle) {
     }
 }
 
+/// Run an overlay update from a stopped recording's pipeline, unless a
+/// recording queued behind it is on screen by now.
+fn pipeline_overlay(app: &AppHandle, update: impl FnOnce(&AppHandle)) {
+    let recording = app
+        .try_state::<Arc<AudioRecordingManager>>()
+        .is_some_and(|rm| rm.is_recording());
+    if !recording {
+        update(app);
+    }
+}
+
 /// Check if Chinese variant conversion would be needed (without doing it)
 fn maybe_needs_chinese_conversion(settings: &AppSettings) -> bool {
     settings.selected_language == "zh-Hans" || settings.selected_language == "zh-Hant"
 }
 
-/// Apply post-processing (Chinese conversion + LLM) to transcription text.
-/// Returns (final_text, post_processed_text_for_history, post_process_prompt_for_history).
-async fn apply_post_processing(
+/// Apply post-processing (punctuation restoring, Chinese conversion + LLM) to
+/// transcription text. `verbatim` skips the punctuation and casing restorer;
+/// `private` keeps the prompt and LLM output out of the logs.
+/// Returns (final_text, post_processed_text_for_history, post_process_prompt_for_history,
+/// post_process_steps_for_history).
+#[tracing::instrument(name = "post_process", skip_all, fields(llm = post_process))]
+pub(crate) async fn apply_post_processing(
+    app: &AppHandle,
     settings: &AppSettings,
     transcription: &str,
     post_process: bool,
-) -> (String, Option<String>, Option<String>) {
-    let mut final_text = transcription.to_string();
+    verbatim: bool,
+    private: bool,
+) -> (String, Option<String>, Option<String>, Vec<PostProcessStep>) {
     let mut post_processed_text: Option<String> = None;
     let mut post_process_prompt: Option<String> = None;
 
+    // Lowercase or unpunctuated output is fixed up locally, so it reads
+    // right with or without an LLM
+    let mut final_text = punctuation::restore(app, settings, transcription, verbatim);
+
     // Chinese variant conversion
-    if let Some(converted_text) = maybe_convert_chinese_variant(settings, transcription).await {
+    if let Some(converted_text) = maybe_convert_chinese_variant(settings, &final_text).await {
         final_text = converted_text;
     }
 
-    // LLM post-processing
-    let processed = if post_process {
-        post_process_transcription(settings, &final_text).await
+    // LLM post-processing (single prompt or chain)
+    let steps = if post_process {
+        post_process_transcription(app, settings, &final_text, private).await
     } else {
-        None
+        Vec::new()
     };
-    if let Some(processed_text) = processed {
-        post_processed_text = Some(processed_text.clone());
-        final_text = processed_text;
-
-        if let Some(prompt_id) = &settings.post_process_selected_prompt_id {
-            if let Some(prompt) = settings
-                .post_process_prompts
-                .iter()
-                .find(|p| &p.id == prompt_id)
-            {
-                post_process_prompt = Some(prompt.prompt.clone());
-            }
-        }
+    if let Some(last) = steps.last() {
+        post_processed_text = Some(last.output.clone());
+        final_text = last.output.clone();
+
+        // For chains, the history records the prompt of the last stage that ran
+        post_process_prompt = settings
+            .post_process_prompts
+            .iter()
+            .find(|p| p.id == last.prompt_id)
+            .map(|p| p.prompt.clone());
     } else if final_text != transcription {
-        // Chinese conversion was applied but no LLM post-processing
+        // Local restoring or Chinese conversion was applied but no LLM
+        // post-processing
         post_processed_text = Some(final_text.clone());
     }
 
-    (final_text, post_processed_text, post_process_prompt)
+    // A single prompt is fully described by post_process_prompt; only keep
+    // intermediate results when a real chain ran.
+    let steps = if steps.len() > 1 { steps } else { Vec::new() };
+
+    (final_text, post_processed_text, post_process_prompt, steps)
 }
 
 fn streaming_transcription_loop(
-    active: Arc<AtomicBool>,
-    final_text_out: Arc<std::sync::Mutex<Option<String>>>,
+    signals: mpsc::Receiver<StreamSignal>,
     app: AppHandle,
-) {
-    info!("Streaming loop: started, waiting for audio to accumulate");
-
-    // Wait for model to be ready + audio to accumulate (~1.2s)
-    // Check flag every 50ms so we can exit quickly if recording stops
-    for i in 0..24 {
-        if !active.load(Ordering::SeqCst) {
-            info!(
-                "Streaming loop: cancelled during initial delay (after {}ms)",
-                i * 50
-            );
-            return;
-        }
-        std::thread::sleep(std::time::Duration::from_millis(50));
-    }
-
-    info!("Streaming loop: entering main loop");
+    private: bool,
+    timings: Arc<std::sync::Mutex<DictationTimings>>,
+    recording_start_time: Instant,
+    profanity: ProfanityFilter,
+) -> Option<StreamedTranscript> {
+    let _span = info_span!("streaming").entered();
+    info!("Streaming loop: started, waiting for the model and audio");
 
     // ── Chunked streaming state ──
     // Audio is processed in bounded chunks to keep transcription fast for long recordings.
@@ -423,6 +598,8 @@ fn streaming_transcription_loop(
     const FORCE_FINALIZE_SECS: usize = 20;
     const FORCE_FINALIZE_SAMPLES: usize = WHISPER_SAMPLE_RATE * FORCE_FINALIZE_SECS;
     const STABILIZE_ITERS: usize = 2;
+    // New audio required before the next partial transcription (~500ms)
+    const MIN_NEW_SAMPLES: usize = WHISPER_SAMPLE_RATE / 2;
 
     let mut finalized_text = String::new();
     let mut finalized_offset: usize = 0;
@@ -430,31 +607,55 @@ fn streaming_transcription_loop(
     let mut stable_count: usize = 0;
     let mut prev_displayed = String::new();
 
-    while active.load(Ordering::SeqCst) {
+    let mut model_ready = false;
+    // Raw samples recorded so far, and how far the last partial reached
+    let mut available: usize = 0;
+    let mut transcribed_to: usize = 0;
+
+    'stream: loop {
+        // Sleep until the model is loaded and enough new audio has arrived
+        while !model_ready || available < transcribed_to + MIN_NEW_SAMPLES {
+            match signals.recv() {
+                Ok(StreamSignal::Samples(count)) => available = count,
+                Ok(StreamSignal::ModelReady) => {
+                    debug!("Streaming loop: model ready");
+                    model_ready = true;
+                }
+                Ok(StreamSignal::Stop) | Err(_) => break 'stream,
+            }
+        }
+        // Catch up on signals that queued while the last partial was transcribed
+        for signal in signals.try_iter() {
+            match signal {
+                StreamSignal::Samples(count) => available = count,
+                StreamSignal::ModelReady => model_ready = true,
+                StreamSignal::Stop => break 'stream,
+            }
+        }
+
         let rm = app.state::<Arc<AudioRecordingManager>>();
-        let chunk = rm.peek_samples_from(finalized_offset);
+        let start = chunk_start(finalized_offset);
+        let chunk = rm.peek_samples_from(start);
 
         if let Some(chunk) = chunk {
             let chunk_len = chunk.len();
+            transcribed_to = start + chunk_len;
+            let new_len = transcribed_to.saturating_sub(finalized_offset);
 
             // Only transcribe if we have at least 0.5s of new audio
-            if chunk_len > WHISPER_SAMPLE_RATE / 2 {
+            if new_len > WHISPER_SAMPLE_RATE / 2 {
                 debug!(
                     "Streaming loop: chunk {:.1}s (offset {}, +{} samples), transcribing...",
                     chunk_len as f64 / WHISPER_SAMPLE_RATE as f64,
-                    finalized_offset,
-                    chunk_len,
+                    start,
+                    new_len,
                 );
 
                 let tm = app.state::<Arc<TranscriptionManager>>();
-                match tm.transcribe_partial(chunk) {
+                match tm.transcribe_partial(chunk, private) {
                     Ok(partial) => {
                         // Build full display text: finalized + current partial
-                        let full_text = match (finalized_text.is_empty(), partial.is_empty()) {
-                            (true, _) => partial.clone(),
-                            (_, true) => finalized_text.clone(),
-                            _ => format!("{} {}", finalized_text, partial),
-                        };
+                        let full_text = join_transcript(&finalized_text, &partial);
 
                         // Track text stability for chunk finalization
                         if !partial.is_empty() && partial == prev_partial {
@@ -471,13 +672,13 @@ fn streaming_transcription_loop(
                             || chunk_len >= FORCE_FINALIZE_SAMPLES;
                         if should_finalize {
                             info!(
-                                "Streaming loop: finalizing chunk at offset {} ({:.1}s total), text so far: '{}'",
-                                finalized_offset + chunk_len,
-                                (finalized_offset + chunk_len) as f64 / WHISPER_SAMPLE_RATE as f64,
-                                full_text,
+                                "Streaming loop: finalizing chunk at offset {} ({:.1}s total), text so far: {}",
+                                transcribed_to,
+                                transcribed_to as f64 / WHISPER_SAMPLE_RATE as f64,
+                                log_redaction::session_text(&full_text, private),
                             );
                             finalized_text = full_text.clone();
-                            finalized_offset += chunk_len;
+                            finalized_offset = transcribed_to;
                             prev_partial.clear();
                             stable_count = 0;
                         } else {
@@ -486,8 +687,15 @@ fn streaming_transcription_loop(
 
                         // Show streaming text in overlay (not typed into active window)
                         if full_text != prev_displayed {
-                            debug!("Streaming loop: overlay display '{}'", full_text);
-                            crate::overlay::emit_streaming_text(&app, &full_text);
+                            if prev_displayed.is_empty() {
+                                timings.lock().unwrap().first_partial_ms =
+                                    Some(latency::elapsed_ms(recording_start_time));
+                            }
+                            debug!(
+                                "Streaming loop: overlay display {}",
+                                log_redaction::session_text(&full_text, private)
+                            );
+                            crate::overlay::emit_streaming_text(&app, &profanity.apply(&full_text));
                             prev_displayed = full_text;
                         } else {
                             debug!("Streaming loop: text unchanged, skipping update");
@@ -499,32 +707,17 @@ fn streaming_transcription_loop(
                 }
             } else {
                 debug!(
-                    "Streaming loop: chunk only {:.1}s, too short",
-                    chunk_len as f64 / WHISPER_SAMPLE_RATE as f64
+                    "Streaming loop: only {:.1}s of new audio, too short",
+                    new_len as f64 / WHISPER_SAMPLE_RATE as f64
                 );
             }
         } else {
+            // Not recording any more; wait for the stop signal
             debug!("Streaming loop: peek returned None");
-        }
-
-        // Wait before next peek (~500ms in 50ms increments for fast exit)
-        for _ in 0..10 {
-            if !active.load(Ordering::SeqCst) {
-                break;
-            }
-            std::thread::sleep(std::time::Duration::from_millis(50));
+            transcribed_to = available;
         }
     }
 
-    // Store final streamed text so stop() can use it for the final paste
-    if !prev_displayed.is_empty() {
-        info!(
-            "Streaming loop: final streamed text: '{}' ({} chars)",
-            prev_displayed,
-            prev_displayed.chars().count()
-        );
-        *final_text_out.lock().unwrap() = Some(prev_displayed);
-    }
     info!(
         "Streaming loop: exited (finalized {} chunks, offset {})",
         if finalized_offset > 0 {
@@ -534,16 +727,36 @@ fn streaming_transcription_loop(
         },
         finalized_offset,
     );
+
+    // Hand the final streamed text to stop() for the final paste
+    if prev_displayed.is_empty() {
+        return None;
+    }
+    info!(
+        "Streaming loop: final streamed text: {}",
+        log_redaction::session_text(&prev_displayed, private)
+    );
+    Some(StreamedTranscript {
+        text: prev_displayed,
+        finalized_text,
+        finalized_offset,
+    })
 }
 
 impl ShortcutAction for TranscribeAction {
     fn start(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
+        let _span = info_span!("recording_start", binding = %binding_id).entered();
         let start_time = Instant::now();
         debug!("TranscribeAction::start called for binding: {}", binding_id);
 
         // Load model in the background
         let tm = app.state::<Arc<TranscriptionManager>>();
+        let model_was_loaded = tm.is_model_loaded();
         tm.initiate_model_load();
+        *self.session_timings.lock().unwrap() = DictationTimings::default();
+
+        // Don't record the last read-back
+        crate::tts::stop();
 
         let binding_id = binding_id.to_string();
         change_tray_icon(app, TrayIconState::Recording);
@@ -553,10 +766,33 @@ impl ShortcutAction for TranscribeAction {
 
         // Get the microphone mode to determine audio feedback timing
         let settings = get_settings(app);
-        let is_always_on = settings.always_on_microphone;
+        let power_profile = PowerProfile::current(&settings);
+        let is_always_on = power_profile.always_on_microphone;
         debug!("Microphone mode - always_on: {}", is_always_on);
 
+        let private = self.private || settings.private_mode;
+        self.session_private.store(private, Ordering::SeqCst);
+        if private {
+            info!("Private dictation: history and transcript logging disabled");
+        }
+
+        // Capture the focused app now, while the user is still in it; it picks
+        // the app behavior and tells whether focus moved before the paste
+        *self.session_app_name.lock().unwrap() = None;
+        if !settings.app_behaviors.is_empty()
+            || settings.completion_notifications
+            || settings.continue_dictation
+        {
+            let app_name_slot = self.session_app_name.clone();
+            std::thread::spawn(move || {
+                let app_name = utils::get_focused_app_name();
+                debug!("Focused application at record start: {:?}", app_name);
+                *app_name_slot.lock().unwrap() = app_name;
+            });
+        }
+
         let mut recording_started = false;
+        let recording_start_time = Instant::now();
         if is_always_on {
             // Always-on mode: Play audio feedback immediately, then apply mute after sound finishes
             debug!("Always-on mode: Playing audio feedback immediately");
@@ -575,7 +811,6 @@ impl ShortcutAction for TranscribeAction {
             // On-demand mode: Start recording first, then play audio feedback, then apply mute
             // This allows the microphone to be activated before playing the sound
             debug!("On-demand mode: Starting recording first, then audio feedback");
-            let recording_start_time = Instant::now();
             if rm.try_start_recording(&binding_id) {
                 recording_started = true;
                 debug!("Recording started in {:?}", recording_start_time.elapsed());
@@ -596,19 +831,51 @@ impl ShortcutAction for TranscribeAction {
         }
 
         if recording_started {
+            self.session_timings.lock().unwrap().mic_start_ms =
+                Some(latency::elapsed_ms(recording_start_time));
+
             // Dynamically register the cancel shortcut in a separate task to avoid deadlock
             shortcut::register_cancel_shortcut(app);
 
-            // Start streaming transcription loop
-            self.streaming_active.store(true, Ordering::SeqCst);
-            *self.streaming_final_text.lock().unwrap() = None;
-            let streaming_flag = self.streaming_active.clone();
-            let final_text_out = self.streaming_final_text.clone();
-            let app_clone = app.clone();
-            let handle = std::thread::spawn(move || {
-                streaming_transcription_loop(streaming_flag, final_text_out, app_clone);
+            // Start streaming transcription loop. It is woken by the recorder
+            // when new audio arrives and once the model has finished loading.
+            let (signal_tx, signal_rx) = mpsc::channel();
+            let ready_tx = signal_tx.clone();
+            let tm_clone = Arc::clone(&tm);
+            let timings = self.session_timings.clone();
+            std::thread::spawn(move || {
+                tm_clone.wait_for_load();
+                if !model_was_loaded {
+                    timings.lock().unwrap().model_load_ms = Some(latency::elapsed_ms(start_time));
+                }
+                let _ = ready_tx.send(StreamSignal::ModelReady);
             });
-            *self.streaming_handle.lock().unwrap() = Some(handle);
+
+            if power_profile.streaming {
+                rm.set_sample_listener(Box::new(move |event| {
+                    let _ = signal_tx.send(match event {
+                        SampleEvent::Available(count) => StreamSignal::Samples(count),
+                        SampleEvent::Ended => StreamSignal::Stop,
+                    });
+                }));
+
+                let timings = self.session_timings.clone();
+                let app_clone = app.clone();
+                let profanity = ProfanityFilter::for_binding(&settings, &binding_id);
+                let handle = std::thread::spawn(move || {
+                    streaming_transcription_loop(
+                        signal_rx,
+                        app_clone,
+                        private,
+                        timings,
+                        recording_start_time,
+                        profanity,
+                    )
+                });
+                *self.streaming_handle.lock().unwrap() = Some(handle);
+            } else {
+                debug!("Battery saver: streaming partials disabled");
+            }
         }
 
         debug!(
@@ -619,7 +886,8 @@ impl ShortcutAction for TranscribeAction {
 
     fn stop(&self, app: &AppHandle, binding_id: &str, _shortcut_str: &str) {
         // Signal the streaming loop to stop (non-blocking)
-        self.streaming_active.store(false, Ordering::SeqCst);
+        app.state::<Arc<AudioRecordingManager>>()
+            .clear_sample_listener();
         // Take the join handle so the async task can wait for it
         let streaming_join = self.streaming_handle.lock().unwrap().take();
 
@@ -645,9 +913,13 @@ impl ShortcutAction for TranscribeAction {
 
         let binding_id = binding_id.to_string(); // Clone binding_id for the async task
         let post_process = self.post_process;
-        let streaming_final_text = self.streaming_final_text.clone();
+        let private = self.session_private.load(Ordering::SeqCst);
+        let session_app_name = self.session_app_name.clone();
+        let session_timings = self.session_timings.clone();
+        // Covers everything from stop to paste; stages get child spans
+        let dictation_span = info_span!("dictation", binding = %binding_id, private);
 
-        tauri::async_runtime::spawn(async move {
+        let pipeline = async move {
             let _guard = FinishGuard(ah.clone());
             let binding_id = binding_id.clone(); // Clone for the inner async task
             debug!(
@@ -655,25 +927,56 @@ impl ShortcutAction for TranscribeAction {
                 binding_id
             );
 
-            // Wait for streaming loop to finish
-            if let Some(handle) = streaming_join {
-                info!("Waiting for streaming loop to finish...");
-                let _ = handle.join();
-                info!("Streaming loop finished");
-            }
-
-            // Grab the text the streaming loop produced (shown in overlay, not typed)
-            let streamed_text = streaming_final_text.lock().unwrap().take();
+            // Read session state before releasing the recorder; a queued
+            // recording may start as soon as it is free
+            let app_name = session_app_name.lock().unwrap().clone();
+            let mut timings = session_timings.lock().unwrap().clone();
 
+            // Take the samples before waiting for the streaming loop, which
+            // may be in the middle of a partial transcription, so a queued
+            // recording doesn't wait for it. The loop was told to stop above
+            // and exits once that partial is done
             let stop_recording_time = Instant::now();
-            if let Some(samples) = rm.stop_recording(&binding_id) {
+            let stopped = rm.stop_recording_with_raw(&binding_id);
+
+            // The text the streaming loop produced (shown in overlay, not typed)
+            let streamed_text = match streaming_join {
+                Some(handle) => {
+                    info!("Waiting for streaming loop to finish...");
+                    let streamed = handle.join().ok().flatten();
+                    info!("Streaming loop finished");
+                    streamed
+                }
+                None => None,
+            };
+
+            if let Some((samples, raw)) = stopped {
                 debug!(
                     "Recording stopped and samples retrieved in {:?}, sample count: {}",
                     stop_recording_time.elapsed(),
                     samples.len()
                 );
-
+                // Audio after the finalized chunks (with the usual overlap). The
+                // finalized offset indexes the raw buffer, not the VAD-filtered one
+                let unfinalized_tail = streamed_text
+                    .as_ref()
+                    .filter(|s| s.finalized_offset > 0)
+                    .map(|s| raw[chunk_start(s.finalized_offset).min(raw.len())..].to_vec());
+                drop(raw);
+
+                let _pipeline = PIPELINE_LOCK.lock().await;
                 let settings = get_settings(&ah);
+                let profanity = ProfanityFilter::for_binding(&settings, &binding_id);
+
+                // Code editors and terminals get verbatim text: no rewriting, typed directly
+                let verbatim =
+                    app_context::resolve_behavior(&settings.app_behaviors, app_name.as_deref())
+                        == AppOutputBehavior::Verbatim;
+                if verbatim {
+                    info!("Verbatim output for focused app {:?}", app_name);
+                }
+                let post_process = post_process && !verbatim;
+                let paste_method_override = verbatim.then_some(PasteMethod::Direct);
 
                 // Decide whether we need a full re-transcription.
                 // If streaming already produced text and no post-processing is needed,
@@ -681,191 +984,517 @@ impl ShortcutAction for TranscribeAction {
                 let needs_post_processing =
                     post_process || maybe_needs_chinese_conversion(&settings);
 
-                let (transcription, final_text, post_processed_text, post_process_prompt) =
-                    if let Some(ref streamed) = streamed_text {
-                        if !needs_post_processing {
-                            // Fast path: streaming text is already on screen, no post-processing needed.
-                            // Skip full re-transcription entirely.
-                            info!(
-                                "Using streamed text directly (no post-processing): '{}'",
-                                streamed
-                            );
-                            // Unload the model since we won't call transcribe()
-                            tm.maybe_unload_immediately("streaming-only transcription");
-                            (streamed.clone(), streamed.clone(), None, None)
-                        } else {
-                            // Post-processing needed: do full transcription for best quality,
-                            // then replace the streamed text with the post-processed result.
-                            info!("Post-processing requested, running full transcription");
-                            if post_process {
-                                show_processing_overlay(&ah);
-                            }
-                            let transcription_time = Instant::now();
-                            match tm.transcribe(samples.clone()) {
-                                Ok(transcription) => {
-                                    debug!(
-                                        "Transcription completed in {:?}: '{}'",
-                                        transcription_time.elapsed(),
-                                        transcription
-                                    );
-                                    let (ft, ppt, ppp) = apply_post_processing(
-                                        &settings,
-                                        &transcription,
-                                        post_process,
-                                    )
-                                    .await;
-                                    (transcription, ft, ppt, ppp)
-                                }
-                                Err(err) => {
-                                    error!(
-                                        "Full transcription failed, using streamed text: {}",
-                                        err
-                                    );
-                                    (streamed.clone(), streamed.clone(), None, None)
-                                }
-                            }
-                        }
+                let (
+                    transcription,
+                    final_text,
+                    post_processed_text,
+                    post_process_prompt,
+                    post_process_steps,
+                ) = if let Some(ref streamed) = streamed_text {
+                    if !needs_post_processing {
+                        // Fast path: streaming text is already on screen, no post-processing needed.
+                        // Skip full re-transcription entirely.
+                        info!(
+                            "Using streamed text directly (no post-processing): {}",
+                            log_redaction::session_text(&streamed.text, private)
+                        );
+                        // Unload the model since we won't call transcribe()
+                        tm.maybe_unload_immediately("streaming-only transcription");
+                        // Only the local punctuation restorer runs here
+                        let (ft, ppt, ppp, steps) = apply_post_processing(
+                            &ah,
+                            &settings,
+                            &streamed.text,
+                            false,
+                            verbatim,
+                            private,
+                        )
+                        .await;
+                        (streamed.text.clone(), ft, ppt, ppp, steps)
                     } else {
-                        // No streaming text — do full transcription as usual
+                        // Post-processing needed: finish the transcription for best
+                        // quality, then replace the streamed text with the
+                        // post-processed result. Chunks the streaming loop finalized
+                        // are kept; only the audio after them is transcribed again.
+                        if post_process {
+                            pipeline_overlay(&ah, show_processing_overlay);
+                        }
                         let transcription_time = Instant::now();
-                        match tm.transcribe(samples.clone()) {
+                        let result = match unfinalized_tail {
+                            Some(tail) => {
+                                info!(
+                                    "Post-processing requested, transcribing the last {:.1}s after finalized chunks",
+                                    tail.len() as f64 / WHISPER_SAMPLE_RATE as f64
+                                );
+                                tm.transcribe(tail, private)
+                                    .map(|t| join_transcript(&streamed.finalized_text, &t))
+                            }
+                            None => {
+                                info!("Post-processing requested, running full transcription");
+                                tm.transcribe(samples.clone(), private)
+                            }
+                        };
+                        match result {
                             Ok(transcription) => {
                                 debug!(
-                                    "Transcription completed in {:?}: '{}'",
+                                    "Transcription completed in {:?}: {}",
                                     transcription_time.elapsed(),
-                                    transcription
+                                    log_redaction::session_text(&transcription, private)
                                 );
-                                if transcription.is_empty() {
-                                    utils::hide_recording_overlay(&ah);
-                                    change_tray_icon(&ah, TrayIconState::Idle);
-                                    return;
-                                }
-                                if post_process {
-                                    show_processing_overlay(&ah);
-                                }
-                                let (ft, ppt, ppp) =
-                                    apply_post_processing(&settings, &transcription, post_process)
-                                        .await;
-                                (transcription, ft, ppt, ppp)
+                                timings.transcribe_ms =
+                                    Some(latency::elapsed_ms(transcription_time));
+                                let post_process_time = Instant::now();
+                                let (ft, ppt, ppp, steps) = apply_post_processing(
+                                    &ah,
+                                    &settings,
+                                    &transcription,
+                                    post_process,
+                                    verbatim,
+                                    private,
+                                )
+                                .await;
+                                timings.post_process_ms =
+                                    Some(latency::elapsed_ms(post_process_time));
+                                (transcription, ft, ppt, ppp, steps)
                             }
                             Err(err) => {
-                                debug!("Global Shortcut Transcription error: {}", err);
-                                utils::hide_recording_overlay(&ah);
+                                error!("Final transcription failed, using streamed text: {}", err);
+                                (
+                                    streamed.text.clone(),
+                                    streamed.text.clone(),
+                                    None,
+                                    None,
+                                    Vec::new(),
+                                )
+                            }
+                        }
+                    }
+                } else {
+                    // No streaming text — do full transcription as usual
+                    let transcription_time = Instant::now();
+                    match tm.transcribe(samples.clone(), private) {
+                        Ok(transcription) => {
+                            debug!(
+                                "Transcription completed in {:?}: {}",
+                                transcription_time.elapsed(),
+                                log_redaction::session_text(&transcription, private)
+                            );
+                            timings.transcribe_ms = Some(latency::elapsed_ms(transcription_time));
+                            if transcription.is_empty() {
+                                pipeline_overlay(&ah, utils::show_no_speech_overlay);
                                 change_tray_icon(&ah, TrayIconState::Idle);
                                 return;
                             }
+                            if post_process {
+                                pipeline_overlay(&ah, show_processing_overlay);
+                            }
+                            let post_process_time = Instant::now();
+                            let (ft, ppt, ppp, steps) = apply_post_processing(
+                                &ah,
+                                &settings,
+                                &transcription,
+                                post_process,
+                                verbatim,
+                                private,
+                            )
+                            .await;
+                            if needs_post_processing {
+                                timings.post_process_ms =
+                                    Some(latency::elapsed_ms(post_process_time));
+                            }
+                            (transcription, ft, ppt, ppp, steps)
+                        }
+                        Err(err) => {
+                            app_error::report(
+                                &ah,
+                                AppError::TranscriptionFailed {
+                                    message: err.to_string(),
+                                },
+                            );
+                            change_tray_icon(&ah, TrayIconState::Idle);
+                            return;
                         }
-                    };
+                    }
+                };
 
                 if final_text.is_empty() {
-                    utils::hide_recording_overlay(&ah);
+                    pipeline_overlay(&ah, utils::show_no_speech_overlay);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    return;
+                }
+                let final_text = if verbatim {
+                    app_context::to_verbatim(&final_text)
+                } else {
+                    final_text
+                };
+                // Spoken numbers -> digits, before voice-command matching and paste
+                let final_text = if settings.number_normalization {
+                    normalize_numbers(&final_text, &settings.selected_language)
+                } else {
+                    final_text
+                };
+                // Mask profanity before the text is shown, pasted or saved
+                let final_text = profanity.apply(&final_text);
+                if final_text.is_empty() {
+                    pipeline_overlay(&ah, utils::hide_recording_overlay);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    return;
+                }
+                // The main thread reports (paste_ms, total_ms) once the paste has run;
+                // the sender is dropped if it fails or never happens
+                let (pasted_tx, pasted_rx) = tokio::sync::oneshot::channel::<(u64, u64)>();
+
+                // Continue-dictation: join onto the block pasted just before
+                let continuation_seq = continuation::next_seq();
+                let continuing = continuation::current(&settings, app_name.as_deref());
+                let continued_from = continuing.as_ref().and_then(|c| c.entry_id);
+
+                // A dictation made while another is held for confirmation
+                // answers or extends it instead of being output, and isn't
+                // saved or announced itself
+                if confirmation::handle_dictation(&ah, &final_text) {
                     change_tray_icon(&ah, TrayIconState::Idle);
                     return;
                 }
+                let dictated_text = final_text.clone();
+                let (final_text, stop_time) = if settings.confirm_before_paste {
+                    // Held text doesn't keep the pipeline busy, so the answer
+                    // can be dictated
+                    drop(_pipeline);
+                    drop(_guard);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    let held_time = Instant::now();
+                    match confirmation::confirm(&ah, final_text).await {
+                        Decision::Send { text, from_overlay } => {
+                            if from_overlay {
+                                // Clicking the overlay may have activated it; let
+                                // focus go back to the target app before pasting
+                                pipeline_overlay(&ah, utils::hide_recording_overlay);
+                                tokio::time::sleep(FOCUS_RETURN_DELAY).await;
+                            }
+                            // Time spent deciding isn't latency
+                            (text, stop_time + held_time.elapsed())
+                        }
+                        Decision::Discard => {
+                            pipeline_overlay(&ah, utils::hide_recording_overlay);
+                            return;
+                        }
+                    }
+                } else {
+                    (final_text, stop_time)
+                };
+
+                #[cfg(target_os = "linux")]
+                if !private {
+                    crate::dbus_control::transcription_finished(&final_text);
+                }
 
-                // Save to history
+                // Save to history once the timings are complete (private dictation
+                // leaves no trace). Text edited while held is saved as sent
                 let hm_clone = Arc::clone(&hm);
-                let transcription_for_history = transcription.clone();
-                let pp_text = post_processed_text.clone();
+                let transcription_for_history = profanity.apply(&transcription);
+                let pp_text = if final_text != dictated_text {
+                    Some(final_text.clone())
+                } else {
+                    post_processed_text.as_deref().map(|t| profanity.apply(t))
+                };
                 let pp_prompt = post_process_prompt.clone();
+                let pp_steps: Vec<_> = post_process_steps
+                    .into_iter()
+                    .map(|mut step| {
+                        step.output = profanity.apply(&step.output);
+                        step
+                    })
+                    .collect();
                 let samples_clone = samples;
-                tauri::async_runtime::spawn(async move {
-                    if let Err(e) = hm_clone
+                let history_task = async move {
+                    if let Ok((paste_ms, total_ms)) = pasted_rx.await {
+                        timings.paste_ms = Some(paste_ms);
+                        timings.total_ms = Some(total_ms);
+                    }
+                    info!(?timings, "Dictation timings");
+
+                    if private {
+                        debug!("Private dictation: skipping history save");
+                        return;
+                    }
+                    match hm_clone
                         .save_transcription(
-                            samples_clone,
+                            Some(samples_clone),
                             transcription_for_history,
                             pp_text,
                             pp_prompt,
+                            pp_steps,
+                            timings,
+                            continued_from,
                         )
                         .await
                     {
-                        error!("Failed to save transcription to history: {}", e);
+                        Ok(entry_id) => continuation::set_entry_id(continuation_seq, entry_id),
+                        Err(e) => error!("Failed to save transcription to history: {}", e),
                     }
-                });
+                };
+                tauri::async_runtime::spawn(history_task.in_current_span());
+
+                // File, webhook and command outputs. Without a paste they are
+                // the delivery and are waited for; alongside one they run in
+                // the background so the paste isn't held up
+                let output_actions = settings
+                    .binding_output
+                    .get(&binding_id)
+                    .cloned()
+                    .unwrap_or_default();
+                let output_context = OutputContext {
+                    binding: binding_id.clone(),
+                    app_name: app_name.clone(),
+                    language: settings.selected_language.clone(),
+                };
+                if !output_action::pastes(&output_actions) {
+                    let output_time = Instant::now();
+                    if output_action::run(
+                        &ah,
+                        &settings,
+                        &output_actions,
+                        &final_text,
+                        &output_context,
+                    )
+                    .await
+                    {
+                        let _ = pasted_tx.send((
+                            latency::elapsed_ms(output_time),
+                            latency::elapsed_ms(stop_time),
+                        ));
+                        pipeline_overlay(&ah, |app| {
+                            crate::overlay::emit_overlay_done(app, &final_text)
+                        });
+                        crate::tts::speak_transcript(&ah, &final_text, private);
+                    }
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    return;
+                }
+                if !output_actions.is_empty() {
+                    let ah = ah.clone();
+                    let settings = settings.clone();
+                    let text = final_text.clone();
+                    tauri::async_runtime::spawn(
+                        async move {
+                            output_action::run(
+                                &ah,
+                                &settings,
+                                &output_actions,
+                                &text,
+                                &output_context,
+                            )
+                            .await;
+                        }
+                        .in_current_span(),
+                    );
+                }
 
                 // Streaming text was shown in overlay only (not typed into active window).
                 // Always do a single paste via clipboard at the end.
                 let settings_for_vc = get_settings(&ah);
                 let voice_commands_enabled = settings_for_vc.voice_commands_enabled;
+                // "New paragraph" ends a continued block even with voice commands off
+                let check_commands = voice_commands_enabled
+                    || (settings_for_vc.continue_dictation
+                        && voice_commands::is_new_paragraph(&final_text));
+                let trailing_space = if settings_for_vc.append_trailing_space {
+                    " "
+                } else {
+                    ""
+                };
+                let join_continued = |text: String| match &continuing {
+                    Some(block) => continuation::join(&block.tail, &text),
+                    None => text,
+                };
+
+                // Key events are dropped silently without Accessibility permission on
+                // macOS; fail loudly instead (the text is already in history)
+                let sends_input = voice_commands_enabled
+                    || paste_method_override.unwrap_or(settings_for_vc.paste_method)
+                        != PasteMethod::None;
+                if sends_input && !input::is_accessibility_trusted() {
+                    app_error::report(&ah, AppError::AccessibilityPermissionMissing);
+                    change_tray_icon(&ah, TrayIconState::Idle);
+                    return;
+                }
 
                 let ah_clone = ah.clone();
                 let paste_time = Instant::now();
+                // Entered on the main thread, where the paste runs
+                let paste_span = info_span!("paste");
 
                 // Clone final_text for overlay-done emission after paste
                 let done_text = final_text.clone();
+                let started_in = app_name.clone();
 
-                if voice_commands_enabled {
-                    match voice_commands::check_voice_command(&final_text) {
+                if check_commands {
+                    match voice_commands::check_voice_command(&final_text, private) {
                         VoiceCommandResult::Command(cmd) => {
                             info!(
-                                "Executing voice command: {} (from '{}')",
-                                cmd.description, final_text
+                                "Executing voice command: {} (from {})",
+                                cmd.description,
+                                log_redaction::session_text(&final_text, private)
                             );
                             let action = cmd.action.clone();
                             ah.run_on_main_thread(move || {
+                                let _entered = paste_span.enter();
                                 match execute_voice_command(&ah_clone, &action) {
-                                    Ok(()) => debug!(
-                                        "Voice command executed in {:?}",
-                                        paste_time.elapsed()
+                                    Ok(()) => {
+                                        debug!(
+                                            "Voice command executed in {:?}",
+                                            paste_time.elapsed()
+                                        );
+                                        continuation::after_command(&action);
+                                        let _ = pasted_tx.send((
+                                            latency::elapsed_ms(paste_time),
+                                            latency::elapsed_ms(stop_time),
+                                        ));
+                                        // Voice commands: hide overlay (no text to show)
+                                        pipeline_overlay(&ah_clone, utils::hide_recording_overlay);
+                                    }
+                                    Err(e) => app_error::report(
+                                        &ah_clone,
+                                        AppError::VoiceCommandFailed { message: e },
                                     ),
-                                    Err(e) => error!("Failed to execute voice command: {}", e),
                                 }
-                                // Voice commands: hide overlay (no text to show)
-                                utils::hide_recording_overlay(&ah_clone);
                                 change_tray_icon(&ah_clone, TrayIconState::Idle);
                             })
                             .unwrap_or_else(|e| {
-                                error!("Failed to run voice command on main thread: {:?}", e);
-                                utils::hide_recording_overlay(&ah);
+                                app_error::report(
+                                    &ah,
+                                    AppError::VoiceCommandFailed {
+                                        message: format!("could not run on main thread: {:?}", e),
+                                    },
+                                );
                                 change_tray_icon(&ah, TrayIconState::Idle);
                             });
                         }
                         VoiceCommandResult::Text(text) => {
                             let dt = done_text.clone();
+                            let text = join_continued(text);
+                            let continued = continuing.is_some();
+                            let inserted = format!("{}{}", text, trailing_space);
                             ah.run_on_main_thread(move || {
-                                match utils::paste(text, ah_clone.clone()) {
-                                    Ok(()) => debug!(
-                                        "Text pasted successfully in {:?}",
-                                        paste_time.elapsed()
-                                    ),
-                                    Err(e) => error!("Failed to paste transcription: {}", e),
+                                let _entered = paste_span.enter();
+                                match utils::paste_with_method(
+                                    text,
+                                    ah_clone.clone(),
+                                    paste_method_override,
+                                ) {
+                                    Ok(()) => {
+                                        debug!(
+                                            "Text pasted successfully in {:?}",
+                                            paste_time.elapsed()
+                                        );
+                                        continuation::record(
+                                            continuation_seq,
+                                            started_in.clone(),
+                                            &inserted,
+                                            continued,
+                                        );
+                                        let _ = pasted_tx.send((
+                                            latency::elapsed_ms(paste_time),
+                                            latency::elapsed_ms(stop_time),
+                                        ));
+                                        // Transition overlay to "done" state with copy/close buttons
+                                        pipeline_overlay(&ah_clone, |app| {
+                                            crate::overlay::emit_overlay_done(app, &dt)
+                                        });
+                                        crate::tts::speak_transcript(&ah_clone, &dt, private);
+                                        completion_notice::notify_if_focus_moved(
+                                            &ah_clone, started_in, dt, private,
+                                        );
+                                    }
+                                    Err(e) => {
+                                        app_error::report(
+                                            &ah_clone,
+                                            AppError::PasteFailed { message: e },
+                                        );
+                                        completion_notice::send(
+                                            &ah_clone,
+                                            Notice::PasteFailed,
+                                            &dt,
+                                            private,
+                                        );
+                                    }
                                 }
-                                // Transition overlay to "done" state with copy/close buttons
-                                crate::overlay::emit_overlay_done(&ah_clone, &dt);
                                 change_tray_icon(&ah_clone, TrayIconState::Idle);
                             })
                             .unwrap_or_else(|e| {
-                                error!("Failed to run paste on main thread: {:?}", e);
-                                utils::hide_recording_overlay(&ah);
+                                app_error::report(
+                                    &ah,
+                                    AppError::PasteFailed {
+                                        message: format!("could not run on main thread: {:?}", e),
+                                    },
+                                );
                                 change_tray_icon(&ah, TrayIconState::Idle);
                             });
                         }
                     }
                 } else {
                     // Voice commands disabled — single paste
+                    let text = join_continued(final_text);
+                    let continued = continuing.is_some();
+                    let inserted = format!("{}{}", text, trailing_space);
                     ah.run_on_main_thread(move || {
-                        match utils::paste(final_text, ah_clone.clone()) {
+                        let _entered = paste_span.enter();
+                        match utils::paste_with_method(
+                            text,
+                            ah_clone.clone(),
+                            paste_method_override,
+                        ) {
                             Ok(()) => {
-                                debug!("Text pasted successfully in {:?}", paste_time.elapsed())
+                                debug!("Text pasted successfully in {:?}", paste_time.elapsed());
+                                continuation::record(
+                                    continuation_seq,
+                                    started_in.clone(),
+                                    &inserted,
+                                    continued,
+                                );
+                                let _ = pasted_tx.send((
+                                    latency::elapsed_ms(paste_time),
+                                    latency::elapsed_ms(stop_time),
+                                ));
+                                // Transition overlay to "done" state with copy/close buttons
+                                pipeline_overlay(&ah_clone, |app| {
+                                    crate::overlay::emit_overlay_done(app, &done_text)
+                                });
+                                crate::tts::speak_transcript(&ah_clone, &done_text, private);
+                                completion_notice::notify_if_focus_moved(
+                                    &ah_clone, started_in, done_text, private,
+                                );
+                            }
+                            Err(e) => {
+                                app_error::report(&ah_clone, AppError::PasteFailed { message: e });
+                                completion_notice::send(
+                                    &ah_clone,
+                                    Notice::PasteFailed,
+                                    &done_text,
+                                    private,
+                                );
                             }
-                            Err(e) => error!("Failed to paste transcription: {}", e),
                         }
-                        // Transition overlay to "done" state with copy/close buttons
-                        crate::overlay::emit_overlay_done(&ah_clone, &done_text);
                         change_tray_icon(&ah_clone, TrayIconState::Idle);
                     })
                     .unwrap_or_else(|e| {
-                        error!("Failed to run paste on main thread: {:?}", e);
-                        utils::hide_recording_overlay(&ah);
+                        app_error::report(
+                            &ah,
+                            AppError::PasteFailed {
+                                message: format!("could not run on main thread: {:?}", e),
+                            },
+                        );
                         change_tray_icon(&ah, TrayIconState::Idle);
                     });
                 }
             } else {
                 debug!("No samples retrieved from recording stop");
-                utils::hide_recording_overlay(&ah);
+                pipeline_overlay(&ah, utils::hide_recording_overlay);
                 change_tray_icon(&ah, TrayIconState::Idle);
             }
-        });
+        };
+        tauri::async_runtime::spawn(pipeline.instrument(dictation_span));
 
         debug!(
             "TranscribeAction::stop completed in {:?}",
@@ -915,12 +1544,25 @@ impl ShortcutAction for CancelAction {
     }
 }
 
+// Meeting Action
+struct MeetingAction;
+
+impl ShortcutAction for MeetingAction {
+    fn start(&self, app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        meeting::toggle(app);
+    }
+
+    fn stop(&self, _app: &AppHandle, _binding_id: &str, _shortcut_str: &str) {
+        // The meeting keeps running until the binding is pressed again
+    }
+}
+
 // Test Action
 struct TestAction;
 
 impl ShortcutAction for TestAction {
     fn start(&self, app: &AppHandle, binding_id: &str, shortcut_str: &str) {
-        log::info!(
+        info!(
             "Shortcut ID '{}': Started - {} (App: {})", // Changed "Pressed" to "Started" for consistency
             binding_id,
             shortcut_str,
@@ -929,7 +1571,7 @@ impl ShortcutAction for TestAction {
     }
 
     fn stop(&self, app: &AppHandle, binding_id: &str, shortcut_str: &str) {
-        log::info!(
+        info!(
             "Shortcut ID '{}': Stopped - {} (App: {})", // Changed "Released" to "Stopped" for consistency
             binding_id,
             shortcut_str,
@@ -945,24 +1587,43 @@ pub static ACTION_MAP: Lazy<HashMap<String, Arc<dyn ShortcutAction>>> = Lazy::ne
         "transcribe".to_string(),
         Arc::new(TranscribeAction {
             post_process: false,
-            streaming_active: Arc::new(AtomicBool::new(false)),
+            private: false,
+            session_private: Arc::new(AtomicBool::new(false)),
+            session_app_name: Arc::new(std::sync::Mutex::new(None)),
             streaming_handle: Arc::new(std::sync::Mutex::new(None)),
-            streaming_final_text: Arc::new(std::sync::Mutex::new(None)),
+            session_timings: Arc::new(std::sync::Mutex::new(DictationTimings::default())),
         }) as Arc<dyn ShortcutAction>,
     );
     map.insert(
         "transcribe_with_post_process".to_string(),
         Arc::new(TranscribeAction {
             post_process: true,
-            streaming_active: Arc::new(AtomicBool::new(false)),
+            private: false,
+            session_private: Arc::new(AtomicBool::new(false)),
+            session_app_name: Arc::new(std::sync::Mutex::new(None)),
+            streaming_handle: Arc::new(std::sync::Mutex::new(None)),
+            session_timings: Arc::new(std::sync::Mutex::new(DictationTimings::default())),
+        }) as Arc<dyn ShortcutAction>,
+    );
+    map.insert(
+        "transcribe_private".to_string(),
+        Arc::new(TranscribeAction {
+            post_process: false,
+            private: true,
+            session_private: Arc::new(AtomicBool::new(false)),
+            session_app_name: Arc::new(std::sync::Mutex::new(None)),
             streaming_handle: Arc::new(std::sync::Mutex::new(None)),
-            streaming_final_text: Arc::new(std::sync::Mutex::new(None)),
+            session_timings: Arc::new(std::sync::Mutex::new(DictationTimings::default())),
         }) as Arc<dyn ShortcutAction>,
     );
     map.insert(
         "toggle_settings".to_string(),
         Arc::new(ToggleSettingsAction) as Arc<dyn ShortcutAction>,
     );
+    map.insert(
+        meeting::BINDING.to_string(),
+        Arc::new(MeetingAction) as Arc<dyn ShortcutAction>,
+    );
     map.insert(
         "cancel".to_string(),
         Arc::new(CancelAction) as Arc<dyn ShortcutAction>,
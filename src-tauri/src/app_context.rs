@@ -0,0 +1,93 @@
+//! Per-application output behavior.
+//!
+//! The focused application is captured when recording starts and matched
+//! against the `app_behaviors` rules in settings. Code editors and terminals
+//! default to verbatim output: no LLM/grammar rewriting, no automatic
+//! sentence capitalization or trailing period, and direct typing instead of a
+//! clipboard paste so editor autocomplete doesn't interfere.
+
+use crate::settings::{AppBehaviorRule, AppOutputBehavior};
+
+/// Find the behavior for `app_name`. Patterns are matched case-insensitively
+/// against the whole name; a trailing `*` matches any suffix.
+pub fn resolve_behavior(rules: &[AppBehaviorRule], app_name: Option<&str>) -> AppOutputBehavior {
+    let Some(app_name) = app_name else {
+        return AppOutputBehavior::Default;
+    };
+    let app_name = app_name.to_lowercase();
+
+    rules
+        .iter()
+        .find(|rule| {
+            let pattern = rule.app_pattern.trim().to_lowercase();
+            match pattern.strip_suffix('*') {
+                Some(prefix) => app_name.starts_with(prefix),
+                None => app_name == pattern,
+            }
+        })
+        .map(|rule| rule.behavior)
+        .unwrap_or_default()
+}
+
+/// Undo the sentence formatting speech models add: a capitalized first word
+/// and a single trailing period. Acronyms ("API") are left untouched.
+pub fn to_verbatim(text: &str) -> String {
+    let text = text.trim();
+    let text = match text.strip_suffix('.') {
+        Some(stripped) if !stripped.ends_with('.') => stripped,
+        _ => text,
+    };
+
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(first), second)
+            if first.is_uppercase() && !second.is_some_and(|c| c.is_uppercase()) =>
+        {
+            first.to_lowercase().collect::<String>() + &text[first.len_utf8()..]
+        }
+        _ => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, behavior: AppOutputBehavior) -> AppBehaviorRule {
+        AppBehaviorRule {
+            app_pattern: pattern.to_string(),
+            behavior,
+        }
+    }
+
+    #[test]
+    fn resolves_exact_and_prefix_patterns() {
+        let rules = vec![
+            rule("code", AppOutputBehavior::Verbatim),
+            rule("jetbrains*", AppOutputBehavior::Verbatim),
+        ];
+        assert_eq!(
+            resolve_behavior(&rules, Some("Code")),
+            AppOutputBehavior::Verbatim
+        );
+        assert_eq!(
+            resolve_behavior(&rules, Some("JetBrains Rider")),
+            AppOutputBehavior::Verbatim
+        );
+        assert_eq!(
+            resolve_behavior(&rules, Some("Xcode")),
+            AppOutputBehavior::Default
+        );
+        assert_eq!(resolve_behavior(&rules, None), AppOutputBehavior::Default);
+    }
+
+    #[test]
+    fn verbatim_strips_sentence_formatting() {
+        assert_eq!(to_verbatim("Git status."), "git status");
+        assert_eq!(to_verbatim("API client"), "API client");
+        assert_eq!(to_verbatim("Wait..."), "wait...");
+        assert_eq!(to_verbatim(""), "");
+        // Unformatted dictation comes out as it was spoken
+        assert_eq!(to_verbatim("what is ls"), "what is ls");
+    }
+}
@@ -0,0 +1,137 @@
+//! User-facing errors from the dictation pipeline.
+//!
+//! Failures that used to end in a log line and a silent fallback are reported
+//! through [`report`], which logs them, emits an `app-error` event (shown as a
+//! toast in the main window) and switches the overlay to its error state, so
+//! users can tell why nothing was pasted.
+
+use serde::Serialize;
+use specta::Type;
+use std::fmt;
+use tauri::{AppHandle, Emitter};
+use tracing::error;
+
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AppError {
+    ModelLoadFailed {
+        model_id: String,
+        message: String,
+    },
+    MicrophoneUnavailable {
+        message: String,
+    },
+    TranscriptionFailed {
+        message: String,
+    },
+    PostProcessingFailed {
+        provider_id: String,
+        message: String,
+    },
+    PasteFailed {
+        message: String,
+    },
+    /// macOS Accessibility permission is missing, so text can't be typed.
+    AccessibilityPermissionMissing,
+    VoiceCommandFailed {
+        message: String,
+    },
+    /// Appending to a file, a webhook or a command failed.
+    OutputFailed {
+        target: String,
+        message: String,
+    },
+}
+
+impl AppError {
+    /// Whether the pipeline carried on with a fallback (e.g. the raw
+    /// transcription was pasted without post-processing).
+    pub fn recovered(&self) -> bool {
+        matches!(self, AppError::PostProcessingFailed { .. })
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::ModelLoadFailed { model_id, message } => {
+                write!(f, "Failed to load model '{}': {}", model_id, message)
+            }
+            AppError::MicrophoneUnavailable { message } => {
+                write!(f, "Microphone unavailable: {}", message)
+            }
+            AppError::TranscriptionFailed { message } => {
+                write!(f, "Transcription failed: {}", message)
+            }
+            AppError::PostProcessingFailed {
+                provider_id,
+                message,
+            } => write!(
+                f,
+                "Post-processing with '{}' failed, kept the text from before this step: {}",
+                provider_id, message
+            ),
+            AppError::PasteFailed { message } => write!(f, "Failed to paste text: {}", message),
+            AppError::AccessibilityPermissionMissing => write!(
+                f,
+                "Accessibility permission is required to paste. Enable it in System Settings > Privacy & Security > Accessibility"
+            ),
+            AppError::VoiceCommandFailed { message } => {
+                write!(f, "Voice command failed: {}", message)
+            }
+            AppError::OutputFailed { target, message } => {
+                write!(f, "Failed to write output to '{}': {}", target, message)
+            }
+        }
+    }
+}
+
+/// Log the error, notify the frontend and show it in the overlay.
+pub fn report(app: &AppHandle, err: AppError) {
+    error!("{}", err);
+    let _ = app.emit("app-error", &err);
+
+    // A recovered error still produces output; don't replace it in the overlay
+    if !err.recovered() {
+        crate::overlay::show_error_overlay(app, &err.to_string());
+        crate::tts::speak_error(app, &err.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_with_kind_tag() {
+        let err = AppError::PostProcessingFailed {
+            provider_id: "openai".to_string(),
+            message: "HTTP 401".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&err).unwrap(),
+            serde_json::json!({
+                "kind": "post_processing_failed",
+                "provider_id": "openai",
+                "message": "HTTP 401"
+            })
+        );
+    }
+
+    #[test]
+    fn only_post_processing_is_recovered() {
+        let paste = AppError::PasteFailed {
+            message: "no focus".to_string(),
+        };
+        assert!(!paste.recovered());
+        assert_eq!(paste.to_string(), "Failed to paste text: no focus");
+    }
+
+    #[test]
+    fn unit_variant_serializes_as_kind_only() {
+        assert_eq!(
+            serde_json::to_value(AppError::AccessibilityPermissionMissing).unwrap(),
+            serde_json::json!({ "kind": "accessibility_permission_missing" })
+        );
+    }
+}
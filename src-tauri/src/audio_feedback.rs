@@ -1,17 +1,19 @@
 use crate::settings::SoundTheme;
 use crate::settings::{self, AppSettings};
 use cpal::traits::{DeviceTrait, HostTrait};
-use log::{debug, error, warn};
 use rodio::OutputStreamBuilder;
 use std::fs::File;
 use std::io::BufReader;
 use std::path::{Path, PathBuf};
 use std::thread;
 use tauri::{AppHandle, Manager};
+use tracing::{debug, error, warn};
 
 pub enum SoundType {
     Start,
     Stop,
+    /// A shortcut was rejected because a transcription is still running
+    Busy,
 }
 
 fn resolve_sound_path(
@@ -19,13 +21,14 @@ fn resolve_sound_path(
     settings: &AppSettings,
     sound_type: SoundType,
 ) -> Option<PathBuf> {
+    let base_dir = get_sound_base_dir(settings, &sound_type);
     let sound_file = get_sound_path(settings, sound_type);
-    let base_dir = get_sound_base_dir(settings);
     app.path().resolve(&sound_file, base_dir).ok()
 }
 
 fn get_sound_path(settings: &AppSettings, sound_type: SoundType) -> String {
     match (settings.sound_theme, sound_type) {
+        (_, SoundType::Busy) => "resources/busy.wav".to_string(),
         (SoundTheme::Custom, SoundType::Start) => "custom_start.wav".to_string(),
         (SoundTheme::Custom, SoundType::Stop) => "custom_stop.wav".to_string(),
         (_, SoundType::Start) => settings.sound_theme.to_start_path(),
@@ -33,9 +36,15 @@ fn get_sound_path(settings: &AppSettings, sound_type: SoundType) -> String {
     }
 }
 
-fn get_sound_base_dir(settings: &AppSettings) -> tauri::path::BaseDirectory {
-    match settings.sound_theme {
-        SoundTheme::Custom => tauri::path::BaseDirectory::AppData,
+fn get_sound_base_dir(
+    settings: &AppSettings,
+    sound_type: &SoundType,
+) -> tauri::path::BaseDirectory {
+    // The busy sound is bundled, custom themes only replace start/stop
+    match (settings.sound_theme, sound_type) {
+        (SoundTheme::Custom, SoundType::Start | SoundType::Stop) => {
+            tauri::path::BaseDirectory::AppData
+        }
         _ => tauri::path::BaseDirectory::Resource,
     }
 }
@@ -60,6 +69,15 @@ pub fn play_feedback_sound_blocking(app: &AppHandle, sound_type: SoundType) {
     }
 }
 
+/// Play a sound even when start/stop feedback is disabled, for errors the
+/// user would otherwise not notice.
+pub fn play_alert_sound(app: &AppHandle, sound_type: SoundType) {
+    let settings = settings::get_settings(app);
+    if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
+        play_sound_async(app, path);
+    }
+}
+
 pub fn play_test_sound(app: &AppHandle, sound_type: SoundType) {
     let settings = settings::get_settings(app);
     if let Some(path) = resolve_sound_path(app, &settings, sound_type) {
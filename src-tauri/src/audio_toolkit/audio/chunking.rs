@@ -0,0 +1,87 @@
+use std::ops::Range;
+
+/// Length of the frames compared when looking for the quietest cut point.
+const FRAME_MS: usize = 20;
+
+/// Split audio into chunks of roughly `chunk_samples`, cutting at the quietest
+/// frame within `search_samples` of each boundary so words aren't split.
+///
+/// The returned ranges are contiguous and cover all of `samples`. A trailing
+/// piece shorter than half a chunk is merged into the previous chunk.
+pub fn split_at_silence(
+    samples: &[f32],
+    sample_rate: usize,
+    chunk_samples: usize,
+    search_samples: usize,
+) -> Vec<Range<usize>> {
+    let len = samples.len();
+    let frame = (sample_rate * FRAME_MS / 1000).max(1);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+
+    while chunk_samples > 0 && len - start > chunk_samples + chunk_samples / 2 {
+        let target = start + chunk_samples;
+        let lo = target.saturating_sub(search_samples).max(start + frame);
+        let hi = (target + search_samples).min(len - frame);
+        let cut = quietest_frame(samples, lo, hi, frame).unwrap_or(target);
+        ranges.push(start..cut);
+        start = cut;
+    }
+    ranges.push(start..len);
+    ranges
+}
+
+/// Start of the frame with the lowest energy in `lo..hi`, scanned in half-frame steps.
+fn quietest_frame(samples: &[f32], lo: usize, hi: usize, frame: usize) -> Option<usize> {
+    let step = (frame / 2).max(1);
+    (lo..hi)
+        .step_by(step)
+        .map(|pos| {
+            let energy: f32 = samples[pos..pos + frame].iter().map(|s| s * s).sum();
+            (pos, energy)
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(pos, _)| pos + frame / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: usize = 16000;
+
+    #[test]
+    fn short_audio_is_a_single_chunk() {
+        let samples = vec![0.5; RATE * 10];
+        let ranges = split_at_silence(&samples, RATE, RATE * 30, RATE * 5);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0], 0..samples.len());
+    }
+
+    #[test]
+    fn cuts_at_silence_near_boundary() {
+        // 70s of tone with a short pause at 27s
+        let mut samples = vec![0.5; RATE * 70];
+        let pause = RATE * 27;
+        samples[pause..pause + RATE / 4].fill(0.0);
+
+        let ranges = split_at_silence(&samples, RATE, RATE * 30, RATE * 5);
+        assert_eq!(ranges.len(), 2);
+        assert!(ranges[0].end > pause && ranges[0].end < pause + RATE / 4);
+        assert_eq!(ranges[0].end, ranges[1].start);
+        assert_eq!(ranges[1].end, samples.len());
+    }
+
+    #[test]
+    fn ranges_cover_everything_without_silence() {
+        let samples = vec![0.5; RATE * 100];
+        let ranges = split_at_silence(&samples, RATE, RATE * 30, RATE * 5);
+        assert_eq!(ranges.first().unwrap().start, 0);
+        assert_eq!(ranges.last().unwrap().end, samples.len());
+        for pair in ranges.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        // The short tail is merged instead of becoming its own chunk
+        assert!(ranges.iter().all(|r| r.len() >= RATE * 15));
+    }
+}
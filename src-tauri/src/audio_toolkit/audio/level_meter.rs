@@ -0,0 +1,140 @@
+use serde::Serialize;
+
+/// Peak level at or above which the input is reported as clipping.
+const CLIP_THRESHOLD: f32 = 0.99;
+/// Floor used when converting silence to decibels.
+const DB_FLOOR: f32 = -96.0;
+
+/// Loudness of one block of input audio.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq)]
+pub struct InputLevel {
+    /// Root-mean-square amplitude, 0.0 - 1.0
+    pub rms: f32,
+    /// Absolute peak amplitude, 0.0 - 1.0
+    pub peak: f32,
+    /// RMS in dBFS (`DB_FLOOR` for silence)
+    pub rms_db: f32,
+    pub clipping: bool,
+}
+
+impl InputLevel {
+    fn from_sums(sum_squares: f64, count: usize, peak: f32) -> Self {
+        let rms = if count == 0 {
+            0.0
+        } else {
+            (sum_squares / count as f64).sqrt() as f32
+        };
+        Self {
+            rms: rms.min(1.0),
+            peak: peak.min(1.0),
+            rms_db: to_db(rms),
+            clipping: peak >= CLIP_THRESHOLD,
+        }
+    }
+
+    /// Level of a whole buffer of mono samples.
+    pub fn measure(samples: &[f32]) -> Self {
+        let sum_squares = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+        let peak = samples.iter().fold(0.0f32, |peak, s| peak.max(s.abs()));
+        Self::from_sums(sum_squares, samples.len(), peak)
+    }
+}
+
+fn to_db(amplitude: f32) -> f32 {
+    if amplitude <= 0.0 {
+        DB_FLOOR
+    } else {
+        (20.0 * amplitude.log10()).max(DB_FLOOR)
+    }
+}
+
+/// Accumulates samples and reports RMS/peak once per fixed-size block.
+pub struct LevelMeter {
+    block_size: usize,
+    count: usize,
+    sum_squares: f64,
+    peak: f32,
+}
+
+impl LevelMeter {
+    /// Create a meter reporting every `block_ms` milliseconds of audio.
+    pub fn new(sample_rate: u32, block_ms: u32) -> Self {
+        let block_size = (sample_rate as usize * block_ms as usize / 1000).max(1);
+        Self {
+            block_size,
+            count: 0,
+            sum_squares: 0.0,
+            peak: 0.0,
+        }
+    }
+
+    /// Feed mono samples; returns the levels of every block completed by them.
+    pub fn feed(&mut self, samples: &[f32]) -> Vec<InputLevel> {
+        let mut levels = Vec::new();
+        for &sample in samples {
+            self.sum_squares += (sample as f64) * (sample as f64);
+            self.peak = self.peak.max(sample.abs());
+            self.count += 1;
+
+            if self.count == self.block_size {
+                levels.push(InputLevel::from_sums(
+                    self.sum_squares,
+                    self.count,
+                    self.peak,
+                ));
+                self.reset();
+            }
+        }
+        levels
+    }
+
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.sum_squares = 0.0;
+        self.peak = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_one_level_per_block() {
+        // 50ms blocks at 16kHz = 800 samples
+        let mut meter = LevelMeter::new(16000, 50);
+        assert!(meter.feed(&[0.1; 500]).is_empty());
+        assert_eq!(meter.feed(&[0.1; 500]).len(), 1);
+        assert_eq!(meter.feed(&[0.1; 1600]).len(), 2);
+    }
+
+    #[test]
+    fn test_rms_and_peak() {
+        let mut meter = LevelMeter::new(1000, 4);
+        let levels = meter.feed(&[0.5, -0.5, 0.5, -0.5]);
+        assert_eq!(levels.len(), 1);
+        assert!((levels[0].rms - 0.5).abs() < 1e-6);
+        assert_eq!(levels[0].peak, 0.5);
+        assert!((levels[0].rms_db - -6.0206).abs() < 1e-3);
+        assert!(!levels[0].clipping);
+
+        let levels = meter.feed(&[0.0, 1.0, 0.0, 0.0]);
+        assert!(levels[0].clipping);
+    }
+
+    #[test]
+    fn test_silence_uses_db_floor() {
+        let mut meter = LevelMeter::new(1000, 2);
+        let levels = meter.feed(&[0.0, 0.0]);
+        assert_eq!(levels[0].rms_db, DB_FLOOR);
+    }
+
+    #[test]
+    fn test_measure_whole_buffer() {
+        let level = InputLevel::measure(&[0.5, -0.5, 0.5, -0.5]);
+        assert!((level.rms - 0.5).abs() < 1e-6);
+        assert_eq!(level.peak, 0.5);
+
+        assert_eq!(InputLevel::measure(&[]).rms_db, DB_FLOOR);
+    }
+}
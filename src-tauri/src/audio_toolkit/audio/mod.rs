@@ -1,12 +1,16 @@
 // Re-export all audio components
+mod chunking;
 mod device;
+mod level_meter;
 mod recorder;
 mod resampler;
 mod utils;
 mod visualizer;
 
+pub use chunking::split_at_silence;
 pub use device::{list_input_devices, list_output_devices, CpalDeviceInfo};
-pub use recorder::AudioRecorder;
+pub use level_meter::{InputLevel, LevelMeter};
+pub use recorder::{AudioRecorder, CapturedAudio};
 pub use resampler::FrameResampler;
-pub use utils::save_wav_file;
+pub use utils::{apply_gain, save_wav_file};
 pub use visualizer::AudioVisualiser;
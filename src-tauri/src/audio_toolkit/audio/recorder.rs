@@ -10,7 +10,7 @@ use cpal::{
 };
 
 use crate::audio_toolkit::{
-    audio::{AudioVisualiser, FrameResampler},
+    audio::{AudioVisualiser, FrameResampler, InputLevel, LevelMeter},
     constants,
     vad::{self, VadFrame},
     VoiceActivityDetector,
@@ -18,9 +18,15 @@ use crate::audio_toolkit::{
 
 enum Cmd {
     Start,
-    Stop(mpsc::Sender<Vec<f32>>),
+    /// Replies with the VAD-filtered and the raw samples.
+    Stop(mpsc::Sender<(Vec<f32>, Vec<f32>)>),
     Peek(mpsc::Sender<Vec<f32>>),
     PeekFrom(usize, mpsc::Sender<Vec<f32>>),
+    TakeSegment {
+        min_silence: usize,
+        max_len: usize,
+        reply: mpsc::Sender<Vec<f32>>,
+    },
     Shutdown,
 }
 
@@ -30,8 +36,21 @@ pub struct AudioRecorder {
     worker_handle: Option<std::thread::JoinHandle<()>>,
     vad: Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    input_level_cb: Option<Arc<dyn Fn(InputLevel) + Send + Sync + 'static>>,
+    samples_cb: Option<Arc<dyn Fn(usize) + Send + Sync + 'static>>,
 }
 
+/// Audio captured by [`AudioRecorder::capture_for`].
+pub struct CapturedAudio {
+    pub sample_rate: u32,
+    pub channels: u16,
+    /// Mono samples at the device sample rate
+    pub samples: Vec<f32>,
+}
+
+/// Block length for input-level (RMS/peak) reports.
+const INPUT_LEVEL_BLOCK_MS: u32 = 50;
+
 impl AudioRecorder {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         Ok(AudioRecorder {
@@ -40,6 +59,8 @@ impl AudioRecorder {
             worker_handle: None,
             vad: None,
             level_cb: None,
+            input_level_cb: None,
+            samples_cb: None,
         })
     }
 
@@ -56,6 +77,25 @@ impl AudioRecorder {
         self
     }
 
+    /// Receive RMS/peak levels for every ~50ms block while recording.
+    pub fn with_input_level_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(InputLevel) + Send + Sync + 'static,
+    {
+        self.input_level_cb = Some(Arc::new(cb));
+        self
+    }
+
+    /// Receive the number of raw (pre-VAD) samples recorded so far whenever
+    /// it grows, so readers of [`peek_from`](Self::peek_from) don't have to poll.
+    pub fn with_samples_callback<F>(mut self, cb: F) -> Self
+    where
+        F: Fn(usize) + Send + Sync + 'static,
+    {
+        self.samples_cb = Some(Arc::new(cb));
+        self
+    }
+
     pub fn open(&mut self, device: Option<Device>) -> Result<(), Box<dyn std::error::Error>> {
         if self.worker_handle.is_some() {
             return Ok(()); // already open
@@ -76,6 +116,8 @@ impl AudioRecorder {
         let vad = self.vad.clone();
         // Move the optional level callback into the worker thread
         let level_cb = self.level_cb.clone();
+        let input_level_cb = self.input_level_cb.clone();
+        let samples_cb = self.samples_cb.clone();
 
         let worker = std::thread::spawn(move || {
             let config = AudioRecorder::get_preferred_config(&thread_device)
@@ -84,7 +126,7 @@ impl AudioRecorder {
             let sample_rate = config.sample_rate().0;
             let channels = config.channels() as usize;
 
-            log::info!(
+            tracing::info!(
                 "Using device: {:?}\nSample rate: {}\nChannels: {}\nFormat: {:?}",
                 thread_device.name(),
                 sample_rate,
@@ -119,7 +161,15 @@ impl AudioRecorder {
             stream.play().expect("failed to start stream");
 
             // keep the stream alive while we process samples
-            run_consumer(sample_rate, vad, sample_rx, cmd_rx, level_cb);
+            run_consumer(
+                sample_rate,
+                vad,
+                sample_rx,
+                cmd_rx,
+                level_cb,
+                input_level_cb,
+                samples_cb,
+            );
             // stream is dropped here, after run_consumer returns
         });
 
@@ -130,6 +180,47 @@ impl AudioRecorder {
         Ok(())
     }
 
+    /// Open `device` for `duration` outside of the recording worker and return
+    /// whatever it delivered. Blocks the calling thread.
+    pub fn capture_for(
+        device: &Device,
+        duration: Duration,
+    ) -> Result<CapturedAudio, Box<dyn std::error::Error>> {
+        let config = AudioRecorder::get_preferred_config(device)?;
+        let sample_rate = config.sample_rate().0;
+        let channels = config.channels();
+        let (sample_tx, sample_rx) = mpsc::channel::<Vec<f32>>();
+
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::U8 => {
+                AudioRecorder::build_stream::<u8>(device, &config, sample_tx, channels as usize)?
+            }
+            cpal::SampleFormat::I8 => {
+                AudioRecorder::build_stream::<i8>(device, &config, sample_tx, channels as usize)?
+            }
+            cpal::SampleFormat::I16 => {
+                AudioRecorder::build_stream::<i16>(device, &config, sample_tx, channels as usize)?
+            }
+            cpal::SampleFormat::I32 => {
+                AudioRecorder::build_stream::<i32>(device, &config, sample_tx, channels as usize)?
+            }
+            cpal::SampleFormat::F32 => {
+                AudioRecorder::build_stream::<f32>(device, &config, sample_tx, channels as usize)?
+            }
+            format => return Err(format!("unsupported sample format {:?}", format).into()),
+        };
+
+        stream.play()?;
+        std::thread::sleep(duration);
+        drop(stream);
+
+        Ok(CapturedAudio {
+            sample_rate,
+            channels,
+            samples: sample_rx.try_iter().flatten().collect(),
+        })
+    }
+
     pub fn start(&self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(tx) = &self.cmd_tx {
             tx.send(Cmd::Start)?;
@@ -138,6 +229,12 @@ impl AudioRecorder {
     }
 
     pub fn stop(&self) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        Ok(self.stop_with_raw()?.0)
+    }
+
+    /// Stop and return the VAD-filtered samples along with the raw ones,
+    /// which offsets taken from streaming peeks index into.
+    pub fn stop_with_raw(&self) -> Result<(Vec<f32>, Vec<f32>), Box<dyn std::error::Error>> {
         let (resp_tx, resp_rx) = mpsc::channel();
         if let Some(tx) = &self.cmd_tx {
             tx.send(Cmd::Stop(resp_tx))?;
@@ -161,6 +258,27 @@ impl AudioRecorder {
         Ok(resp_rx.recv()?)
     }
 
+    /// Take the speech recorded so far, without stopping, once it is
+    /// followed by `min_silence` samples of non-speech or has reached
+    /// `max_len` samples. Returns an empty buffer otherwise. Lets a long
+    /// recording be transcribed piece by piece at natural pauses while
+    /// keeping the buffers small.
+    pub fn take_segment(
+        &self,
+        min_silence: usize,
+        max_len: usize,
+    ) -> Result<Vec<f32>, Box<dyn std::error::Error>> {
+        let (resp_tx, resp_rx) = mpsc::channel();
+        if let Some(tx) = &self.cmd_tx {
+            tx.send(Cmd::TakeSegment {
+                min_silence,
+                max_len,
+                reply: resp_tx,
+            })?;
+        }
+        Ok(resp_rx.recv()?)
+    }
+
     pub fn close(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(tx) = self.cmd_tx.take() {
             let _ = tx.send(Cmd::Shutdown);
@@ -206,14 +324,14 @@ impl AudioRecorder {
             }
 
             if sample_tx.send(output_buffer.clone()).is_err() {
-                log::error!("Failed to send samples");
+                tracing::error!("Failed to send samples");
             }
         };
 
         device.build_input_stream(
             &config.clone().into(),
             stream_cb,
-            |err| log::error!("Stream error: {}", err),
+            |err| tracing::error!("Stream error: {}", err),
             None,
         )
     }
@@ -263,6 +381,8 @@ fn run_consumer(
     sample_rx: mpsc::Receiver<Vec<f32>>,
     cmd_rx: mpsc::Receiver<Cmd>,
     level_cb: Option<Arc<dyn Fn(Vec<f32>) + Send + Sync + 'static>>,
+    input_level_cb: Option<Arc<dyn Fn(InputLevel) + Send + Sync + 'static>>,
+    samples_cb: Option<Arc<dyn Fn(usize) + Send + Sync + 'static>>,
 ) {
     let mut frame_resampler = FrameResampler::new(
         in_sample_rate as usize,
@@ -273,6 +393,10 @@ fn run_consumer(
     let mut processed_samples = Vec::<f32>::new();
     // Raw samples (pre-VAD) for streaming peek — always accumulates during recording
     let mut raw_samples = Vec::<f32>::new();
+    // Length of `raw_samples` last passed to the samples callback
+    let mut reported_samples = 0;
+    // Non-speech samples since the last speech frame
+    let mut trailing_silence = 0;
     let mut recording = false;
 
     // ---------- spectrum visualisation setup ---------------------------- //
@@ -286,24 +410,31 @@ fn run_consumer(
         4000.0, // vocal_max_hz
     );
 
+    let mut level_meter = LevelMeter::new(in_sample_rate, INPUT_LEVEL_BLOCK_MS);
+
+    /// Returns whether the frame was kept as speech.
     fn handle_frame(
         samples: &[f32],
         recording: bool,
         vad: &Option<Arc<Mutex<Box<dyn vad::VoiceActivityDetector>>>>,
         out_buf: &mut Vec<f32>,
-    ) {
+    ) -> bool {
         if !recording {
-            return;
+            return false;
         }
 
         if let Some(vad_arc) = vad {
             let mut det = vad_arc.lock().unwrap();
             match det.push_frame(samples).unwrap_or(VadFrame::Speech(samples)) {
-                VadFrame::Speech(buf) => out_buf.extend_from_slice(buf),
-                VadFrame::Noise => {}
+                VadFrame::Speech(buf) => {
+                    out_buf.extend_from_slice(buf);
+                    true
+                }
+                VadFrame::Noise => false,
             }
         } else {
             out_buf.extend_from_slice(samples);
+            true
         }
     }
 
@@ -320,23 +451,47 @@ fn run_consumer(
             }
         }
 
+        // ---------- input level metering (recording only) ----------------- //
+        if recording {
+            if let Some(cb) = &input_level_cb {
+                for level in level_meter.feed(&raw) {
+                    cb(level);
+                }
+            }
+        }
+
         // ---------- existing pipeline ------------------------------------ //
         frame_resampler.push(&raw, &mut |frame: &[f32]| {
             // Always accumulate raw (pre-VAD) samples for streaming peek
             if recording {
                 raw_samples.extend_from_slice(frame);
             }
-            handle_frame(frame, recording, &vad, &mut processed_samples)
+            if handle_frame(frame, recording, &vad, &mut processed_samples) {
+                trailing_silence = 0;
+            } else if recording {
+                trailing_silence += frame.len();
+            }
         });
 
+        // ---------- new-sample notification (recording only) ------------- //
+        if recording && raw_samples.len() > reported_samples {
+            reported_samples = raw_samples.len();
+            if let Some(cb) = &samples_cb {
+                cb(reported_samples);
+            }
+        }
+
         // non-blocking check for a command
         while let Ok(cmd) = cmd_rx.try_recv() {
             match cmd {
                 Cmd::Start => {
                     processed_samples.clear();
                     raw_samples.clear();
+                    reported_samples = 0;
+                    trailing_silence = 0;
                     recording = true;
                     visualizer.reset(); // Reset visualization buffer
+                    level_meter.reset();
                     if let Some(v) = &vad {
                         v.lock().unwrap().reset();
                     }
@@ -346,11 +501,13 @@ fn run_consumer(
 
                     frame_resampler.finish(&mut |frame: &[f32]| {
                         // we still want to process the last few frames
-                        handle_frame(frame, true, &vad, &mut processed_samples)
+                        handle_frame(frame, true, &vad, &mut processed_samples);
                     });
 
-                    raw_samples.clear();
-                    let _ = reply_tx.send(std::mem::take(&mut processed_samples));
+                    let _ = reply_tx.send((
+                        std::mem::take(&mut processed_samples),
+                        std::mem::take(&mut raw_samples),
+                    ));
                 }
                 Cmd::Peek(reply_tx) => {
                     // Return raw (pre-VAD) samples for streaming — always grows
@@ -361,6 +518,26 @@ fn run_consumer(
                     let start = offset.min(raw_samples.len());
                     let _ = reply_tx.send(raw_samples[start..].to_vec());
                 }
+                Cmd::TakeSegment {
+                    min_silence,
+                    max_len,
+                    reply,
+                } => {
+                    let ready = !processed_samples.is_empty()
+                        && (trailing_silence >= min_silence || processed_samples.len() >= max_len);
+                    // Raw samples are only kept for streaming peeks; nothing
+                    // peeks at a recording that is taken in segments
+                    if ready || processed_samples.is_empty() {
+                        raw_samples.clear();
+                        reported_samples = 0;
+                    }
+                    let segment = if ready {
+                        std::mem::take(&mut processed_samples)
+                    } else {
+                        Vec::new()
+                    };
+                    let _ = reply.send(segment);
+                }
                 Cmd::Shutdown => return,
             }
         }
@@ -1,7 +1,18 @@
 use anyhow::Result;
 use hound::{WavSpec, WavWriter};
-use log::debug;
 use std::path::Path;
+use tracing::debug;
+
+/// Scale samples by a gain in dB, clamping to the valid [-1, 1] range.
+pub fn apply_gain(samples: &mut [f32], gain_db: f32) {
+    if gain_db == 0.0 {
+        return;
+    }
+    let factor = 10f32.powf(gain_db / 20.0);
+    for s in samples.iter_mut() {
+        *s = (*s * factor).clamp(-1.0, 1.0);
+    }
+}
 
 /// Save audio samples as a WAV file
 pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Result<()> {
@@ -24,3 +35,22 @@ pub async fn save_wav_file<P: AsRef<Path>>(file_path: P, samples: &[f32]) -> Res
     debug!("Saved WAV file: {:?}", file_path.as_ref());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_gain() {
+        let mut samples = [0.1, -0.2, 0.6];
+        apply_gain(&mut samples, 6.0);
+        assert!((samples[0] - 0.1995).abs() < 1e-3);
+        assert!((samples[1] + 0.399).abs() < 1e-3);
+        // Clamped instead of wrapping past full scale
+        assert_eq!(samples[2], 1.0);
+
+        let mut unchanged = [0.5];
+        apply_gain(&mut unchanged, 0.0);
+        assert_eq!(unchanged, [0.5]);
+    }
+}
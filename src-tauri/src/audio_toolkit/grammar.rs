@@ -0,0 +1,271 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// Common English misspellings and apostrophe-less contractions produced by
+/// speech recognition, mapped to their corrected form (lowercase).
+const ENGLISH_CORRECTIONS: &[(&str, &str)] = &[
+    ("teh", "the"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("seperate", "separate"),
+    ("definately", "definitely"),
+    ("occured", "occurred"),
+    ("untill", "until"),
+    ("wich", "which"),
+    ("becuase", "because"),
+    ("alot", "a lot"),
+    ("tommorow", "tomorrow"),
+    ("goverment", "government"),
+    ("enviroment", "environment"),
+    ("dont", "don't"),
+    ("doesnt", "doesn't"),
+    ("didnt", "didn't"),
+    ("isnt", "isn't"),
+    ("arent", "aren't"),
+    ("wasnt", "wasn't"),
+    ("werent", "weren't"),
+    ("couldnt", "couldn't"),
+    ("shouldnt", "shouldn't"),
+    ("wouldnt", "wouldn't"),
+    ("havent", "haven't"),
+    ("hasnt", "hasn't"),
+    ("im", "I'm"),
+    ("ive", "I've"),
+    ("youre", "you're"),
+    ("theyre", "they're"),
+    ("thats", "that's"),
+];
+
+/// Words that are legitimately doubled in English ("I had had enough").
+const ALLOWED_REPEATS: &[&str] = &["had", "that", "is", "very", "so"];
+
+static CORRECTIONS: Lazy<HashMap<&'static str, &'static str>> =
+    Lazy::new(|| ENGLISH_CORRECTIONS.iter().copied().collect());
+
+static SPACE_BEFORE_PUNCT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+([,.!?;:])").unwrap());
+static MISSING_SPACE_AFTER_PUNCT: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"([,!?;:])(\p{L})").unwrap());
+static MULTI_SPACE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+
+/// English-only rules need a known English language; "auto" may be anything.
+fn is_english(language: &str) -> bool {
+    language == "en"
+}
+
+/// Uppercase the first character of `word`, leaving the rest untouched.
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// Fix spelling, drop accidental word repeats and normalize English-only casing.
+fn correct_words(text: &str, english: bool) -> String {
+    let mut result: Vec<String> = Vec::new();
+    let mut prev_key = String::new();
+
+    for word in text.split_whitespace() {
+        let key: String = word
+            .trim_matches(|c: char| !c.is_alphanumeric() && c != '\'')
+            .to_lowercase();
+
+        // "the the" -> "the" (only when the first copy has no trailing punctuation)
+        if !key.is_empty()
+            && key == prev_key
+            && !ALLOWED_REPEATS.contains(&key.as_str())
+            && result
+                .last()
+                .is_some_and(|last| last.chars().last().is_some_and(|c| c.is_alphanumeric()))
+        {
+            continue;
+        }
+        prev_key = key.clone();
+
+        if !english {
+            result.push(word.to_string());
+            continue;
+        }
+
+        let start = word.find(|c: char| c.is_alphanumeric()).unwrap_or(0);
+        let end = word
+            .rfind(|c: char| c.is_alphanumeric())
+            .map(|i| i + word[i..].chars().next().map_or(1, |c| c.len_utf8()))
+            .unwrap_or(word.len());
+        let (prefix, core, suffix) = (&word[..start], &word[start..end], &word[end..]);
+
+        let corrected = if core == "i" {
+            "I".to_string()
+        } else if let Some(fixed) = CORRECTIONS.get(core.to_lowercase().as_str()) {
+            if core.chars().next().is_some_and(|c| c.is_uppercase()) {
+                capitalize(fixed)
+            } else {
+                fixed.to_string()
+            }
+        } else {
+            core.to_string()
+        };
+
+        result.push(format!("{}{}{}", prefix, corrected, suffix));
+    }
+
+    result.join(" ")
+}
+
+/// Split run-on sentences like "okay.Then" into "okay. Then".
+///
+/// Only a single dot between a lowercase letter and an uppercase one is
+/// treated as a sentence break, so abbreviations ("e.g.", "U.S.A."),
+/// domains and version numbers are left alone.
+fn split_run_on_sentences(text: &str) -> String {
+    text.split(' ')
+        .map(|token| {
+            let core = token.trim_end_matches(['.', '!', '?']);
+            let mut dots = core.match_indices('.');
+            let (Some((pos, _)), None) = (dots.next(), dots.next()) else {
+                return token.to_string();
+            };
+            let (before, after) = (&core[..pos], &core[pos + 1..]);
+            if before.chars().last().is_some_and(|c| c.is_lowercase())
+                && after.chars().next().is_some_and(|c| c.is_uppercase())
+            {
+                format!("{}. {}", before, &token[pos + 1..])
+            } else {
+                token.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Capitalize the first letter of the text and of every sentence.
+fn capitalize_sentences(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut at_sentence_start = true;
+
+    for c in text.chars() {
+        if at_sentence_start && c.is_alphabetic() {
+            result.extend(c.to_uppercase());
+            at_sentence_start = false;
+        } else {
+            result.push(c);
+            if matches!(c, '.' | '!' | '?') {
+                at_sentence_start = true;
+            } else if !c.is_whitespace() && !matches!(c, '"' | '\'' | '(' | '«') {
+                at_sentence_start = false;
+            }
+        }
+    }
+
+    result
+}
+
+/// Offline grammar and spelling cleanup used when no LLM is configured.
+///
+/// This is a heuristic pass, not a full grammar checker:
+/// 1. Normalizes whitespace and spacing around punctuation
+/// 2. Removes accidental repeated words ("the the")
+/// 3. Fixes common English misspellings and missing apostrophes
+/// 4. Capitalizes sentences (and "I" in English)
+/// 5. Adds a final period when the text has no terminal punctuation
+///
+/// # Arguments
+/// * `text` - The transcription to clean up
+/// * `language` - The selected transcription language code (e.g. "en", "ru", "auto")
+pub fn apply_local_grammar(text: &str, language: &str) -> String {
+    let text = MULTI_SPACE.replace_all(text.trim(), " ");
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let text = SPACE_BEFORE_PUNCT.replace_all(&text, "$1");
+    let text = correct_words(&text, is_english(language));
+    let text = MISSING_SPACE_AFTER_PUNCT.replace_all(&text, "$1 $2");
+    let text = split_run_on_sentences(&text);
+    let mut text = capitalize_sentences(&text);
+
+    if text.chars().last().is_some_and(|c| c.is_alphanumeric()) {
+        text.push('.');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capitalizes_and_terminates_sentences() {
+        assert_eq!(
+            apply_local_grammar("hello there. how are you", "en"),
+            "Hello there. How are you."
+        );
+    }
+
+    #[test]
+    fn test_fixes_spacing_around_punctuation() {
+        assert_eq!(
+            apply_local_grammar("well , okay.Then  we go !", "en"),
+            "Well, okay. Then we go!"
+        );
+    }
+
+    #[test]
+    fn test_keeps_abbreviations_domains_and_versions() {
+        assert_eq!(
+            apply_local_grammar("see e.g. the docs on example.com", "en"),
+            "See e.g. the docs on example.com."
+        );
+        assert_eq!(
+            apply_local_grammar("the U.S.A. and version 1.2.3", "en"),
+            "The U.S.A. and version 1.2.3."
+        );
+        assert_eq!(
+            apply_local_grammar("open file.txt now", "en"),
+            "Open file.txt now."
+        );
+    }
+
+    #[test]
+    fn test_fixes_common_misspellings_and_contractions() {
+        assert_eq!(
+            apply_local_grammar("i dont think teh plan works", "en"),
+            "I don't think the plan works."
+        );
+    }
+
+    #[test]
+    fn test_removes_accidental_repeats_but_keeps_allowed_ones() {
+        assert_eq!(
+            apply_local_grammar("open the the file", "en"),
+            "Open the file."
+        );
+        assert_eq!(
+            apply_local_grammar("i had had enough", "en"),
+            "I had had enough."
+        );
+    }
+
+    #[test]
+    fn test_non_english_skips_english_corrections() {
+        assert_eq!(
+            apply_local_grammar("привет мир. как дела", "ru"),
+            "Привет мир. Как дела."
+        );
+        assert_eq!(apply_local_grammar("im test", "de"), "Im test.");
+    }
+
+    #[test]
+    fn test_auto_language_skips_english_corrections() {
+        assert_eq!(apply_local_grammar("teh plan", "auto"), "Teh plan.");
+        assert_eq!(apply_local_grammar("im test", "auto"), "Im test.");
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert_eq!(apply_local_grammar("   ", "en"), "");
+    }
+}
@@ -1,5 +1,7 @@
 pub mod audio;
 pub mod constants;
+pub mod grammar;
+pub mod numbers;
 pub mod text;
 pub mod utils;
 pub mod vad;
@@ -7,6 +9,8 @@ pub mod vad;
 pub use audio::{
     list_input_devices, list_output_devices, save_wav_file, AudioRecorder, CpalDeviceInfo,
 };
-pub use text::{apply_custom_words, filter_transcription_output};
+pub use grammar::apply_local_grammar;
+pub use numbers::normalize_numbers;
+pub use text::{apply_custom_words, filter_transcription_output, stitch_transcripts};
 pub use utils::get_cpal_host;
 pub use vad::{SileroVad, VoiceActivityDetector};
@@ -0,0 +1,766 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lang {
+    En,
+    Ru,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// 0-9
+    Unit,
+    /// 10-19
+    Teen,
+    /// 20, 30, ... 90
+    Tens,
+    /// English "hundred" (multiplies the preceding units)
+    HundredMul,
+    /// Russian "сто", "двести", ... (additive hundreds)
+    Hundreds,
+    /// thousand, million, billion
+    Scale,
+}
+
+type WordTable = &'static [(&'static str, u64, Kind)];
+
+#[derive(Debug, Clone, Copy)]
+struct NumberWord {
+    value: u64,
+    kind: Kind,
+    lang: Lang,
+    ordinal: bool,
+}
+
+const EN_WORDS: WordTable = &[
+    ("zero", 0, Kind::Unit),
+    ("one", 1, Kind::Unit),
+    ("two", 2, Kind::Unit),
+    ("three", 3, Kind::Unit),
+    ("four", 4, Kind::Unit),
+    ("five", 5, Kind::Unit),
+    ("six", 6, Kind::Unit),
+    ("seven", 7, Kind::Unit),
+    ("eight", 8, Kind::Unit),
+    ("nine", 9, Kind::Unit),
+    ("ten", 10, Kind::Teen),
+    ("eleven", 11, Kind::Teen),
+    ("twelve", 12, Kind::Teen),
+    ("thirteen", 13, Kind::Teen),
+    ("fourteen", 14, Kind::Teen),
+    ("fifteen", 15, Kind::Teen),
+    ("sixteen", 16, Kind::Teen),
+    ("seventeen", 17, Kind::Teen),
+    ("eighteen", 18, Kind::Teen),
+    ("nineteen", 19, Kind::Teen),
+    ("twenty", 20, Kind::Tens),
+    ("thirty", 30, Kind::Tens),
+    ("forty", 40, Kind::Tens),
+    ("fifty", 50, Kind::Tens),
+    ("sixty", 60, Kind::Tens),
+    ("seventy", 70, Kind::Tens),
+    ("eighty", 80, Kind::Tens),
+    ("ninety", 90, Kind::Tens),
+    ("hundred", 100, Kind::HundredMul),
+    ("thousand", 1_000, Kind::Scale),
+    ("million", 1_000_000, Kind::Scale),
+    ("billion", 1_000_000_000, Kind::Scale),
+];
+
+const EN_ORDINALS: WordTable = &[
+    ("first", 1, Kind::Unit),
+    ("second", 2, Kind::Unit),
+    ("third", 3, Kind::Unit),
+    ("fourth", 4, Kind::Unit),
+    ("fifth", 5, Kind::Unit),
+    ("sixth", 6, Kind::Unit),
+    ("seventh", 7, Kind::Unit),
+    ("eighth", 8, Kind::Unit),
+    ("ninth", 9, Kind::Unit),
+    ("tenth", 10, Kind::Teen),
+    ("eleventh", 11, Kind::Teen),
+    ("twelfth", 12, Kind::Teen),
+    ("thirteenth", 13, Kind::Teen),
+    ("fourteenth", 14, Kind::Teen),
+    ("fifteenth", 15, Kind::Teen),
+    ("sixteenth", 16, Kind::Teen),
+    ("seventeenth", 17, Kind::Teen),
+    ("eighteenth", 18, Kind::Teen),
+    ("nineteenth", 19, Kind::Teen),
+    ("twentieth", 20, Kind::Tens),
+    ("thirtieth", 30, Kind::Tens),
+    ("fortieth", 40, Kind::Tens),
+    ("fiftieth", 50, Kind::Tens),
+    ("sixtieth", 60, Kind::Tens),
+    ("seventieth", 70, Kind::Tens),
+    ("eightieth", 80, Kind::Tens),
+    ("ninetieth", 90, Kind::Tens),
+    ("hundredth", 100, Kind::HundredMul),
+    ("thousandth", 1_000, Kind::Scale),
+];
+
+const RU_WORDS: WordTable = &[
+    ("ноль", 0, Kind::Unit),
+    ("нуль", 0, Kind::Unit),
+    ("один", 1, Kind::Unit),
+    ("одна", 1, Kind::Unit),
+    ("одно", 1, Kind::Unit),
+    ("одну", 1, Kind::Unit),
+    ("два", 2, Kind::Unit),
+    ("две", 2, Kind::Unit),
+    ("три", 3, Kind::Unit),
+    ("четыре", 4, Kind::Unit),
+    ("пять", 5, Kind::Unit),
+    ("шесть", 6, Kind::Unit),
+    ("семь", 7, Kind::Unit),
+    ("восемь", 8, Kind::Unit),
+    ("девять", 9, Kind::Unit),
+    ("десять", 10, Kind::Teen),
+    ("одиннадцать", 11, Kind::Teen),
+    ("двенадцать", 12, Kind::Teen),
+    ("тринадцать", 13, Kind::Teen),
+    ("четырнадцать", 14, Kind::Teen),
+    ("пятнадцать", 15, Kind::Teen),
+    ("шестнадцать", 16, Kind::Teen),
+    ("семнадцать", 17, Kind::Teen),
+    ("восемнадцать", 18, Kind::Teen),
+    ("девятнадцать", 19, Kind::Teen),
+    ("двадцать", 20, Kind::Tens),
+    ("тридцать", 30, Kind::Tens),
+    ("сорок", 40, Kind::Tens),
+    ("пятьдесят", 50, Kind::Tens),
+    ("шестьдесят", 60, Kind::Tens),
+    ("семьдесят", 70, Kind::Tens),
+    ("восемьдесят", 80, Kind::Tens),
+    ("девяносто", 90, Kind::Tens),
+    ("сто", 100, Kind::Hundreds),
+    ("двести", 200, Kind::Hundreds),
+    ("триста", 300, Kind::Hundreds),
+    ("четыреста", 400, Kind::Hundreds),
+    ("пятьсот", 500, Kind::Hundreds),
+    ("шестьсот", 600, Kind::Hundreds),
+    ("семьсот", 700, Kind::Hundreds),
+    ("восемьсот", 800, Kind::Hundreds),
+    ("девятьсот", 900, Kind::Hundreds),
+    ("тысяча", 1_000, Kind::Scale),
+    ("тысячи", 1_000, Kind::Scale),
+    ("тысяч", 1_000, Kind::Scale),
+    ("тысячу", 1_000, Kind::Scale),
+    ("миллион", 1_000_000, Kind::Scale),
+    ("миллиона", 1_000_000, Kind::Scale),
+    ("миллионов", 1_000_000, Kind::Scale),
+    ("миллиард", 1_000_000_000, Kind::Scale),
+    ("миллиарда", 1_000_000_000, Kind::Scale),
+    ("миллиардов", 1_000_000_000, Kind::Scale),
+];
+
+/// Words after which even a single small number is converted ("line five" -> "line 5").
+const CONTEXT_WORDS: &[&str] = &[
+    "line",
+    "page",
+    "number",
+    "step",
+    "chapter",
+    "item",
+    "option",
+    "slide",
+    "row",
+    "column",
+    "version",
+    "tab",
+    "room",
+    "строка",
+    "строку",
+    "строке",
+    "номер",
+    "страница",
+    "страницу",
+    "шаг",
+    "пункт",
+    "глава",
+    "главу",
+    "вкладка",
+    "вкладку",
+];
+
+const EN_MONTHS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+static NUMBER_WORDS: Lazy<HashMap<&'static str, NumberWord>> = Lazy::new(|| {
+    let mut map = HashMap::new();
+    let tables: [(WordTable, Lang, bool); 3] = [
+        (EN_WORDS, Lang::En, false),
+        (EN_ORDINALS, Lang::En, true),
+        (RU_WORDS, Lang::Ru, false),
+    ];
+    for (table, lang, ordinal) in tables {
+        for &(word, value, kind) in table {
+            map.insert(
+                word,
+                NumberWord {
+                    value,
+                    kind,
+                    lang,
+                    ordinal,
+                },
+            );
+        }
+    }
+    map
+});
+
+/// A whitespace-separated word split into leading punctuation, a lowercase core
+/// and trailing punctuation, so numbers can be replaced without losing "(", "," etc.
+#[derive(Debug, Clone)]
+struct Token {
+    raw: String,
+    prefix: String,
+    core: String,
+    suffix: String,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    for raw in text.split_whitespace() {
+        let start = raw.find(|c: char| c.is_alphanumeric()).unwrap_or(raw.len());
+        let end = raw
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_alphanumeric())
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(start);
+        let (prefix, core, suffix) = (&raw[..start], &raw[start..end], &raw[end..]);
+        let core = core.to_lowercase();
+
+        // Split hyphenated numbers ("twenty-three") into separate number tokens
+        let parts: Vec<&str> = core.split('-').collect();
+        if parts.len() > 1 && parts.iter().all(|p| NUMBER_WORDS.contains_key(p)) {
+            let last = parts.len() - 1;
+            for (i, part) in parts.iter().enumerate() {
+                tokens.push(Token {
+                    raw: raw.to_string(),
+                    prefix: if i == 0 {
+                        prefix.to_string()
+                    } else {
+                        String::new()
+                    },
+                    core: part.to_string(),
+                    suffix: if i == last {
+                        suffix.to_string()
+                    } else {
+                        String::new()
+                    },
+                });
+            }
+            continue;
+        }
+
+        tokens.push(Token {
+            raw: raw.to_string(),
+            prefix: prefix.to_string(),
+            core,
+            suffix: suffix.to_string(),
+        });
+    }
+    tokens
+}
+
+fn lookup(token: &Token, langs: &[Lang]) -> Option<NumberWord> {
+    NUMBER_WORDS
+        .get(token.core.as_str())
+        .copied()
+        .filter(|w| langs.contains(&w.lang))
+}
+
+#[derive(Debug)]
+struct ParsedNumber {
+    value: u64,
+    /// Number of tokens consumed
+    len: usize,
+    /// Number of number words (excluding "and"/"a")
+    words: usize,
+    ordinal: bool,
+    decimal: Option<String>,
+    lang: Lang,
+}
+
+/// Parse the longest number phrase starting at `tokens[start]`.
+fn parse_number(tokens: &[Token], start: usize, langs: &[Lang]) -> Option<ParsedNumber> {
+    let mut total: u64 = 0;
+    let mut current: u64 = 0;
+    let mut last: Option<Kind> = None;
+    let mut last_scale = u64::MAX;
+    let mut i = start;
+    let mut words = 0;
+    let mut ordinal = false;
+    let mut lang = None;
+
+    while i < tokens.len() {
+        let token = &tokens[i];
+
+        // "a hundred", "a thousand"
+        if last.is_none() && token.core == "a" && langs.contains(&Lang::En) {
+            let next_is_multiplier = tokens
+                .get(i + 1)
+                .and_then(|t| lookup(t, langs))
+                .is_some_and(|w| matches!(w.kind, Kind::HundredMul | Kind::Scale) && !w.ordinal);
+            if next_is_multiplier && token.suffix.is_empty() {
+                current = 1;
+                last = Some(Kind::Unit);
+                lang = Some(Lang::En);
+                i += 1;
+                continue;
+            }
+            break;
+        }
+
+        // "one hundred and five"
+        if token.core == "and" && matches!(last, Some(Kind::HundredMul) | Some(Kind::Scale)) {
+            let next_is_small = tokens
+                .get(i + 1)
+                .and_then(|t| lookup(t, langs))
+                .is_some_and(|w| matches!(w.kind, Kind::Unit | Kind::Teen | Kind::Tens));
+            if next_is_small && tokens[i - 1].suffix.is_empty() {
+                i += 1;
+                continue;
+            }
+            break;
+        }
+
+        let Some(word) = lookup(token, langs) else {
+            break;
+        };
+        if lang.is_some_and(|l| l != word.lang) {
+            break;
+        }
+
+        let accepted = match word.kind {
+            Kind::Unit => matches!(
+                last,
+                None | Some(Kind::Tens)
+                    | Some(Kind::HundredMul)
+                    | Some(Kind::Hundreds)
+                    | Some(Kind::Scale)
+            ),
+            Kind::Teen | Kind::Tens => matches!(
+                last,
+                None | Some(Kind::HundredMul) | Some(Kind::Hundreds) | Some(Kind::Scale)
+            ),
+            Kind::HundredMul => {
+                matches!(last, Some(Kind::Unit) | Some(Kind::Teen)) && current < 100
+            }
+            Kind::Hundreds => matches!(last, None | Some(Kind::Scale)),
+            Kind::Scale => !matches!(last, Some(Kind::Scale)) && word.value < last_scale,
+        };
+        if !accepted {
+            break;
+        }
+
+        match word.kind {
+            Kind::HundredMul => current = current.max(1) * 100,
+            Kind::Scale => {
+                total += current.max(1) * word.value;
+                current = 0;
+                last_scale = word.value;
+            }
+            _ => current += word.value,
+        }
+        last = Some(word.kind);
+        lang = Some(word.lang);
+        words += 1;
+        i += 1;
+
+        // Ordinals and punctuation ("twenty," / "five.") end the phrase
+        if word.ordinal {
+            ordinal = true;
+            break;
+        }
+        if !token.suffix.is_empty() {
+            break;
+        }
+    }
+
+    if words == 0 {
+        return None;
+    }
+    let lang = lang?;
+    let mut value = total + current;
+    let mut len = i - start;
+
+    // English years: "nineteen eighty four" -> 1984, "twenty twenty" -> 2020
+    if lang == Lang::En && !ordinal && total == 0 && (10..100).contains(&value) {
+        if let Some((second, second_len, second_ordinal)) = parse_year_tail(tokens, i, langs) {
+            if tokens[i - 1].suffix.is_empty() {
+                value = value * 100 + second;
+                len += second_len;
+                words += second_len;
+                ordinal = second_ordinal;
+            }
+        }
+    }
+
+    // Decimals: "three point five" -> 3.5
+    let mut decimal = None;
+    let end = start + len;
+    if lang == Lang::En
+        && !ordinal
+        && tokens[end - 1].suffix.is_empty()
+        && tokens.get(end).is_some_and(|t| t.core == "point")
+    {
+        let mut digits = String::new();
+        let mut j = end + 1;
+        while let Some(w) = tokens.get(j).and_then(|t| lookup(t, langs)) {
+            if w.kind != Kind::Unit || w.ordinal || w.lang != Lang::En {
+                break;
+            }
+            digits.push_str(&w.value.to_string());
+            j += 1;
+            if !tokens[j - 1].suffix.is_empty() {
+                break;
+            }
+        }
+        if !digits.is_empty() {
+            decimal = Some(digits);
+            len = j - start;
+        }
+    }
+
+    Some(ParsedNumber {
+        value,
+        len,
+        words,
+        ordinal,
+        decimal,
+        lang,
+    })
+}
+
+/// Second half of a spoken year: a teen, tens (+ unit), or "oh" + unit.
+fn parse_year_tail(tokens: &[Token], start: usize, langs: &[Lang]) -> Option<(u64, usize, bool)> {
+    let first = tokens.get(start)?;
+    if first.core == "oh" && first.suffix.is_empty() {
+        let unit = lookup(tokens.get(start + 1)?, langs)?;
+        return (unit.kind == Kind::Unit && unit.value > 0).then_some((
+            unit.value,
+            2,
+            unit.ordinal,
+        ));
+    }
+
+    let word = lookup(first, langs)?;
+    if word.lang != Lang::En {
+        return None;
+    }
+    match word.kind {
+        Kind::Teen => Some((word.value, 1, word.ordinal)),
+        Kind::Tens => {
+            if !word.ordinal && first.suffix.is_empty() {
+                if let Some(unit) = tokens.get(start + 1).and_then(|t| lookup(t, langs)) {
+                    if unit.kind == Kind::Unit && unit.value > 0 && unit.lang == Lang::En {
+                        return Some((word.value + unit.value, 2, unit.ordinal));
+                    }
+                }
+            }
+            Some((word.value, 1, word.ordinal))
+        }
+        _ => None,
+    }
+}
+
+fn ordinal_suffix(value: u64) -> &'static str {
+    match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+fn is_month(token: Option<&Token>) -> bool {
+    token.is_some_and(|t| EN_MONTHS.contains(&t.core.as_str()))
+}
+
+fn meridiem(token: Option<&Token>) -> Option<&'static str> {
+    let token = token?;
+    match token
+        .raw
+        .to_lowercase()
+        .replace('.', "")
+        .trim_matches(|c: char| !c.is_alphanumeric())
+    {
+        "am" => Some("AM"),
+        "pm" => Some("PM"),
+        _ => None,
+    }
+}
+
+/// Converts spoken numbers to digits for English and Russian.
+///
+/// Handles cardinals ("twenty three" -> "23"), years ("nineteen eighty four"),
+/// decimals ("three point five"), ordinals ("twenty first" -> "21st"),
+/// percentages, currencies ($, €, £, ₽), clock times ("three thirty pm" ->
+/// "3:30 PM") and month dates ("march third" -> "march 3").
+///
+/// Single small numbers ("one", "five") are left as words unless a unit or a
+/// context word like "line"/"строка" makes the intent clear, so phrases such as
+/// "no one" or "one of them" stay intact.
+///
+/// # Arguments
+/// * `text` - The text to normalize
+/// * `language` - The selected transcription language code ("en", "ru", "auto", ...)
+pub fn normalize_numbers(text: &str, language: &str) -> String {
+    let langs: &[Lang] = match language {
+        "auto" => &[Lang::En, Lang::Ru],
+        l if l == "en" || l.starts_with("en-") => &[Lang::En],
+        l if l == "ru" || l.starts_with("ru-") => &[Lang::Ru],
+        _ => return text.to_string(),
+    };
+
+    let tokens = tokenize(text);
+    let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let Some(parsed) = parse_number(&tokens, i, langs) else {
+            // Keep the original spelling; hyphenated numbers always parse, so
+            // split tokens never reach this branch.
+            out.push(tokens[i].raw.clone());
+            i += 1;
+            continue;
+        };
+
+        let first = &tokens[i];
+        let number_end = i + parsed.len;
+        let mut end = number_end;
+        let mut last_suffix = tokens[end - 1].suffix.clone();
+        // The `offset`-th token after the number, if no punctuation separates them
+        let followed = |offset: usize| -> Option<&Token> {
+            if tokens[number_end + offset - 2].suffix.is_empty() {
+                tokens.get(number_end + offset - 1)
+            } else {
+                None
+            }
+        };
+        let next = followed(1);
+        let next_core = next.map(|t| t.core.as_str()).unwrap_or("");
+        let prev = i.checked_sub(1).map(|p| &tokens[p]);
+        let in_context =
+            prev.is_some_and(|p| p.suffix.is_empty() && CONTEXT_WORDS.contains(&p.core.as_str()));
+
+        let number = match &parsed.decimal {
+            Some(decimal) => format!("{}.{}", parsed.value, decimal),
+            None => parsed.value.to_string(),
+        };
+
+        let mut replacement: Option<String> = None;
+
+        if parsed.lang == Lang::En && parsed.decimal.is_none() && parsed.words == 1 {
+            // Clock times: "three thirty pm", "ten oh five am", "seven o'clock"
+            if (1..=12).contains(&parsed.value) && !parsed.ordinal {
+                if next_core == "o'clock" {
+                    replacement = Some(format!("{}:00", parsed.value));
+                    last_suffix = next.unwrap().suffix.clone();
+                    end += 1;
+                } else if let Some(m) = meridiem(next) {
+                    replacement = Some(format!("{} {}", parsed.value, m));
+                    last_suffix = next.unwrap().suffix.trim_start_matches('.').to_string();
+                    end += 1;
+                } else if let Some((minutes, minutes_len, _)) = next
+                    .is_some()
+                    .then(|| parse_year_tail(&tokens, end, langs))
+                    .flatten()
+                {
+                    let after = end + minutes_len;
+                    let m = if tokens[after - 1].suffix.is_empty() {
+                        meridiem(tokens.get(after))
+                    } else {
+                        None
+                    };
+                    if let (Some(m), true) = (m, minutes < 60) {
+                        replacement = Some(format!("{}:{:02} {}", parsed.value, minutes, m));
+                        last_suffix = tokens[after].suffix.trim_start_matches('.').to_string();
+                        end = after + 1;
+                    }
+                }
+            }
+        }
+
+        if replacement.is_none() {
+            let is_percent = matches!(next_core, "percent" | "процент" | "процента" | "процентов")
+                || (next_core == "per" && followed(2).is_some_and(|t| t.core == "cent"));
+            let currency = match next_core {
+                "dollar" | "dollars" | "buck" | "bucks" | "доллар" | "доллара" | "долларов" => {
+                    Some("$")
+                }
+                "euro" | "euros" | "евро" => Some("€"),
+                "pound" | "pounds" => Some("£"),
+                _ => None,
+            };
+            let rubles = matches!(next_core, "рубль" | "рубля" | "рублей");
+
+            if is_percent && !parsed.ordinal {
+                let unit_len = if next_core == "per" { 2 } else { 1 };
+                last_suffix = tokens[end + unit_len - 1].suffix.clone();
+                end += unit_len;
+                replacement = Some(format!("{}%", number));
+            } else if let (Some(symbol), false) = (currency, parsed.ordinal) {
+                last_suffix = next.unwrap().suffix.clone();
+                end += 1;
+                let mut amount = number.clone();
+                // "five dollars and twenty cents" -> "$5.20"
+                if parsed.decimal.is_none()
+                    && last_suffix.is_empty()
+                    && tokens.get(end).is_some_and(|t| t.core == "and")
+                {
+                    if let Some(cents) = parse_number(&tokens, end + 1, langs) {
+                        let cents_end = end + 1 + cents.len;
+                        let cents_word = tokens.get(cents_end);
+                        if cents.value < 100
+                            && cents.decimal.is_none()
+                            && tokens[cents_end - 1].suffix.is_empty()
+                            && cents_word.is_some_and(|t| t.core == "cents" || t.core == "cent")
+                        {
+                            amount = format!("{}.{:02}", parsed.value, cents.value);
+                            last_suffix = cents_word.unwrap().suffix.clone();
+                            end = cents_end + 1;
+                        }
+                    }
+                }
+                replacement = Some(format!("{}{}", symbol, amount));
+            } else if rubles && !parsed.ordinal {
+                last_suffix = next.unwrap().suffix.clone();
+                end += 1;
+                replacement = Some(format!("{} ₽", number));
+            } else if parsed.ordinal {
+                let month_before = prev.is_some_and(|p| p.suffix.is_empty()) && is_month(prev);
+                if month_before {
+                    // "march third" -> "march 3"
+                    replacement = Some(number.clone());
+                } else if parsed.words > 1
+                    || in_context
+                    || (next_core == "of" && is_month(followed(2)))
+                {
+                    replacement = Some(format!("{}{}", number, ordinal_suffix(parsed.value)));
+                }
+            } else if parsed.words > 1
+                || parsed.decimal.is_some()
+                || parsed.value >= 10
+                || in_context
+                || (prev.is_some_and(|p| p.suffix.is_empty()) && is_month(prev))
+            {
+                replacement = Some(number.clone());
+            }
+        }
+
+        match replacement {
+            Some(replacement) => {
+                out.push(format!("{}{}{}", first.prefix, replacement, last_suffix));
+                i = end;
+            }
+            None => {
+                out.push(tokens[i].raw.clone());
+                i += 1;
+            }
+        }
+    }
+
+    out.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn en(text: &str) -> String {
+        normalize_numbers(text, "en")
+    }
+
+    fn ru(text: &str) -> String {
+        normalize_numbers(text, "ru")
+    }
+
+    #[test]
+    fn test_english_cardinals() {
+        assert_eq!(en("twenty three apples"), "23 apples");
+        assert_eq!(en("twenty-three apples"), "23 apples");
+        assert_eq!(en("one hundred and five"), "105");
+        assert_eq!(en("a thousand people"), "1000 people");
+        assert_eq!(en("two million three hundred thousand"), "2300000");
+        assert_eq!(en("go to line forty two"), "go to line 42");
+    }
+
+    #[test]
+    fn test_small_numbers_need_context() {
+        assert_eq!(en("no one knows"), "no one knows");
+        assert_eq!(en("one of them"), "one of them");
+        assert_eq!(en("go to line five"), "go to line 5");
+        assert_eq!(en("one two three"), "one two three");
+        assert_eq!(en("twelve cats"), "12 cats");
+    }
+
+    #[test]
+    fn test_english_years_and_decimals() {
+        assert_eq!(en("in nineteen eighty four"), "in 1984");
+        assert_eq!(en("since twenty twenty"), "since 2020");
+        assert_eq!(en("nineteen oh five"), "1905");
+        assert_eq!(en("three point one four"), "3.14");
+    }
+
+    #[test]
+    fn test_english_ordinals_and_dates() {
+        assert_eq!(en("the twenty first floor"), "the 21st floor");
+        assert_eq!(en("on march third"), "on march 3");
+        assert_eq!(en("the third of march"), "the 3rd of march");
+        assert_eq!(en("wait a second"), "wait a second");
+    }
+
+    #[test]
+    fn test_english_units() {
+        assert_eq!(en("ten percent off"), "10% off");
+        assert_eq!(en("five dollars"), "$5");
+        assert_eq!(en("five dollars and twenty cents."), "$5.20.");
+        assert_eq!(en("twenty euros"), "€20");
+        assert_eq!(en("meet at three thirty pm"), "meet at 3:30 PM");
+        assert_eq!(en("at seven o'clock"), "at 7:00");
+        assert_eq!(en("at ten a.m."), "at 10 AM");
+    }
+
+    #[test]
+    fn test_preserves_punctuation() {
+        assert_eq!(en("(twenty three), then"), "(23), then");
+        assert_eq!(en("forty, two"), "40, two");
+    }
+
+    #[test]
+    fn test_russian() {
+        assert_eq!(ru("двадцать три яблока"), "23 яблока");
+        assert_eq!(ru("сто двадцать пять"), "125");
+        assert_eq!(ru("две тысячи двадцать четыре"), "2024");
+        assert_eq!(ru("перейди на строку пять"), "перейди на строку 5");
+        assert_eq!(ru("десять процентов"), "10%");
+        assert_eq!(ru("пятьсот рублей"), "500 ₽");
+        assert_eq!(ru("сорок долларов"), "$40");
+        assert_eq!(ru("один из них"), "один из них");
+    }
+
+    #[test]
+    fn test_language_selection() {
+        assert_eq!(normalize_numbers("twenty три", "auto"), "20 три");
+        assert_eq!(normalize_numbers("двадцать", "en"), "двадцать");
+        assert_eq!(normalize_numbers("twenty three", "de"), "twenty three");
+    }
+}
@@ -283,6 +283,113 @@ pub fn filter_transcription_output(text: &str) -> String {
     filtered.trim().to_string()
 }
 
+/// Words a stitch may drop around the overlap. About a second of overlap
+/// holds two or three words, so a run further from the boundary than this
+/// is a repeated phrase, not the overlap.
+const MAX_STITCH_SKIPPED_WORDS: usize = 3;
+
+/// Join the transcripts of two chunks of audio that overlap a little,
+/// dropping the words both of them heard.
+///
+/// A run of equal words (ignoring case and punctuation) between the last
+/// `window` words of `before` and the first `window` words of `after` is
+/// taken as the overlap, the one nearest the boundary winning and the longer
+/// one breaking ties. Words of `before` after the run and of `after` before
+/// it are cut-off words at the chunk edges and are dropped, at most
+/// [`MAX_STITCH_SKIPPED_WORDS`] of them. A single common word only counts
+/// within a word of the boundary, so a stray "the" doesn't align the chunks.
+/// Without a common run the texts are simply joined.
+pub fn stitch_transcripts(before: &str, after: &str, window: usize) -> String {
+    let before_words = word_spans(before);
+    let after_words = word_spans(after);
+    let tail_start = before_words.len().saturating_sub(window);
+    let tail: Vec<String> = before_words[tail_start..]
+        .iter()
+        .map(|(_, w)| overlap_key(w))
+        .collect();
+    let head: Vec<String> = after_words
+        .iter()
+        .take(window)
+        .map(|(_, w)| overlap_key(w))
+        .collect();
+
+    // (start in tail, start in head, length) of the best run
+    let mut best: Option<(usize, usize, usize)> = None;
+    for i in 0..tail.len() {
+        for j in 0..head.len() {
+            let mut len = 0;
+            while i + len < tail.len()
+                && j + len < head.len()
+                && !tail[i + len].is_empty()
+                && tail[i + len] == head[j + len]
+            {
+                len += 1;
+            }
+            // Words left over on either side of the run
+            let skipped = (tail.len() - i - len) + j;
+            if len == 0 || skipped > MAX_STITCH_SKIPPED_WORDS || (len == 1 && skipped > 1) {
+                continue;
+            }
+            // Nearest the boundary first, then longest
+            let better = match best {
+                None => true,
+                Some((bi, bj, blen)) => {
+                    let best_skipped = (tail.len() - bi - blen) + bj;
+                    skipped < best_skipped || (skipped == best_skipped && len > blen)
+                }
+            };
+            if better {
+                best = Some((i, j, len));
+            }
+        }
+    }
+
+    let Some((i, j, len)) = best else {
+        return match (before.trim().is_empty(), after.trim().is_empty()) {
+            (true, _) => after.to_string(),
+            (_, true) => before.to_string(),
+            _ => format!("{} {}", before.trim_end(), after.trim_start()),
+        };
+    };
+
+    // The run comes from `before`, except its last word: `before` may have
+    // ended its chunk on it with a period
+    let (last_start, last_before) = before_words[tail_start + i + len - 1];
+    let (_, last_after) = after_words[j + len - 1];
+    let mut stitched = before[..last_start].to_string();
+    stitched.push_str(&merge_overlap_word(last_before, last_after));
+    if let Some((rest_start, _)) = after_words.get(j + len) {
+        stitched.push(' ');
+        stitched.push_str(&after[*rest_start..]);
+    }
+    stitched
+}
+
+/// Byte offset and text of each whitespace-separated word.
+fn word_spans(text: &str) -> Vec<(usize, &str)> {
+    text.split_whitespace()
+        .map(|word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+        .collect()
+}
+
+fn overlap_key(word: &str) -> String {
+    word.trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// `after`'s copy of a word, lowercased again if it was only capitalized
+/// for starting its chunk.
+fn merge_overlap_word(before: &str, after: &str) -> String {
+    let starts_lower = before.chars().next().is_some_and(char::is_lowercase);
+    let mut chars = after.chars();
+    match chars.next() {
+        Some(first) if starts_lower && first.is_uppercase() => {
+            first.to_lowercase().chain(chars).collect()
+        }
+        _ => after.to_string(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,4 +565,74 @@ mod tests {
             result
         );
     }
+
+    #[test]
+    fn test_stitch_transcripts_drops_repeated_words() {
+        assert_eq!(
+            stitch_transcripts(
+                "the quick brown fox jumps",
+                "fox jumps over the lazy dog",
+                8
+            ),
+            "the quick brown fox jumps over the lazy dog"
+        );
+        // Whisper ended the first chunk with a period and started the
+        // second with a capital
+        assert_eq!(
+            stitch_transcripts("I think we should go.", "We should go to the park.", 8),
+            "I think we should go to the park."
+        );
+        assert_eq!(
+            stitch_transcripts("Send it to the", "The office manager.", 8),
+            "Send it to the office manager."
+        );
+    }
+
+    #[test]
+    fn test_stitch_transcripts_replaces_cut_off_words() {
+        assert_eq!(
+            stitch_transcripts("We will meet on tues", "on Tuesday at noon.", 8),
+            "We will meet on Tuesday at noon."
+        );
+        assert_eq!(
+            stitch_transcripts("the report is due", "ue due on Friday", 8),
+            "the report is due on Friday"
+        );
+    }
+
+    #[test]
+    fn test_stitch_transcripts_without_overlap() {
+        assert_eq!(
+            stitch_transcripts("Hello there.", "How are you?", 8),
+            "Hello there. How are you?"
+        );
+        // A lone common word far from the boundary doesn't align the chunks
+        assert_eq!(
+            stitch_transcripts("the cat sat down", "and the dog barked", 8),
+            "the cat sat down and the dog barked"
+        );
+        assert_eq!(stitch_transcripts("", "Hello.", 8), "Hello.");
+        assert_eq!(stitch_transcripts("Hello.", "", 8), "Hello.");
+    }
+
+    #[test]
+    fn test_stitch_transcripts_ignores_repeated_phrases() {
+        // Common phrases said again after the overlap must not align the chunks
+        assert_eq!(
+            stitch_transcripts("I went to the store", "store and then to the bank", 8),
+            "I went to the store and then to the bank"
+        );
+        assert_eq!(
+            stitch_transcripts(
+                "we met at the end of the day",
+                "day and talked about the end of the project",
+                8
+            ),
+            "we met at the end of the day and talked about the end of the project"
+        );
+        assert_eq!(
+            stitch_transcripts("we walked to the", "to the park and back to the car", 8),
+            "we walked to the park and back to the car"
+        );
+    }
 }
@@ -27,6 +27,27 @@ impl SileroVad {
             threshold,
         })
     }
+
+    /// Share of the whole 30-ms frames of `audio` whose speech probability
+    /// is at or below the threshold: 1.0 when nothing sounds like speech.
+    pub fn no_speech_probability(&mut self, audio: &[f32]) -> Result<f32> {
+        let mut frames = 0;
+        let mut silent = 0;
+        for frame in audio.chunks_exact(SILERO_FRAME_SAMPLES) {
+            let result = self
+                .engine
+                .compute(frame)
+                .map_err(|e| anyhow::anyhow!("Silero VAD error: {e}"))?;
+            frames += 1;
+            if result.prob <= self.threshold {
+                silent += 1;
+            }
+        }
+        if frames == 0 {
+            return Ok(1.0);
+        }
+        Ok(silent as f32 / frames as f32)
+    }
 }
 
 impl VoiceActivityDetector for SileroVad {
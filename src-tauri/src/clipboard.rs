@@ -1,454 +1,94 @@
-use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
-use enigo::Enigo;
-use log::info;
+use crate::input::EnigoState;
+use crate::input_backend::{self, InputBackend};
+use crate::settings::{get_settings, ClipboardHandling, PasteMethod, SelectionTarget};
 use std::time::Duration;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tracing::{info, warn};
 
-#[cfg(target_os = "linux")]
-use crate::utils::{is_kde_wayland, is_wayland};
-#[cfg(target_os = "linux")]
-use std::process::Command;
+/// Where a clipboard paste failed.
+enum ClipboardPasteError {
+    /// The text never reached the clipboard, so nothing was pasted.
+    Write(String),
+    /// The paste keystroke failed; part of it may still have gone through.
+    Keys(String),
+}
 
 /// Pastes text using the clipboard: saves current content, writes text, sends paste keystroke, restores clipboard.
 fn paste_via_clipboard(
-    enigo: &mut Enigo,
+    backend: &mut dyn InputBackend,
     text: &str,
     app_handle: &AppHandle,
     paste_method: &PasteMethod,
     paste_delay_ms: u64,
-) -> Result<(), String> {
+) -> Result<(), ClipboardPasteError> {
     let clipboard = app_handle.clipboard();
     let clipboard_content = clipboard.read_text().unwrap_or_default();
 
-    // Write text to clipboard first
-    // On Wayland, prefer wl-copy for better compatibility (especially with umlauts)
-    #[cfg(target_os = "linux")]
-    let write_result = if is_wayland() && is_wl_copy_available() {
-        info!("Using wl-copy for clipboard write on Wayland");
-        write_clipboard_via_wl_copy(text)
-    } else {
-        clipboard
-            .write_text(text)
-            .map_err(|e| format!("Failed to write to clipboard: {}", e))
-    };
-
-    #[cfg(not(target_os = "linux"))]
-    let write_result = clipboard
-        .write_text(text)
-        .map_err(|e| format!("Failed to write to clipboard: {}", e));
+    // Write text to clipboard first, through the backend's own tool if it has one
+    if !backend
+        .write_clipboard(text)
+        .map_err(ClipboardPasteError::Write)?
+    {
+        clipboard.write_text(text).map_err(|e| {
+            ClipboardPasteError::Write(format!("Failed to write to clipboard: {}", e))
+        })?;
 
-    write_result?;
+        // Clipboard managers and some IMEs can swallow the write; pasting would
+        // then insert whatever was there before
+        if clipboard.read_text().ok().as_deref() != Some(text) {
+            return Err(ClipboardPasteError::Write(
+                "Clipboard did not keep the transcription".into(),
+            ));
+        }
+    }
 
     std::thread::sleep(Duration::from_millis(paste_delay_ms));
 
     // Send paste key combo
-    #[cfg(target_os = "linux")]
-    let key_combo_sent = try_send_key_combo_linux(paste_method)?;
-
-    #[cfg(not(target_os = "linux"))]
-    let key_combo_sent = false;
-
-    // Fall back to enigo if no native tool handled it
-    if !key_combo_sent {
-        match paste_method {
-            PasteMethod::CtrlV => input::send_paste_ctrl_v(enigo)?,
-            PasteMethod::CtrlShiftV => input::send_paste_ctrl_shift_v(enigo)?,
-            PasteMethod::ShiftInsert => input::send_paste_shift_insert(enigo)?,
-            _ => return Err("Invalid paste method for clipboard paste".into()),
-        }
-    }
+    let result = backend.send_paste_keys(paste_method);
 
     std::thread::sleep(std::time::Duration::from_millis(50));
 
     // Restore original clipboard content
-    // On Wayland, prefer wl-copy for better compatibility
-    #[cfg(target_os = "linux")]
-    if is_wayland() && is_wl_copy_available() {
-        let _ = write_clipboard_via_wl_copy(&clipboard_content);
-    } else {
+    if !backend.write_clipboard(&clipboard_content).unwrap_or(false) {
         let _ = clipboard.write_text(&clipboard_content);
     }
 
-    #[cfg(not(target_os = "linux"))]
-    let _ = clipboard.write_text(&clipboard_content);
-
-    Ok(())
-}
-
-/// Attempts to send a key combination using Linux-native tools.
-/// Returns `Ok(true)` if a native tool handled it, `Ok(false)` to fall back to enigo.
-#[cfg(target_os = "linux")]
-fn try_send_key_combo_linux(paste_method: &PasteMethod) -> Result<bool, String> {
-    if is_wayland() {
-        // Wayland: prefer wtype (but not on KDE), then dotool, then ydotool
-        // Note: wtype doesn't work on KDE (no zwp_virtual_keyboard_manager_v1 support)
-        if !is_kde_wayland() && is_wtype_available() {
-            info!("Using wtype for key combo");
-            send_key_combo_via_wtype(paste_method)?;
-            return Ok(true);
-        }
-        if is_dotool_available() {
-            info!("Using dotool for key combo");
-            send_key_combo_via_dotool(paste_method)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for key combo");
-            send_key_combo_via_ydotool(paste_method)?;
-            return Ok(true);
-        }
-    } else {
-        // X11: prefer xdotool, then ydotool
-        if is_xdotool_available() {
-            info!("Using xdotool for key combo");
-            send_key_combo_via_xdotool(paste_method)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for key combo");
-            send_key_combo_via_ydotool(paste_method)?;
-            return Ok(true);
-        }
-    }
-
-    Ok(false)
+    result.map_err(ClipboardPasteError::Keys)
 }
 
-/// Attempts to type text directly using Linux-native tools.
-/// Returns `Ok(true)` if a native tool handled it, `Ok(false)` to fall back to enigo.
-#[cfg(target_os = "linux")]
-fn try_direct_typing_linux(text: &str) -> Result<bool, String> {
-    if is_wayland() {
-        // KDE Wayland: prefer kwtype (uses KDE Fake Input protocol, supports umlauts)
-        if is_kde_wayland() && is_kwtype_available() {
-            info!("Using kwtype for direct text input on KDE Wayland");
-            type_text_via_kwtype(text)?;
-            return Ok(true);
-        }
-        // Wayland: prefer wtype, then dotool, then ydotool
-        // Note: wtype doesn't work on KDE (no zwp_virtual_keyboard_manager_v1 support)
-        if !is_kde_wayland() && is_wtype_available() {
-            info!("Using wtype for direct text input");
-            type_text_via_wtype(text)?;
-            return Ok(true);
-        }
-        if is_dotool_available() {
-            info!("Using dotool for direct text input");
-            type_text_via_dotool(text)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for direct text input");
-            type_text_via_ydotool(text)?;
-            return Ok(true);
-        }
-    } else {
-        // X11: prefer xdotool, then ydotool
-        if is_xdotool_available() {
-            info!("Using xdotool for direct text input");
-            type_text_via_xdotool(text)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for direct text input");
-            type_text_via_ydotool(text)?;
-            return Ok(true);
-        }
-    }
-
-    Ok(false)
+/// Whether `text` contains Chinese, Japanese or Korean characters.
+fn contains_cjk(text: &str) -> bool {
+    text.chars().any(|c| {
+        matches!(c as u32,
+            0x1100..=0x11FF     // Hangul Jamo
+            | 0x3000..=0x303F   // CJK symbols and punctuation
+            | 0x3040..=0x30FF   // Hiragana, Katakana
+            | 0x3130..=0x318F   // Hangul compatibility Jamo
+            | 0x31F0..=0x31FF   // Katakana phonetic extensions
+            | 0x3400..=0x4DBF   // CJK extension A
+            | 0x4E00..=0x9FFF   // CJK unified ideographs
+            | 0xAC00..=0xD7AF   // Hangul syllables
+            | 0xF900..=0xFAFF   // CJK compatibility ideographs
+            | 0xFF00..=0xFFEF   // Half/full-width forms
+            | 0x20000..=0x2FA1F // CJK extensions B-F, compatibility supplement
+        )
+    })
 }
 
-/// Check if wtype is available (Wayland text input tool)
-#[cfg(target_os = "linux")]
-fn is_wtype_available() -> bool {
-    Command::new("which")
-        .arg("wtype")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-/// Check if dotool is available (another Wayland text input tool)
-#[cfg(target_os = "linux")]
-fn is_dotool_available() -> bool {
-    Command::new("which")
-        .arg("dotool")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-/// Check if ydotool is available (uinput-based, works on both Wayland and X11)
-#[cfg(target_os = "linux")]
-fn is_ydotool_available() -> bool {
-    Command::new("which")
-        .arg("ydotool")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-#[cfg(target_os = "linux")]
-fn is_xdotool_available() -> bool {
-    Command::new("which")
-        .arg("xdotool")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-/// Check if kwtype is available (KDE Wayland virtual keyboard input tool)
-#[cfg(target_os = "linux")]
-fn is_kwtype_available() -> bool {
-    Command::new("which")
-        .arg("kwtype")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-/// Check if wl-copy is available (Wayland clipboard tool)
-#[cfg(target_os = "linux")]
-fn is_wl_copy_available() -> bool {
-    Command::new("which")
-        .arg("wl-copy")
-        .output()
-        .map(|output| output.status.success())
-        .unwrap_or(false)
-}
-
-/// Type text directly via wtype on Wayland.
-#[cfg(target_os = "linux")]
-fn type_text_via_wtype(text: &str) -> Result<(), String> {
-    let output = Command::new("wtype")
-        .arg("--") // Protect against text starting with -
-        .arg(text)
-        .output()
-        .map_err(|e| format!("Failed to execute wtype: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("wtype failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Type text directly via xdotool on X11.
-#[cfg(target_os = "linux")]
-fn type_text_via_xdotool(text: &str) -> Result<(), String> {
-    let output = Command::new("xdotool")
-        .arg("type")
-        .arg("--clearmodifiers")
-        .arg("--")
-        .arg(text)
-        .output()
-        .map_err(|e| format!("Failed to execute xdotool: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("xdotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Type text directly via dotool (works on both Wayland and X11 via uinput).
-#[cfg(target_os = "linux")]
-fn type_text_via_dotool(text: &str) -> Result<(), String> {
-    use std::io::Write;
-    use std::process::Stdio;
-
-    let mut child = Command::new("dotool")
-        .stdin(Stdio::piped())
-        .spawn()
-        .map_err(|e| format!("Failed to spawn dotool: {}", e))?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        // dotool uses "type <text>" command
-        writeln!(stdin, "type {}", text)
-            .map_err(|e| format!("Failed to write to dotool stdin: {}", e))?;
-    }
-
-    let output = child
-        .wait_with_output()
-        .map_err(|e| format!("Failed to wait for dotool: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("dotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Type text directly via ydotool (uinput-based, requires ydotoold daemon).
-#[cfg(target_os = "linux")]
-fn type_text_via_ydotool(text: &str) -> Result<(), String> {
-    let output = Command::new("ydotool")
-        .arg("type")
-        .arg("--")
-        .arg(text)
-        .output()
-        .map_err(|e| format!("Failed to execute ydotool: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("ydotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Type text directly via kwtype (KDE Wayland virtual keyboard, uses KDE Fake Input protocol).
-#[cfg(target_os = "linux")]
-fn type_text_via_kwtype(text: &str) -> Result<(), String> {
-    let output = Command::new("kwtype")
-        .arg("--")
-        .arg(text)
-        .output()
-        .map_err(|e| format!("Failed to execute kwtype: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("kwtype failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Write text to clipboard via wl-copy (Wayland clipboard tool).
-#[cfg(target_os = "linux")]
-fn write_clipboard_via_wl_copy(text: &str) -> Result<(), String> {
-    let output = Command::new("wl-copy")
-        .arg("--")
-        .arg(text)
-        .output()
-        .map_err(|e| format!("Failed to execute wl-copy: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("wl-copy failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Send a key combination (e.g., Ctrl+V) via wtype on Wayland.
-#[cfg(target_os = "linux")]
-fn send_key_combo_via_wtype(paste_method: &PasteMethod) -> Result<(), String> {
-    let args: Vec<&str> = match paste_method {
-        PasteMethod::CtrlV => vec!["-M", "ctrl", "-k", "v"],
-        PasteMethod::ShiftInsert => vec!["-M", "shift", "-k", "Insert"],
-        PasteMethod::CtrlShiftV => vec!["-M", "ctrl", "-M", "shift", "-k", "v"],
-        _ => return Err("Unsupported paste method".into()),
-    };
-
-    let output = Command::new("wtype")
-        .args(&args)
-        .output()
-        .map_err(|e| format!("Failed to execute wtype: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("wtype failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Send a key combination (e.g., Ctrl+V) via dotool.
-#[cfg(target_os = "linux")]
-fn send_key_combo_via_dotool(paste_method: &PasteMethod) -> Result<(), String> {
-    let command;
-    match paste_method {
-        PasteMethod::CtrlV => command = "echo key ctrl+v | dotool",
-        PasteMethod::ShiftInsert => command = "echo key shift+insert | dotool",
-        PasteMethod::CtrlShiftV => command = "echo key ctrl+shift+v | dotool",
-        _ => return Err("Unsupported paste method".into()),
-    }
-    let output = Command::new("sh")
-        .arg("-c")
-        .arg(command)
-        .output()
-        .map_err(|e| format!("Failed to execute dotool: {}", e))?;
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("dotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Send a key combination (e.g., Ctrl+V) via ydotool (requires ydotoold daemon).
-#[cfg(target_os = "linux")]
-fn send_key_combo_via_ydotool(paste_method: &PasteMethod) -> Result<(), String> {
-    // ydotool uses Linux input event keycodes with format <keycode>:<pressed>
-    // where pressed is 1 for down, 0 for up. Keycodes: ctrl=29, shift=42, v=47, insert=110
-    let args: Vec<&str> = match paste_method {
-        PasteMethod::CtrlV => vec!["key", "29:1", "47:1", "47:0", "29:0"],
-        PasteMethod::ShiftInsert => vec!["key", "42:1", "110:1", "110:0", "42:0"],
-        PasteMethod::CtrlShiftV => vec!["key", "29:1", "42:1", "47:1", "47:0", "42:0", "29:0"],
-        _ => return Err("Unsupported paste method".into()),
-    };
-
-    let output = Command::new("ydotool")
-        .args(&args)
-        .output()
-        .map_err(|e| format!("Failed to execute ydotool: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("ydotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Send a key combination (e.g., Ctrl+V) via xdotool on X11.
-#[cfg(target_os = "linux")]
-fn send_key_combo_via_xdotool(paste_method: &PasteMethod) -> Result<(), String> {
-    let key_combo = match paste_method {
-        PasteMethod::CtrlV => "ctrl+v",
-        PasteMethod::CtrlShiftV => "ctrl+shift+v",
-        PasteMethod::ShiftInsert => "shift+Insert",
-        _ => return Err("Unsupported paste method".into()),
-    };
-
-    let output = Command::new("xdotool")
-        .arg("key")
-        .arg("--clearmodifiers")
-        .arg(key_combo)
-        .output()
-        .map_err(|e| format!("Failed to execute xdotool: {}", e))?;
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(format!("xdotool failed: {}", stderr));
-    }
-
-    Ok(())
-}
-
-/// Types text directly by simulating individual key presses.
-fn paste_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
-    #[cfg(target_os = "linux")]
-    {
-        if try_direct_typing_linux(text)? {
-            return Ok(());
-        }
-        info!("Falling back to enigo for direct text input");
-    }
-
-    input::paste_text_direct(enigo, text)
+pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
+    paste_with_method(text, app_handle, None)
 }
 
-pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
+/// Paste using `method_override` instead of the configured paste method, if given.
+pub fn paste_with_method(
+    text: String,
+    app_handle: AppHandle,
+    method_override: Option<PasteMethod>,
+) -> Result<(), String> {
     let settings = get_settings(&app_handle);
-    let paste_method = settings.paste_method;
+    let paste_method = method_override.unwrap_or(settings.paste_method);
     let paste_delay_ms = settings.paste_delay_ms;
 
     // Append trailing space if setting is enabled
@@ -472,22 +112,78 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         .lock()
         .map_err(|e| format!("Failed to lock Enigo: {}", e))?;
 
+    let mut backend = input_backend::select_backend(&mut enigo);
+    let per_character_delay = Duration::from_millis(settings.per_character_delay_ms);
+
+    // enigo's text() garbles CJK while an IME is composing
+    let paste_method = if paste_method == PasteMethod::Direct
+        && settings.paste_fallback_enabled
+        && contains_cjk(&text)
+    {
+        info!("CJK text with direct typing, typing per character instead");
+        PasteMethod::PerCharacter
+    } else {
+        paste_method
+    };
+
+    // The PRIMARY selection only exists on X11/Wayland
+    let selection_target = if cfg!(target_os = "linux") {
+        settings.selection_target
+    } else {
+        SelectionTarget::Clipboard
+    };
+    if selection_target.includes_primary() {
+        if let Err(e) = backend.write_primary(&text) {
+            // Only the clipboard paste is left to do then
+            if selection_target == SelectionTarget::Primary {
+                return Err(e);
+            }
+            warn!("Failed to set the primary selection: {}", e);
+        }
+    }
+
     // Perform the paste operation
     match paste_method {
         PasteMethod::None => {
             info!("PasteMethod::None selected - skipping paste action");
         }
         PasteMethod::Direct => {
-            paste_direct(&mut enigo, &text)?;
+            backend.type_text(&text)?;
+        }
+        PasteMethod::PerCharacter => {
+            backend.type_per_character(&text, per_character_delay)?;
+        }
+        PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert
+            if selection_target == SelectionTarget::Primary =>
+        {
+            // CLIPBOARD is left alone; the text waits in PRIMARY
+            if settings.middle_click_paste {
+                backend.middle_click()?;
+            } else {
+                info!("Text placed in the primary selection");
+            }
         }
         PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
-            paste_via_clipboard(
-                &mut enigo,
+            match paste_via_clipboard(
+                backend.as_mut(),
                 &text,
                 &app_handle,
                 &paste_method,
                 paste_delay_ms,
-            )?
+            ) {
+                Ok(()) => {}
+                // Typing after a sent keystroke could insert the text twice
+                Err(ClipboardPasteError::Write(e)) if settings.paste_fallback_enabled => {
+                    warn!(
+                        "Clipboard paste failed ({}), typing per character instead",
+                        e
+                    );
+                    backend.type_per_character(&text, per_character_delay)?;
+                }
+                Err(ClipboardPasteError::Write(e) | ClipboardPasteError::Keys(e)) => {
+                    return Err(e);
+                }
+            }
         }
     }
 
@@ -501,3 +197,19 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_cjk_text() {
+        assert!(contains_cjk("你好世界"));
+        assert!(contains_cjk("こんにちは"));
+        assert!(contains_cjk("カタカナ"));
+        assert!(contains_cjk("안녕하세요"));
+        assert!(contains_cjk("meeting at 3時"));
+        assert!(!contains_cjk("Hello, world"));
+        assert!(!contains_cjk("Привет, мир"));
+    }
+}
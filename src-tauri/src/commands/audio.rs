@@ -1,12 +1,14 @@
 use crate::audio_feedback;
 use crate::audio_toolkit::audio::{list_input_devices, list_output_devices};
-use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
-use crate::settings::{get_settings, write_settings};
-use log::warn;
+use crate::diagnostics::{self, MicrophoneDiagnostics};
+use crate::managers::audio::AudioRecordingManager;
+use crate::power_policy::PowerProfile;
+use crate::settings::{get_settings, write_settings, BindingAudioOverride};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
+use tracing::warn;
 
 #[derive(Serialize, Type)]
 pub struct CustomSounds {
@@ -45,15 +47,12 @@ pub fn update_microphone_mode(app: AppHandle, always_on: bool) -> Result<(), Str
     // Update settings
     let mut settings = get_settings(&app);
     settings.always_on_microphone = always_on;
+    // The battery saver may keep the microphone on demand
+    let new_mode = PowerProfile::current(&settings).microphone_mode();
     write_settings(&app, settings);
 
     // Update the audio manager mode
     let rm = app.state::<Arc<AudioRecordingManager>>();
-    let new_mode = if always_on {
-        MicrophoneMode::AlwaysOn
-    } else {
-        MicrophoneMode::OnDemand
-    };
 
     rm.update_mode(new_mode)
         .map_err(|e| format!("Failed to update microphone mode: {}", e))
@@ -106,6 +105,34 @@ pub fn set_selected_microphone(app: AppHandle, device_name: String) -> Result<()
     Ok(())
 }
 
+/// Set the microphone and gain used by a single binding. A `None` microphone
+/// and 0 dB gain remove the override.
+#[tauri::command]
+#[specta::specta]
+pub fn set_binding_audio_override(
+    app: AppHandle,
+    binding_id: String,
+    microphone: Option<String>,
+    gain_db: f32,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if !settings.bindings.contains_key(&binding_id) {
+        return Err(format!("Unknown binding '{}'", binding_id));
+    }
+
+    let entry = BindingAudioOverride {
+        microphone: microphone.filter(|m| m != "default"),
+        gain_db: gain_db.clamp(-20.0, 20.0),
+    };
+    if entry.is_noop() {
+        settings.binding_audio.remove(&binding_id);
+    } else {
+        settings.binding_audio.insert(binding_id, entry);
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_selected_microphone(app: AppHandle) -> Result<String, String> {
@@ -200,3 +227,9 @@ pub fn is_recording(app: AppHandle) -> bool {
     let audio_manager = app.state::<Arc<AudioRecordingManager>>();
     audio_manager.is_recording()
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn run_microphone_diagnostics(app: AppHandle) -> Result<MicrophoneDiagnostics, String> {
+    diagnostics::run_microphone_diagnostics(&app).await
+}
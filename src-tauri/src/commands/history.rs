@@ -1,4 +1,6 @@
-use crate::managers::history::{HistoryEntry, HistoryManager};
+use crate::latency::{self, LatencyReport};
+use crate::managers::history::{HistoryCleanupReport, HistoryEntry, HistoryManager};
+use crate::settings::HistoryRetentionPolicy;
 use std::sync::Arc;
 use tauri::{AppHandle, State};
 
@@ -99,3 +101,49 @@ pub async fn update_recording_retention_period(
 
     Ok(())
 }
+
+#[tauri::command]
+#[specta::specta]
+pub async fn update_history_retention_policy(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    policy: HistoryRetentionPolicy,
+) -> Result<HistoryCleanupReport, String> {
+    let mut settings = crate::settings::get_settings(&app);
+    settings.history_retention = policy;
+    crate::settings::write_settings(&app, settings);
+
+    history_manager
+        .enforce_retention_policy()
+        .map_err(|e| e.to_string())
+}
+
+/// Run the retention cleanup immediately instead of waiting for the background task.
+#[tauri::command]
+#[specta::specta]
+pub async fn run_history_cleanup(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+) -> Result<HistoryCleanupReport, String> {
+    history_manager
+        .cleanup_old_entries()
+        .map_err(|e| e.to_string())?;
+
+    history_manager
+        .enforce_retention_policy()
+        .map_err(|e| e.to_string())
+}
+
+/// Per-stage latency over the `limit` most recent timed dictations.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_latency_report(
+    _app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    limit: usize,
+) -> Result<LatencyReport, String> {
+    let timings = history_manager
+        .get_recent_timings(limit)
+        .map_err(|e| e.to_string())?;
+    Ok(latency::build_report(&timings))
+}
@@ -0,0 +1,43 @@
+use crate::file_output;
+use crate::meeting::{self, MeetingStatus};
+use crate::settings::{get_settings, write_settings};
+use tauri::{AppHandle, Manager};
+
+#[tauri::command]
+#[specta::specta]
+pub fn start_meeting(app: AppHandle) -> Result<MeetingStatus, String> {
+    meeting::start(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn stop_meeting(app: AppHandle) -> Result<(), String> {
+    meeting::stop(&app)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_meeting_paused(app: AppHandle, paused: bool) -> Result<MeetingStatus, String> {
+    meeting::set_paused(&app, paused)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_meeting_status() -> Option<MeetingStatus> {
+    meeting::status()
+}
+
+/// Set the folder for meeting notes; an empty or missing folder goes back
+/// to the default in the app data directory.
+#[tauri::command]
+#[specta::specta]
+pub fn set_meeting_notes_folder(app: AppHandle, folder: Option<String>) -> Result<(), String> {
+    let folder = folder.filter(|folder| !folder.trim().is_empty());
+    if let Some(folder) = &folder {
+        file_output::resolve_path(folder, app.path().home_dir().ok().as_deref())?;
+    }
+    let mut settings = get_settings(&app);
+    settings.meeting_notes_folder = folder;
+    write_settings(&app, settings);
+    Ok(())
+}
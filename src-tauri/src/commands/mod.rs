@@ -1,11 +1,20 @@
 pub mod audio;
 pub mod history;
+pub mod meeting;
 pub mod models;
+pub mod overlay;
 pub mod transcription;
+pub mod tts;
+pub mod updater;
 
-use crate::settings::{get_settings, write_settings, AppSettings, LogLevel};
+use crate::settings::{
+    get_settings, write_settings, AppSettings, LogLevel, OutputAction, ProfanityMask,
+};
+use crate::settings_migration::SettingsFieldError;
+use crate::settings_profile::{ConflictResolution, ImportReport};
+use crate::structured_log::LogEvent;
 use crate::utils::cancel_current_operation;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_opener::OpenerExt;
 
 #[tauri::command]
@@ -37,6 +46,86 @@ pub fn get_default_settings() -> Result<AppSettings, String> {
     Ok(crate::settings::get_default_settings())
 }
 
+/// Settings that failed to load and were reset to defaults since the last call.
+#[tauri::command]
+#[specta::specta]
+pub fn get_settings_load_errors() -> Result<Vec<SettingsFieldError>, String> {
+    Ok(crate::settings_migration::take_load_errors())
+}
+
+/// Write the portable part of the settings to a profile file. API keys and
+/// device choices are left out.
+#[tauri::command]
+#[specta::specta]
+pub fn export_settings(app: AppHandle, path: String) -> Result<(), String> {
+    let contents = crate::settings_profile::export_profile(&get_settings(&app))?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    tracing::info!("Exported settings profile to {}", path);
+    Ok(())
+}
+
+/// Merge a profile file into the settings. With `dry_run` nothing is saved,
+/// so the frontend can look at the conflicts before choosing a resolution.
+#[tauri::command]
+#[specta::specta]
+pub fn import_settings(
+    app: AppHandle,
+    path: String,
+    resolution: ConflictResolution,
+    dry_run: bool,
+) -> Result<ImportReport, String> {
+    let contents =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let (settings, report) =
+        crate::settings_profile::import_profile(&get_settings(&app), &contents, resolution)?;
+
+    if !dry_run {
+        let reset_bindings = crate::shortcut::replace_settings(&app, settings);
+        if !reset_bindings.is_empty() {
+            tracing::warn!(
+                "Imported shortcuts reset to defaults: {}",
+                reset_bindings.join(", ")
+            );
+        }
+        tracing::info!(
+            "Imported settings profile from {}: {} added, {} replaced, {} kept, {} invalid",
+            path,
+            report.added.len(),
+            report.replaced.len(),
+            report.kept.len(),
+            report.errors.len()
+        );
+        apply_reloaded_settings(&app, "import");
+    }
+
+    Ok(report)
+}
+
+/// Bring logging, power policy, overlay placement, the tray and the settings
+/// window in line with settings that were replaced wholesale, by an import
+/// or a SIGHUP reload. Must run on the main thread.
+pub fn apply_reloaded_settings(app: &AppHandle, source: &str) {
+    let settings = get_settings(app);
+    let _ = set_log_level(app.clone(), settings.log_level);
+    let _ = set_log_transcripts(app.clone(), settings.log_transcripts);
+    crate::power_policy::apply(app);
+    crate::utils::update_overlay_position(app);
+    crate::utils::reset_overlay_size(app);
+    crate::tray::update_tray_menu(app, &crate::tray::current_state(app), None);
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": "all", "source": source }),
+    );
+}
+
+/// The most recent structured pipeline log events, oldest first, for the
+/// log viewer and bug reports.
+#[tauri::command]
+#[specta::specta]
+pub fn get_recent_log_events(limit: usize) -> Result<Vec<LogEvent>, String> {
+    Ok(crate::structured_log::recent_events(limit))
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_log_dir_path(app: AppHandle) -> Result<String, String> {
@@ -66,6 +155,66 @@ pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
     Ok(())
 }
 
+#[specta::specta]
+#[tauri::command]
+pub fn set_log_transcripts(app: AppHandle, enabled: bool) -> Result<(), String> {
+    crate::log_redaction::set_log_transcripts(enabled);
+
+    let mut settings = get_settings(&app);
+    settings.log_transcripts = enabled;
+    write_settings(&app, settings);
+
+    Ok(())
+}
+
+/// Set what happens to a binding's final text. An empty list, or pasting
+/// alone, goes back to the default.
+#[specta::specta]
+#[tauri::command]
+pub fn set_binding_output_actions(
+    app: AppHandle,
+    binding_id: String,
+    actions: Vec<OutputAction>,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if !settings.bindings.contains_key(&binding_id) {
+        return Err(format!("Unknown binding '{}'", binding_id));
+    }
+    for action in &actions {
+        crate::output_action::validate(action)?;
+    }
+
+    if actions.iter().all(|action| *action == OutputAction::Paste) {
+        settings.binding_output.remove(&binding_id);
+    } else {
+        settings.binding_output.insert(binding_id, actions);
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Set how profanity in a binding's output is masked.
+#[specta::specta]
+#[tauri::command]
+pub fn set_binding_profanity_mask(
+    app: AppHandle,
+    binding_id: String,
+    mask: ProfanityMask,
+) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+    if !settings.bindings.contains_key(&binding_id) {
+        return Err(format!("Unknown binding '{}'", binding_id));
+    }
+
+    if mask == ProfanityMask::Keep {
+        settings.binding_profanity.remove(&binding_id);
+    } else {
+        settings.binding_profanity.insert(binding_id, mask);
+    }
+    write_settings(&app, settings);
+    Ok(())
+}
+
 #[specta::specta]
 #[tauri::command]
 pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
@@ -116,6 +265,34 @@ pub fn open_app_data_dir(app: AppHandle) -> Result<(), String> {
     Ok(())
 }
 
+/// Check whether the app may send keystrokes (macOS Accessibility permission).
+/// Always true on other platforms.
+#[specta::specta]
+#[tauri::command]
+pub fn check_accessibility_permission() -> bool {
+    crate::input::is_accessibility_trusted()
+}
+
+/// Open the Accessibility pane of System Settings.
+#[specta::specta]
+#[tauri::command]
+pub fn open_accessibility_settings(app: AppHandle) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        app.opener()
+            .open_url(
+                "x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility",
+                None::<String>,
+            )
+            .map_err(|e| format!("Failed to open System Settings: {}", e))
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = app;
+        Err("Accessibility settings are only available on macOS".to_string())
+    }
+}
+
 /// Check if Apple Intelligence is available on this device.
 /// Called by the frontend when the user selects Apple Intelligence provider.
 #[specta::specta]
@@ -140,7 +317,7 @@ pub fn initialize_enigo(app: AppHandle) -> Result<(), String> {
 
     // Check if already initialized
     if app.try_state::<EnigoState>().is_some() {
-        log::debug!("Enigo already initialized");
+        tracing::debug!("Enigo already initialized");
         return Ok(());
     }
 
@@ -148,17 +325,17 @@ pub fn initialize_enigo(app: AppHandle) -> Result<(), String> {
     match EnigoState::new() {
         Ok(enigo_state) => {
             app.manage(enigo_state);
-            log::info!("Enigo initialized successfully after permission grant");
+            tracing::info!("Enigo initialized successfully after permission grant");
             Ok(())
         }
         Err(e) => {
             if cfg!(target_os = "macos") {
-                log::warn!(
+                tracing::warn!(
                     "Failed to initialize Enigo: {} (accessibility permissions may not be granted)",
                     e
                 );
             } else {
-                log::warn!("Failed to initialize Enigo: {}", e);
+                tracing::warn!("Failed to initialize Enigo: {}", e);
             }
             Err(format!("Failed to initialize input system: {}", e))
         }
@@ -176,7 +353,7 @@ pub struct ShortcutsInitialized;
 pub fn initialize_shortcuts(app: AppHandle) -> Result<(), String> {
     // Check if already initialized
     if app.try_state::<ShortcutsInitialized>().is_some() {
-        log::debug!("Shortcuts already initialized");
+        tracing::debug!("Shortcuts already initialized");
         return Ok(());
     }
 
@@ -186,6 +363,6 @@ pub fn initialize_shortcuts(app: AppHandle) -> Result<(), String> {
     // Mark as initialized
     app.manage(ShortcutsInitialized);
 
-    log::info!("Shortcuts initialized successfully");
+    tracing::info!("Shortcuts initialized successfully");
     Ok(())
 }
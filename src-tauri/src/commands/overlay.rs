@@ -0,0 +1,92 @@
+use crate::confirmation;
+use crate::overlay;
+use crate::settings::{OverlayLayout, OverlayPosition};
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct OverlayMonitor {
+    /// Index to store in `OverlayLayout::monitor`
+    pub index: u32,
+    pub name: String,
+    /// Logical work-area size, for bounding custom coordinates
+    pub width: f64,
+    pub height: f64,
+    pub is_primary: bool,
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn list_overlay_monitors(app: AppHandle) -> Result<Vec<OverlayMonitor>, String> {
+    let monitors = app
+        .available_monitors()
+        .map_err(|e| format!("Failed to list monitors: {}", e))?;
+    let primary_name = app
+        .primary_monitor()
+        .ok()
+        .flatten()
+        .and_then(|m| m.name().cloned());
+
+    Ok(monitors
+        .iter()
+        .enumerate()
+        .map(|(index, monitor)| {
+            let scale = monitor.scale_factor();
+            let work_area = monitor.work_area();
+            let name = monitor.name().cloned();
+            OverlayMonitor {
+                index: index as u32,
+                is_primary: name.is_some() && name == primary_name,
+                name: name.unwrap_or_else(|| format!("Display {}", index + 1)),
+                width: work_area.size.width as f64 / scale,
+                height: work_area.size.height as f64 / scale,
+            }
+        })
+        .collect())
+}
+
+/// Show the overlay with unsaved placement settings. Call repeatedly while the
+/// user adjusts controls, then `end_overlay_preview` when done.
+#[tauri::command]
+#[specta::specta]
+pub fn preview_overlay_layout(
+    app: AppHandle,
+    position: OverlayPosition,
+    layout: OverlayLayout,
+) -> Result<(), String> {
+    overlay::preview_overlay_placement(&app, position, layout);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn end_overlay_preview(app: AppHandle) -> Result<(), String> {
+    overlay::end_overlay_preview(&app);
+    Ok(())
+}
+
+/// Send the text held by confirmation mode, with `text` replacing it if the
+/// user edited it.
+#[tauri::command]
+#[specta::specta]
+pub fn send_pending_text(app: AppHandle, text: Option<String>) -> Result<(), String> {
+    overlay::set_overlay_editing(&app, false);
+    confirmation::send(text, true)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn discard_pending_text(app: AppHandle) -> Result<(), String> {
+    overlay::set_overlay_editing(&app, false);
+    confirmation::discard();
+    Ok(())
+}
+
+/// Give the overlay keyboard focus so the held text can be edited in place.
+#[tauri::command]
+#[specta::specta]
+pub fn edit_pending_text(app: AppHandle) -> Result<(), String> {
+    overlay::set_overlay_editing(&app, true);
+    Ok(())
+}
@@ -1,7 +1,13 @@
-use crate::managers::transcription::TranscriptionManager;
-use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
+use crate::file_transcription::{self, FileTranscription};
+use crate::managers::transcription::{ModelMemoryUsage, TranscriptionManager};
+use crate::power_policy;
+use crate::settings::{
+    get_settings, write_settings, BatteryPolicy, ModelKeepAlive, ModelUnloadTimeout,
+};
 use serde::Serialize;
 use specta::Type;
+use std::path::Path;
+use std::sync::Arc;
 use tauri::{AppHandle, State};
 
 #[derive(Serialize, Type)]
@@ -18,6 +24,49 @@ pub fn set_model_unload_timeout(app: AppHandle, timeout: ModelUnloadTimeout) {
     write_settings(&app, settings);
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn set_model_keep_alive(
+    app: AppHandle,
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+    policy: ModelKeepAlive,
+) {
+    let mut settings = get_settings(&app);
+    settings.model_keep_alive = policy;
+    write_settings(&app, settings);
+    // Don't wait for the next janitor pass to load or unload
+    transcription_manager.apply_keep_alive_policy();
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_battery_policy(app: AppHandle, policy: BatteryPolicy) {
+    let mut settings = get_settings(&app);
+    settings.battery_policy = policy;
+    write_settings(&app, settings);
+    // Takes effect right away if already on battery
+    power_policy::apply(&app);
+}
+
+/// Largest worker pool offered; more copies rarely pay off on desktop hardware.
+const MAX_TRANSCRIPTION_WORKERS: usize = 8;
+
+#[tauri::command]
+#[specta::specta]
+pub fn set_transcription_workers(app: AppHandle, workers: usize) {
+    let mut settings = get_settings(&app);
+    settings.transcription_workers = workers.clamp(1, MAX_TRANSCRIPTION_WORKERS);
+    write_settings(&app, settings);
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_model_memory_usage(
+    transcription_manager: State<'_, Arc<TranscriptionManager>>,
+) -> Result<ModelMemoryUsage, String> {
+    Ok(transcription_manager.memory_usage())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn get_model_load_status(
@@ -38,3 +87,15 @@ pub fn unload_model_manually(
         .unload_model()
         .map_err(|e| format!("Failed to unload model: {}", e))
 }
+
+/// Transcribe an audio file (wav, mp3, m4a or ogg) like a dictation, with
+/// progress in `file-transcription-progress` events.
+#[tauri::command]
+#[specta::specta]
+pub async fn transcribe_audio_file(
+    app: AppHandle,
+    path: String,
+    post_process: bool,
+) -> Result<FileTranscription, String> {
+    file_transcription::transcribe(&app, Path::new(&path), post_process).await
+}
@@ -0,0 +1,18 @@
+use crate::settings::get_settings;
+use crate::tts;
+use tauri::AppHandle;
+
+#[tauri::command]
+#[specta::specta]
+pub async fn list_tts_voices() -> Result<Vec<String>, String> {
+    tauri::async_runtime::spawn_blocking(tts::list_voices)
+        .await
+        .map_err(|e| format!("Voice listing task failed: {}", e))?
+}
+
+/// Speak `text` with the current voice and rate, so they can be tried out.
+#[tauri::command]
+#[specta::specta]
+pub fn preview_tts(app: AppHandle, text: String) -> Result<(), String> {
+    tts::speak(&get_settings(&app), &text)
+}
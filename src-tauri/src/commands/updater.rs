@@ -0,0 +1,28 @@
+use crate::updater::{self, UpdateInfo, UpdateStatus};
+use tauri::AppHandle;
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_update_status() -> UpdateStatus {
+    updater::status()
+}
+
+/// Check the selected channel now, regardless of the staged rollout.
+#[tauri::command]
+#[specta::specta]
+pub async fn check_for_updates(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    updater::check(&app, true).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn download_update(app: AppHandle) -> Result<(), String> {
+    updater::download(&app).await
+}
+
+/// Install the downloaded update and restart into it.
+#[tauri::command]
+#[specta::specta]
+pub fn apply_update(app: AppHandle) -> Result<(), String> {
+    updater::apply(&app)
+}
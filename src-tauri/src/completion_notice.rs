@@ -0,0 +1,132 @@
+//! Native notifications for finished dictations that may not have reached
+//! the user: the paste failed, or they switched to another app while the
+//! text was being processed. Long post-processed dictations would otherwise
+//! be lost silently.
+//!
+//! Desktop notifications can't carry action buttons, so the "copy" action
+//! happens up front: the transcript is put on the clipboard and the
+//! notification says so, showing its first line.
+
+use crate::settings::get_settings;
+use crate::tray_i18n::get_tray_translations;
+use crate::utils;
+use std::thread;
+use tauri::AppHandle;
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tauri_plugin_notification::NotificationExt;
+use tracing::{info, warn};
+
+/// Longest transcript preview shown in a notification, in characters.
+const PREVIEW_CHARS: usize = 120;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Notice {
+    PasteFailed,
+    FocusChanged,
+}
+
+/// Copy `text` to the clipboard and tell the user where it went. Private
+/// dictations are copied but their text is kept out of the notification,
+/// which the OS may keep in its notification center.
+pub fn send(app: &AppHandle, notice: Notice, text: &str, private: bool) {
+    let settings = get_settings(app);
+    if !settings.completion_notifications || text.trim().is_empty() {
+        return;
+    }
+
+    if let Err(e) = app.clipboard().write_text(text) {
+        warn!("Failed to copy transcript for notification: {}", e);
+        return;
+    }
+
+    let strings = get_tray_translations(Some(settings.app_language));
+    let title = match notice {
+        Notice::PasteFailed => strings.notification_paste_failed,
+        Notice::FocusChanged => strings.notification_focus_changed,
+    };
+    let mut notification = app.notification().builder().title(title);
+    if !private {
+        notification = notification.body(first_line(text, PREVIEW_CHARS));
+    }
+    match notification.show() {
+        Ok(()) => info!("Sent {:?} notification", notice),
+        Err(e) => warn!("Failed to show {:?} notification: {}", notice, e),
+    }
+}
+
+/// After a successful paste, check in the background whether the focused
+/// app is still the one recording started in, and notify if it isn't.
+/// Does nothing when the app at record start is unknown.
+pub fn notify_if_focus_moved(
+    app: &AppHandle,
+    started_in: Option<String>,
+    text: String,
+    private: bool,
+) {
+    let Some(started_in) = started_in else {
+        return;
+    };
+    if !get_settings(app).completion_notifications {
+        return;
+    }
+
+    let app = app.clone();
+    thread::spawn(move || {
+        let focused = utils::get_focused_app_name();
+        if focus_moved(&started_in, focused.as_deref()) {
+            info!(
+                "Focus moved from {:?} to {:?} during dictation",
+                started_in, focused
+            );
+            send(&app, Notice::FocusChanged, &text, private);
+        }
+    });
+}
+
+/// Whether focus moved away from `started_in`. An unknown current app
+/// counts as unchanged, so a failed lookup never notifies.
+fn focus_moved(started_in: &str, focused: Option<&str>) -> bool {
+    focused.is_some_and(|focused| !focused.eq_ignore_ascii_case(started_in))
+}
+
+/// First non-empty line of `text`, cut to `max_chars` with an ellipsis.
+/// An ellipsis is also added when lines were dropped.
+pub fn first_line(text: &str, max_chars: usize) -> String {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.next().unwrap_or_default();
+    let more_lines = lines.next().is_some();
+
+    if first.chars().count() > max_chars {
+        let cut: String = first.chars().take(max_chars).collect();
+        format!("{}…", cut.trim_end())
+    } else if more_lines {
+        format!("{}…", first)
+    } else {
+        first.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_line_skips_blank_lines_and_marks_the_rest() {
+        assert_eq!(first_line("hello world", 120), "hello world");
+        assert_eq!(first_line("\n  hello\nsecond line", 120), "hello…");
+        assert_eq!(first_line("", 120), "");
+    }
+
+    #[test]
+    fn first_line_truncates_on_char_boundaries() {
+        assert_eq!(first_line("привет мир", 6), "привет…");
+        assert_eq!(first_line("one two three", 8), "one two…");
+    }
+
+    #[test]
+    fn unknown_focus_is_not_a_move() {
+        assert!(!focus_moved("Slack", None));
+        assert!(!focus_moved("Code.exe", Some("code.exe")));
+        assert!(focus_moved("Slack", Some("Firefox")));
+    }
+}
@@ -0,0 +1,131 @@
+//! Confirmation mode: with `confirm_before_paste` on, a finished dictation
+//! is held in the overlay with Accept / Edit / Discard buttons instead of
+//! being pasted straight away, for chat apps where a misheard message is
+//! sent as soon as it lands.
+//!
+//! The pipeline waits in [`confirm`] without keeping the coordinator busy,
+//! so the answer can be dictated too: "send it" and "discard" answer the
+//! held text, corrections like "replace X with Y" edit it (see
+//! [`text_edit`](crate::text_edit)), anything else is added to it.
+
+use crate::continuation;
+use crate::overlay;
+use crate::text_edit;
+use crate::voice_commands::{self, ConfirmCommand};
+use once_cell::sync::Lazy;
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tokio::sync::oneshot;
+use tracing::{debug, info};
+
+/// What the user decided about the held text.
+#[derive(Debug, PartialEq)]
+pub enum Decision {
+    /// Paste `text`, which may have been edited. `from_overlay` when the
+    /// overlay buttons were used, which may have taken focus from the
+    /// target app.
+    Send {
+        text: String,
+        from_overlay: bool,
+    },
+    Discard,
+}
+
+struct Pending {
+    text: String,
+    /// Where the text the last dictation added starts, for "... that"
+    last_start: usize,
+    decide: oneshot::Sender<Decision>,
+}
+
+static PENDING: Lazy<Mutex<Option<Pending>>> = Lazy::new(|| Mutex::new(None));
+
+/// Hold `text` in the overlay until the user decides what to do with it.
+pub async fn confirm(app: &AppHandle, text: String) -> Decision {
+    let (decide, decision) = oneshot::channel();
+    let previous = PENDING.lock().unwrap().replace(Pending {
+        text: text.clone(),
+        last_start: 0,
+        decide,
+    });
+    if let Some(previous) = previous {
+        // One dictation is held at a time; it's in history if wanted
+        let _ = previous.decide.send(Decision::Discard);
+    }
+    info!("Holding dictation for confirmation");
+    overlay::show_confirm_overlay(app, &text);
+    decision.await.unwrap_or(Decision::Discard)
+}
+
+/// Send the held text, replaced by `edited` if the user changed it.
+pub fn send(edited: Option<String>, from_overlay: bool) -> Result<(), String> {
+    let pending = PENDING
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or("No dictation is waiting for confirmation")?;
+    let text = edited.unwrap_or(pending.text);
+    if text.trim().is_empty() {
+        let _ = pending.decide.send(Decision::Discard);
+        return Ok(());
+    }
+    pending
+        .decide
+        .send(Decision::Send { text, from_overlay })
+        .map_err(|_| "The dictation is no longer waiting".to_string())
+}
+
+/// Throw the held text away. Returns whether anything was held.
+pub fn discard() -> bool {
+    match PENDING.lock().unwrap().take() {
+        Some(pending) => {
+            info!("Held dictation discarded");
+            let _ = pending.decide.send(Decision::Discard);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Apply a dictation made while text is held: "send it" and "discard"
+/// answer it, a correction edits it, anything else is added to it. Returns
+/// false, leaving the dictation to be output as usual, if nothing is held.
+pub fn handle_dictation(app: &AppHandle, text: &str) -> bool {
+    match voice_commands::check_confirm_command(text) {
+        Some(ConfirmCommand::Send) => send(None, false).is_ok(),
+        Some(ConfirmCommand::Discard) => discard(),
+        None => {
+            let held = {
+                let mut pending = PENDING.lock().unwrap();
+                let Some(pending) = pending.as_mut() else {
+                    return false;
+                };
+                match text_edit::parse(text) {
+                    Some(command) => {
+                        match text_edit::apply(&pending.text, pending.last_start, &command) {
+                            Some(edited) => {
+                                debug!("Applied a correction to the held text");
+                                pending.text = edited;
+                                pending.last_start = text_edit::floor_char_boundary(
+                                    &pending.text,
+                                    pending.last_start,
+                                );
+                            }
+                            // Shown unchanged, so it's clear nothing happened
+                            None => debug!("Correction doesn't apply to the held text"),
+                        }
+                    }
+                    None => {
+                        let joined = continuation::join(&pending.text, text);
+                        pending.last_start =
+                            pending.text.len() + joined.len() - joined.trim_start().len();
+                        pending.text.push_str(&joined);
+                    }
+                }
+                pending.text.clone()
+            };
+            overlay::show_confirm_overlay(app, &held);
+            true
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Continue-dictation: with `continue_dictation` on, a dictation that starts
+//! within `continue_dictation_window_secs` of the last one pasted into the
+//! same app continues it as one block of text. The new text is joined onto
+//! the old with a space and cased to match: capitalized after a finished
+//! sentence, lowercased in the middle of one. Its history entry links to
+//! the previous dictation's.
+//!
+//! A "new paragraph" voice command (or any voice command that moves the
+//! cursor, like Enter) ends the block; the next dictation starts fresh.
+
+use crate::settings::AppSettings;
+use crate::voice_commands::{KeyAction, VoiceAction};
+use once_cell::sync::Lazy;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// How much of the block's end is kept; enough to see how it ended.
+const TAIL_CHARS: usize = 16;
+
+/// The block of text the last dictations were pasted into.
+struct Block {
+    /// Dictation that last extended the block, see [`next_seq`].
+    seq: u64,
+    /// End of the text inserted so far.
+    tail: String,
+    app_name: Option<String>,
+    updated_at: Instant,
+    /// History entry of the last dictation, once it is saved.
+    entry_id: Option<i64>,
+}
+
+/// What a new dictation continues.
+#[derive(Debug, Clone)]
+pub struct Continuation {
+    pub tail: String,
+    pub entry_id: Option<i64>,
+}
+
+static BLOCK: Lazy<Mutex<Option<Block>>> = Lazy::new(|| Mutex::new(None));
+static SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Id for a dictation, used to attach its history entry to the block only
+/// if no newer dictation has extended it meanwhile.
+pub fn next_seq() -> u64 {
+    SEQ.fetch_add(1, Ordering::Relaxed) + 1
+}
+
+/// The block a dictation into `app_name` continues, if continue-dictation
+/// is on and the last one was pasted there recently enough.
+pub fn current(settings: &AppSettings, app_name: Option<&str>) -> Option<Continuation> {
+    if !settings.continue_dictation {
+        return None;
+    }
+    let window = Duration::from_secs(settings.continue_dictation_window_secs.into());
+    let block = BLOCK.lock().unwrap();
+    let block = block.as_ref()?;
+    if block.updated_at.elapsed() > window || block.app_name.as_deref() != app_name {
+        return None;
+    }
+    Some(Continuation {
+        tail: block.tail.clone(),
+        entry_id: block.entry_id,
+    })
+}
+
+/// Remember text that was just pasted. It extends the block when it was
+/// joined onto it (`continued`), and starts a new one otherwise.
+pub fn record(seq: u64, app_name: Option<String>, inserted: &str, continued: bool) {
+    let mut block = BLOCK.lock().unwrap();
+    let tail = match block.as_ref() {
+        Some(previous) if continued => format!("{}{}", previous.tail, inserted),
+        _ => inserted.to_string(),
+    };
+    *block = Some(Block {
+        seq,
+        tail: keep_tail(&tail),
+        app_name,
+        updated_at: Instant::now(),
+        entry_id: None,
+    });
+}
+
+/// Link the history entry of dictation `seq`, so the next one can point
+/// back to it.
+pub fn set_entry_id(seq: u64, entry_id: i64) {
+    if let Some(block) = BLOCK.lock().unwrap().as_mut() {
+        if block.seq == seq {
+            block.entry_id = Some(entry_id);
+        }
+    }
+}
+
+/// Keep the block in step with a voice command that just ran: typed
+/// punctuation extends it, anything that breaks the line or moves the
+/// cursor ends it.
+pub fn after_command(action: &VoiceAction) {
+    let mut block = BLOCK.lock().unwrap();
+    match (action, block.as_mut()) {
+        (VoiceAction::TypeText(text), Some(current)) if !text.contains('\n') => {
+            current.tail = keep_tail(&format!("{}{}", current.tail, text));
+            current.updated_at = Instant::now();
+        }
+        (VoiceAction::KeyPress(KeyAction::Space), Some(current)) => {
+            current.tail = keep_tail(&format!("{} ", current.tail));
+            current.updated_at = Instant::now();
+        }
+        (_, Some(_)) => {
+            debug!("Voice command ended the dictation block");
+            *block = None;
+        }
+        (_, None) => {}
+    }
+}
+
+fn keep_tail(text: &str) -> String {
+    let skip = text.chars().count().saturating_sub(TAIL_CHARS);
+    text.chars().skip(skip).collect()
+}
+
+/// `text` as it should be pasted after `tail`: with a separating space
+/// unless one is already there or `text` starts with punctuation, and its
+/// first letter cased for where the previous text left off.
+pub fn join(tail: &str, text: &str) -> String {
+    let text = text.trim_start();
+    if text.is_empty() {
+        return String::new();
+    }
+
+    let needs_space = !tail.is_empty()
+        && !tail.ends_with(char::is_whitespace)
+        && !text.starts_with(|c: char| ",.;:!?)…".contains(c));
+    let ends_sentence = tail
+        .trim_end()
+        .chars()
+        .last()
+        .map_or(true, |c| ".!?…".contains(c));
+
+    let mut joined = String::with_capacity(text.len() + 1);
+    if needs_space {
+        joined.push(' ');
+    }
+    if ends_sentence {
+        joined.push_str(&capitalize(text));
+    } else if keeps_capital(text) {
+        joined.push_str(text);
+    } else {
+        joined.push_str(&lowercase_first(text));
+    }
+    joined
+}
+
+/// Words that stay capitalized mid-sentence: "I", "I'm", and acronyms.
+fn keeps_capital(text: &str) -> bool {
+    let word: String = text.chars().take_while(|c| c.is_alphanumeric()).collect();
+    word == "I" || (word.chars().count() > 1 && word.chars().all(|c| !c.is_lowercase()))
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_a_new_sentence_after_terminal_punctuation() {
+        assert_eq!(join("went home.", "then we ate."), " Then we ate.");
+        assert_eq!(join("Really?", "Yes."), " Yes.");
+        assert_eq!(join("", "hello"), "Hello");
+    }
+
+    #[test]
+    fn continues_a_sentence_in_lowercase() {
+        assert_eq!(join("we went home", "And then ate."), " and then ate.");
+        assert_eq!(join("and then", "I left."), " I left.");
+        assert_eq!(join("send it to", "NASA today."), " NASA today.");
+        assert_eq!(join("привет", "Как дела?"), " как дела?");
+    }
+
+    #[test]
+    fn does_not_double_spaces_or_space_before_punctuation() {
+        assert_eq!(join("went home. ", "Then"), "Then");
+        assert_eq!(join("and so on", ", right?"), ", right?");
+        assert_eq!(join("line\n", "next"), "next");
+    }
+
+    #[test]
+    fn keeps_only_the_end_of_the_block() {
+        let tail = keep_tail("a long dictation that ends here.");
+        assert_eq!(tail.chars().count(), TAIL_CHARS);
+        assert!(tail.ends_with("here."));
+    }
+}
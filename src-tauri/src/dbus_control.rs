@@ -0,0 +1,133 @@
+//! D-Bus control interface on the session bus, for desktop extensions and
+//! scripts that would rather not send raw signals:
+//!
+//! ```text
+//! busctl --user call org.voiceinput.Control /org/voiceinput/Control \
+//!     org.voiceinput.Control StartRecording
+//! ```
+//!
+//! Recording goes through the [`TranscriptionCoordinator`] like a
+//! push-to-talk press/release of the `transcribe` binding, so the busy
+//! behavior and debouncing apply. `TranscriptionFinished` carries the final
+//! text of every non-private dictation.
+
+use crate::transcription_coordinator::{InputMode, Stage};
+use crate::utils::cancel_current_operation;
+use crate::TranscriptionCoordinator;
+use once_cell::sync::OnceCell;
+use tauri::{AppHandle, Manager};
+use tracing::{info, warn};
+use zbus::object_server::SignalEmitter;
+use zbus::{connection, interface, Connection};
+
+const BUS_NAME: &str = "org.voiceinput.Control";
+const OBJECT_PATH: &str = "/org/voiceinput/Control";
+/// Binding started by `StartRecording`.
+const BINDING: &str = "transcribe";
+/// Reported as the hotkey string of D-Bus input.
+const SOURCE: &str = "dbus";
+
+static CONNECTION: OnceCell<Connection> = OnceCell::new();
+
+struct Control {
+    app: AppHandle,
+}
+
+#[interface(name = "org.voiceinput.Control")]
+impl Control {
+    /// Start recording; ignored while already recording.
+    fn start_recording(&self) {
+        if let Some(coordinator) = self.app.try_state::<TranscriptionCoordinator>() {
+            coordinator.send_input(BINDING, SOURCE, true, InputMode::PushToTalk);
+        }
+    }
+
+    /// Stop the current recording and transcribe it, whichever binding
+    /// started it.
+    fn stop_recording(&self) {
+        if let Some(coordinator) = self.app.try_state::<TranscriptionCoordinator>() {
+            if let Stage::Recording(binding_id) = coordinator.stage() {
+                coordinator.send_input(&binding_id, SOURCE, false, InputMode::PushToTalk);
+            }
+        }
+    }
+
+    /// Discard the current recording or transcription.
+    fn cancel(&self) {
+        cancel_current_operation(&self.app);
+    }
+
+    /// "idle", "recording" or "processing".
+    fn get_state(&self) -> String {
+        self.app
+            .try_state::<TranscriptionCoordinator>()
+            .map_or("idle", |coordinator| state_name(&coordinator.stage()))
+            .to_string()
+    }
+
+    #[zbus(signal)]
+    async fn transcription_finished(emitter: &SignalEmitter<'_>, text: &str) -> zbus::Result<()>;
+}
+
+fn state_name(stage: &Stage) -> &'static str {
+    match stage {
+        Stage::Idle => "idle",
+        Stage::Recording(_) => "recording",
+        Stage::Processing => "processing",
+    }
+}
+
+/// Claim the bus name and serve the interface. Failures (no session bus,
+/// name taken by another instance) are logged and otherwise ignored.
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match connect(app).await {
+            Ok(connection) => {
+                let _ = CONNECTION.set(connection);
+                info!("D-Bus control interface available as {}", BUS_NAME);
+            }
+            Err(e) => warn!("D-Bus control interface unavailable: {}", e),
+        }
+    });
+}
+
+async fn connect(app: AppHandle) -> zbus::Result<Connection> {
+    connection::Builder::session()?
+        .name(BUS_NAME)?
+        .serve_at(OBJECT_PATH, Control { app })?
+        .build()
+        .await
+}
+
+/// Broadcast the final text of a dictation.
+pub fn transcription_finished(text: &str) {
+    let Some(connection) = CONNECTION.get().cloned() else {
+        return;
+    };
+    let text = text.to_string();
+    tauri::async_runtime::spawn(async move {
+        let result = match SignalEmitter::new(&connection, OBJECT_PATH) {
+            Ok(emitter) => Control::transcription_finished(&emitter, &text).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!("Failed to emit TranscriptionFinished: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn state_names_match_the_documented_values() {
+        assert_eq!(state_name(&Stage::Idle), "idle");
+        assert_eq!(
+            state_name(&Stage::Recording(BINDING.to_string())),
+            "recording"
+        );
+        assert_eq!(state_name(&Stage::Processing), "processing");
+    }
+}
@@ -0,0 +1,319 @@
+//! Pre-flight microphone checks.
+//!
+//! Most "nothing happens when I dictate" reports come down to a missing OS
+//! permission, a disconnected device or a muted input. [`run_microphone_diagnostics`]
+//! checks each of those in turn: the platform permission (macOS TCC, Windows
+//! privacy settings), opening the configured device, and the level of a short
+//! capture. It runs once on first launch and from the "Test microphone" button.
+
+use crate::app_error::{self, AppError};
+use crate::audio_toolkit::audio::{AudioRecorder, InputLevel};
+use crate::audio_toolkit::get_cpal_host;
+use crate::managers::audio::AudioRecordingManager;
+use crate::settings::{get_settings, write_settings};
+use cpal::traits::{DeviceTrait, HostTrait};
+use serde::Serialize;
+use specta::Type;
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{info, warn};
+
+/// Length of the test capture.
+const CAPTURE_DURATION: Duration = Duration::from_millis(1200);
+/// Captures quieter than this are reported as silent.
+const SILENCE_THRESHOLD_DB: f32 = -60.0;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionStatus {
+    Granted,
+    Denied,
+    /// The platform has a permission but it could not be read
+    Unknown,
+    /// The platform has no microphone permission (Linux)
+    NotRequired,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum MicrophoneVerdict {
+    Ok,
+    PermissionDenied,
+    NoDevice,
+    StreamFailed,
+    /// The stream opened but delivered no audio
+    NoSamples,
+    Silent,
+    Clipping,
+}
+
+impl MicrophoneVerdict {
+    /// Whether recording cannot work at all, as opposed to a quiet or loud input.
+    pub fn blocks_recording(self) -> bool {
+        matches!(
+            self,
+            MicrophoneVerdict::PermissionDenied
+                | MicrophoneVerdict::NoDevice
+                | MicrophoneVerdict::StreamFailed
+                | MicrophoneVerdict::NoSamples
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct MicrophoneDiagnostics {
+    pub verdict: MicrophoneVerdict,
+    pub permission: PermissionStatus,
+    pub device_name: Option<String>,
+    pub sample_rate: Option<u32>,
+    pub channels: Option<u16>,
+    pub samples_received: u32,
+    pub rms_db: Option<f32>,
+    pub peak: Option<f32>,
+    pub error: Option<String>,
+}
+
+impl MicrophoneDiagnostics {
+    fn new(verdict: MicrophoneVerdict, permission: PermissionStatus) -> Self {
+        Self {
+            verdict,
+            permission,
+            device_name: None,
+            sample_rate: None,
+            channels: None,
+            samples_received: 0,
+            rms_db: None,
+            peak: None,
+            error: None,
+        }
+    }
+
+    fn summary(&self) -> String {
+        match self.verdict {
+            MicrophoneVerdict::Ok => "Microphone is working".to_string(),
+            MicrophoneVerdict::PermissionDenied => {
+                "Microphone access is denied in the system privacy settings".to_string()
+            }
+            MicrophoneVerdict::NoDevice => "No input device found".to_string(),
+            MicrophoneVerdict::StreamFailed => format!(
+                "Could not open the microphone: {}",
+                self.error.as_deref().unwrap_or("unknown error")
+            ),
+            MicrophoneVerdict::NoSamples => {
+                "The microphone delivered no audio; check the system privacy settings".to_string()
+            }
+            MicrophoneVerdict::Silent => {
+                "The microphone is silent; check that it is not muted".to_string()
+            }
+            MicrophoneVerdict::Clipping => {
+                "The input is clipping; lower the input gain".to_string()
+            }
+        }
+    }
+}
+
+/// Classify the level of a capture that delivered samples.
+fn verdict_for_level(level: &InputLevel) -> MicrophoneVerdict {
+    if level.rms_db < SILENCE_THRESHOLD_DB {
+        MicrophoneVerdict::Silent
+    } else if level.clipping {
+        MicrophoneVerdict::Clipping
+    } else {
+        MicrophoneVerdict::Ok
+    }
+}
+
+#[cfg(target_os = "macos")]
+async fn check_permission() -> PermissionStatus {
+    if tauri_plugin_macos_permissions::check_microphone_permission().await {
+        PermissionStatus::Granted
+    } else {
+        PermissionStatus::Denied
+    }
+}
+
+#[cfg(target_os = "windows")]
+async fn check_permission() -> PermissionStatus {
+    const CONSENT_KEY: &str =
+        "Software\\Microsoft\\Windows\\CurrentVersion\\CapabilityAccessManager\\ConsentStore\\microphone";
+
+    // Both the global toggle and the "Let desktop apps access your microphone"
+    // toggle have to allow access
+    let global = windows_consent_value(CONSENT_KEY);
+    let desktop = windows_consent_value(&format!("{}\\NonPackaged", CONSENT_KEY));
+
+    match (global.as_deref(), desktop.as_deref()) {
+        (Some("Deny"), _) | (_, Some("Deny")) => PermissionStatus::Denied,
+        (Some(_), _) => PermissionStatus::Granted,
+        (None, _) => PermissionStatus::Unknown,
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn windows_consent_value(subkey: &str) -> Option<String> {
+    use windows::core::{HSTRING, PCWSTR};
+    use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_SZ};
+
+    let subkey = HSTRING::from(subkey);
+    let value = HSTRING::from("Value");
+    let mut buffer = [0u16; 32];
+    let mut size = std::mem::size_of_val(&buffer) as u32;
+
+    let status = unsafe {
+        RegGetValueW(
+            HKEY_CURRENT_USER,
+            PCWSTR(subkey.as_ptr()),
+            PCWSTR(value.as_ptr()),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr().cast()),
+            Some(&mut size),
+        )
+    };
+    if status.is_err() {
+        return None;
+    }
+
+    // `size` is in bytes and includes the terminating NUL
+    let len = (size as usize / 2).saturating_sub(1);
+    Some(String::from_utf16_lossy(&buffer[..len]))
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+async fn check_permission() -> PermissionStatus {
+    PermissionStatus::NotRequired
+}
+
+/// Check permission, open the configured microphone and measure a short capture.
+pub async fn run_microphone_diagnostics(app: &AppHandle) -> Result<MicrophoneDiagnostics, String> {
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    if rm.is_recording() {
+        return Err("Cannot test the microphone while recording".to_string());
+    }
+
+    let permission = check_permission().await;
+    if permission == PermissionStatus::Denied {
+        return Ok(MicrophoneDiagnostics::new(
+            MicrophoneVerdict::PermissionDenied,
+            permission,
+        ));
+    }
+
+    let settings = get_settings(app);
+    let device = rm
+        .get_effective_microphone_device(&settings)
+        .or_else(|| get_cpal_host().default_input_device());
+    let Some(device) = device else {
+        return Ok(MicrophoneDiagnostics::new(
+            MicrophoneVerdict::NoDevice,
+            permission,
+        ));
+    };
+
+    let mut result = MicrophoneDiagnostics::new(MicrophoneVerdict::Ok, permission);
+    result.device_name = device.name().ok();
+
+    let capture = tauri::async_runtime::spawn_blocking(move || {
+        AudioRecorder::capture_for(&device, CAPTURE_DURATION).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| format!("Microphone test task failed: {}", e))?;
+
+    match capture {
+        Ok(audio) => {
+            result.sample_rate = Some(audio.sample_rate);
+            result.channels = Some(audio.channels);
+            result.samples_received = audio.samples.len() as u32;
+
+            if audio.samples.is_empty() {
+                result.verdict = MicrophoneVerdict::NoSamples;
+            } else {
+                let level = InputLevel::measure(&audio.samples);
+                result.rms_db = Some(level.rms_db);
+                result.peak = Some(level.peak);
+                result.verdict = verdict_for_level(&level);
+            }
+        }
+        Err(e) => {
+            result.verdict = MicrophoneVerdict::StreamFailed;
+            result.error = Some(e);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Run the diagnostics once after the first launch and report problems.
+///
+/// The check stays pending while permission has not been granted so it does
+/// not pre-empt the onboarding permission prompt.
+pub fn run_first_launch_check(app: &AppHandle) {
+    if get_settings(app).microphone_check_completed {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let diagnostics = match run_microphone_diagnostics(&app).await {
+            Ok(diagnostics) => diagnostics,
+            Err(e) => {
+                warn!("Skipping first-launch microphone check: {}", e);
+                return;
+            }
+        };
+
+        if diagnostics.verdict == MicrophoneVerdict::PermissionDenied {
+            info!("Microphone permission not granted yet, deferring microphone check");
+            return;
+        }
+
+        let mut settings = get_settings(&app);
+        settings.microphone_check_completed = true;
+        write_settings(&app, settings);
+
+        info!("First-launch microphone check: {:?}", diagnostics);
+        let _ = app.emit("microphone-diagnostics", &diagnostics);
+
+        // The user isn't speaking on first launch, so only hard failures count
+        if diagnostics.verdict.blocks_recording() {
+            app_error::report(
+                &app,
+                AppError::MicrophoneUnavailable {
+                    message: diagnostics.summary(),
+                },
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_capture_levels() {
+        assert_eq!(
+            verdict_for_level(&InputLevel::measure(&[0.0; 100])),
+            MicrophoneVerdict::Silent
+        );
+        assert_eq!(
+            verdict_for_level(&InputLevel::measure(&[0.1, -0.1, 0.05, -0.05])),
+            MicrophoneVerdict::Ok
+        );
+        assert_eq!(
+            verdict_for_level(&InputLevel::measure(&[0.2, 1.0, -0.3, 0.1])),
+            MicrophoneVerdict::Clipping
+        );
+    }
+
+    #[test]
+    fn serializes_verdict_in_snake_case() {
+        let diagnostics =
+            MicrophoneDiagnostics::new(MicrophoneVerdict::NoSamples, PermissionStatus::Unknown);
+        let json = serde_json::to_value(&diagnostics).unwrap();
+        assert_eq!(json["verdict"], "no_samples");
+        assert_eq!(json["permission"], "unknown");
+        assert_eq!(json["samples_received"], 0);
+    }
+}
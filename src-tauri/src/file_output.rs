@@ -0,0 +1,168 @@
+//! Append-to-file output action: a binding with
+//! [`OutputAction::AppendToFile`] writes its final text to a file (a daily
+//! notes page, a journal in an Obsidian vault), usually instead of pasting it.
+//!
+//! The path and the header line above each entry are rendered like
+//! post-processing prompts, so `~/notes/${date}.md` with the header
+//! `## ${time}` gives one file per day with a timestamped entry per dictation.
+//!
+//! [`OutputAction::AppendToFile`]: crate::settings::OutputAction::AppendToFile
+
+use crate::prompt_template::{self, PromptVariables};
+use crate::settings::AppSettings;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use tracing::debug;
+
+/// Append `text` under a rendered `header` to the file at `path`, creating
+/// the file and its parent directories as needed. Returns the file written.
+pub fn append(
+    app: &AppHandle,
+    settings: &AppSettings,
+    path: &str,
+    header: &str,
+    text: &str,
+    app_name: Option<&str>,
+) -> Result<PathBuf, String> {
+    let now = chrono::Local::now();
+    let vars = PromptVariables {
+        language: settings.selected_language.clone(),
+        app_name: app_name.map(str::to_string),
+        date: now.format("%Y-%m-%d").to_string(),
+        time: now.format("%H:%M").to_string(),
+        ..Default::default()
+    };
+
+    let home = app.path().home_dir().ok();
+    let path = resolve_path(&prompt_template::render(path, &vars), home.as_deref())?;
+    let header = prompt_template::render(header, &vars);
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create {}: {}", parent.display(), e))?;
+    }
+    let mut file = OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(&path)
+        .map_err(|e| format!("could not open {}: {}", path.display(), e))?;
+
+    let entry = format_entry(&header, text, last_byte(&mut file));
+    file.write_all(entry.as_bytes())
+        .map_err(|e| format!("could not write to {}: {}", path.display(), e))?;
+    debug!("Appended {} bytes to {}", entry.len(), path.display());
+
+    Ok(path)
+}
+
+/// Expand a leading `~/` and insist on an absolute path, so output never
+/// lands relative to whatever the working directory happens to be.
+pub(crate) fn resolve_path(path: &str, home: Option<&Path>) -> Result<PathBuf, String> {
+    let path = path.trim();
+    if path.is_empty() {
+        return Err("no output file configured".to_string());
+    }
+
+    let resolved = match (path.strip_prefix("~/"), home) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    };
+    if !resolved.is_absolute() {
+        return Err(format!("'{}' is not an absolute path", path));
+    }
+    Ok(resolved)
+}
+
+/// Last byte of the file, `None` when it is empty or can't be read.
+fn last_byte(file: &mut fs::File) -> Option<u8> {
+    file.seek(SeekFrom::End(-1)).ok()?;
+    let mut byte = [0u8; 1];
+    file.read_exact(&mut byte).ok()?;
+    Some(byte[0])
+}
+
+/// The text to append, given the last byte already in the file. Entries
+/// with a header are separated by a blank line, so Markdown headings stay
+/// headings; without one, each dictation is simply a new line.
+fn format_entry(header: &str, text: &str, last_byte: Option<u8>) -> String {
+    let header = header.trim_end();
+    let text = text.trim_end();
+
+    let mut entry = String::new();
+    match last_byte {
+        None => {}
+        Some(b'\n') if header.is_empty() => {}
+        Some(b'\n') => entry.push('\n'),
+        Some(_) if header.is_empty() => entry.push('\n'),
+        Some(_) => entry.push_str("\n\n"),
+    }
+    if !header.is_empty() {
+        entry.push_str(header);
+        entry.push('\n');
+    }
+    entry.push_str(text);
+    entry.push('\n');
+    entry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_with_a_header_are_separated_by_a_blank_line() {
+        assert_eq!(format_entry("## 09:30", "hello", None), "## 09:30\nhello\n");
+        assert_eq!(
+            format_entry("## 09:30", "hello", Some(b'\n')),
+            "\n## 09:30\nhello\n"
+        );
+        assert_eq!(
+            format_entry("## 09:30", "hello", Some(b'.')),
+            "\n\n## 09:30\nhello\n"
+        );
+    }
+
+    #[test]
+    fn entries_without_a_header_are_one_per_line() {
+        assert_eq!(format_entry("", "hello \n", None), "hello\n");
+        assert_eq!(format_entry("  ", "hello", Some(b'\n')), "hello\n");
+        assert_eq!(format_entry("", "hello", Some(b'.')), "\nhello\n");
+    }
+
+    #[test]
+    fn resolves_home_and_rejects_relative_paths() {
+        let home = Path::new("/home/me");
+        assert_eq!(
+            resolve_path("~/notes/today.md", Some(home)).unwrap(),
+            PathBuf::from("/home/me/notes/today.md")
+        );
+        assert!(resolve_path("notes/today.md", Some(home)).is_err());
+        assert!(resolve_path("~/notes.md", None).is_err());
+        assert!(resolve_path("  ", Some(home)).is_err());
+    }
+
+    #[test]
+    fn appends_after_existing_content() {
+        let dir = std::env::temp_dir().join(format!("file-output-{}", std::process::id()));
+        let path = dir.join("notes.md");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "# Notes").unwrap();
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .open(&path)
+            .unwrap();
+        let entry = format_entry("## 09:30", "hello", last_byte(&mut file));
+        file.write_all(entry.as_bytes()).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# Notes\n\n## 09:30\nhello\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,407 @@
+//! Transcription of audio files that already exist (voice memos, meeting
+//! recordings) through the same model, post-processing and history as a
+//! dictation. The file is decoded, mixed down to mono and resampled to
+//! 16 kHz, then transcribed in chunks on the transcription worker pool so
+//! progress can be reported in [`PROGRESS_EVENT`] events:
+//!
+//! ```text
+//! decoding 0.0 … 1.0 → transcribing 0.0 … 1.0 → post_processing
+//! ```
+//!
+//! Nothing is pasted; the text is returned to the caller and saved to the
+//! history unless private mode is on.
+
+use crate::actions;
+use crate::audio_toolkit::audio::FrameResampler;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::audio_toolkit::normalize_numbers;
+use crate::latency::{self, DictationTimings};
+use crate::managers::history::HistoryManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::profanity::ProfanityFilter;
+use crate::settings::get_settings;
+use serde::Serialize;
+use specta::Type;
+use std::fs::File;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, error, info};
+
+/// Event carrying a [`FileTranscriptionProgress`].
+pub const PROGRESS_EVENT: &str = "file-transcription-progress";
+
+/// Extensions of the formats that can be transcribed.
+pub const SUPPORTED_EXTENSIONS: &[&str] = &["wav", "mp3", "m4a", "ogg"];
+
+/// Binding whose profanity setting applies to files.
+const BINDING: &str = "transcribe";
+
+/// Length of the decoded frames handed on by the resampler.
+const RESAMPLE_FRAME: Duration = Duration::from_millis(100);
+/// Longer files are saved to the history without their audio; the
+/// original is still on disk.
+const HISTORY_AUDIO_MAX_SECS: usize = 10 * 60;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FileTranscriptionStage {
+    Decoding,
+    Transcribing,
+    PostProcessing,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct FileTranscriptionProgress {
+    pub file: String,
+    pub stage: FileTranscriptionStage,
+    /// Share of the stage that is done, from 0 to 1.
+    pub progress: f64,
+}
+
+/// The outcome of transcribing a file.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct FileTranscription {
+    /// The text as it would have been pasted.
+    pub text: String,
+    pub duration_secs: f64,
+    /// The history entry, unless private mode kept it out.
+    pub history_id: Option<i64>,
+}
+
+/// Set while a file is being transcribed; one file at a time.
+static BUSY: AtomicBool = AtomicBool::new(false);
+
+struct BusyGuard;
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        BUSY.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Emits progress at most once per percent, so long files don't flood the
+/// frontend with events.
+struct Progress<'a> {
+    app: &'a AppHandle,
+    file: String,
+    stage: FileTranscriptionStage,
+    percent: Option<u32>,
+}
+
+impl<'a> Progress<'a> {
+    fn new(app: &'a AppHandle, file: &str, stage: FileTranscriptionStage) -> Self {
+        let mut progress = Self {
+            app,
+            file: file.to_string(),
+            stage,
+            percent: None,
+        };
+        progress.report(0.0);
+        progress
+    }
+
+    fn report(&mut self, fraction: f64) {
+        let fraction = fraction.clamp(0.0, 1.0);
+        let percent = (fraction * 100.0) as u32;
+        if self.percent == Some(percent) {
+            return;
+        }
+        self.percent = Some(percent);
+        let _ = self.app.emit(
+            PROGRESS_EVENT,
+            FileTranscriptionProgress {
+                file: self.file.clone(),
+                stage: self.stage,
+                progress: fraction,
+            },
+        );
+    }
+}
+
+/// Whether `path` has the extension of a supported format.
+pub fn is_supported(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| {
+            SUPPORTED_EXTENSIONS
+                .iter()
+                .any(|supported| ext.eq_ignore_ascii_case(supported))
+        })
+}
+
+/// Transcribe the file at `path`, optionally running the LLM
+/// post-processing of the `transcribe_with_post_process` binding.
+pub async fn transcribe(
+    app: &AppHandle,
+    path: &Path,
+    post_process: bool,
+) -> Result<FileTranscription, String> {
+    if !is_supported(path) {
+        return Err(format!(
+            "Unsupported audio file {}; use {}",
+            path.display(),
+            SUPPORTED_EXTENSIONS.join(", ")
+        ));
+    }
+    if BUSY.swap(true, Ordering::SeqCst) {
+        return Err("Another file is already being transcribed".to_string());
+    }
+    let _busy = BusyGuard;
+
+    let settings = get_settings(app);
+    let private = settings.private_mode;
+    let file = path.to_string_lossy().into_owned();
+    info!("Transcribing file {}", file);
+
+    let tm = app.state::<Arc<TranscriptionManager>>().inner().clone();
+    // The model loads while the file is decoded
+    tm.initiate_model_load();
+
+    let (app_handle, name, decode_path) = (app.clone(), file.clone(), path.to_path_buf());
+    let samples = tauri::async_runtime::spawn_blocking(move || {
+        let mut progress = Progress::new(&app_handle, &name, FileTranscriptionStage::Decoding);
+        let samples = decode(&decode_path, |fraction| progress.report(fraction))?;
+        progress.report(1.0);
+        Ok::<_, String>(samples)
+    })
+    .await
+    .map_err(|e| format!("Decoding failed: {}", e))??;
+
+    let sample_rate = WHISPER_SAMPLE_RATE as usize;
+    let duration_secs = samples.len() as f64 / sample_rate as f64;
+    debug!("Decoded {:.1}s of audio", duration_secs);
+
+    // Longer files are saved to the history without their audio
+    let history_audio = (!private && samples.len() <= HISTORY_AUDIO_MAX_SECS * sample_rate)
+        .then(|| samples.clone());
+
+    let mut timings = DictationTimings::default();
+    let transcription_time = Instant::now();
+    let (app_handle, name) = (app.clone(), file.clone());
+    let transcription = tauri::async_runtime::spawn_blocking(move || {
+        let text = transcribe_chunks(&app_handle, &tm, &name, samples, private);
+        tm.maybe_unload_immediately("file transcription");
+        text
+    })
+    .await
+    .map_err(|e| format!("Transcription failed: {}", e))??;
+    timings.transcribe_ms = Some(latency::elapsed_ms(transcription_time));
+
+    if transcription.is_empty() {
+        return Err("No speech detected".to_string());
+    }
+
+    Progress::new(app, &file, FileTranscriptionStage::PostProcessing);
+    let post_process_time = Instant::now();
+    let (final_text, post_processed_text, post_process_prompt, steps) =
+        actions::apply_post_processing(
+            app,
+            &settings,
+            &transcription,
+            post_process,
+            false,
+            private,
+        )
+        .await;
+    timings.post_process_ms = Some(latency::elapsed_ms(post_process_time));
+
+    let final_text = if settings.number_normalization {
+        normalize_numbers(&final_text, &settings.selected_language)
+    } else {
+        final_text
+    };
+    let profanity = ProfanityFilter::for_binding(&settings, BINDING);
+    let text = profanity.apply(&final_text);
+
+    let history_id = if private {
+        debug!("Private mode: skipping history save");
+        None
+    } else {
+        let steps = steps
+            .into_iter()
+            .map(|mut step| {
+                step.output = profanity.apply(&step.output);
+                step
+            })
+            .collect();
+        let hm = app.state::<Arc<HistoryManager>>().inner().clone();
+        match hm
+            .save_transcription(
+                history_audio,
+                profanity.apply(&transcription),
+                post_processed_text.as_deref().map(|t| profanity.apply(t)),
+                post_process_prompt,
+                steps,
+                timings,
+                None,
+            )
+            .await
+        {
+            Ok(id) => Some(id),
+            Err(e) => {
+                error!("Failed to save file transcription to history: {}", e);
+                None
+            }
+        }
+    };
+
+    info!("Transcribed {:.1}s from {}", duration_secs, file);
+    Ok(FileTranscription {
+        text,
+        duration_secs,
+        history_id,
+    })
+}
+
+/// Transcribe `samples` on the transcription worker pool, reporting progress
+/// as chunks finish.
+fn transcribe_chunks(
+    app: &AppHandle,
+    tm: &TranscriptionManager,
+    file: &str,
+    samples: Vec<f32>,
+    private: bool,
+) -> Result<String, String> {
+    let progress = Mutex::new(Progress::new(
+        app,
+        file,
+        FileTranscriptionStage::Transcribing,
+    ));
+    tm.transcribe_long(samples, private, &|fraction| {
+        progress.lock().unwrap().report(fraction)
+    })
+    .map(|text| text.trim().to_string())
+    .map_err(|e| e.to_string())
+}
+
+/// Decode the first audio track of `path` to mono 16 kHz samples.
+/// `on_progress` gets the share decoded so far, when the length is known.
+fn decode(path: &Path, mut on_progress: impl FnMut(f64)) -> Result<Vec<f32>, String> {
+    let source =
+        File::open(path).map_err(|e| format!("Could not open {}: {}", path.display(), e))?;
+    let stream = MediaSourceStream::new(Box::new(source), Default::default());
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|ext| ext.to_str()) {
+        hint.with_extension(ext);
+    }
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| format!("Unrecognized audio file: {}", e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| "The file has no audio track".to_string())?;
+    let track_id = track.id;
+    let total_frames = track.codec_params.n_frames;
+    let source_rate = track
+        .codec_params
+        .sample_rate
+        .ok_or_else(|| "The audio track has no sample rate".to_string())?;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| format!("Unsupported audio codec: {}", e))?;
+
+    let mut resampler = FrameResampler::new(
+        source_rate as usize,
+        WHISPER_SAMPLE_RATE as usize,
+        RESAMPLE_FRAME,
+    );
+    let mut samples = Vec::new();
+    let mut interleaved: Option<SampleBuffer<f32>> = None;
+    let mut mono = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // The end of the stream is reported as an EOF error
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
+                break
+            }
+            Err(e) => return Err(format!("Could not read {}: {}", path.display(), e)),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            Err(SymphoniaError::DecodeError(e)) => {
+                // A damaged packet costs a few milliseconds, not the file
+                debug!("Skipping undecodable packet: {}", e);
+                continue;
+            }
+            Err(e) => return Err(format!("Could not decode {}: {}", path.display(), e)),
+        };
+
+        let spec = *decoded.spec();
+        let channels = spec.channels.count();
+        let needed = decoded.capacity() * channels;
+        if !matches!(&interleaved, Some(buffer) if buffer.capacity() >= needed) {
+            interleaved = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        let buffer = interleaved.as_mut().unwrap();
+        buffer.copy_interleaved_ref(decoded);
+        downmix(buffer.samples(), channels, &mut mono);
+        resampler.push(&mono, |frame| samples.extend_from_slice(frame));
+
+        if let Some(total) = total_frames.filter(|total| *total > 0) {
+            on_progress((packet.ts() + packet.dur()) as f64 / total as f64);
+        }
+    }
+    resampler.finish(|frame| samples.extend_from_slice(frame));
+    Ok(samples)
+}
+
+/// Average interleaved `channels` into `out`, replacing its contents.
+fn downmix(interleaved: &[f32], channels: usize, out: &mut Vec<f32>) {
+    out.clear();
+    if channels <= 1 {
+        out.extend_from_slice(interleaved);
+        return;
+    }
+    out.extend(
+        interleaved
+            .chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn supported_formats_match_by_extension() {
+        assert!(is_supported(Path::new("/tmp/memo.m4a")));
+        assert!(is_supported(Path::new("C:\\Recordings\\Call.MP3")));
+        assert!(is_supported(Path::new("notes.ogg")));
+        assert!(!is_supported(Path::new("video.mp4")));
+        assert!(!is_supported(Path::new("wav")));
+    }
+
+    #[test]
+    fn downmix_averages_channels() {
+        let mut mono = vec![9.0];
+        downmix(&[0.25, 0.75, -1.0, 1.0, 0.5, 0.5], 2, &mut mono);
+        assert_eq!(mono, vec![0.5, 0.0, 0.5]);
+
+        downmix(&[0.1, 0.2], 1, &mut mono);
+        assert_eq!(mono, vec![0.1, 0.2]);
+    }
+}
@@ -0,0 +1,257 @@
+//! Filter for the phantom text Whisper makes up for near-silent audio, like
+//! "Thank you for watching." or "Продолжение следует...", learned from
+//! subtitled video.
+//!
+//! A transcript is dropped when the audio is too quiet to hold speech, or
+//! when it is made up only of known phantom phrases and the voice activity
+//! model hears little speech in the audio. The phrase list alone isn't
+//! enough: people do dictate "Thank you."
+
+use crate::audio_toolkit::SileroVad;
+use crate::managers::audio::vad_model_path;
+use crate::settings::AppSettings;
+use std::collections::HashMap;
+use std::fmt;
+use tauri::AppHandle;
+use tracing::warn;
+
+/// Speech probability above which a VAD frame counts as speech
+const SPEECH_PROBABILITY: f32 = 0.5;
+
+pub const MIN_ENERGY_FLOOR_DB: f32 = -80.0;
+pub const MAX_ENERGY_FLOOR_DB: f32 = -30.0;
+pub const MIN_NO_SPEECH_THRESHOLD: f32 = 0.5;
+pub const MAX_NO_SPEECH_THRESHOLD: f32 = 1.0;
+
+/// Why a transcript was taken for a hallucination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reason {
+    /// The audio is below the energy floor
+    Silent,
+    /// Only blocklisted phrases, over audio with little speech in it
+    PhantomPhrase,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Silent => write!(f, "audio below the energy floor"),
+            Reason::PhantomPhrase => write!(f, "blocklisted phrase over non-speech"),
+        }
+    }
+}
+
+/// Phrases Whisper is known to produce from silence, by language code.
+pub fn default_phrases() -> HashMap<String, Vec<String>> {
+    let lists: &[(&str, &[&str])] = &[
+        (
+            "en",
+            &[
+                "Thank you.",
+                "Thanks for watching!",
+                "Thank you for watching.",
+                "Thank you so much for watching.",
+                "Please subscribe to my channel.",
+                "Like and subscribe.",
+                "See you next time.",
+                "See you in the next video.",
+                "Bye.",
+                "You",
+            ],
+        ),
+        (
+            "ru",
+            &[
+                "Спасибо.",
+                "Спасибо за просмотр!",
+                "Продолжение следует...",
+                "Субтитры сделал DimaTorzok",
+                "Субтитры создавал DimaTorzok",
+                "Редактор субтитров А.Синецкая Корректор А.Егорова",
+                "Подписывайтесь на канал!",
+                "До новых встреч!",
+            ],
+        ),
+        (
+            "de",
+            &[
+                "Untertitel im Auftrag des ZDF für funk, 2017",
+                "Untertitel der Amara.org-Community",
+                "Vielen Dank.",
+                "Danke fürs Zuschauen!",
+                "Bis zum nächsten Mal.",
+            ],
+        ),
+        (
+            "fr",
+            &[
+                "Sous-titres réalisés par la communauté d'Amara.org",
+                "Merci d'avoir regardé cette vidéo !",
+                "Merci.",
+            ],
+        ),
+        (
+            "es",
+            &[
+                "Subtítulos realizados por la comunidad de Amara.org",
+                "¡Gracias por ver el video!",
+                "Gracias.",
+            ],
+        ),
+    ];
+    lists
+        .iter()
+        .map(|(language, phrases)| {
+            let phrases = phrases.iter().map(|p| p.to_string()).collect();
+            (language.to_string(), phrases)
+        })
+        .collect()
+}
+
+/// Check a transcript of `audio` against the filter settings.
+pub fn detect(
+    app: &AppHandle,
+    audio: &[f32],
+    text: &str,
+    settings: &AppSettings,
+) -> Option<Reason> {
+    if !settings.hallucination_filter || text.trim().is_empty() {
+        return None;
+    }
+    if rms_dbfs(audio) < settings.hallucination_energy_floor_db {
+        return Some(Reason::Silent);
+    }
+    if !is_phantom(text, &phrases_for(settings)) {
+        return None;
+    }
+
+    // The VAD only runs for transcripts that look made up
+    let no_speech = vad_model_path(app)
+        .and_then(|path| SileroVad::new(path, SPEECH_PROBABILITY))
+        .and_then(|mut vad| vad.no_speech_probability(audio));
+    match no_speech {
+        Ok(p) if p >= settings.hallucination_no_speech_threshold => Some(Reason::PhantomPhrase),
+        Ok(_) => None,
+        Err(e) => {
+            warn!(
+                "Couldn't measure speech for the hallucination filter: {}",
+                e
+            );
+            None
+        }
+    }
+}
+
+/// RMS level of `samples` in dBFS; `-inf` for digital silence.
+pub fn rms_dbfs(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return f32::NEG_INFINITY;
+    }
+    let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+    10.0 * mean_square.log10()
+}
+
+/// Normalized phrases that apply to the transcription language: all of them
+/// for auto-detect, plus English when translating.
+fn phrases_for(settings: &AppSettings) -> Vec<Vec<String>> {
+    settings
+        .hallucination_phrases
+        .iter()
+        .filter(|(code, _)| settings.may_transcribe_language(code))
+        .flat_map(|(_, phrases)| phrases.iter().map(|p| words(p)))
+        .filter(|phrase| !phrase.is_empty())
+        .collect()
+}
+
+/// Whether `text` is nothing but blocklisted phrases, in any order and
+/// number, ignoring case and punctuation.
+fn is_phantom(text: &str, phrases: &[Vec<String>]) -> bool {
+    let text = words(text);
+    if text.is_empty() {
+        return false;
+    }
+    // covered[i]: the first i words split into phrases
+    let mut covered = vec![false; text.len() + 1];
+    covered[0] = true;
+    for start in 0..text.len() {
+        if !covered[start] {
+            continue;
+        }
+        for phrase in phrases {
+            let end = start + phrase.len();
+            if end <= text.len() && text[start..end] == phrase[..] {
+                covered[end] = true;
+            }
+        }
+    }
+    covered[text.len()]
+}
+
+/// Lowercase words of `text`, split at anything that isn't a letter or digit.
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn phrases(list: &[&str]) -> Vec<Vec<String>> {
+        list.iter().map(|p| words(p)).collect()
+    }
+
+    #[test]
+    fn matches_only_text_made_of_phrases() {
+        let list = phrases(&["Thank you.", "Thanks for watching!", "Bye."]);
+        assert!(is_phantom("Thank you.", &list));
+        assert!(is_phantom("Thanks for watching! Bye!", &list));
+        assert!(is_phantom("thank you thank you", &list));
+        assert!(!is_phantom("Thank you for the update.", &list));
+        assert!(!is_phantom("", &list));
+    }
+
+    #[test]
+    fn ignores_punctuation_inside_phrases() {
+        let list = phrases(&["Sous-titres réalisés par la communauté d'Amara.org"]);
+        assert!(is_phantom(
+            "Sous-titres réalisés par la communauté d'Amara.org.",
+            &list
+        ));
+        let list = phrases(&["Редактор субтитров А.Синецкая Корректор А.Егорова"]);
+        assert!(is_phantom(
+            "Редактор субтитров А. Синецкая. Корректор А. Егорова.",
+            &list
+        ));
+    }
+
+    #[test]
+    fn measures_rms_level() {
+        assert_eq!(rms_dbfs(&[]), f32::NEG_INFINITY);
+        assert_eq!(rms_dbfs(&[0.0; 160]), f32::NEG_INFINITY);
+        assert!(rms_dbfs(&[1.0, -1.0]).abs() < 1e-4);
+        assert!((rms_dbfs(&[0.01; 160]) + 40.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn picks_phrases_for_the_language() {
+        let mut settings = get_default_settings();
+        settings.selected_language = "ru".to_string();
+        assert!(is_phantom(
+            "Продолжение следует...",
+            &phrases_for(&settings)
+        ));
+        assert!(!is_phantom("Thank you.", &phrases_for(&settings)));
+
+        settings.translate_to_english = true;
+        assert!(is_phantom("Thank you.", &phrases_for(&settings)));
+
+        settings.translate_to_english = false;
+        settings.selected_language = "auto".to_string();
+        assert!(is_phantom("Thank you.", &phrases_for(&settings)));
+    }
+}
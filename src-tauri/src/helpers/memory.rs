@@ -0,0 +1,102 @@
+//! Process and system memory, for the model memory report and for sizing the
+//! transcription worker pool.
+
+#[cfg(target_os = "macos")]
+use std::process::Command;
+
+/// Resident set size of this process in bytes, if it can be read.
+#[cfg(target_os = "linux")]
+pub fn process_resident_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    parse_kb_field(&status, "VmRSS:")
+}
+
+/// Resident set size of this process in bytes, if it can be read.
+#[cfg(target_os = "macos")]
+pub fn process_resident_bytes() -> Option<u64> {
+    let output = Command::new("ps")
+        .args(["-o", "rss=", "-p", &std::process::id().to_string()])
+        .output()
+        .ok()?;
+    // ps reports kilobytes
+    let kb: u64 = String::from_utf8_lossy(&output.stdout)
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+/// Resident set size (working set) of this process in bytes, if it can be read.
+#[cfg(target_os = "windows")]
+pub fn process_resident_bytes() -> Option<u64> {
+    use windows::Win32::System::ProcessStatus::{GetProcessMemoryInfo, PROCESS_MEMORY_COUNTERS};
+    use windows::Win32::System::Threading::GetCurrentProcess;
+
+    let mut counters = PROCESS_MEMORY_COUNTERS::default();
+    let size = std::mem::size_of::<PROCESS_MEMORY_COUNTERS>() as u32;
+    unsafe { GetProcessMemoryInfo(GetCurrentProcess(), &mut counters, size) }.ok()?;
+    Some(counters.WorkingSetSize as u64)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn process_resident_bytes() -> Option<u64> {
+    None
+}
+
+/// Memory available to new allocations without swapping, if it can be read.
+#[cfg(target_os = "linux")]
+pub fn available_bytes() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_kb_field(&meminfo, "MemAvailable:")
+}
+
+/// Memory available to new allocations without swapping, if it can be read.
+#[cfg(target_os = "windows")]
+pub fn available_bytes() -> Option<u64> {
+    use windows::Win32::System::SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+    let mut status = MEMORYSTATUSEX {
+        dwLength: std::mem::size_of::<MEMORYSTATUSEX>() as u32,
+        ..Default::default()
+    };
+    unsafe { GlobalMemoryStatusEx(&mut status) }.ok()?;
+    Some(status.ullAvailPhys)
+}
+
+/// macOS keeps most free memory as reclaimable cache, so there is no
+/// meaningful "available" figure to read.
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+pub fn available_bytes() -> Option<u64> {
+    None
+}
+
+/// Extract a `Name:   1234 kB` field as bytes (`/proc/self/status`, `/proc/meminfo`).
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn parse_kb_field(text: &str, field: &str) -> Option<u64> {
+    let line = text.lines().find(|l| l.starts_with(field))?;
+    let kb: u64 = line
+        .trim_start_matches(field)
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_kb_fields() {
+        let status = "Name:\thandy\nVmPeak:\t  900000 kB\nVmRSS:\t  123456 kB\nThreads:\t12\n";
+        assert_eq!(parse_kb_field(status, "VmRSS:"), Some(123456 * 1024));
+        assert_eq!(parse_kb_field(status, "VmSwap:"), None);
+
+        let meminfo = "MemTotal:       16314388 kB\nMemAvailable:    9876543 kB\n";
+        assert_eq!(
+            parse_kb_field(meminfo, "MemAvailable:"),
+            Some(9876543 * 1024)
+        );
+    }
+}
@@ -1 +1,3 @@
 pub mod clamshell;
+pub mod memory;
+pub mod power;
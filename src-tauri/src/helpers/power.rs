@@ -0,0 +1,117 @@
+//! Power source detection for the model keep-alive policy.
+//!
+//! Every check is best-effort: when the power source can't be determined the
+//! machine is treated as being on AC power, so a desktop never unloads.
+
+#[cfg(target_os = "macos")]
+use std::process::Command;
+
+/// Returns true if the machine is running on battery power.
+#[cfg(target_os = "linux")]
+pub fn is_on_battery() -> bool {
+    let Ok(entries) = std::fs::read_dir("/sys/class/power_supply") else {
+        return false;
+    };
+
+    let read = |dir: &std::path::Path, name: &str| {
+        std::fs::read_to_string(dir.join(name))
+            .ok()
+            .map(|s| s.trim().to_string())
+    };
+
+    let supplies: Vec<PowerSupply> = entries
+        .flatten()
+        .map(|entry| {
+            let dir = entry.path();
+            PowerSupply {
+                kind: read(&dir, "type").unwrap_or_default(),
+                online: read(&dir, "online"),
+                status: read(&dir, "status"),
+            }
+        })
+        .collect();
+
+    on_battery(&supplies)
+}
+
+/// Returns true if the machine is running on battery power.
+///
+/// Uses pmset, which reports "Now drawing from 'Battery Power'" on battery.
+#[cfg(target_os = "macos")]
+pub fn is_on_battery() -> bool {
+    Command::new("pmset")
+        .args(["-g", "batt"])
+        .output()
+        .map(|output| String::from_utf8_lossy(&output.stdout).contains("'Battery Power'"))
+        .unwrap_or(false)
+}
+
+/// Returns true if the machine is running on battery power.
+#[cfg(target_os = "windows")]
+pub fn is_on_battery() -> bool {
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    let mut status = SYSTEM_POWER_STATUS::default();
+    // ACLineStatus: 0 = offline, 1 = online, 255 = unknown
+    unsafe { GetSystemPowerStatus(&mut status) }.is_ok() && status.ACLineStatus == 0
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub fn is_on_battery() -> bool {
+    false
+}
+
+/// One entry of `/sys/class/power_supply`
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+struct PowerSupply {
+    /// "Mains", "Battery", "USB", ...
+    kind: String,
+    online: Option<String>,
+    status: Option<String>,
+}
+
+/// On battery when no mains adapter is online and a battery is discharging.
+#[cfg_attr(not(any(target_os = "linux", test)), allow(dead_code))]
+fn on_battery(supplies: &[PowerSupply]) -> bool {
+    let mains_online = supplies
+        .iter()
+        .any(|s| s.kind == "Mains" && s.online.as_deref() == Some("1"));
+    let discharging = supplies
+        .iter()
+        .any(|s| s.kind == "Battery" && s.status.as_deref() == Some("Discharging"));
+    !mains_online && discharging
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn supply(kind: &str, online: Option<&str>, status: Option<&str>) -> PowerSupply {
+        PowerSupply {
+            kind: kind.to_string(),
+            online: online.map(str::to_string),
+            status: status.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn discharging_battery_without_mains_is_on_battery() {
+        let supplies = [
+            supply("Mains", Some("0"), None),
+            supply("Battery", None, Some("Discharging")),
+        ];
+        assert!(on_battery(&supplies));
+    }
+
+    #[test]
+    fn mains_or_no_battery_is_not_on_battery() {
+        let plugged_in = [
+            supply("Mains", Some("1"), None),
+            supply("Battery", None, Some("Charging")),
+        ];
+        assert!(!on_battery(&plugged_in));
+        // Desktops have no battery at all
+        assert!(!on_battery(&[supply("Mains", Some("1"), None)]));
+        assert!(!on_battery(&[]));
+    }
+}
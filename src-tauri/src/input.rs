@@ -14,6 +14,26 @@ impl EnigoState {
     }
 }
 
+#[cfg(target_os = "macos")]
+#[link(name = "ApplicationServices", kind = "framework")]
+extern "C" {
+    fn AXIsProcessTrusted() -> bool;
+}
+
+/// Whether the process may send synthetic key events.
+/// Without Accessibility permission macOS drops Enigo's events silently;
+/// other platforms have no such permission.
+pub fn is_accessibility_trusted() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        unsafe { AXIsProcessTrusted() }
+    }
+    #[cfg(not(target_os = "macos"))]
+    {
+        true
+    }
+}
+
 /// Get the current mouse cursor position using the managed Enigo instance.
 /// Returns None if the state is not available or if getting the location fails.
 pub fn get_cursor_position(app_handle: &AppHandle) -> Option<(i32, i32)> {
@@ -112,6 +132,13 @@ pub fn send_paste_shift_insert(enigo: &mut Enigo) -> Result<(), String> {
     Ok(())
 }
 
+/// Clicks the middle mouse button, which pastes the PRIMARY selection on Linux.
+pub fn send_middle_click(enigo: &mut Enigo) -> Result<(), String> {
+    enigo
+        .button(enigo::Button::Middle, enigo::Direction::Click)
+        .map_err(|e| format!("Failed to click middle mouse button: {}", e))
+}
+
 /// Pastes text directly using the enigo text method.
 /// This tries to use system input methods if possible, otherwise simulates keystrokes one by one.
 pub fn paste_text_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
@@ -0,0 +1,474 @@
+//! Backends that deliver text to the focused application.
+//!
+//! The paste path in `clipboard.rs` only talks to [`InputBackend`]. Enigo
+//! covers macOS, Windows and X11, but most Wayland compositors ignore its
+//! synthetic events, so [`select_backend`] picks [`LinuxToolBackend::wayland`]
+//! there: wl-clipboard for the clipboard and wtype (virtual-keyboard protocol)
+//! for keystrokes, with kwtype/dotool/ydotool as alternatives.
+
+use crate::input;
+use crate::settings::PasteMethod;
+use enigo::Enigo;
+use std::time::Duration;
+
+/// A way to type text and send the paste shortcut.
+pub trait InputBackend {
+    fn name(&self) -> &'static str;
+
+    /// Type `text` into the focused application.
+    fn type_text(&mut self, text: &str) -> Result<(), String>;
+
+    /// Type `text` one character at a time, pausing `delay` after each so an
+    /// active IME can commit it instead of composing the whole string.
+    fn type_per_character(&mut self, text: &str, delay: Duration) -> Result<(), String> {
+        let mut buf = [0u8; 4];
+        for c in text.chars() {
+            self.type_text(c.encode_utf8(&mut buf))?;
+            std::thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    /// Send the key combination for `paste_method`.
+    fn send_paste_keys(&mut self, paste_method: &PasteMethod) -> Result<(), String>;
+
+    /// Write `text` to the clipboard. Returns `Ok(false)` when the backend has
+    /// no clipboard tool of its own and the Tauri clipboard should be used.
+    fn write_clipboard(&mut self, _text: &str) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    /// Write `text` to the PRIMARY selection (X11/Wayland only).
+    fn write_primary(&mut self, _text: &str) -> Result<(), String> {
+        Err("The primary selection is only available on Linux".into())
+    }
+
+    /// Click the middle mouse button to paste the PRIMARY selection.
+    fn middle_click(&mut self) -> Result<(), String>;
+}
+
+/// Synthetic key events through Enigo.
+pub struct EnigoBackend<'a> {
+    enigo: &'a mut Enigo,
+}
+
+impl<'a> EnigoBackend<'a> {
+    pub fn new(enigo: &'a mut Enigo) -> Self {
+        Self { enigo }
+    }
+}
+
+impl InputBackend for EnigoBackend<'_> {
+    fn name(&self) -> &'static str {
+        "enigo"
+    }
+
+    fn type_text(&mut self, text: &str) -> Result<(), String> {
+        input::paste_text_direct(self.enigo, text)
+    }
+
+    fn send_paste_keys(&mut self, paste_method: &PasteMethod) -> Result<(), String> {
+        match paste_method {
+            PasteMethod::CtrlV => input::send_paste_ctrl_v(self.enigo),
+            PasteMethod::CtrlShiftV => input::send_paste_ctrl_shift_v(self.enigo),
+            PasteMethod::ShiftInsert => input::send_paste_shift_insert(self.enigo),
+            _ => Err("Invalid paste method for clipboard paste".into()),
+        }
+    }
+
+    fn middle_click(&mut self) -> Result<(), String> {
+        input::send_middle_click(self.enigo)
+    }
+}
+
+/// Pick the backend for the current session.
+pub fn select_backend(enigo: &mut Enigo) -> Box<dyn InputBackend + '_> {
+    #[cfg(target_os = "linux")]
+    {
+        let backend = if crate::utils::is_wayland() {
+            LinuxToolBackend::wayland(enigo)
+        } else {
+            LinuxToolBackend::x11(enigo)
+        };
+        tracing::info!("Using {} input backend", backend.name());
+        Box::new(backend)
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        Box::new(EnigoBackend::new(enigo))
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) use linux::is_command_available;
+#[cfg(target_os = "linux")]
+pub use linux::LinuxToolBackend;
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::{EnigoBackend, InputBackend};
+    use crate::settings::PasteMethod;
+    use crate::utils::is_kde_wayland;
+    use enigo::Enigo;
+    use std::process::Command;
+    use tracing::info;
+
+    /// Command-line input tools used on Linux.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum LinuxTool {
+        /// wlroots/GNOME virtual-keyboard protocol (not supported by KDE)
+        Wtype,
+        /// KDE Fake Input protocol, supports umlauts
+        Kwtype,
+        /// uinput, works on Wayland and X11
+        Dotool,
+        /// uinput, requires the ydotoold daemon
+        Ydotool,
+        Xdotool,
+    }
+
+    impl LinuxTool {
+        pub fn command(self) -> &'static str {
+            match self {
+                LinuxTool::Wtype => "wtype",
+                LinuxTool::Kwtype => "kwtype",
+                LinuxTool::Dotool => "dotool",
+                LinuxTool::Ydotool => "ydotool",
+                LinuxTool::Xdotool => "xdotool",
+            }
+        }
+
+        pub fn is_available(self) -> bool {
+            is_command_available(self.command())
+        }
+
+        /// First available tool out of `candidates`.
+        fn first_available(candidates: &[LinuxTool]) -> Option<LinuxTool> {
+            candidates.iter().copied().find(|tool| tool.is_available())
+        }
+
+        /// Arguments that send the paste shortcut, for tools that take them on the
+        /// command line.
+        pub fn key_combo_args(self, paste_method: &PasteMethod) -> Option<Vec<&'static str>> {
+            let args = match (self, paste_method) {
+                (LinuxTool::Wtype, PasteMethod::CtrlV) => vec!["-M", "ctrl", "-k", "v"],
+                (LinuxTool::Wtype, PasteMethod::ShiftInsert) => {
+                    vec!["-M", "shift", "-k", "Insert"]
+                }
+                (LinuxTool::Wtype, PasteMethod::CtrlShiftV) => {
+                    vec!["-M", "ctrl", "-M", "shift", "-k", "v"]
+                }
+                // ydotool uses Linux input event keycodes with format <keycode>:<pressed>
+                // where pressed is 1 for down, 0 for up. Keycodes: ctrl=29, shift=42, v=47, insert=110
+                (LinuxTool::Ydotool, PasteMethod::CtrlV) => {
+                    vec!["key", "29:1", "47:1", "47:0", "29:0"]
+                }
+                (LinuxTool::Ydotool, PasteMethod::ShiftInsert) => {
+                    vec!["key", "42:1", "110:1", "110:0", "42:0"]
+                }
+                (LinuxTool::Ydotool, PasteMethod::CtrlShiftV) => {
+                    vec!["key", "29:1", "42:1", "47:1", "47:0", "42:0", "29:0"]
+                }
+                (LinuxTool::Xdotool, PasteMethod::CtrlV) => {
+                    vec!["key", "--clearmodifiers", "ctrl+v"]
+                }
+                (LinuxTool::Xdotool, PasteMethod::CtrlShiftV) => {
+                    vec!["key", "--clearmodifiers", "ctrl+shift+v"]
+                }
+                (LinuxTool::Xdotool, PasteMethod::ShiftInsert) => {
+                    vec!["key", "--clearmodifiers", "shift+Insert"]
+                }
+                _ => return None,
+            };
+            Some(args)
+        }
+
+        pub fn type_text(self, text: &str) -> Result<(), String> {
+            match self {
+                // Protect against text starting with -
+                LinuxTool::Wtype | LinuxTool::Kwtype | LinuxTool::Ydotool => {
+                    let mut command = Command::new(self.command());
+                    if self == LinuxTool::Ydotool {
+                        command.arg("type");
+                    }
+                    run(command.arg("--").arg(text), self.command())
+                }
+                LinuxTool::Xdotool => run(
+                    Command::new("xdotool")
+                        .arg("type")
+                        .arg("--clearmodifiers")
+                        .arg("--")
+                        .arg(text),
+                    "xdotool",
+                ),
+                // dotool uses "type <text>" command
+                LinuxTool::Dotool => run_dotool(&format!("type {}", text)),
+            }
+        }
+
+        pub fn send_key_combo(self, paste_method: &PasteMethod) -> Result<(), String> {
+            if self == LinuxTool::Dotool {
+                let combo = match paste_method {
+                    PasteMethod::CtrlV => "ctrl+v",
+                    PasteMethod::ShiftInsert => "shift+insert",
+                    PasteMethod::CtrlShiftV => "ctrl+shift+v",
+                    _ => return Err("Unsupported paste method".into()),
+                };
+                return run_dotool(&format!("key {}", combo));
+            }
+
+            let args = self
+                .key_combo_args(paste_method)
+                .ok_or("Unsupported paste method")?;
+            run(Command::new(self.command()).args(&args), self.command())
+        }
+
+        /// Click the middle mouse button, for tools that can emit pointer events.
+        pub fn middle_click(self) -> Result<(), String> {
+            match self {
+                LinuxTool::Xdotool => run(Command::new("xdotool").args(["click", "2"]), "xdotool"),
+                // 0xC2 = middle button down + up
+                LinuxTool::Ydotool => {
+                    run(Command::new("ydotool").args(["click", "0xC2"]), "ydotool")
+                }
+                LinuxTool::Dotool => run_dotool("click middle"),
+                LinuxTool::Wtype | LinuxTool::Kwtype => {
+                    Err(format!("{} cannot click", self.command()))
+                }
+            }
+        }
+    }
+
+    pub(crate) fn is_command_available(command: &str) -> bool {
+        Command::new("which")
+            .arg(command)
+            .output()
+            .map(|output| output.status.success())
+            .unwrap_or(false)
+    }
+
+    fn run(command: &mut Command, name: &str) -> Result<(), String> {
+        let output = command
+            .output()
+            .map_err(|e| format!("Failed to execute {}: {}", name, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("{} failed: {}", name, stderr));
+        }
+
+        Ok(())
+    }
+
+    fn run_dotool(line: &str) -> Result<(), String> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut child = Command::new("dotool")
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("Failed to spawn dotool: {}", e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "{}", line)
+                .map_err(|e| format!("Failed to write to dotool stdin: {}", e))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| format!("Failed to wait for dotool: {}", e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("dotool failed: {}", stderr));
+        }
+
+        Ok(())
+    }
+
+    /// Write text to clipboard via wl-copy (Wayland clipboard tool).
+    fn write_clipboard_via_wl_copy(text: &str) -> Result<(), String> {
+        run(Command::new("wl-copy").arg("--").arg(text), "wl-copy")
+    }
+
+    /// Write text to the PRIMARY selection via wl-copy on Wayland.
+    fn write_primary_via_wl_copy(text: &str) -> Result<(), String> {
+        run(
+            Command::new("wl-copy").arg("--primary").arg("--").arg(text),
+            "wl-copy",
+        )
+    }
+
+    /// Write text to the PRIMARY selection via xclip or xsel on X11.
+    ///
+    /// Both fork a process that keeps serving the selection, so their output
+    /// is discarded instead of waited on.
+    fn write_primary_x11(text: &str) -> Result<(), String> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let (tool, args): (&str, &[&str]) = if is_command_available("xclip") {
+            ("xclip", &["-selection", "primary"])
+        } else if is_command_available("xsel") {
+            ("xsel", &["--primary", "--input"])
+        } else {
+            return Err("xclip or xsel is required for the primary selection".into());
+        };
+
+        let mut child = Command::new(tool)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("Failed to spawn {}: {}", tool, e))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(text.as_bytes())
+                .map_err(|e| format!("Failed to write to {} stdin: {}", tool, e))?;
+        }
+
+        let status = child
+            .wait()
+            .map_err(|e| format!("Failed to wait for {}: {}", tool, e))?;
+        if !status.success() {
+            return Err(format!("{} failed with {}", tool, status));
+        }
+
+        Ok(())
+    }
+
+    /// Native command-line tools, falling back to Enigo for whatever no
+    /// installed tool can do.
+    pub struct LinuxToolBackend<'a> {
+        name: &'static str,
+        typer: Option<LinuxTool>,
+        key_sender: Option<LinuxTool>,
+        clicker: Option<LinuxTool>,
+        wayland: bool,
+        wl_copy: bool,
+        fallback: EnigoBackend<'a>,
+    }
+
+    impl<'a> LinuxToolBackend<'a> {
+        /// wl-copy + wtype, or kwtype on KDE (wtype needs
+        /// zwp_virtual_keyboard_manager_v1, which KDE doesn't implement).
+        pub fn wayland(enigo: &'a mut Enigo) -> Self {
+            let kde = is_kde_wayland();
+            let typers: &[LinuxTool] = if kde {
+                &[LinuxTool::Kwtype, LinuxTool::Dotool, LinuxTool::Ydotool]
+            } else {
+                &[LinuxTool::Wtype, LinuxTool::Dotool, LinuxTool::Ydotool]
+            };
+            let key_senders: &[LinuxTool] = if kde {
+                &[LinuxTool::Dotool, LinuxTool::Ydotool]
+            } else {
+                &[LinuxTool::Wtype, LinuxTool::Dotool, LinuxTool::Ydotool]
+            };
+
+            Self {
+                name: "wayland",
+                typer: LinuxTool::first_available(typers),
+                key_sender: LinuxTool::first_available(key_senders),
+                clicker: LinuxTool::first_available(&[LinuxTool::Dotool, LinuxTool::Ydotool]),
+                wayland: true,
+                // Better compatibility than the Tauri clipboard (especially with umlauts)
+                wl_copy: is_command_available("wl-copy"),
+                fallback: EnigoBackend::new(enigo),
+            }
+        }
+
+        pub fn x11(enigo: &'a mut Enigo) -> Self {
+            let tools = [LinuxTool::Xdotool, LinuxTool::Ydotool];
+            let tool = LinuxTool::first_available(&tools);
+            Self {
+                name: "x11",
+                typer: tool,
+                key_sender: tool,
+                clicker: tool,
+                wayland: false,
+                wl_copy: false,
+                fallback: EnigoBackend::new(enigo),
+            }
+        }
+    }
+
+    impl InputBackend for LinuxToolBackend<'_> {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn type_text(&mut self, text: &str) -> Result<(), String> {
+            match self.typer {
+                Some(tool) => {
+                    info!("Using {} for direct text input", tool.command());
+                    tool.type_text(text)
+                }
+                None => {
+                    info!("Falling back to enigo for direct text input");
+                    self.fallback.type_text(text)
+                }
+            }
+        }
+
+        fn send_paste_keys(&mut self, paste_method: &PasteMethod) -> Result<(), String> {
+            match self.key_sender {
+                Some(tool) => {
+                    info!("Using {} for key combo", tool.command());
+                    tool.send_key_combo(paste_method)
+                }
+                None => self.fallback.send_paste_keys(paste_method),
+            }
+        }
+
+        fn write_clipboard(&mut self, text: &str) -> Result<bool, String> {
+            if !self.wl_copy {
+                return Ok(false);
+            }
+            info!("Using wl-copy for clipboard write on Wayland");
+            write_clipboard_via_wl_copy(text)?;
+            Ok(true)
+        }
+
+        fn write_primary(&mut self, text: &str) -> Result<(), String> {
+            if !self.wayland {
+                return write_primary_x11(text);
+            }
+            if !self.wl_copy {
+                return Err("wl-copy is required for the primary selection on Wayland".into());
+            }
+            write_primary_via_wl_copy(text)
+        }
+
+        fn middle_click(&mut self) -> Result<(), String> {
+            match self.clicker {
+                Some(tool) => {
+                    info!("Using {} for middle click", tool.command());
+                    tool.middle_click()
+                }
+                None => self.fallback.middle_click(),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn key_combo_args_per_tool() {
+            assert_eq!(
+                LinuxTool::Wtype.key_combo_args(&PasteMethod::CtrlShiftV),
+                Some(vec!["-M", "ctrl", "-M", "shift", "-k", "v"])
+            );
+            assert_eq!(
+                LinuxTool::Xdotool.key_combo_args(&PasteMethod::ShiftInsert),
+                Some(vec!["key", "--clearmodifiers", "shift+Insert"])
+            );
+            assert_eq!(LinuxTool::Kwtype.key_combo_args(&PasteMethod::CtrlV), None);
+            assert_eq!(LinuxTool::Wtype.key_combo_args(&PasteMethod::Direct), None);
+        }
+    }
+}
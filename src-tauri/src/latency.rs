@@ -0,0 +1,147 @@
+//! Per-dictation pipeline timings and the aggregate latency report built
+//! from them.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::time::Instant;
+
+/// Where the time went in one dictation, in milliseconds. A stage that
+/// didn't run (e.g. no post-processing) is `None`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct DictationTimings {
+    /// Opening the microphone stream on key press
+    pub mic_start_ms: Option<u64>,
+    /// Waiting for the model after key press; `None` if it was already loaded
+    pub model_load_ms: Option<u64>,
+    /// Key press until the first streaming partial was shown
+    pub first_partial_ms: Option<u64>,
+    /// Final transcription pass after key release
+    pub transcribe_ms: Option<u64>,
+    pub post_process_ms: Option<u64>,
+    /// Pasting the text, or running the voice command
+    pub paste_ms: Option<u64>,
+    /// Key release until the text was pasted
+    pub total_ms: Option<u64>,
+}
+
+impl DictationTimings {
+    /// Stage names, as used in [`StageLatency::stage`], with their values.
+    pub fn stages(&self) -> [(&'static str, Option<u64>); 7] {
+        [
+            ("mic_start", self.mic_start_ms),
+            ("model_load", self.model_load_ms),
+            ("first_partial", self.first_partial_ms),
+            ("transcribe", self.transcribe_ms),
+            ("post_process", self.post_process_ms),
+            ("paste", self.paste_ms),
+            ("total", self.total_ms),
+        ]
+    }
+}
+
+/// Milliseconds elapsed since `start`.
+pub fn elapsed_ms(start: Instant) -> u64 {
+    start.elapsed().as_millis().try_into().unwrap_or(u64::MAX)
+}
+
+/// Latency of one stage across the dictations it ran in.
+#[derive(Clone, Debug, PartialEq, Serialize, Type)]
+pub struct StageLatency {
+    pub stage: String,
+    /// Dictations in which the stage ran
+    pub count: usize,
+    pub median_ms: u64,
+    pub p90_ms: u64,
+    pub max_ms: u64,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Type)]
+pub struct LatencyReport {
+    /// Dictations with recorded timings
+    pub dictations: usize,
+    /// Stages in pipeline order; stages that never ran are left out
+    pub stages: Vec<StageLatency>,
+}
+
+/// Aggregate the timings of several dictations.
+pub fn build_report(timings: &[DictationTimings]) -> LatencyReport {
+    let stage_names = DictationTimings::default().stages().map(|(name, _)| name);
+    let stages = stage_names
+        .iter()
+        .enumerate()
+        .filter_map(|(index, name)| {
+            let mut values: Vec<u64> = timings.iter().filter_map(|t| t.stages()[index].1).collect();
+            if values.is_empty() {
+                return None;
+            }
+            values.sort_unstable();
+            Some(StageLatency {
+                stage: name.to_string(),
+                count: values.len(),
+                median_ms: percentile(&values, 50),
+                p90_ms: percentile(&values, 90),
+                max_ms: values[values.len() - 1],
+            })
+        })
+        .collect();
+
+    LatencyReport {
+        dictations: timings.len(),
+        stages,
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty `values`.
+fn percentile(values: &[u64], pct: usize) -> u64 {
+    let rank = (pct * values.len()).div_ceil(100).max(1);
+    values[rank - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings(transcribe: u64, post_process: Option<u64>) -> DictationTimings {
+        DictationTimings {
+            transcribe_ms: Some(transcribe),
+            post_process_ms: post_process,
+            total_ms: Some(transcribe + post_process.unwrap_or(0)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn report_skips_stages_that_never_ran() {
+        let report = build_report(&[timings(100, None), timings(300, None)]);
+        assert_eq!(report.dictations, 2);
+        let stages: Vec<&str> = report.stages.iter().map(|s| s.stage.as_str()).collect();
+        assert_eq!(stages, vec!["transcribe", "total"]);
+    }
+
+    #[test]
+    fn report_computes_percentiles_per_stage() {
+        let all: Vec<DictationTimings> = (1..=10)
+            .map(|i| timings(i * 100, (i % 2 == 0).then_some(i * 10)))
+            .collect();
+        let report = build_report(&all);
+
+        let transcribe = &report.stages[0];
+        assert_eq!(transcribe.stage, "transcribe");
+        assert_eq!(transcribe.count, 10);
+        assert_eq!(transcribe.median_ms, 500);
+        assert_eq!(transcribe.p90_ms, 900);
+        assert_eq!(transcribe.max_ms, 1000);
+
+        let post_process = &report.stages[1];
+        assert_eq!(post_process.count, 5);
+        assert_eq!(post_process.median_ms, 60);
+        assert_eq!(post_process.max_ms, 100);
+    }
+
+    #[test]
+    fn empty_history_gives_empty_report() {
+        let report = build_report(&[]);
+        assert_eq!(report.dictations, 0);
+        assert!(report.stages.is_empty());
+    }
+}
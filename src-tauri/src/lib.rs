@@ -1,21 +1,48 @@
 mod actions;
+mod app_context;
+mod app_error;
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
 mod clipboard;
 mod commands;
+mod completion_notice;
+mod confirmation;
+mod continuation;
+#[cfg(target_os = "linux")]
+mod dbus_control;
+mod diagnostics;
+mod file_output;
+mod file_transcription;
+mod hallucination;
 mod helpers;
 mod input;
+mod input_backend;
+mod latency;
 mod llm_client;
+mod log_redaction;
 mod managers;
+mod meeting;
+mod output_action;
 mod overlay;
+mod power_policy;
+mod profanity;
+mod prompt_template;
+mod punctuation;
+mod secret_store;
 mod settings;
+mod settings_migration;
+mod settings_profile;
 mod shortcut;
 mod signal_handle;
+mod structured_log;
+mod text_edit;
 mod transcription_coordinator;
 mod tray;
 mod tray_i18n;
+mod tts;
+mod updater;
 mod utils;
 pub mod voice_commands;
 use specta_typescript::{BigIntExportBehavior, Typescript};
@@ -26,17 +53,13 @@ use managers::audio::AudioRecordingManager;
 use managers::history::HistoryManager;
 use managers::model::ModelManager;
 use managers::transcription::TranscriptionManager;
-#[cfg(unix)]
-use signal_hook::consts::{SIGUSR1, SIGUSR2};
-#[cfg(unix)]
-use signal_hook::iterator::Signals;
 use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 use tauri::image::Image;
 pub use transcription_coordinator::TranscriptionCoordinator;
 
 use tauri::tray::TrayIconBuilder;
-use tauri::{AppHandle, Emitter, Listener, Manager};
+use tauri::{AppHandle, Listener, Manager};
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
 use tauri_plugin_log::{Builder as LogBuilder, RotationStrategy, Target, TargetKind};
 
@@ -64,7 +87,7 @@ fn build_console_filter() -> env_filter::Filter {
     match std::env::var("RUST_LOG") {
         Ok(spec) if !spec.trim().is_empty() => {
             if let Err(err) = builder.try_parse(&spec) {
-                log::warn!(
+                tracing::warn!(
                     "Ignoring invalid RUST_LOG value '{}': {}. Falling back to info-level console logging",
                     spec,
                     err
@@ -84,21 +107,21 @@ fn show_main_window(app: &AppHandle) {
     if let Some(main_window) = app.get_webview_window("main") {
         // First, ensure the window is visible
         if let Err(e) = main_window.show() {
-            log::error!("Failed to show window: {}", e);
+            tracing::error!("Failed to show window: {}", e);
         }
         // Then, bring it to the front and give it focus
         if let Err(e) = main_window.set_focus() {
-            log::error!("Failed to focus window: {}", e);
+            tracing::error!("Failed to focus window: {}", e);
         }
         // Optional: On macOS, ensure the app becomes active if it was an accessory
         #[cfg(target_os = "macos")]
         {
             if let Err(e) = app.set_activation_policy(tauri::ActivationPolicy::Regular) {
-                log::error!("Failed to set activation policy to Regular: {}", e);
+                tracing::error!("Failed to set activation policy to Regular: {}", e);
             }
         }
     } else {
-        log::error!("Main window not found.");
+        tracing::error!("Main window not found.");
     }
 }
 
@@ -109,6 +132,7 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // on macOS before the user is ready.
 
     // Initialize the managers
+    power_policy::init();
     let recording_manager = Arc::new(
         AudioRecordingManager::new(app_handle).expect("Failed to initialize recording manager"),
     );
@@ -127,16 +151,19 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     app_handle.manage(transcription_manager.clone());
     app_handle.manage(history_manager.clone());
 
+    power_policy::start_watcher(app_handle);
+    updater::start(app_handle);
+
     // Note: Shortcuts are NOT initialized here.
     // The frontend is responsible for calling the `initialize_shortcuts` command
     // after permissions are confirmed (on macOS) or after onboarding completes.
     // This matches the pattern used for Enigo initialization.
 
+    // Set up signal handlers for toggling transcription, reloading settings and cancelling
     #[cfg(unix)]
-    let signals = Signals::new(&[SIGUSR1, SIGUSR2]).unwrap();
-    // Set up signal handlers for toggling transcription
-    #[cfg(unix)]
-    signal_handle::setup_signal_handler(app_handle.clone(), signals);
+    signal_handle::setup_signal_handler(app_handle.clone());
+    #[cfg(target_os = "linux")]
+    dbus_control::start(app_handle);
 
     // Apply macOS Accessory policy if starting hidden
     #[cfg(target_os = "macos")]
@@ -146,6 +173,33 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             let _ = app_handle.set_activation_policy(tauri::ActivationPolicy::Accessory);
         }
     }
+    if utils::is_headless() {
+        tracing::info!("Headless mode: skipping tray icon and overlay window");
+    } else {
+        create_tray(app_handle);
+    }
+
+    // Get the autostart manager and configure based on user setting
+    let autostart_manager = app_handle.autolaunch();
+    let settings = settings::get_settings(&app_handle);
+
+    if settings.autostart_enabled {
+        // Enable autostart if user has opted in
+        let _ = autostart_manager.enable();
+    } else {
+        // Disable autostart if user has opted out
+        let _ = autostart_manager.disable();
+    }
+
+    // Create the recording overlay window (hidden by default)
+    if !utils::is_headless() {
+        utils::create_recording_overlay(app_handle);
+    }
+
+    diagnostics::run_first_launch_check(app_handle);
+}
+
+fn create_tray(app_handle: &AppHandle) {
     // Get the current theme to set the appropriate initial icon
     let initial_theme = tray::get_current_theme(app_handle);
 
@@ -185,7 +239,17 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                 let settings = settings::get_settings(app);
                 if settings.update_checks_enabled {
                     show_main_window(app);
-                    let _ = app.emit("check-for-updates", ());
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = updater::check(&app, true).await {
+                            tracing::warn!("Update check from the tray failed: {}", e);
+                        }
+                    });
+                }
+            }
+            "apply_update" => {
+                if let Err(e) = updater::apply(app) {
+                    tracing::error!("Failed to apply update: {}", e);
                 }
             }
             "copy_last_transcript" => {
@@ -194,12 +258,12 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             "unload_model" => {
                 let transcription_manager = app.state::<Arc<TranscriptionManager>>();
                 if !transcription_manager.is_model_loaded() {
-                    log::warn!("No model is currently loaded.");
+                    tracing::warn!("No model is currently loaded.");
                     return;
                 }
                 match transcription_manager.unload_model() {
-                    Ok(()) => log::info!("Model unloaded via tray."),
-                    Err(e) => log::error!("Failed to unload model via tray: {}", e),
+                    Ok(()) => tracing::info!("Model unloaded via tray."),
+                    Err(e) => tracing::error!("Failed to unload model via tray: {}", e),
                 }
             }
             "cancel" => {
@@ -211,7 +275,7 @@ fn initialize_core_logic(app_handle: &AppHandle) {
             "quit" => {
                 app.exit(0);
             }
-            _ => {}
+            id => tray::handle_quick_action(app, id),
         })
         .build(app_handle)
         .unwrap();
@@ -226,32 +290,14 @@ fn initialize_core_logic(app_handle: &AppHandle) {
         tray::update_tray_menu(&app_handle_for_listener, &tray::TrayIconState::Idle, None);
     });
 
-    // Get the autostart manager and configure based on user setting
-    let autostart_manager = app_handle.autolaunch();
-    let settings = settings::get_settings(&app_handle);
-
-    if settings.autostart_enabled {
-        // Enable autostart if user has opted in
-        let _ = autostart_manager.enable();
-    } else {
-        // Disable autostart if user has opted out
-        let _ = autostart_manager.disable();
-    }
-
-    // Create the recording overlay window (hidden by default)
-    utils::create_recording_overlay(app_handle);
-}
+    // Refresh the recent transcripts submenu
+    let app_handle_for_listener = app_handle.clone();
+    app_handle.listen("history-updated", move |_| {
+        tray::update_tray_menu(&app_handle_for_listener, &tray::TrayIconState::Idle, None);
+    });
 
-#[tauri::command]
-#[specta::specta]
-fn trigger_update_check(app: AppHandle) -> Result<(), String> {
-    let settings = settings::get_settings(&app);
-    if !settings.update_checks_enabled {
-        return Ok(());
-    }
-    app.emit("check-for-updates", ())
-        .map_err(|e| e.to_string())?;
-    Ok(())
+    // Fill the microphone picker
+    tray::refresh_input_devices(app_handle);
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -264,29 +310,42 @@ pub fn run() {
         shortcut::change_binding,
         shortcut::reset_binding,
         shortcut::change_ptt_setting,
+        shortcut::change_hotkey_gestures_setting,
+        shortcut::change_busy_behavior_setting,
         shortcut::change_audio_feedback_setting,
         shortcut::change_audio_feedback_volume_setting,
         shortcut::change_sound_theme_setting,
         shortcut::change_start_hidden_setting,
+        shortcut::change_headless_setting,
         shortcut::change_autostart_setting,
         shortcut::change_translate_to_english_setting,
         shortcut::change_selected_language_setting,
         shortcut::change_overlay_position_setting,
+        shortcut::change_overlay_layout_setting,
         shortcut::change_debug_mode_setting,
         shortcut::change_word_correction_threshold_setting,
         shortcut::change_paste_method_setting,
+        shortcut::change_per_character_delay_setting,
+        shortcut::change_paste_fallback_setting,
         shortcut::change_clipboard_handling_setting,
+        shortcut::change_selection_target_setting,
+        shortcut::change_middle_click_paste_setting,
         shortcut::change_post_process_enabled_setting,
         shortcut::change_experimental_enabled_setting,
         shortcut::change_post_process_base_url_setting,
+        shortcut::change_post_process_api_version_setting,
         shortcut::change_post_process_api_key_setting,
+        shortcut::get_post_process_api_key,
+        shortcut::check_post_process_provider,
         shortcut::change_post_process_model_setting,
         shortcut::set_post_process_provider,
         shortcut::fetch_post_process_models,
         shortcut::add_post_process_prompt,
         shortcut::update_post_process_prompt,
+        shortcut::update_post_process_prompt_options,
         shortcut::delete_post_process_prompt,
         shortcut::set_post_process_selected_prompt,
+        shortcut::set_post_process_chain,
         shortcut::update_custom_words,
         shortcut::suspend_binding,
         shortcut::resume_binding,
@@ -294,21 +353,48 @@ pub fn run() {
         shortcut::change_append_trailing_space_setting,
         shortcut::change_app_language_setting,
         shortcut::change_update_checks_setting,
+        shortcut::change_update_channel_setting,
+        shortcut::change_update_check_interval_setting,
         shortcut::change_keyboard_implementation_setting,
         shortcut::get_keyboard_implementation,
         shortcut::change_voice_commands_setting,
+        shortcut::change_private_mode_setting,
+        shortcut::change_app_behaviors_setting,
+        shortcut::change_number_normalization_setting,
+        shortcut::change_completion_notifications_setting,
+        shortcut::change_continue_dictation_setting,
+        shortcut::change_continue_dictation_window_setting,
+        shortcut::change_tts_read_transcripts_setting,
+        shortcut::change_tts_read_errors_setting,
+        shortcut::change_tts_voice_setting,
+        shortcut::change_tts_rate_setting,
+        shortcut::change_confirm_before_paste_setting,
+        shortcut::change_hallucination_filter_setting,
+        shortcut::change_hallucination_energy_floor_setting,
+        shortcut::change_hallucination_no_speech_threshold_setting,
+        shortcut::update_hallucination_phrases,
+        shortcut::change_punctuation_restore_setting,
+        shortcut::change_punctuation_restore_casing_model_setting,
         shortcut::handy_keys::start_handy_keys_recording,
         shortcut::handy_keys::stop_handy_keys_recording,
-        trigger_update_check,
         commands::cancel_operation,
         commands::get_app_dir_path,
         commands::get_app_settings,
         commands::get_default_settings,
+        commands::get_settings_load_errors,
+        commands::export_settings,
+        commands::import_settings,
         commands::get_log_dir_path,
+        commands::get_recent_log_events,
         commands::set_log_level,
+        commands::set_log_transcripts,
+        commands::set_binding_output_actions,
+        commands::set_binding_profanity_mask,
         commands::open_recordings_folder,
         commands::open_log_dir,
         commands::open_app_data_dir,
+        commands::check_accessibility_permission,
+        commands::open_accessibility_settings,
         commands::check_apple_intelligence_available,
         commands::initialize_enigo,
         commands::initialize_shortcuts,
@@ -323,10 +409,17 @@ pub fn run() {
         commands::models::is_model_loading,
         commands::models::has_any_models_available,
         commands::models::has_any_models_or_downloads,
+        commands::overlay::list_overlay_monitors,
+        commands::overlay::preview_overlay_layout,
+        commands::overlay::end_overlay_preview,
+        commands::overlay::send_pending_text,
+        commands::overlay::discard_pending_text,
+        commands::overlay::edit_pending_text,
         commands::audio::update_microphone_mode,
         commands::audio::get_microphone_mode,
         commands::audio::get_available_microphones,
         commands::audio::set_selected_microphone,
+        commands::audio::set_binding_audio_override,
         commands::audio::get_selected_microphone,
         commands::audio::get_available_output_devices,
         commands::audio::set_selected_output_device,
@@ -336,15 +429,35 @@ pub fn run() {
         commands::audio::set_clamshell_microphone,
         commands::audio::get_clamshell_microphone,
         commands::audio::is_recording,
+        commands::audio::run_microphone_diagnostics,
         commands::transcription::set_model_unload_timeout,
+        commands::transcription::set_model_keep_alive,
+        commands::transcription::set_battery_policy,
+        commands::transcription::set_transcription_workers,
+        commands::transcription::get_model_memory_usage,
         commands::transcription::get_model_load_status,
         commands::transcription::unload_model_manually,
+        commands::transcription::transcribe_audio_file,
+        commands::updater::get_update_status,
+        commands::updater::check_for_updates,
+        commands::updater::download_update,
+        commands::updater::apply_update,
         commands::history::get_history_entries,
         commands::history::toggle_history_entry_saved,
         commands::history::get_audio_file_path,
         commands::history::delete_history_entry,
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
+        commands::history::update_history_retention_policy,
+        commands::history::run_history_cleanup,
+        commands::history::get_latency_report,
+        commands::meeting::start_meeting,
+        commands::meeting::stop_meeting,
+        commands::meeting::set_meeting_paused,
+        commands::meeting::get_meeting_status,
+        commands::meeting::set_meeting_notes_folder,
+        commands::tts::list_tts_voices,
+        commands::tts::preview_tts,
         helpers::clamshell::is_laptop,
     ]);
 
@@ -390,13 +503,16 @@ pub fn run() {
 
     builder
         .plugin(tauri_plugin_single_instance::init(|app, _args, _cwd| {
-            show_main_window(app);
+            if !utils::is_headless() {
+                show_main_window(app);
+            }
         }))
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_macos_permissions::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_store::Builder::default().build())
@@ -406,16 +522,43 @@ pub fn run() {
             Some(vec![]),
         ))
         .setup(move |app| {
+            // Before anything logs: events sent without a subscriber are lost
+            match app.path().app_log_dir() {
+                Ok(log_dir) => structured_log::init(&log_dir),
+                Err(e) => log::warn!("No log directory for the pipeline log: {}", e),
+            }
             let settings = get_settings(&app.handle());
             let tauri_log_level: tauri_plugin_log::LogLevel = settings.log_level.into();
             let file_log_level: log::Level = tauri_log_level.into();
             // Store the file log level in the atomic for the filter to use
             FILE_LOG_LEVEL.store(file_log_level.to_level_filter() as u8, Ordering::Relaxed);
+            log_redaction::set_log_transcripts(settings.log_transcripts);
             let app_handle = app.handle().clone();
             app.manage(TranscriptionCoordinator::new(app_handle.clone()));
 
+            let headless = settings.headless || std::env::args().any(|arg| arg == "--headless");
+            utils::set_headless(headless);
+
             initialize_core_logic(&app_handle);
 
+            if headless {
+                // Nothing will call these from the frontend, so do it here
+                if let Err(e) = commands::initialize_enigo(app_handle.clone()) {
+                    tracing::warn!("Headless mode: {}", e);
+                }
+                let _ = commands::initialize_shortcuts(app_handle.clone());
+                #[cfg(target_os = "macos")]
+                {
+                    let _ = app_handle.set_activation_policy(tauri::ActivationPolicy::Accessory);
+                }
+                return Ok(());
+            }
+
+            // The main window is declared with `create: false` so headless mode can skip it
+            if let Some(config) = app.config().app.windows.iter().find(|w| w.label == "main") {
+                tauri::WebviewWindowBuilder::from_config(app.handle(), config)?.build()?;
+            }
+
             // Show main window only if not starting hidden
             if !settings.start_hidden {
                 if let Some(main_window) = app_handle.get_webview_window("main") {
@@ -436,18 +579,24 @@ pub fn run() {
                         .app_handle()
                         .set_activation_policy(tauri::ActivationPolicy::Accessory);
                     if let Err(e) = res {
-                        log::error!("Failed to set activation policy: {}", e);
+                        tracing::error!("Failed to set activation policy: {}", e);
                     }
                 }
             }
             tauri::WindowEvent::ThemeChanged(theme) => {
-                log::info!("Theme changed to: {:?}", theme);
+                tracing::info!("Theme changed to: {:?}", theme);
                 // Update tray icon to match new theme, maintaining idle state
                 utils::change_tray_icon(&window.app_handle(), utils::TrayIconState::Idle);
             }
             _ => {}
         })
         .invoke_handler(specta_builder.invoke_handler())
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            // A downloaded update is applied on the way out
+            if let tauri::RunEvent::Exit = event {
+                updater::install_on_exit();
+            }
+        });
 }
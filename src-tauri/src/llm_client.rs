@@ -1,7 +1,10 @@
-use crate::settings::PostProcessProvider;
-use log::debug;
+use crate::log_redaction;
+use crate::settings::{PostProcessProvider, ProviderApi, AZURE_OPENAI_API_VERSION};
 use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, REFERER, USER_AGENT};
 use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::time::Instant;
+use tracing::debug;
 
 #[derive(Debug, Serialize)]
 struct ChatMessage {
@@ -13,6 +16,18 @@ struct ChatMessage {
 struct ChatCompletionRequest {
     model: String,
     messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+}
+
+/// Request parameters a prompt can set; `None` leaves them to the provider.
+#[derive(Debug, Clone, Default)]
+pub struct CompletionOptions {
+    pub system_message: Option<String>,
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<u32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,6 +45,87 @@ struct ChatMessageResponse {
     content: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiRequest {
+    contents: Vec<GeminiContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<GeminiContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation_config: Option<GeminiGenerationConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiContent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(default)]
+    parts: Vec<GeminiPart>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct GeminiPart {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GeminiGenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiResponse {
+    #[serde(default)]
+    candidates: Vec<GeminiCandidate>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GeminiCandidate {
+    content: Option<GeminiContent>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckStatus {
+    Ok,
+    Failed,
+    Skipped,
+}
+
+/// Result of a single stage of a provider health check.
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct HealthCheckStep {
+    pub name: String,
+    pub status: HealthCheckStatus,
+    pub detail: Option<String>,
+}
+
+/// Structured diagnosis of a post-processing provider configuration.
+#[derive(Debug, Clone, Serialize, Type)]
+pub struct ProviderHealthReport {
+    pub provider_id: String,
+    pub model: String,
+    pub healthy: bool,
+    pub steps: Vec<HealthCheckStep>,
+    /// Round-trip time of the test completion, if it was attempted.
+    pub completion_latency_ms: Option<u64>,
+}
+
+impl ProviderHealthReport {
+    fn push(&mut self, name: &str, status: HealthCheckStatus, detail: Option<String>) {
+        self.steps.push(HealthCheckStep {
+            name: name.to_string(),
+            status,
+            detail,
+        });
+    }
+}
+
 /// Build headers for API requests based on provider type
 fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<HeaderMap, String> {
     let mut headers = HeaderMap::new();
@@ -48,12 +144,16 @@ fn build_headers(provider: &PostProcessProvider, api_key: &str) -> Result<Header
 
     // Provider-specific auth headers
     if !api_key.is_empty() {
-        if provider.id == "anthropic" {
-            headers.insert(
-                "x-api-key",
-                HeaderValue::from_str(api_key)
-                    .map_err(|e| format!("Invalid API key header value: {}", e))?,
-            );
+        let key_header = || {
+            HeaderValue::from_str(api_key)
+                .map_err(|e| format!("Invalid API key header value: {}", e))
+        };
+        if provider.api == ProviderApi::AzureOpenAi {
+            headers.insert("api-key", key_header()?);
+        } else if provider.api == ProviderApi::Gemini {
+            headers.insert("x-goog-api-key", key_header()?);
+        } else if provider.id == "anthropic" {
+            headers.insert("x-api-key", key_header()?);
             headers.insert("anthropic-version", HeaderValue::from_static("2023-06-01"));
         } else {
             headers.insert(
@@ -76,33 +176,132 @@ fn create_client(provider: &PostProcessProvider, api_key: &str) -> Result<reqwes
         .map_err(|e| format!("Failed to build HTTP client: {}", e))
 }
 
-/// Send a chat completion request to an OpenAI-compatible API
+/// Send a chat completion request in the provider's API format
 /// Returns Ok(Some(content)) on success, Ok(None) if response has no content,
 /// or Err on actual errors (HTTP, parsing, etc.)
+/// When `private` is set, the prompt and response are always redacted in logs.
 pub async fn send_chat_completion(
     provider: &PostProcessProvider,
     api_key: String,
     model: &str,
     prompt: String,
+    options: &CompletionOptions,
+    private: bool,
 ) -> Result<Option<String>, String> {
-    let base_url = provider.base_url.trim_end_matches('/');
-    let url = format!("{}/chat/completions", base_url);
+    chat_completion(provider, &api_key, model, prompt, options, private).await
+}
 
-    debug!("Sending chat completion request to: {}", url);
+/// Where completions from `model` are requested.
+fn completion_url(provider: &PostProcessProvider, model: &str) -> String {
+    let base_url = provider.base_url.trim_end_matches('/');
+    match provider.api {
+        ProviderApi::OpenAi => format!("{}/chat/completions", base_url),
+        // On Azure the model is the name of a deployment
+        ProviderApi::AzureOpenAi => format!(
+            "{}/deployments/{}/chat/completions?api-version={}",
+            base_url,
+            model,
+            provider
+                .api_version
+                .as_deref()
+                .unwrap_or(AZURE_OPENAI_API_VERSION)
+        ),
+        ProviderApi::Gemini => format!(
+            "{}/models/{}:generateContent",
+            base_url,
+            model.trim_start_matches("models/")
+        ),
+    }
+}
 
-    let client = create_client(provider, &api_key)?;
+/// Where the provider lists its models, if it can. Azure OpenAI has no
+/// list of the deployments requests go to.
+fn models_url(provider: &PostProcessProvider) -> Option<String> {
+    let base_url = provider.base_url.trim_end_matches('/');
+    let path = provider.models_endpoint.as_deref()?;
+    Some(format!("{}/{}", base_url, path.trim_start_matches('/')))
+}
 
-    let request_body = ChatCompletionRequest {
+/// The request body: the system message, if any, then the prompt.
+fn build_request(
+    model: &str,
+    prompt: String,
+    options: &CompletionOptions,
+) -> ChatCompletionRequest {
+    let mut messages = Vec::new();
+    if let Some(system) = &options.system_message {
+        messages.push(ChatMessage {
+            role: "system".to_string(),
+            content: system.clone(),
+        });
+    }
+    messages.push(ChatMessage {
+        role: "user".to_string(),
+        content: prompt,
+    });
+    ChatCompletionRequest {
         model: model.to_string(),
-        messages: vec![ChatMessage {
-            role: "user".to_string(),
-            content: prompt,
-        }],
+        messages,
+        temperature: options.temperature,
+        max_tokens: options.max_tokens,
+    }
+}
+
+/// The Gemini `generateContent` body for the same prompt and options.
+fn build_gemini_request(prompt: String, options: &CompletionOptions) -> GeminiRequest {
+    let text_content = |role: Option<&str>, text: String| GeminiContent {
+        role: role.map(str::to_string),
+        parts: vec![GeminiPart { text: Some(text) }],
     };
+    let generation_config = (options.temperature.is_some() || options.max_tokens.is_some())
+        .then_some(GeminiGenerationConfig {
+            temperature: options.temperature,
+            max_output_tokens: options.max_tokens,
+        });
+    GeminiRequest {
+        contents: vec![text_content(Some("user"), prompt)],
+        system_instruction: options
+            .system_message
+            .clone()
+            .map(|system| text_content(None, system)),
+        generation_config,
+    }
+}
 
-    let response = client
-        .post(&url)
-        .json(&request_body)
+/// Text of the first Gemini candidate; `None` if it has none.
+fn gemini_text(response: GeminiResponse) -> Option<String> {
+    let content = response.candidates.into_iter().next()?.content?;
+    let text: String = content.parts.into_iter().filter_map(|p| p.text).collect();
+    (!text.is_empty()).then_some(text)
+}
+
+async fn chat_completion(
+    provider: &PostProcessProvider,
+    api_key: &str,
+    model: &str,
+    prompt: String,
+    options: &CompletionOptions,
+    private: bool,
+) -> Result<Option<String>, String> {
+    let url = completion_url(provider, model);
+
+    debug!("Sending chat completion request to: {}", url);
+    debug!(
+        "Chat completion prompt: {}",
+        log_redaction::session_text(&prompt, private)
+    );
+
+    let client = create_client(provider, api_key)?;
+
+    let request = match provider.api {
+        ProviderApi::Gemini => client
+            .post(&url)
+            .json(&build_gemini_request(prompt, options)),
+        ProviderApi::OpenAi | ProviderApi::AzureOpenAi => client
+            .post(&url)
+            .json(&build_request(model, prompt, options)),
+    };
+    let response = request
         .send()
         .await
         .map_err(|e| format!("HTTP request failed: {}", e))?;
@@ -119,25 +318,47 @@ pub async fn send_chat_completion(
         ));
     }
 
-    let completion: ChatCompletionResponse = response
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse API response: {}", e))?;
+    let content = match provider.api {
+        ProviderApi::Gemini => {
+            let completion: GeminiResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse API response: {}", e))?;
+            gemini_text(completion)
+        }
+        ProviderApi::OpenAi | ProviderApi::AzureOpenAi => {
+            let completion: ChatCompletionResponse = response
+                .json()
+                .await
+                .map_err(|e| format!("Failed to parse API response: {}", e))?;
+            completion
+                .choices
+                .first()
+                .and_then(|choice| choice.message.content.clone())
+        }
+    };
+    if let Some(text) = &content {
+        debug!(
+            "Chat completion response: {}",
+            log_redaction::session_text(text, private)
+        );
+    }
 
-    Ok(completion
-        .choices
-        .first()
-        .and_then(|choice| choice.message.content.clone()))
+    Ok(content)
 }
 
-/// Fetch available models from an OpenAI-compatible API
+/// Fetch available models from the provider's models endpoint
 /// Returns a list of model IDs
 pub async fn fetch_models(
     provider: &PostProcessProvider,
     api_key: String,
 ) -> Result<Vec<String>, String> {
-    let base_url = provider.base_url.trim_end_matches('/');
-    let url = format!("{}/models", base_url);
+    let url = models_url(provider).ok_or_else(|| {
+        format!(
+            "{} doesn't list its models; enter the model or deployment name instead.",
+            provider.label
+        )
+    })?;
 
     debug!("Fetching models from: {}", url);
 
@@ -166,10 +387,190 @@ pub async fn fetch_models(
         .await
         .map_err(|e| format!("Failed to parse response: {}", e))?;
 
-    let mut models = Vec::new();
+    Ok(parse_models_response(Some(parsed)).unwrap_or_default())
+}
+
+/// Describe a failed HTTP status in terms of which part of the configuration is wrong.
+fn describe_http_failure(status: u16) -> (&'static str, &'static str) {
+    match status {
+        401 | 403 => ("auth", "API key was rejected"),
+        404 => ("base_url", "Endpoint not found; check the base URL"),
+        429 => ("auth", "Rate limited or out of quota"),
+        500..=599 => ("base_url", "Provider returned a server error"),
+        _ => ("base_url", "Unexpected response from provider"),
+    }
+}
+
+/// Validate a provider configuration end-to-end: reachability, authentication,
+/// model availability and a 1-token completion.
+pub async fn check_provider_health(
+    provider: &PostProcessProvider,
+    api_key: &str,
+    model: &str,
+) -> ProviderHealthReport {
+    let mut report = ProviderHealthReport {
+        provider_id: provider.id.clone(),
+        model: model.to_string(),
+        healthy: false,
+        steps: Vec::new(),
+        completion_latency_ms: None,
+    };
+
+    let client = match create_client(provider, api_key) {
+        Ok(client) => client,
+        Err(e) => {
+            report.push("auth", HealthCheckStatus::Failed, Some(e));
+            return report;
+        }
+    };
+
+    // Reachability + auth via the models endpoint
+    let base_url = provider.base_url.trim_end_matches('/');
+    let mut available_models: Option<Vec<String>> = None;
+
+    let Some(models_url) = models_url(provider) else {
+        // Without a model list, the completion below checks everything
+        report.push(
+            "base_url",
+            HealthCheckStatus::Skipped,
+            Some("Provider has no model list to check against".to_string()),
+        );
+        report.push("auth", HealthCheckStatus::Skipped, None);
+        if model.trim().is_empty() {
+            report.push(
+                "model",
+                HealthCheckStatus::Failed,
+                Some("No model selected".to_string()),
+            );
+            return report;
+        }
+        report.push("model", HealthCheckStatus::Skipped, None);
+        return check_completion(report, provider, api_key, model).await;
+    };
+
+    match client.get(&models_url).send().await {
+        Err(e) => {
+            report.push(
+                "base_url",
+                HealthCheckStatus::Failed,
+                Some(format!("Could not reach {}: {}", base_url, e)),
+            );
+            return report;
+        }
+        Ok(response) => {
+            report.push("base_url", HealthCheckStatus::Ok, None);
+            let status = response.status();
+            if status.is_success() {
+                report.push("auth", HealthCheckStatus::Ok, None);
+                available_models = parse_models_response(response.json().await.ok());
+            } else {
+                let (stage, reason) = describe_http_failure(status.as_u16());
+                if stage == "auth" {
+                    report.push(
+                        "auth",
+                        HealthCheckStatus::Failed,
+                        Some(format!("{} ({})", reason, status)),
+                    );
+                    return report;
+                }
+                // Some providers don't expose a model list; the completion below
+                // still tells us whether auth works.
+                report.push(
+                    "auth",
+                    HealthCheckStatus::Skipped,
+                    Some(format!("Model list unavailable ({})", status)),
+                );
+            }
+        }
+    }
+
+    // Model existence
+    if model.trim().is_empty() {
+        report.push(
+            "model",
+            HealthCheckStatus::Failed,
+            Some("No model selected".to_string()),
+        );
+        return report;
+    }
+    match &available_models {
+        Some(models) if !models.is_empty() => {
+            if models.iter().any(|m| m == model) {
+                report.push("model", HealthCheckStatus::Ok, None);
+            } else {
+                report.push(
+                    "model",
+                    HealthCheckStatus::Failed,
+                    Some(format!("Model '{}' is not offered by this provider", model)),
+                );
+                return report;
+            }
+        }
+        _ => report.push(
+            "model",
+            HealthCheckStatus::Skipped,
+            Some("Provider did not return a model list".to_string()),
+        ),
+    }
+
+    check_completion(report, provider, api_key, model).await
+}
+
+/// Finish a health check with a 1-token completion.
+async fn check_completion(
+    mut report: ProviderHealthReport,
+    provider: &PostProcessProvider,
+    api_key: &str,
+    model: &str,
+) -> ProviderHealthReport {
+    let started = Instant::now();
+    let options = CompletionOptions {
+        max_tokens: Some(1),
+        ..Default::default()
+    };
+    let result = chat_completion(
+        provider,
+        api_key,
+        model,
+        "ping".to_string(),
+        &options,
+        false,
+    )
+    .await;
+    report.completion_latency_ms = Some(started.elapsed().as_millis() as u64);
+    match result {
+        Ok(_) => {
+            report.push("completion", HealthCheckStatus::Ok, None);
+            report.healthy = true;
+        }
+        Err(e) => report.push("completion", HealthCheckStatus::Failed, Some(e)),
+    }
 
-    // Handle OpenAI format: { data: [ { id: "..." }, ... ] }
-    if let Some(data) = parsed.get("data").and_then(|d| d.as_array()) {
+    report
+}
+
+/// Extract model ids from the OpenAI format (`{ data: [ { id } ] }`), the
+/// Gemini format (`{ models: [ { name: "models/..." } ] }`, keeping models
+/// that can generate content) or a plain array of names.
+fn parse_models_response(parsed: Option<serde_json::Value>) -> Option<Vec<String>> {
+    let parsed = parsed?;
+    let mut models = Vec::new();
+    if let Some(gemini) = parsed.get("models").and_then(|m| m.as_array()) {
+        for entry in gemini {
+            let generates = match entry
+                .get("supportedGenerationMethods")
+                .and_then(|m| m.as_array())
+            {
+                Some(methods) => methods.iter().any(|m| m == "generateContent"),
+                None => true,
+            };
+            if let Some(name) = entry.get("name").and_then(|n| n.as_str()) {
+                if generates {
+                    models.push(name.trim_start_matches("models/").to_string());
+                }
+            }
+        }
+    } else if let Some(data) = parsed.get("data").and_then(|d| d.as_array()) {
         for entry in data {
             if let Some(id) = entry.get("id").and_then(|i| i.as_str()) {
                 models.push(id.to_string());
@@ -177,17 +578,14 @@ pub async fn fetch_models(
                 models.push(name.to_string());
             }
         }
-    }
-    // Handle array format: [ "model1", "model2", ... ]
-    else if let Some(array) = parsed.as_array() {
+    } else if let Some(array) = parsed.as_array() {
         for entry in array {
             if let Some(model) = entry.as_str() {
                 models.push(model.to_string());
             }
         }
     }
-
-    Ok(models)
+    Some(models)
 }
 
 #[cfg(test)]
@@ -202,6 +600,15 @@ mod tests {
             base_url: base_url.to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
+        }
+    }
+
+    fn make_typed_provider(id: &str, base_url: &str, api: ProviderApi) -> PostProcessProvider {
+        PostProcessProvider {
+            api,
+            ..make_provider(id, base_url)
         }
     }
 
@@ -229,6 +636,27 @@ mod tests {
         assert!(headers.get("authorization").is_none());
     }
 
+    #[test]
+    fn test_build_headers_azure_and_gemini_keys() {
+        let azure = make_typed_provider(
+            "azure_openai",
+            "https://res.openai.azure.com/openai",
+            ProviderApi::AzureOpenAi,
+        );
+        let headers = build_headers(&azure, "azure-key").unwrap();
+        assert_eq!(headers.get("api-key").unwrap(), "azure-key");
+        assert!(headers.get("authorization").is_none());
+
+        let gemini = make_typed_provider(
+            "gemini",
+            "https://generativelanguage.googleapis.com/v1beta",
+            ProviderApi::Gemini,
+        );
+        let headers = build_headers(&gemini, "gemini-key").unwrap();
+        assert_eq!(headers.get("x-goog-api-key").unwrap(), "gemini-key");
+        assert!(headers.get("authorization").is_none());
+    }
+
     #[test]
     fn test_build_headers_no_api_key() {
         let provider = make_provider("custom", "http://localhost:11434/v1");
@@ -265,6 +693,40 @@ mod tests {
         assert_eq!(url, "https://api.openai.com/v1/chat/completions");
     }
 
+    #[test]
+    fn test_completion_url_by_api() {
+        let openai = make_provider("openai", "https://api.openai.com/v1/");
+        assert_eq!(
+            completion_url(&openai, "gpt-4o"),
+            "https://api.openai.com/v1/chat/completions"
+        );
+
+        let mut azure = make_typed_provider(
+            "azure_openai",
+            "https://res.openai.azure.com/openai",
+            ProviderApi::AzureOpenAi,
+        );
+        assert_eq!(
+            completion_url(&azure, "cleanup"),
+            format!(
+                "https://res.openai.azure.com/openai/deployments/cleanup/chat/completions?api-version={}",
+                AZURE_OPENAI_API_VERSION
+            )
+        );
+        azure.api_version = Some("2025-01-01-preview".to_string());
+        assert!(completion_url(&azure, "cleanup").ends_with("?api-version=2025-01-01-preview"));
+
+        let gemini = make_typed_provider(
+            "gemini",
+            "https://generativelanguage.googleapis.com/v1beta",
+            ProviderApi::Gemini,
+        );
+        assert_eq!(
+            completion_url(&gemini, "models/gemini-2.0-flash"),
+            "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent"
+        );
+    }
+
     #[test]
     fn test_models_url() {
         let base_url = "https://api.groq.com/openai/v1/";
@@ -282,12 +744,53 @@ mod tests {
                 role: "user".to_string(),
                 content: "Fix this: hello wrold".to_string(),
             }],
+            temperature: None,
+            max_tokens: None,
         };
 
         let json = serde_json::to_value(&request).unwrap();
         assert_eq!(json["model"], "gpt-4");
         assert_eq!(json["messages"][0]["role"], "user");
         assert_eq!(json["messages"][0]["content"], "Fix this: hello wrold");
+        assert!(json.get("max_tokens").is_none());
+        assert!(json.get("temperature").is_none());
+    }
+
+    #[test]
+    fn test_chat_completion_request_with_max_tokens() {
+        let request = ChatCompletionRequest {
+            model: "gpt-4".to_string(),
+            messages: vec![],
+            temperature: None,
+            max_tokens: Some(1),
+        };
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["max_tokens"], 1);
+    }
+
+    #[test]
+    fn test_build_request_with_prompt_options() {
+        let options = CompletionOptions {
+            system_message: Some("You fix transcripts.".to_string()),
+            temperature: Some(0.2),
+            max_tokens: Some(500),
+        };
+        let request = build_request("gpt-4o-mini", "hello wrold".to_string(), &options);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["model"], "gpt-4o-mini");
+        assert_eq!(json["messages"][0]["role"], "system");
+        assert_eq!(json["messages"][0]["content"], "You fix transcripts.");
+        assert_eq!(json["messages"][1]["role"], "user");
+        assert_eq!(json["messages"][1]["content"], "hello wrold");
+        assert!((json["temperature"].as_f64().unwrap() - 0.2).abs() < 1e-6);
+        assert_eq!(json["max_tokens"], 500);
+
+        let request = build_request("gpt-4o", "hi".to_string(), &CompletionOptions::default());
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["messages"].as_array().unwrap().len(), 1);
+        assert!(json.get("temperature").is_none());
     }
 
     // ── Response Parsing ────────────────────────────────────────────
@@ -334,6 +837,88 @@ mod tests {
         assert!(response.choices[0].message.content.is_none());
     }
 
+    #[test]
+    fn test_gemini_request_serialization() {
+        let options = CompletionOptions {
+            system_message: Some("You fix transcripts.".to_string()),
+            temperature: None,
+            max_tokens: Some(256),
+        };
+        let request = build_gemini_request("hello wrold".to_string(), &options);
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["contents"][0]["role"], "user");
+        assert_eq!(json["contents"][0]["parts"][0]["text"], "hello wrold");
+        assert_eq!(
+            json["systemInstruction"]["parts"][0]["text"],
+            "You fix transcripts."
+        );
+        assert!(json["systemInstruction"].get("role").is_none());
+        assert_eq!(json["generationConfig"]["maxOutputTokens"], 256);
+        assert!(json["generationConfig"].get("temperature").is_none());
+
+        let request = build_gemini_request("hi".to_string(), &CompletionOptions::default());
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("systemInstruction").is_none());
+        assert!(json.get("generationConfig").is_none());
+    }
+
+    #[test]
+    fn test_gemini_response_parsing() {
+        let json = serde_json::json!({
+            "candidates": [{
+                "content": {
+                    "role": "model",
+                    "parts": [{ "text": "Hello " }, { "text": "world" }]
+                },
+                "finishReason": "STOP"
+            }]
+        });
+        let response: GeminiResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(gemini_text(response).as_deref(), Some("Hello world"));
+
+        // Blocked prompts come back without candidates
+        let json = serde_json::json!({ "promptFeedback": { "blockReason": "SAFETY" } });
+        let response: GeminiResponse = serde_json::from_value(json).unwrap();
+        assert!(gemini_text(response).is_none());
+    }
+
+    // ── Health Check ────────────────────────────────────────────────
+
+    #[test]
+    fn test_describe_http_failure_stages() {
+        assert_eq!(describe_http_failure(401).0, "auth");
+        assert_eq!(describe_http_failure(403).0, "auth");
+        assert_eq!(describe_http_failure(404).0, "base_url");
+        assert_eq!(describe_http_failure(503).0, "base_url");
+    }
+
+    #[test]
+    fn test_parse_models_response_formats() {
+        let openai = serde_json::json!({ "data": [{ "id": "gpt-4o" }, { "name": "llama" }] });
+        assert_eq!(
+            parse_models_response(Some(openai)).unwrap(),
+            vec!["gpt-4o", "llama"]
+        );
+
+        let array = serde_json::json!(["a", "b"]);
+        assert_eq!(parse_models_response(Some(array)).unwrap(), vec!["a", "b"]);
+
+        assert!(parse_models_response(None).is_none());
+    }
+
+    #[test]
+    fn test_parse_gemini_models_response() {
+        let gemini = serde_json::json!({ "models": [
+            { "name": "models/gemini-2.0-flash", "supportedGenerationMethods": ["generateContent", "countTokens"] },
+            { "name": "models/text-embedding-004", "supportedGenerationMethods": ["embedContent"] }
+        ] });
+        assert_eq!(
+            parse_models_response(Some(gemini)).unwrap(),
+            vec!["gemini-2.0-flash"]
+        );
+    }
+
     // ── Client Creation ─────────────────────────────────────────────
 
     #[test]
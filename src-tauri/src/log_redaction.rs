@@ -0,0 +1,92 @@
+//! Sanitizer for user content (transcripts, prompts, LLM output) in logs.
+//!
+//! By default logs only carry the length and a short hash of the text so
+//! that entries can still be correlated without exposing what was said.
+//! Enabling the `log_transcripts` setting writes the raw text instead, which
+//! is useful when debugging recognition issues locally.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static LOG_TRANSCRIPTS: AtomicBool = AtomicBool::new(false);
+
+/// Update whether raw transcript text may be written to logs.
+pub fn set_log_transcripts(enabled: bool) {
+    LOG_TRANSCRIPTS.store(enabled, Ordering::Relaxed);
+}
+
+pub fn log_transcripts() -> bool {
+    LOG_TRANSCRIPTS.load(Ordering::Relaxed)
+}
+
+/// Display wrapper that renders either the quoted text or a redacted summary.
+pub struct Redacted<'a> {
+    text: &'a str,
+    force: bool,
+}
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.force && log_transcripts() {
+            write!(f, "'{}'", self.text)
+        } else {
+            write!(
+                f,
+                "<redacted, {} chars, #{:08x}>",
+                self.text.chars().count(),
+                short_hash(self.text)
+            )
+        }
+    }
+}
+
+/// Wrap user content for logging, honouring the `log_transcripts` setting.
+/// Text from a private session is always redacted.
+pub fn session_text(text: &str, private: bool) -> Redacted<'_> {
+    Redacted {
+        text,
+        force: private,
+    }
+}
+
+fn short_hash(text: &str) -> u32 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_by_default_and_when_private() {
+        set_log_transcripts(false);
+        let out = session_text("hello world", false).to_string();
+        assert!(out.starts_with("<redacted, 11 chars, #"));
+        assert!(!out.contains("hello"));
+
+        set_log_transcripts(true);
+        assert_eq!(
+            session_text("hello world", false).to_string(),
+            "'hello world'"
+        );
+        assert!(!session_text("hello world", true)
+            .to_string()
+            .contains("hello"));
+        set_log_transcripts(false);
+    }
+
+    #[test]
+    fn hash_is_stable_for_same_text() {
+        assert_eq!(
+            session_text("привет", true).to_string(),
+            session_text("привет", true).to_string()
+        );
+        assert!(session_text("привет", true)
+            .to_string()
+            .starts_with("<redacted, 6 chars"));
+    }
+}
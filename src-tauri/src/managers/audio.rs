@@ -1,11 +1,15 @@
+use crate::app_error::{self, AppError};
+use crate::audio_toolkit::audio::apply_gain;
 use crate::audio_toolkit::{list_input_devices, vad::SmoothedVad, AudioRecorder, SileroVad};
 use crate::helpers::clamshell;
+use crate::power_policy::PowerProfile;
 use crate::settings::{get_settings, AppSettings};
 use crate::utils;
-use log::{debug, error, info};
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tauri::Manager;
+use tracing::{debug, error, info, warn};
 
 fn set_mute(mute: bool) {
     // Expected behavior:
@@ -100,6 +104,17 @@ const WHISPER_SAMPLE_RATE: usize = 16000;
 
 /* ──────────────────────────────────────────────────────────────── */
 
+/// Progress of the current recording, for [`AudioRecordingManager::set_sample_listener`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SampleEvent {
+    /// Raw samples recorded so far (offsets for `peek_samples_from`)
+    Available(usize),
+    /// The recording was stopped or cancelled; no further events follow
+    Ended,
+}
+
+pub type SampleListener = Box<dyn Fn(SampleEvent) + Send + Sync>;
+
 #[derive(Clone, Debug)]
 pub enum RecordingState {
     Idle,
@@ -114,16 +129,41 @@ pub enum MicrophoneMode {
 
 /* ──────────────────────────────────────────────────────────────── */
 
+fn find_input_device(device_name: &str) -> Option<cpal::Device> {
+    match list_input_devices() {
+        Ok(devices) => devices
+            .into_iter()
+            .find(|d| d.name == device_name)
+            .map(|d| d.device),
+        Err(e) => {
+            debug!("Failed to list devices, using default: {}", e);
+            None
+        }
+    }
+}
+
+/// Path of the bundled Silero VAD model.
+pub fn vad_model_path(app_handle: &tauri::AppHandle) -> Result<PathBuf, anyhow::Error> {
+    app_handle
+        .path()
+        .resolve(
+            "resources/models/silero_vad_v4.onnx",
+            tauri::path::BaseDirectory::Resource,
+        )
+        .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}", e))
+}
+
 fn create_audio_recorder(
     vad_path: &str,
     app_handle: &tauri::AppHandle,
+    sample_listener: Arc<Mutex<Option<SampleListener>>>,
 ) -> Result<AudioRecorder, anyhow::Error> {
     let silero = SileroVad::new(vad_path, 0.3)
         .map_err(|e| anyhow::anyhow!("Failed to create SileroVad: {}", e))?;
     let smoothed_vad = SmoothedVad::new(Box::new(silero), 15, 15, 2);
 
-    // Recorder with VAD plus a spectrum-level callback that forwards updates to
-    // the frontend.
+    // Recorder with VAD plus spectrum and RMS/peak level callbacks that forward
+    // updates to the frontend.
     let recorder = AudioRecorder::new()
         .map_err(|e| anyhow::anyhow!("Failed to create AudioRecorder: {}", e))?
         .with_vad(Box::new(smoothed_vad))
@@ -132,6 +172,17 @@ fn create_audio_recorder(
             move |levels| {
                 utils::emit_levels(&app_handle, &levels);
             }
+        })
+        .with_input_level_callback({
+            let app_handle = app_handle.clone();
+            move |level| {
+                utils::emit_input_level(&app_handle, &level);
+            }
+        })
+        .with_samples_callback(move |count| {
+            if let Some(listener) = sample_listener.lock().unwrap().as_ref() {
+                listener(SampleEvent::Available(count));
+            }
         });
 
     Ok(recorder)
@@ -149,18 +200,18 @@ pub struct AudioRecordingManager {
     is_open: Arc<Mutex<bool>>,
     is_recording: Arc<Mutex<bool>>,
     did_mute: Arc<Mutex<bool>>,
+    /// Per-binding device the open stream uses; `None` for the global selection
+    stream_device: Arc<Mutex<Option<String>>>,
+    /// Gain of the binding being recorded, in dB
+    active_gain_db: Arc<Mutex<f32>>,
+    sample_listener: Arc<Mutex<Option<SampleListener>>>,
 }
 
 impl AudioRecordingManager {
     /* ---------- construction ------------------------------------------------ */
 
     pub fn new(app: &tauri::AppHandle) -> Result<Self, anyhow::Error> {
-        let settings = get_settings(app);
-        let mode = if settings.always_on_microphone {
-            MicrophoneMode::AlwaysOn
-        } else {
-            MicrophoneMode::OnDemand
-        };
+        let mode = PowerProfile::current(&get_settings(app)).microphone_mode();
 
         let manager = Self {
             state: Arc::new(Mutex::new(RecordingState::Idle)),
@@ -171,6 +222,9 @@ impl AudioRecordingManager {
             is_open: Arc::new(Mutex::new(false)),
             is_recording: Arc::new(Mutex::new(false)),
             did_mute: Arc::new(Mutex::new(false)),
+            stream_device: Arc::new(Mutex::new(None)),
+            active_gain_db: Arc::new(Mutex::new(0.0)),
+            sample_listener: Arc::new(Mutex::new(None)),
         };
 
         // Always-on?  Open immediately.
@@ -183,7 +237,7 @@ impl AudioRecordingManager {
 
     /* ---------- helper methods --------------------------------------------- */
 
-    fn get_effective_microphone_device(&self, settings: &AppSettings) -> Option<cpal::Device> {
+    pub fn get_effective_microphone_device(&self, settings: &AppSettings) -> Option<cpal::Device> {
         // Check if we're in clamshell mode and have a clamshell microphone configured
         let use_clamshell_mic = if let Ok(is_clamshell) = clamshell::is_clamshell() {
             is_clamshell && settings.clamshell_microphone.is_some()
@@ -197,17 +251,7 @@ impl AudioRecordingManager {
             settings.selected_microphone.as_ref()?
         };
 
-        // Find the device by name
-        match list_input_devices() {
-            Ok(devices) => devices
-                .into_iter()
-                .find(|d| d.name == *device_name)
-                .map(|d| d.device),
-            Err(e) => {
-                debug!("Failed to list devices, using default: {}", e);
-                None
-            }
-        }
+        find_input_device(device_name)
     }
 
     /* ---------- microphone life-cycle -------------------------------------- */
@@ -235,6 +279,12 @@ impl AudioRecordingManager {
     }
 
     pub fn start_microphone_stream(&self) -> Result<(), anyhow::Error> {
+        self.open_microphone_stream(None)
+    }
+
+    /// Open the stream on `device_override` if given and present, otherwise on
+    /// the globally selected microphone.
+    fn open_microphone_stream(&self, device_override: Option<&str>) -> Result<(), anyhow::Error> {
         let mut open_flag = self.is_open.lock().unwrap();
         if *open_flag {
             debug!("Microphone stream already active");
@@ -247,26 +297,31 @@ impl AudioRecordingManager {
         let mut did_mute_guard = self.did_mute.lock().unwrap();
         *did_mute_guard = false;
 
-        let vad_path = self
-            .app_handle
-            .path()
-            .resolve(
-                "resources/models/silero_vad_v4.onnx",
-                tauri::path::BaseDirectory::Resource,
-            )
-            .map_err(|e| anyhow::anyhow!("Failed to resolve VAD path: {}", e))?;
+        let vad_path = vad_model_path(&self.app_handle)?;
         let mut recorder_opt = self.recorder.lock().unwrap();
 
         if recorder_opt.is_none() {
             *recorder_opt = Some(create_audio_recorder(
                 vad_path.to_str().unwrap(),
                 &self.app_handle,
+                self.sample_listener.clone(),
             )?);
         }
 
         // Get the selected device from settings, considering clamshell mode
         let settings = get_settings(&self.app_handle);
-        let selected_device = self.get_effective_microphone_device(&settings);
+        let override_device = device_override.and_then(|name| {
+            let device = find_input_device(name);
+            if device.is_none() {
+                warn!(
+                    "Binding microphone '{}' not found, using the selected one",
+                    name
+                );
+            }
+            device
+        });
+        let selected_device =
+            override_device.or_else(|| self.get_effective_microphone_device(&settings));
 
         if let Some(rec) = recorder_opt.as_mut() {
             rec.open(selected_device)
@@ -274,6 +329,7 @@ impl AudioRecordingManager {
         }
 
         *open_flag = true;
+        *self.stream_device.lock().unwrap() = device_override.map(str::to_string);
         info!(
             "Microphone stream initialized in {:?}",
             start_time.elapsed()
@@ -336,10 +392,28 @@ impl AudioRecordingManager {
         let mut state = self.state.lock().unwrap();
 
         if let RecordingState::Idle = *state {
-            // Ensure microphone is open in on-demand mode
-            if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
-                if let Err(e) = self.start_microphone_stream() {
-                    error!("Failed to open microphone stream: {e}");
+            let audio = get_settings(&self.app_handle)
+                .binding_audio
+                .get(binding_id)
+                .cloned()
+                .unwrap_or_default();
+
+            // An always-on stream may be open on another binding's device
+            let is_open = *self.is_open.lock().unwrap();
+            if is_open && *self.stream_device.lock().unwrap() != audio.microphone {
+                debug!("Switching microphone for binding {binding_id}");
+                self.stop_microphone_stream();
+            }
+
+            // Ensure microphone is open (on-demand mode, or after a switch)
+            if !*self.is_open.lock().unwrap() {
+                if let Err(e) = self.open_microphone_stream(audio.microphone.as_deref()) {
+                    app_error::report(
+                        &self.app_handle,
+                        AppError::MicrophoneUnavailable {
+                            message: e.to_string(),
+                        },
+                    );
                     return false;
                 }
             }
@@ -347,6 +421,7 @@ impl AudioRecordingManager {
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                 if rec.start().is_ok() {
                     *self.is_recording.lock().unwrap() = true;
+                    *self.active_gain_db.lock().unwrap() = audio.gain_db;
                     *state = RecordingState::Recording {
                         binding_id: binding_id.to_string(),
                     };
@@ -354,7 +429,12 @@ impl AudioRecordingManager {
                     return true;
                 }
             }
-            error!("Recorder not available");
+            app_error::report(
+                &self.app_handle,
+                AppError::MicrophoneUnavailable {
+                    message: "recorder not available".to_string(),
+                },
+            );
             false
         } else {
             false
@@ -364,13 +444,22 @@ impl AudioRecordingManager {
     pub fn update_selected_device(&self) -> Result<(), anyhow::Error> {
         // If currently open, restart the microphone stream to use the new device
         if *self.is_open.lock().unwrap() {
+            let device = self.stream_device.lock().unwrap().clone();
             self.stop_microphone_stream();
-            self.start_microphone_stream()?;
+            self.open_microphone_stream(device.as_deref())?;
         }
         Ok(())
     }
 
     pub fn stop_recording(&self, binding_id: &str) -> Option<Vec<f32>> {
+        self.stop_recording_with_raw(binding_id)
+            .map(|(samples, _)| samples)
+    }
+
+    /// Stop `binding_id`'s recording and return its samples along with the
+    /// raw (pre-VAD) buffer, which offsets from [`Self::peek_samples_from`]
+    /// index into.
+    pub fn stop_recording_with_raw(&self, binding_id: &str) -> Option<(Vec<f32>, Vec<f32>)> {
         let mut state = self.state.lock().unwrap();
 
         match *state {
@@ -379,21 +468,26 @@ impl AudioRecordingManager {
             } if active == binding_id => {
                 *state = RecordingState::Idle;
                 drop(state);
-
-                let samples = if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
-                    match rec.stop() {
-                        Ok(buf) => buf,
-                        Err(e) => {
-                            error!("stop() failed: {e}");
-                            Vec::new()
+                self.clear_sample_listener();
+
+                let (mut samples, mut raw) =
+                    if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
+                        match rec.stop_with_raw() {
+                            Ok(bufs) => bufs,
+                            Err(e) => {
+                                error!("stop() failed: {e}");
+                                (Vec::new(), Vec::new())
+                            }
                         }
-                    }
-                } else {
-                    error!("Recorder not available");
-                    Vec::new()
-                };
+                    } else {
+                        error!("Recorder not available");
+                        (Vec::new(), Vec::new())
+                    };
 
                 *self.is_recording.lock().unwrap() = false;
+                let gain_db = *self.active_gain_db.lock().unwrap();
+                apply_gain(&mut samples, gain_db);
+                apply_gain(&mut raw, gain_db);
 
                 // In on-demand mode turn the mic off again
                 if matches!(*self.mode.lock().unwrap(), MicrophoneMode::OnDemand) {
@@ -406,9 +500,9 @@ impl AudioRecordingManager {
                 if s_len < WHISPER_SAMPLE_RATE && s_len > 0 {
                     let mut padded = samples;
                     padded.resize(WHISPER_SAMPLE_RATE * 5 / 4, 0.0);
-                    Some(padded)
+                    Some((padded, raw))
                 } else {
-                    Some(samples)
+                    Some((samples, raw))
                 }
             }
             _ => None,
@@ -421,7 +515,10 @@ impl AudioRecordingManager {
         }
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
             match rec.peek() {
-                Ok(samples) => Some(samples),
+                Ok(mut samples) => {
+                    apply_gain(&mut samples, *self.active_gain_db.lock().unwrap());
+                    Some(samples)
+                }
                 Err(e) => {
                     error!("peek() failed: {e}");
                     None
@@ -438,7 +535,10 @@ impl AudioRecordingManager {
         }
         if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
             match rec.peek_from(offset) {
-                Ok(samples) => Some(samples),
+                Ok(mut samples) => {
+                    apply_gain(&mut samples, *self.active_gain_db.lock().unwrap());
+                    Some(samples)
+                }
                 Err(e) => {
                     error!("peek_from() failed: {e}");
                     None
@@ -449,6 +549,39 @@ impl AudioRecordingManager {
         }
     }
 
+    /// The next stretch of speech of `binding_id`'s recording once the
+    /// speaker pauses for `min_silence` (or it reaches `max_len`), leaving
+    /// the recording running. See [`AudioRecorder::take_segment`].
+    pub fn take_segment(
+        &self,
+        binding_id: &str,
+        min_silence: Duration,
+        max_len: Duration,
+    ) -> Option<Vec<f32>> {
+        match &*self.state.lock().unwrap() {
+            RecordingState::Recording { binding_id: active } if active == binding_id => {}
+            _ => return None,
+        }
+        let to_samples = |d: Duration| (d.as_secs_f32() * WHISPER_SAMPLE_RATE as f32) as usize;
+
+        let recorder = self.recorder.lock().unwrap();
+        let mut samples = match recorder
+            .as_ref()?
+            .take_segment(to_samples(min_silence), to_samples(max_len))
+        {
+            Ok(samples) => samples,
+            Err(e) => {
+                error!("take_segment() failed: {e}");
+                return None;
+            }
+        };
+        if samples.is_empty() {
+            return None;
+        }
+        apply_gain(&mut samples, *self.active_gain_db.lock().unwrap());
+        Some(samples)
+    }
+
     pub fn is_recording(&self) -> bool {
         matches!(
             *self.state.lock().unwrap(),
@@ -456,6 +589,19 @@ impl AudioRecordingManager {
         )
     }
 
+    /// Be told whenever new samples of the current recording can be peeked.
+    /// Replaces any previous listener without notifying it.
+    pub fn set_sample_listener(&self, listener: SampleListener) {
+        *self.sample_listener.lock().unwrap() = Some(listener);
+    }
+
+    /// Send [`SampleEvent::Ended`] to the listener and remove it.
+    pub fn clear_sample_listener(&self) {
+        if let Some(listener) = self.sample_listener.lock().unwrap().take() {
+            listener(SampleEvent::Ended);
+        }
+    }
+
     /// Cancel any ongoing recording without returning audio samples
     pub fn cancel_recording(&self) {
         let mut state = self.state.lock().unwrap();
@@ -463,6 +609,7 @@ impl AudioRecordingManager {
         if let RecordingState::Recording { .. } = *state {
             *state = RecordingState::Idle;
             drop(state);
+            self.clear_sample_listener();
 
             if let Some(rec) = self.recorder.lock().unwrap().as_ref() {
                 let _ = rec.stop(); // Discard the result
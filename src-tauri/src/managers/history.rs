@@ -1,15 +1,19 @@
 use anyhow::Result;
 use chrono::{DateTime, Local, Utc};
-use log::{debug, error, info};
 use rusqlite::{params, Connection, OptionalExtension};
 use rusqlite_migration::{Migrations, M};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::fs;
 use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, error, info};
 
 use crate::audio_toolkit::save_wav_file;
+use crate::latency::DictationTimings;
+use crate::settings::HistoryRetentionPolicy;
 
 /// Database migrations for transcription history.
 /// Each migration is applied in order. The library tracks which migrations
@@ -31,8 +35,15 @@ static MIGRATIONS: &[M] = &[
     ),
     M::up("ALTER TABLE transcription_history ADD COLUMN post_processed_text TEXT;"),
     M::up("ALTER TABLE transcription_history ADD COLUMN post_process_prompt TEXT;"),
+    M::up("ALTER TABLE transcription_history ADD COLUMN audio_pruned BOOLEAN NOT NULL DEFAULT 0;"),
+    M::up("ALTER TABLE transcription_history ADD COLUMN post_process_steps TEXT;"),
+    M::up("ALTER TABLE transcription_history ADD COLUMN timings TEXT;"),
+    M::up("ALTER TABLE transcription_history ADD COLUMN continued_from INTEGER;"),
 ];
 
+/// How often the background task re-applies the retention policy.
+const RETENTION_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
 #[derive(Clone, Debug, Serialize, Deserialize, Type)]
 pub struct HistoryEntry {
     pub id: i64,
@@ -43,8 +54,89 @@ pub struct HistoryEntry {
     pub transcription_text: String,
     pub post_processed_text: Option<String>,
     pub post_process_prompt: Option<String>,
+    /// True when the entry has no WAV file: it was removed by audio-only
+    /// pruning, or none was saved (meetings, long audio files).
+    pub audio_pruned: bool,
+    /// Intermediate results of a post-processing chain, in execution order.
+    pub post_process_steps: Vec<PostProcessStep>,
+    /// Pipeline timings; `None` for entries recorded before they were tracked.
+    pub timings: Option<DictationTimings>,
+    /// The entry this dictation continued when continue-dictation joined
+    /// them into one block of text.
+    pub continued_from: Option<i64>,
+}
+
+/// Output of one prompt in a post-processing chain.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+pub struct PostProcessStep {
+    pub prompt_id: String,
+    pub prompt_name: String,
+    pub output: String,
+}
+
+/// Steps are stored as a JSON array; older rows have NULL.
+fn parse_post_process_steps(raw: Option<String>) -> Vec<PostProcessStep> {
+    raw.and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Timings are stored as a JSON object; older rows have NULL.
+fn parse_timings(raw: Option<String>) -> Option<DictationTimings> {
+    raw.and_then(|json| serde_json::from_str(&json).ok())
+}
+
+/// Summary of a retention pass, returned to the frontend by `run_history_cleanup`.
+#[derive(Clone, Debug, Default, Serialize, Type)]
+pub struct HistoryCleanupReport {
+    pub entries_deleted: usize,
+    pub audio_files_deleted: usize,
+    pub bytes_freed: u64,
+}
+
+/// Unsaved history entry considered by the retention policy.
+#[derive(Clone, Debug)]
+struct RetentionCandidate {
+    id: i64,
+    file_name: String,
+    timestamp: i64,
+    audio_size: u64,
+    audio_pruned: bool,
 }
 
+/// Pick the entries that violate `policy`. `candidates` must be ordered newest first.
+fn select_entries_to_prune(
+    candidates: &[RetentionCandidate],
+    policy: &HistoryRetentionPolicy,
+    now: i64,
+) -> Vec<RetentionCandidate> {
+    let age_cutoff = policy
+        .max_age_days
+        .map(|days| now - i64::from(days) * 24 * 60 * 60);
+    let max_bytes = policy.max_audio_size_mb.map(|mb| mb * 1024 * 1024);
+
+    let mut kept_bytes: u64 = 0;
+    // Once an entry overflows the size budget, everything older goes too, so
+    // a small old entry never outlives a larger newer one
+    let mut over_budget = false;
+    let mut selected = Vec::new();
+
+    for (index, candidate) in candidates.iter().enumerate() {
+        let over_count = policy.max_entries.map_or(false, |max| index >= max);
+        let too_old = age_cutoff.map_or(false, |cutoff| candidate.timestamp < cutoff);
+        over_budget =
+            over_budget || max_bytes.map_or(false, |max| kept_bytes + candidate.audio_size > max);
+
+        if over_count || too_old || over_budget {
+            selected.push(candidate.clone());
+        } else {
+            kept_bytes += candidate.audio_size;
+        }
+    }
+
+    selected
+}
+
+#[derive(Clone)]
 pub struct HistoryManager {
     app_handle: AppHandle,
     recordings_dir: PathBuf,
@@ -73,6 +165,20 @@ impl HistoryManager {
         // Initialize database and run migrations synchronously
         manager.init_database()?;
 
+        // Periodically enforce the retention policy, even when nothing new is recorded
+        {
+            let manager_cloned = manager.clone();
+            thread::spawn(move || loop {
+                if let Err(e) = manager_cloned.cleanup_old_entries() {
+                    error!("Scheduled history cleanup failed: {}", e);
+                }
+                if let Err(e) = manager_cloned.enforce_retention_policy() {
+                    error!("Scheduled retention policy enforcement failed: {}", e);
+                }
+                thread::sleep(RETENTION_CHECK_INTERVAL);
+            });
+        }
+
         Ok(manager)
     }
 
@@ -176,60 +282,84 @@ impl HistoryManager {
         Ok(Connection::open(&self.db_path)?)
     }
 
-    /// Save a transcription to history (both database and WAV file)
+    /// Save a transcription to history (both database and WAV file).
+    /// Without `audio_samples` no WAV file is written and the entry is saved
+    /// as having no audio. Returns the id of the new entry.
+    #[allow(clippy::too_many_arguments)]
     pub async fn save_transcription(
         &self,
-        audio_samples: Vec<f32>,
+        audio_samples: Option<Vec<f32>>,
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
-    ) -> Result<()> {
+        post_process_steps: Vec<PostProcessStep>,
+        timings: DictationTimings,
+        continued_from: Option<i64>,
+    ) -> Result<i64> {
         let timestamp = Utc::now().timestamp();
         let file_name = format!("handy-{}.wav", timestamp);
         let title = self.format_timestamp_title(timestamp);
 
         // Save WAV file
-        let file_path = self.recordings_dir.join(&file_name);
-        save_wav_file(file_path, &audio_samples).await?;
+        if let Some(audio_samples) = &audio_samples {
+            let file_path = self.recordings_dir.join(&file_name);
+            save_wav_file(file_path, audio_samples).await?;
+        }
 
         // Save to database
-        self.save_to_database(
+        let id = self.save_to_database(
             file_name,
+            audio_samples.is_none(),
             timestamp,
             title,
             transcription_text,
             post_processed_text,
             post_process_prompt,
+            &post_process_steps,
+            &timings,
+            continued_from,
         )?;
 
         // Clean up old entries
         self.cleanup_old_entries()?;
+        self.enforce_retention_policy()?;
 
         // Emit history updated event
         if let Err(e) = self.app_handle.emit("history-updated", ()) {
             error!("Failed to emit history-updated event: {}", e);
         }
 
-        Ok(())
+        Ok(id)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn save_to_database(
         &self,
         file_name: String,
+        audio_pruned: bool,
         timestamp: i64,
         title: String,
         transcription_text: String,
         post_processed_text: Option<String>,
         post_process_prompt: Option<String>,
-    ) -> Result<()> {
+        post_process_steps: &[PostProcessStep],
+        timings: &DictationTimings,
+        continued_from: Option<i64>,
+    ) -> Result<i64> {
+        let steps_json = if post_process_steps.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(post_process_steps)?)
+        };
+        let timings_json = serde_json::to_string(timings)?;
         let conn = self.get_connection()?;
         conn.execute(
-            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
-            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt],
+            "INSERT INTO transcription_history (file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, post_process_steps, timings, continued_from, audio_pruned) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            params![file_name, timestamp, false, title, transcription_text, post_processed_text, post_process_prompt, steps_json, timings_json, continued_from, audio_pruned],
         )?;
 
         debug!("Saved transcription to database");
-        Ok(())
+        Ok(conn.last_insert_rowid())
     }
 
     pub fn cleanup_old_entries(&self) -> Result<()> {
@@ -352,10 +482,99 @@ impl HistoryManager {
         Ok(())
     }
 
+    /// Apply the size/count/age limits from `history_retention`.
+    /// Depending on `audio_only_pruning`, either whole entries or only their WAV files are removed.
+    pub fn enforce_retention_policy(&self) -> Result<HistoryCleanupReport> {
+        let policy = crate::settings::get_history_retention_policy(&self.app_handle);
+        let mut report = HistoryCleanupReport::default();
+
+        if policy.max_entries.is_none()
+            && policy.max_audio_size_mb.is_none()
+            && policy.max_age_days.is_none()
+        {
+            return Ok(report);
+        }
+
+        let conn = self.get_connection()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, file_name, timestamp, audio_pruned FROM transcription_history WHERE saved = 0 ORDER BY timestamp DESC",
+        )?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, i64>("id")?,
+                row.get::<_, String>("file_name")?,
+                row.get::<_, i64>("timestamp")?,
+                row.get::<_, bool>("audio_pruned")?,
+            ))
+        })?;
+
+        let mut candidates = Vec::new();
+        for row in rows {
+            let (id, file_name, timestamp, audio_pruned) = row?;
+            let audio_size = fs::metadata(self.recordings_dir.join(&file_name))
+                .map(|m| m.len())
+                .unwrap_or(0);
+            candidates.push(RetentionCandidate {
+                id,
+                file_name,
+                timestamp,
+                audio_size,
+                audio_pruned,
+            });
+        }
+
+        let mut to_prune = select_entries_to_prune(&candidates, &policy, Utc::now().timestamp());
+        if policy.audio_only_pruning {
+            // Text-only entries have nothing left to prune
+            to_prune.retain(|c| !c.audio_pruned);
+        }
+        if to_prune.is_empty() {
+            return Ok(report);
+        }
+
+        report.bytes_freed = to_prune.iter().map(|c| c.audio_size).sum();
+
+        if policy.audio_only_pruning {
+            for candidate in &to_prune {
+                let file_path = self.recordings_dir.join(&candidate.file_name);
+                if file_path.exists() {
+                    if let Err(e) = fs::remove_file(&file_path) {
+                        error!("Failed to delete WAV file {}: {}", candidate.file_name, e);
+                        continue;
+                    }
+                    report.audio_files_deleted += 1;
+                }
+                conn.execute(
+                    "UPDATE transcription_history SET audio_pruned = 1 WHERE id = ?1",
+                    params![candidate.id],
+                )?;
+            }
+        } else {
+            let entries: Vec<(i64, String)> = to_prune
+                .iter()
+                .map(|c| (c.id, c.file_name.clone()))
+                .collect();
+            report.audio_files_deleted = self.delete_entries_and_files(&entries)?;
+            report.entries_deleted = entries.len();
+        }
+
+        debug!(
+            "Retention policy pruned {} entries and {} audio files ({} bytes)",
+            report.entries_deleted, report.audio_files_deleted, report.bytes_freed
+        );
+
+        if let Err(e) = self.app_handle.emit("history-updated", ()) {
+            error!("Failed to emit history-updated event: {}", e);
+        }
+
+        Ok(report)
+    }
+
     pub async fn get_history_entries(&self) -> Result<Vec<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt FROM transcription_history ORDER BY timestamp DESC"
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, audio_pruned, post_process_steps, timings, continued_from FROM transcription_history ORDER BY timestamp DESC"
         )?;
 
         let rows = stmt.query_map([], |row| {
@@ -368,6 +587,10 @@ impl HistoryManager {
                 transcription_text: row.get("transcription_text")?,
                 post_processed_text: row.get("post_processed_text")?,
                 post_process_prompt: row.get("post_process_prompt")?,
+                audio_pruned: row.get("audio_pruned")?,
+                post_process_steps: parse_post_process_steps(row.get("post_process_steps")?),
+                timings: parse_timings(row.get("timings")?),
+                continued_from: row.get("continued_from")?,
             })
         })?;
 
@@ -385,15 +608,27 @@ impl HistoryManager {
     }
 
     fn get_latest_entry_with_conn(conn: &Connection) -> Result<Option<HistoryEntry>> {
+        Ok(Self::get_recent_entries_with_conn(conn, 1)?
+            .into_iter()
+            .next())
+    }
+
+    /// The `limit` most recent entries, newest first.
+    pub fn get_recent_entries(&self, limit: usize) -> Result<Vec<HistoryEntry>> {
+        let conn = self.get_connection()?;
+        Self::get_recent_entries_with_conn(&conn, limit)
+    }
+
+    fn get_recent_entries_with_conn(conn: &Connection, limit: usize) -> Result<Vec<HistoryEntry>> {
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, audio_pruned, post_process_steps, timings, continued_from
              FROM transcription_history
              ORDER BY timestamp DESC
-             LIMIT 1",
+             LIMIT ?1",
         )?;
 
-        let entry = stmt
-            .query_row([], |row| {
+        let entries = stmt
+            .query_map([limit as i64], |row| {
                 Ok(HistoryEntry {
                     id: row.get("id")?,
                     file_name: row.get("file_name")?,
@@ -403,11 +638,44 @@ impl HistoryManager {
                     transcription_text: row.get("transcription_text")?,
                     post_processed_text: row.get("post_processed_text")?,
                     post_process_prompt: row.get("post_process_prompt")?,
+                    audio_pruned: row.get("audio_pruned")?,
+                    post_process_steps: parse_post_process_steps(row.get("post_process_steps")?),
+                    timings: parse_timings(row.get("timings")?),
+                    continued_from: row.get("continued_from")?,
                 })
-            })
-            .optional()?;
+            })?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
 
-        Ok(entry)
+        Ok(entries)
+    }
+
+    /// Timings of the `limit` most recent entries that have them.
+    pub fn get_recent_timings(&self, limit: usize) -> Result<Vec<DictationTimings>> {
+        let conn = self.get_connection()?;
+        Self::get_recent_timings_with_conn(&conn, limit)
+    }
+
+    fn get_recent_timings_with_conn(
+        conn: &Connection,
+        limit: usize,
+    ) -> Result<Vec<DictationTimings>> {
+        let mut stmt = conn.prepare(
+            "SELECT timings FROM transcription_history
+             WHERE timings IS NOT NULL
+             ORDER BY timestamp DESC
+             LIMIT ?1",
+        )?;
+
+        let rows = stmt.query_map([limit as i64], |row| {
+            row.get::<_, Option<String>>("timings")
+        })?;
+
+        let mut timings = Vec::new();
+        for row in rows {
+            timings.extend(parse_timings(row?));
+        }
+
+        Ok(timings)
     }
 
     pub async fn toggle_saved_status(&self, id: i64) -> Result<()> {
@@ -444,7 +712,7 @@ impl HistoryManager {
     pub async fn get_entry_by_id(&self, id: i64) -> Result<Option<HistoryEntry>> {
         let conn = self.get_connection()?;
         let mut stmt = conn.prepare(
-            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt
+            "SELECT id, file_name, timestamp, saved, title, transcription_text, post_processed_text, post_process_prompt, audio_pruned, post_process_steps, timings, continued_from
              FROM transcription_history WHERE id = ?1",
         )?;
 
@@ -459,6 +727,10 @@ impl HistoryManager {
                     transcription_text: row.get("transcription_text")?,
                     post_processed_text: row.get("post_processed_text")?,
                     post_process_prompt: row.get("post_process_prompt")?,
+                    audio_pruned: row.get("audio_pruned")?,
+                    post_process_steps: parse_post_process_steps(row.get("post_process_steps")?),
+                    timings: parse_timings(row.get("timings")?),
+                    continued_from: row.get("continued_from")?,
                 })
             })
             .optional()?;
@@ -524,7 +796,11 @@ mod tests {
                 title TEXT NOT NULL,
                 transcription_text TEXT NOT NULL,
                 post_processed_text TEXT,
-                post_process_prompt TEXT
+                post_process_prompt TEXT,
+                audio_pruned BOOLEAN NOT NULL DEFAULT 0,
+                post_process_steps TEXT,
+                timings TEXT,
+                continued_from INTEGER
             );",
         )
         .expect("create transcription_history table");
@@ -568,5 +844,122 @@ mod tests {
         assert_eq!(entry.timestamp, 200);
         assert_eq!(entry.transcription_text, "second");
         assert_eq!(entry.post_processed_text.as_deref(), Some("processed"));
+        assert!(entry.post_process_steps.is_empty());
+    }
+
+    #[test]
+    fn get_recent_entries_returns_newest_first_up_to_limit() {
+        let conn = setup_conn();
+        for timestamp in [100, 300, 200] {
+            insert_entry(&conn, timestamp, &format!("entry {}", timestamp), None);
+        }
+
+        let entries =
+            HistoryManager::get_recent_entries_with_conn(&conn, 2).expect("fetch recent entries");
+        let timestamps: Vec<i64> = entries.iter().map(|e| e.timestamp).collect();
+        assert_eq!(timestamps, vec![300, 200]);
+    }
+
+    #[test]
+    fn post_process_steps_round_trip_through_json() {
+        let steps = vec![PostProcessStep {
+            prompt_id: "grammar".to_string(),
+            prompt_name: "Fix grammar".to_string(),
+            output: "Hello world.".to_string(),
+        }];
+        let json = serde_json::to_string(&steps).unwrap();
+        assert_eq!(parse_post_process_steps(Some(json)), steps);
+        assert!(parse_post_process_steps(None).is_empty());
+        assert!(parse_post_process_steps(Some("not json".to_string())).is_empty());
+    }
+
+    #[test]
+    fn recent_timings_skip_entries_without_them() {
+        let conn = setup_conn();
+        insert_entry(&conn, 100, "untimed", None);
+        insert_entry(&conn, 200, "timed", None);
+        let timings = DictationTimings {
+            transcribe_ms: Some(420),
+            total_ms: Some(510),
+            ..Default::default()
+        };
+        conn.execute(
+            "UPDATE transcription_history SET timings = ?1 WHERE timestamp = 200",
+            params![serde_json::to_string(&timings).unwrap()],
+        )
+        .expect("store timings");
+
+        let recent =
+            HistoryManager::get_recent_timings_with_conn(&conn, 10).expect("fetch timings");
+        assert_eq!(recent, vec![timings]);
+
+        let latest = HistoryManager::get_latest_entry_with_conn(&conn)
+            .expect("fetch latest entry")
+            .expect("entry exists");
+        assert_eq!(latest.timings.and_then(|t| t.transcribe_ms), Some(420));
+    }
+
+    fn candidate(id: i64, timestamp: i64, audio_size: u64) -> RetentionCandidate {
+        RetentionCandidate {
+            id,
+            file_name: format!("handy-{}.wav", timestamp),
+            timestamp,
+            audio_size,
+            audio_pruned: false,
+        }
+    }
+
+    fn pruned_ids(candidates: &[RetentionCandidate], policy: &HistoryRetentionPolicy) -> Vec<i64> {
+        select_entries_to_prune(candidates, policy, 10 * 24 * 60 * 60)
+            .iter()
+            .map(|c| c.id)
+            .collect()
+    }
+
+    #[test]
+    fn retention_without_limits_prunes_nothing() {
+        let candidates = vec![candidate(2, 200, 10), candidate(1, 100, 10)];
+        assert!(pruned_ids(&candidates, &HistoryRetentionPolicy::default()).is_empty());
+    }
+
+    #[test]
+    fn retention_max_entries_prunes_oldest() {
+        let candidates = vec![
+            candidate(3, 300, 10),
+            candidate(2, 200, 10),
+            candidate(1, 100, 10),
+        ];
+        let policy = HistoryRetentionPolicy {
+            max_entries: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(pruned_ids(&candidates, &policy), vec![1]);
+    }
+
+    #[test]
+    fn retention_max_age_prunes_expired_entries() {
+        let day = 24 * 60 * 60;
+        let candidates = vec![candidate(2, 9 * day, 10), candidate(1, 2 * day, 10)];
+        let policy = HistoryRetentionPolicy {
+            max_age_days: Some(7),
+            ..Default::default()
+        };
+        assert_eq!(pruned_ids(&candidates, &policy), vec![1]);
+    }
+
+    #[test]
+    fn retention_max_size_keeps_newest_within_budget() {
+        let mb = 1024 * 1024;
+        let candidates = vec![
+            candidate(3, 300, mb),
+            candidate(2, 200, 2 * mb),
+            candidate(1, 100, mb / 2),
+        ];
+        let policy = HistoryRetentionPolicy {
+            max_audio_size_mb: Some(2),
+            ..Default::default()
+        };
+        // Entry 2 exceeds the budget; entry 1 would still fit but is older
+        assert_eq!(pruned_ids(&candidates, &policy), vec![2, 1]);
     }
 }
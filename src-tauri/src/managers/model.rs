@@ -2,7 +2,6 @@ use crate::settings::{get_settings, write_settings};
 use anyhow::Result;
 use flate2::read::GzDecoder;
 use futures_util::StreamExt;
-use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::{HashMap, HashSet};
@@ -15,6 +14,7 @@ use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tar::Archive;
 use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, info, warn};
 
 /// On Windows, converts a path to its short (8.3) form if it contains non-ASCII characters.
 /// This works around C/C++ libraries (whisper.cpp, ONNX Runtime) that don't handle Unicode paths.
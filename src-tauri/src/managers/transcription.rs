@@ -1,20 +1,58 @@
+use crate::app_error::{self, AppError};
+use crate::audio_toolkit::audio::split_at_silence;
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
 use crate::audio_toolkit::{apply_custom_words, filter_transcription_output};
+use crate::hallucination;
+use crate::helpers::memory;
+use crate::log_redaction;
+use crate::managers::audio::AudioRecordingManager;
 use crate::managers::model::{EngineType, ModelManager};
-use crate::settings::{get_settings, ModelUnloadTimeout};
+use crate::power_policy::{self, PowerProfile};
+use crate::settings::{get_settings, AppSettings, ModelKeepAlive, ModelUnloadTimeout};
 use anyhow::Result;
-use log::{debug, error, info, warn};
 use serde::Serialize;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use specta::Type;
+use std::mem::ManuallyDrop;
+use std::ops::Range;
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Condvar, Mutex};
 use std::thread;
 use std::time::{Duration, SystemTime};
-use tauri::{AppHandle, Emitter};
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, info, info_span, warn};
 use transcribe_rs::{
     engines::parakeet::{ParakeetEngine, ParakeetModelParams},
     engines::whisper::{WhisperEngine, WhisperInferenceParams},
     TranscriptionEngine,
 };
 
+/// Final passes at least this long are split and transcribed in parallel.
+const PARALLEL_MIN_SECS: usize = 60;
+/// Target chunk length for parallel transcription (Whisper's window is 30s).
+const PARALLEL_CHUNK_SECS: usize = 30;
+/// How far from the target length to look for a pause to cut at.
+const PARALLEL_SEARCH_SECS: usize = 5;
+/// Cores reserved per worker; the engines use several threads each.
+const THREADS_PER_WORKER: usize = 4;
+/// Memory kept free when deciding how many copies of the model to load.
+const MEMORY_RESERVE_BYTES: u64 = 1024 * 1024 * 1024;
+/// How long the model must go unused before it is unloaded on battery.
+const BATTERY_UNLOAD_IDLE_SECS: u64 = 30;
+
+/// Called with the fraction of chunks done, from the worker threads.
+pub type ChunkProgress<'a> = &'a (dyn Fn(f64) + Sync);
+
+/// When a transcription is split into chunks for the worker pool.
+#[derive(Clone, Copy)]
+enum Split<'a> {
+    /// Streaming partials: always one pass.
+    Never,
+    /// Final passes: split when long enough and more than one worker is allowed.
+    IfLong,
+    /// Long recordings such as audio files: always split, reporting progress.
+    Always(ChunkProgress<'a>),
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ModelStateEvent {
     pub event_type: String,
@@ -28,6 +66,12 @@ enum LoadedEngine {
     Parakeet(ParakeetEngine),
 }
 
+/// Extra copies of a model for parallel chunk transcription.
+struct WorkerEngines {
+    model_id: String,
+    engines: Vec<LoadedEngine>,
+}
+
 #[derive(Clone)]
 pub struct TranscriptionManager {
     engine: Arc<Mutex<Option<LoadedEngine>>>,
@@ -39,6 +83,20 @@ pub struct TranscriptionManager {
     watcher_handle: Arc<Mutex<Option<thread::JoinHandle<()>>>>,
     is_loading: Arc<Mutex<bool>>,
     loading_condvar: Arc<Condvar>,
+    /// Last model that failed to load in the background; not retried by the janitor.
+    failed_model_id: Arc<Mutex<Option<String>>>,
+    /// Extra copies of the loaded model for parallel chunk transcription.
+    worker_engines: Arc<Mutex<Option<WorkerEngines>>>,
+}
+
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ModelMemoryUsage {
+    pub current_model: Option<String>,
+    /// Approximate memory held by the loaded model
+    pub model_size_bytes: Option<u64>,
+    /// Resident memory of the whole app
+    pub process_resident_bytes: Option<u64>,
+    pub on_battery: bool,
 }
 
 impl TranscriptionManager {
@@ -58,64 +116,22 @@ impl TranscriptionManager {
             watcher_handle: Arc::new(Mutex::new(None)),
             is_loading: Arc::new(Mutex::new(false)),
             loading_condvar: Arc::new(Condvar::new()),
+            failed_model_id: Arc::new(Mutex::new(None)),
+            worker_engines: Arc::new(Mutex::new(None)),
         };
 
-        // Start the idle watcher
+        // Start the janitor, which applies the keep-alive policy. Its copy of
+        // the manager is never dropped, so it doesn't count as an owner in Drop.
         {
-            let app_handle_cloned = app_handle.clone();
-            let manager_cloned = manager.clone();
+            let manager_cloned = ManuallyDrop::new(manager.clone());
             let shutdown_signal = manager.shutdown_signal.clone();
             let handle = thread::spawn(move || {
                 while !shutdown_signal.load(Ordering::Relaxed) {
-                    thread::sleep(Duration::from_secs(10)); // Check every 10 seconds
-
-                    // Check shutdown signal again after sleep
-                    if shutdown_signal.load(Ordering::Relaxed) {
-                        break;
-                    }
-
-                    let settings = get_settings(&app_handle_cloned);
-                    let timeout_seconds = settings.model_unload_timeout.to_seconds();
+                    manager_cloned.apply_keep_alive_policy();
 
-                    if let Some(limit_seconds) = timeout_seconds {
-                        // Skip polling-based unloading for immediate timeout since it's handled directly in transcribe()
-                        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately {
-                            continue;
-                        }
-
-                        let last = manager_cloned.last_activity.load(Ordering::Relaxed);
-                        let now_ms = SystemTime::now()
-                            .duration_since(SystemTime::UNIX_EPOCH)
-                            .unwrap()
-                            .as_millis() as u64;
-
-                        if now_ms.saturating_sub(last) > limit_seconds * 1000 {
-                            // idle -> unload
-                            if manager_cloned.is_model_loaded() {
-                                let unload_start = std::time::Instant::now();
-                                debug!("Starting to unload model due to inactivity");
-
-                                if let Ok(()) = manager_cloned.unload_model() {
-                                    let _ = app_handle_cloned.emit(
-                                        "model-state-changed",
-                                        ModelStateEvent {
-                                            event_type: "unloaded".to_string(),
-                                            model_id: None,
-                                            model_name: None,
-                                            error: None,
-                                        },
-                                    );
-                                    let unload_duration = unload_start.elapsed();
-                                    debug!(
-                                        "Model unloaded due to inactivity (took {}ms)",
-                                        unload_duration.as_millis()
-                                    );
-                                }
-                            }
-                        }
-                    }
+                    thread::sleep(Duration::from_secs(10)); // Check every 10 seconds
                 }
-                debug!("Idle watcher thread shutting down gracefully");
+                debug!("Model janitor thread shutting down gracefully");
             });
             *manager.watcher_handle.lock().unwrap() = Some(handle);
         }
@@ -142,6 +158,7 @@ impl TranscriptionManager {
             }
             *engine = None; // Drop the engine to free memory
         }
+        *self.worker_engines.lock().unwrap() = None;
         {
             let mut current_model = self.current_model_id.lock().unwrap();
             *current_model = None;
@@ -169,7 +186,8 @@ impl TranscriptionManager {
     /// Unloads the model immediately if the setting is enabled and the model is loaded
     pub fn maybe_unload_immediately(&self, context: &str) {
         let settings = get_settings(&self.app_handle);
-        if settings.model_unload_timeout == ModelUnloadTimeout::Immediately
+        if settings.model_keep_alive == ModelKeepAlive::Idle
+            && PowerProfile::current(&settings).unload_timeout == ModelUnloadTimeout::Immediately
             && self.is_model_loaded()
         {
             info!("Immediately unloading model after {}", context);
@@ -179,7 +197,147 @@ impl TranscriptionManager {
         }
     }
 
+    /// Load or unload the model according to the keep-alive policy. Runs on
+    /// the janitor thread and right after the policy is changed.
+    pub fn apply_keep_alive_policy(&self) {
+        let settings = get_settings(&self.app_handle);
+        let model = self.target_model(&settings);
+        match settings.model_keep_alive {
+            ModelKeepAlive::Idle => {
+                self.unload_if_stale(&model);
+                self.unload_if_idle(PowerProfile::current(&settings).unload_timeout);
+            }
+            ModelKeepAlive::AlwaysLoaded => self.keep_warm(&model),
+            ModelKeepAlive::UnloadOnBattery => {
+                if power_policy::on_battery() {
+                    // Wait for a dictation in progress to finish first
+                    if self.is_model_loaded()
+                        && !*self.is_loading.lock().unwrap()
+                        && self.is_idle_for(BATTERY_UNLOAD_IDLE_SECS)
+                    {
+                        info!("Running on battery, unloading model");
+                        if let Err(e) = self.unload_model() {
+                            warn!("Failed to unload model on battery: {}", e);
+                        }
+                    }
+                } else {
+                    self.keep_warm(&model);
+                }
+            }
+        }
+    }
+
+    /// The model to use on the current power source. Falls back to the
+    /// selected model if the battery policy's model isn't downloaded.
+    fn target_model(&self, settings: &AppSettings) -> String {
+        let model = PowerProfile::current(settings).model;
+        let downloaded = self
+            .model_manager
+            .get_model_info(&model)
+            .map_or(false, |info| info.is_downloaded);
+        if model != settings.selected_model && !downloaded {
+            return settings.selected_model.clone();
+        }
+        model
+    }
+
+    /// Unload a model other than `model`, e.g. the full-size one after
+    /// switching to battery. The right one is loaded on the next dictation.
+    fn unload_if_stale(&self, model: &str) {
+        let Some(current) = self.get_current_model() else {
+            return;
+        };
+        if current != model && !*self.is_loading.lock().unwrap() {
+            info!("Unloading {} in favor of {}", current, model);
+            if let Err(e) = self.unload_model() {
+                warn!("Failed to unload {}: {}", current, e);
+            }
+        }
+    }
+
+    fn unload_if_idle(&self, timeout: ModelUnloadTimeout) {
+        // Immediate unloading is handled directly after each transcription
+        if timeout == ModelUnloadTimeout::Immediately {
+            return;
+        }
+        let Some(limit_seconds) = timeout.to_seconds() else {
+            return;
+        };
+
+        if self.is_idle_for(limit_seconds) && self.is_model_loaded() {
+            debug!("Unloading model due to inactivity");
+            if let Err(e) = self.unload_model() {
+                warn!("Failed to unload idle model: {}", e);
+            }
+        }
+    }
+
+    /// Whether nothing is being recorded and no transcription has started
+    /// for `seconds`.
+    fn is_idle_for(&self, seconds: u64) -> bool {
+        let recording = self
+            .app_handle
+            .try_state::<Arc<AudioRecordingManager>>()
+            .map_or(false, |rm| rm.is_recording());
+        let last = self.last_activity.load(Ordering::Relaxed);
+        let now_ms = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        !recording && now_ms.saturating_sub(last) > seconds * 1000
+    }
+
+    /// Start loading the selected model if it isn't loaded. A model that
+    /// failed to load is not retried until the selection changes, so the
+    /// error isn't reported every janitor pass.
+    fn keep_warm(&self, selected_model: &str) {
+        if selected_model.is_empty() || self.get_current_model().as_deref() == Some(selected_model)
+        {
+            return;
+        }
+        let downloaded = self
+            .model_manager
+            .get_model_info(selected_model)
+            .map_or(false, |info| info.is_downloaded);
+        if !downloaded {
+            return;
+        }
+        if self.failed_model_id.lock().unwrap().as_deref() == Some(selected_model) {
+            return;
+        }
+        debug!("Keeping model warm, loading {}", selected_model);
+        self.initiate_model_load();
+    }
+
+    /// Memory held by the transcription engine, for display in the UI.
+    pub fn memory_usage(&self) -> ModelMemoryUsage {
+        let current_model = self.get_current_model();
+        // Model weights are mapped in full, so the file size is a close
+        // estimate of the memory the engine holds
+        let model_size_bytes = current_model
+            .as_deref()
+            .and_then(|id| self.model_manager.get_model_info(id))
+            .map(|info| {
+                let copies = 1 + self
+                    .worker_engines
+                    .lock()
+                    .unwrap()
+                    .as_ref()
+                    .map_or(0, |workers| workers.engines.len() as u64);
+                info.size_mb * 1024 * 1024 * copies
+            });
+
+        ModelMemoryUsage {
+            current_model,
+            model_size_bytes,
+            process_resident_bytes: memory::process_resident_bytes(),
+            on_battery: power_policy::on_battery(),
+        }
+    }
+
     pub fn load_model(&self, model_id: &str) -> Result<()> {
+        let _span = info_span!("model_load", model = %model_id).entered();
         let load_start = std::time::Instant::now();
         debug!("Starting to load model: {}", model_id);
 
@@ -270,6 +428,9 @@ impl TranscriptionManager {
             let mut current_model = self.current_model_id.lock().unwrap();
             *current_model = Some(model_id.to_string());
         }
+        *self.failed_model_id.lock().unwrap() = None;
+        // Worker copies belong to the previous model
+        *self.worker_engines.lock().unwrap() = None;
 
         // Emit loading completed event
         let _ = self.app_handle.emit(
@@ -293,19 +454,26 @@ impl TranscriptionManager {
 
     /// Kicks off the model loading in a background thread if it's not already loaded
     pub fn initiate_model_load(&self) {
+        let model = self.target_model(&get_settings(&self.app_handle));
         let mut is_loading = self.is_loading.lock().unwrap();
-        if *is_loading || self.is_model_loaded() {
+        if *is_loading || self.get_current_model().as_deref() == Some(model.as_str()) {
             return;
         }
 
         *is_loading = true;
         let self_clone = self.clone();
         thread::spawn(move || {
-            let settings = get_settings(&self_clone.app_handle);
-            if settings.selected_model.is_empty() {
+            if model.is_empty() {
                 info!("No model selected yet, skipping initial load");
-            } else if let Err(e) = self_clone.load_model(&settings.selected_model) {
-                error!("Failed to load model: {}", e);
+            } else if let Err(e) = self_clone.load_model(&model) {
+                *self_clone.failed_model_id.lock().unwrap() = Some(model.clone());
+                app_error::report(
+                    &self_clone.app_handle,
+                    AppError::ModelLoadFailed {
+                        model_id: model.clone(),
+                        message: e.to_string(),
+                    },
+                );
             }
             let mut is_loading = self_clone.is_loading.lock().unwrap();
             *is_loading = false;
@@ -313,12 +481,21 @@ impl TranscriptionManager {
         });
     }
 
+    /// Block until a background load started by
+    /// [`initiate_model_load`](Self::initiate_model_load) has finished.
+    pub fn wait_for_load(&self) {
+        let mut is_loading = self.is_loading.lock().unwrap();
+        while *is_loading {
+            is_loading = self.loading_condvar.wait(is_loading).unwrap();
+        }
+    }
+
     pub fn get_current_model(&self) -> Option<String> {
         let current_model = self.current_model_id.lock().unwrap();
         current_model.clone()
     }
 
-    fn transcribe_inner(&self, audio: Vec<f32>) -> Result<String> {
+    fn transcribe_inner(&self, audio: Vec<f32>, split: Split, private: bool) -> Result<String> {
         // Update last activity timestamp
         self.last_activity.store(
             SystemTime::now()
@@ -337,74 +514,51 @@ impl TranscriptionManager {
             return Ok(String::new());
         }
 
-        // Check if model is loaded, if not try to load it
-        {
-            // If the model is loading, wait for it to complete.
-            let mut is_loading = self.is_loading.lock().unwrap();
-            while *is_loading {
-                is_loading = self.loading_condvar.wait(is_loading).unwrap();
-            }
-
-            let engine_guard = self.engine.lock().unwrap();
-            if engine_guard.is_none() {
-                return Err(anyhow::anyhow!("Model is not loaded for transcription."));
-            }
+        // If the model is loading, wait for it to complete.
+        self.wait_for_load();
+        if !self.is_model_loaded() {
+            return Err(anyhow::anyhow!("Model is not loaded for transcription."));
         }
 
         // Get current settings for configuration
         let settings = get_settings(&self.app_handle);
 
-        // Perform transcription with the appropriate engine
-        let result = {
+        // Perform transcription with the appropriate engine. Long final
+        // passes are split into chunks and spread over a worker pool.
+        let sample_rate = WHISPER_SAMPLE_RATE as usize;
+        let chunked = match split {
+            Split::Never => false,
+            Split::IfLong => {
+                settings.transcription_workers > 1 && audio.len() >= PARALLEL_MIN_SECS * sample_rate
+            }
+            Split::Always(_) => true,
+        };
+        let text = if chunked {
+            let on_progress = match split {
+                Split::Always(on_progress) => Some(on_progress),
+                _ => None,
+            };
+            self.transcribe_chunks(&audio, &settings, on_progress)?
+        } else {
             let mut engine_guard = self.engine.lock().unwrap();
             let engine = engine_guard.as_mut().ok_or_else(|| {
                 anyhow::anyhow!(
                     "Model failed to load after auto-load attempt. Please check your model settings."
                 )
             })?;
-
-            match engine {
-                LoadedEngine::Whisper(whisper_engine) => {
-                    // Normalize language code for Whisper
-                    // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
-                    let whisper_language = if settings.selected_language == "auto" {
-                        None
-                    } else {
-                        let normalized = if settings.selected_language == "zh-Hans"
-                            || settings.selected_language == "zh-Hant"
-                        {
-                            "zh".to_string()
-                        } else {
-                            settings.selected_language.clone()
-                        };
-                        Some(normalized)
-                    };
-
-                    let params = WhisperInferenceParams {
-                        language: whisper_language,
-                        translate: settings.translate_to_english,
-                        ..Default::default()
-                    };
-
-                    whisper_engine
-                        .transcribe_samples(audio, Some(params))
-                        .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
-                }
-                LoadedEngine::Parakeet(parakeet_engine) => parakeet_engine
-                    .transcribe_samples(audio, None)
-                    .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?,
-            }
+            // The audio is still needed for the hallucination check
+            run_engine(engine, audio.clone(), &settings)?
         };
 
         // Apply word correction if custom words are configured
         let corrected_result = if !settings.custom_words.is_empty() {
             apply_custom_words(
-                &result.text,
+                &text,
                 &settings.custom_words,
                 settings.word_correction_threshold,
             )
         } else {
-            result.text
+            text
         };
 
         // Filter out filler words and hallucinations
@@ -422,19 +576,162 @@ impl TranscriptionManager {
             translation_note
         );
 
-        let final_result = filtered_result;
+        // Drop phantom text made up for silence
+        let final_result =
+            match hallucination::detect(&self.app_handle, &audio, &filtered_result, &settings) {
+                Some(reason) => {
+                    info!("Dropped a likely hallucination ({})", reason);
+                    String::new()
+                }
+                None => filtered_result,
+            };
 
         if final_result.is_empty() {
             info!("Transcription result is empty");
         } else {
-            info!("Transcription result: {}", final_result);
+            info!(
+                "Transcription result: {}",
+                log_redaction::session_text(&final_result, private)
+            );
         }
 
         Ok(final_result)
     }
 
-    pub fn transcribe(&self, audio: Vec<f32>) -> Result<String> {
-        let result = self.transcribe_inner(audio);
+    /// Split a long recording at pauses and transcribe the chunks in
+    /// parallel, on the main engine plus extra copies of the model that are
+    /// kept loaded for later dictations. Results are joined in order.
+    fn transcribe_chunks(
+        &self,
+        audio: &[f32],
+        settings: &AppSettings,
+        on_progress: Option<ChunkProgress>,
+    ) -> Result<String> {
+        let sample_rate = WHISPER_SAMPLE_RATE as usize;
+        let ranges = split_at_silence(
+            audio,
+            sample_rate,
+            PARALLEL_CHUNK_SECS * sample_rate,
+            PARALLEL_SEARCH_SECS * sample_rate,
+        );
+
+        let model_id = self
+            .get_current_model()
+            .ok_or_else(|| anyhow::anyhow!("Model is not loaded for transcription."))?;
+        let model_bytes = self
+            .model_manager
+            .get_model_info(&model_id)
+            .map_or(0, |info| info.size_mb * 1024 * 1024);
+
+        // Copies left over from another model are dropped
+        let mut extra = match self.worker_engines.lock().unwrap().take() {
+            Some(workers) if workers.model_id == model_id => workers.engines,
+            _ => Vec::new(),
+        };
+        let cpus = thread::available_parallelism().map_or(1, |n| n.get());
+        // Copies that are already loaded don't need new memory
+        let available = memory::available_bytes().map(|a| a + extra.len() as u64 * model_bytes);
+        let workers = worker_count(
+            settings.transcription_workers,
+            ranges.len(),
+            cpus,
+            model_bytes,
+            available,
+        );
+
+        while extra.len() + 1 < workers {
+            match self.load_worker_engine(&model_id) {
+                Ok(engine) => extra.push(engine),
+                Err(e) => {
+                    warn!("Failed to load transcription worker: {}", e);
+                    break;
+                }
+            }
+        }
+        let workers = workers.min(extra.len() + 1);
+        info!(
+            "Transcribing {} chunks on {} workers",
+            ranges.len(),
+            workers
+        );
+
+        let results = {
+            let mut engine_guard = self.engine.lock().unwrap();
+            match engine_guard.as_mut() {
+                Some(main) => {
+                    let engines = std::iter::once(main)
+                        .chain(extra.iter_mut().take(workers - 1))
+                        .collect();
+                    Ok(run_chunks(engines, audio, &ranges, settings, on_progress))
+                }
+                None => Err(anyhow::anyhow!(
+                    "Model failed to load after auto-load attempt. Please check your model settings."
+                )),
+            }
+        };
+        // Keep the copies only if the model wasn't switched or unloaded meanwhile
+        {
+            let current_model = self.current_model_id.lock().unwrap();
+            if current_model.as_deref() == Some(model_id.as_str()) {
+                *self.worker_engines.lock().unwrap() = Some(WorkerEngines {
+                    model_id,
+                    engines: extra,
+                });
+            }
+        }
+
+        let mut results = results?;
+        if results.len() != ranges.len() {
+            return Err(anyhow::anyhow!("A transcription worker panicked"));
+        }
+        results.sort_by_key(|(i, _)| *i);
+
+        let mut texts = Vec::with_capacity(results.len());
+        for (_, result) in results {
+            let text = result?;
+            let text = text.trim();
+            if !text.is_empty() {
+                texts.push(text.to_string());
+            }
+        }
+        Ok(texts.join(" "))
+    }
+
+    /// Load another instance of a model for the parallel worker pool.
+    fn load_worker_engine(&self, model_id: &str) -> Result<LoadedEngine> {
+        let model_info = self
+            .model_manager
+            .get_model_info(model_id)
+            .ok_or_else(|| anyhow::anyhow!("Model not found: {}", model_id))?;
+        let model_path = self.model_manager.get_model_path(model_id)?;
+
+        match model_info.engine_type {
+            EngineType::Whisper => {
+                let mut engine = WhisperEngine::new();
+                engine.load_model(&model_path).map_err(|e| {
+                    anyhow::anyhow!("Failed to load whisper model {}: {}", model_id, e)
+                })?;
+                Ok(LoadedEngine::Whisper(engine))
+            }
+            EngineType::Parakeet => {
+                let mut engine = ParakeetEngine::new();
+                engine
+                    .load_model_with_params(&model_path, ParakeetModelParams::int8())
+                    .map_err(|e| {
+                        anyhow::anyhow!("Failed to load parakeet model {}: {}", model_id, e)
+                    })?;
+                Ok(LoadedEngine::Parakeet(engine))
+            }
+            EngineType::Moonshine => Err(anyhow::anyhow!(
+                "Moonshine engine is not available in this build"
+            )),
+        }
+    }
+
+    /// Transcribe a finished recording. `private` keeps the text out of the logs.
+    pub fn transcribe(&self, audio: Vec<f32>, private: bool) -> Result<String> {
+        let _span = info_span!("transcribe", samples = audio.len()).entered();
+        let result = self.transcribe_inner(audio, Split::IfLong, private);
         if result.as_ref().map_or(true, |s| s.is_empty()) {
             self.maybe_unload_immediately("empty audio");
         } else {
@@ -445,20 +742,145 @@ impl TranscriptionManager {
 
     /// Transcribe without unloading the model afterwards.
     /// Used for streaming/partial transcription during recording.
-    pub fn transcribe_partial(&self, audio: Vec<f32>) -> Result<String> {
-        self.transcribe_inner(audio)
+    pub fn transcribe_partial(&self, audio: Vec<f32>, private: bool) -> Result<String> {
+        self.transcribe_inner(audio, Split::Never, private)
     }
+
+    /// Transcribe a long recording, such as an audio file, in chunks on the
+    /// worker pool, calling `on_progress` as they finish. The model is not
+    /// unloaded afterwards.
+    pub fn transcribe_long(
+        &self,
+        audio: Vec<f32>,
+        private: bool,
+        on_progress: ChunkProgress,
+    ) -> Result<String> {
+        let _span = info_span!("transcribe_long", samples = audio.len()).entered();
+        self.transcribe_inner(audio, Split::Always(on_progress), private)
+    }
+}
+
+/// Run one engine over a block of audio and return the raw text.
+fn run_engine(
+    engine: &mut LoadedEngine,
+    audio: Vec<f32>,
+    settings: &AppSettings,
+) -> Result<String> {
+    let result = match engine {
+        LoadedEngine::Whisper(whisper_engine) => {
+            // Normalize language code for Whisper
+            // Convert zh-Hans and zh-Hant to zh since Whisper uses ISO 639-1 codes
+            let whisper_language = if settings.selected_language == "auto" {
+                None
+            } else {
+                let normalized = if settings.selected_language == "zh-Hans"
+                    || settings.selected_language == "zh-Hant"
+                {
+                    "zh".to_string()
+                } else {
+                    settings.selected_language.clone()
+                };
+                Some(normalized)
+            };
+
+            let params = WhisperInferenceParams {
+                language: whisper_language,
+                translate: settings.translate_to_english,
+                ..Default::default()
+            };
+
+            whisper_engine
+                .transcribe_samples(audio, Some(params))
+                .map_err(|e| anyhow::anyhow!("Whisper transcription failed: {}", e))?
+        }
+        LoadedEngine::Parakeet(parakeet_engine) => parakeet_engine
+            .transcribe_samples(audio, None)
+            .map_err(|e| anyhow::anyhow!("Parakeet transcription failed: {}", e))?,
+    };
+    Ok(result.text)
+}
+
+/// Transcribe `ranges` of `audio` on one thread per engine. Each thread takes
+/// the next untranscribed chunk until none are left; results are tagged with
+/// the chunk index. Chunks of a panicked thread are missing from the result.
+fn run_chunks(
+    engines: Vec<&mut LoadedEngine>,
+    audio: &[f32],
+    ranges: &[Range<usize>],
+    settings: &AppSettings,
+    on_progress: Option<ChunkProgress>,
+) -> Vec<(usize, Result<String>)> {
+    let next = AtomicUsize::new(0);
+    let finished = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        let handles: Vec<_> = engines
+            .into_iter()
+            .map(|engine| {
+                let (next, finished) = (&next, &finished);
+                scope.spawn(move || {
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(range) = ranges.get(i) else {
+                            break;
+                        };
+                        done.push((
+                            i,
+                            run_engine(engine, audio[range.clone()].to_vec(), settings),
+                        ));
+                        if let Some(on_progress) = on_progress {
+                            let finished = finished.fetch_add(1, Ordering::Relaxed) + 1;
+                            on_progress(finished as f64 / ranges.len() as f64);
+                        }
+                    }
+                    done
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    })
+}
+
+/// Number of engines to run in parallel: limited by the setting, the number
+/// of chunks, CPU cores and the memory each extra copy of the model needs.
+fn worker_count(
+    requested: usize,
+    chunks: usize,
+    cpus: usize,
+    model_bytes: u64,
+    available_bytes: Option<u64>,
+) -> usize {
+    let by_cpu = cpus / THREADS_PER_WORKER;
+    let by_memory = match available_bytes {
+        Some(available) if model_bytes > 0 => {
+            1 + (available.saturating_sub(MEMORY_RESERVE_BYTES) / model_bytes) as usize
+        }
+        // Model size unknown; don't let memory be the limit
+        Some(_) => usize::MAX,
+        // Available memory unknown; allow a single extra copy
+        None => 2,
+    };
+    requested.min(chunks).min(by_cpu).min(by_memory).max(1)
 }
 
 impl Drop for TranscriptionManager {
     fn drop(&mut self) {
+        // Clones are handed to background threads; only the last owner
+        // besides the janitor stops it
+        if Arc::strong_count(&self.watcher_handle) > 2 {
+            return;
+        }
         debug!("Shutting down TranscriptionManager");
 
         // Signal the watcher thread to shutdown
         self.shutdown_signal.store(true, Ordering::Relaxed);
 
         // Wait for the thread to finish gracefully
-        if let Some(handle) = self.watcher_handle.lock().unwrap().take() {
+        let handle = self.watcher_handle.lock().unwrap().take();
+        if let Some(handle) = handle {
             if let Err(e) = handle.join() {
                 warn!("Failed to join idle watcher thread: {:?}", e);
             } else {
@@ -5,6 +5,7 @@
 use crate::managers::model::ModelManager;
 use anyhow::Result;
 use serde::Serialize;
+use specta::Type;
 use std::sync::Arc;
 use tauri::AppHandle;
 
@@ -16,6 +17,14 @@ pub struct ModelStateEvent {
     pub error: Option<String>,
 }
 
+#[derive(Clone, Debug, Serialize, Type)]
+pub struct ModelMemoryUsage {
+    pub current_model: Option<String>,
+    pub model_size_bytes: Option<u64>,
+    pub process_resident_bytes: Option<u64>,
+    pub on_battery: bool,
+}
+
 #[derive(Clone)]
 pub struct TranscriptionManager {
     #[allow(dead_code)]
@@ -45,11 +54,37 @@ impl TranscriptionManager {
 
     pub fn initiate_model_load(&self) {}
 
+    pub fn wait_for_load(&self) {}
+
+    pub fn apply_keep_alive_policy(&self) {}
+
+    pub fn memory_usage(&self) -> ModelMemoryUsage {
+        ModelMemoryUsage {
+            current_model: None,
+            model_size_bytes: None,
+            process_resident_bytes: None,
+            on_battery: false,
+        }
+    }
+
     pub fn get_current_model(&self) -> Option<String> {
         None
     }
 
-    pub fn transcribe(&self, _audio: Vec<f32>) -> Result<String> {
+    pub fn transcribe(&self, _audio: Vec<f32>, _private: bool) -> Result<String> {
+        Ok(String::new())
+    }
+
+    pub fn transcribe_partial(&self, _audio: Vec<f32>, _private: bool) -> Result<String> {
+        Ok(String::new())
+    }
+
+    pub fn transcribe_long(
+        &self,
+        _audio: Vec<f32>,
+        _private: bool,
+        _on_progress: &(dyn Fn(f64) + Sync),
+    ) -> Result<String> {
         Ok(String::new())
     }
 }
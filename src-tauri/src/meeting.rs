@@ -0,0 +1,419 @@
+//! Meeting mode: a long-running recording, started from the tray or the
+//! `meeting` binding, that is transcribed continuously for as long as it
+//! runs. The recorder hands out speech at natural pauses (see
+//! [`AudioRecordingManager::take_segment`]), so hours of audio never pile up
+//! in memory, and every segment becomes a timestamped line in a Markdown
+//! session file:
+//!
+//! ```text
+//! # Meeting notes — 2026-01-02 09:30
+//!
+//! [09:30:12] Let's start with the release dates.
+//! [09:30:20] The beta goes out on Friday.
+//! ```
+//!
+//! Nothing is ever pasted. When the session ends, the whole transcript is
+//! saved as one history entry, so a long meeting doesn't push every other
+//! dictation out of the history.
+
+use crate::app_error::{self, AppError};
+use crate::audio_feedback::{play_feedback_sound, SoundType};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
+use crate::file_output;
+use crate::latency::DictationTimings;
+use crate::managers::audio::AudioRecordingManager;
+use crate::managers::history::HistoryManager;
+use crate::managers::transcription::TranscriptionManager;
+use crate::overlay::{emit_meeting_progress, show_meeting_overlay};
+use crate::settings::get_settings;
+use crate::tray::{change_tray_icon, update_tray_menu, TrayIconState};
+use crate::utils::hide_recording_overlay;
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tracing::{debug, error, info, info_span, warn};
+
+/// Binding (and recording id) of meeting sessions.
+pub const BINDING: &str = "meeting";
+
+/// How often the worker asks the recorder for a finished segment.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Pause that ends a segment.
+const SEGMENT_SILENCE: Duration = Duration::from_millis(700);
+/// Longest segment, for speakers who never pause.
+const SEGMENT_MAX: Duration = Duration::from_secs(30);
+
+/// A running meeting, as shown in the overlay and settings.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct MeetingStatus {
+    /// The session file the transcript is appended to.
+    pub file: String,
+    pub words: usize,
+    pub paused: bool,
+    /// Unix timestamp, in seconds.
+    pub started_at: i64,
+}
+
+enum Control {
+    Pause,
+    Resume,
+    Stop,
+}
+
+struct Session {
+    file: PathBuf,
+    started_at: i64,
+    words: Arc<AtomicUsize>,
+    paused: Arc<AtomicBool>,
+    /// Set once stop was requested; the worker is finishing the transcript.
+    stopping: bool,
+    control: mpsc::Sender<Control>,
+}
+
+impl Session {
+    fn status(&self) -> MeetingStatus {
+        MeetingStatus {
+            file: self.file.to_string_lossy().into_owned(),
+            words: self.words.load(Ordering::Relaxed),
+            paused: self.paused.load(Ordering::Relaxed),
+            started_at: self.started_at,
+        }
+    }
+}
+
+/// Cleared by the worker once the session is fully written out.
+static SESSION: Lazy<Mutex<Option<Session>>> = Lazy::new(|| Mutex::new(None));
+
+/// Drop guard that ends the session when the worker finishes — whether it
+/// completes normally or panics, so dictation isn't blocked until restart.
+struct SessionGuard(AppHandle);
+
+impl Drop for SessionGuard {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            error!("Meeting worker panicked, ending the session");
+            if let Some(rm) = self.0.try_state::<Arc<AudioRecordingManager>>() {
+                rm.cancel_recording();
+            }
+        }
+        *SESSION.lock().unwrap_or_else(PoisonError::into_inner) = None;
+        hide_recording_overlay(&self.0);
+        change_tray_icon(&self.0, TrayIconState::Idle);
+    }
+}
+
+/// Whether a meeting is running (or still finishing its last segment).
+/// Dictation bindings are ignored meanwhile.
+pub fn is_active() -> bool {
+    SESSION.lock().unwrap().is_some()
+}
+
+pub fn status() -> Option<MeetingStatus> {
+    SESSION.lock().unwrap().as_ref().map(Session::status)
+}
+
+/// Start a meeting: create the session file and start recording.
+pub fn start(app: &AppHandle) -> Result<MeetingStatus, String> {
+    let mut session = SESSION.lock().unwrap();
+    if session.is_some() {
+        return Err("A meeting is already running".to_string());
+    }
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    if rm.is_recording() {
+        return Err("Finish the current dictation before starting a meeting".to_string());
+    }
+
+    crate::tts::stop();
+    let settings = get_settings(app);
+    let now = Local::now();
+    let file =
+        notes_folder(app, settings.meeting_notes_folder.as_deref())?.join(session_file_name(&now));
+    create_session_file(&file, &now)?;
+
+    // Private mode keeps meetings out of the history and the logs too
+    let private = settings.private_mode;
+    let tm = app.state::<Arc<TranscriptionManager>>();
+    tm.initiate_model_load();
+
+    if !rm.try_start_recording(BINDING) {
+        return Err("Could not start recording".to_string());
+    }
+    info!("Meeting started, writing to {}", file.display());
+
+    let (control, control_rx) = mpsc::channel();
+    let words = Arc::new(AtomicUsize::new(0));
+    let paused = Arc::new(AtomicBool::new(false));
+    let worker = Worker {
+        app: app.clone(),
+        file: file.clone(),
+        words: words.clone(),
+        paused: paused.clone(),
+        private,
+        transcript: Vec::new(),
+        write_failed: false,
+    };
+    thread::spawn(move || worker.run(control_rx));
+
+    let started = Session {
+        file,
+        started_at: now.timestamp(),
+        words,
+        paused,
+        stopping: false,
+        control,
+    };
+    let status = started.status();
+    *session = Some(started);
+    // The tray menu reads the session, so it is built after the lock is gone
+    drop(session);
+
+    play_feedback_sound(app, SoundType::Start);
+    change_tray_icon(app, TrayIconState::Recording);
+    show_meeting_overlay(app);
+    emit_meeting_progress(app, 0, false);
+    Ok(status)
+}
+
+/// Stop the meeting. The last segment is transcribed in the background.
+pub fn stop(app: &AppHandle) -> Result<(), String> {
+    let mut session = SESSION.lock().unwrap();
+    let Some(session) = session.as_mut().filter(|s| !s.stopping) else {
+        return Err("No meeting is running".to_string());
+    };
+    session.stopping = true;
+    let _ = session.control.send(Control::Stop);
+    play_feedback_sound(app, SoundType::Stop);
+    Ok(())
+}
+
+/// Pause or resume recording; the session file stays open.
+pub fn set_paused(app: &AppHandle, paused: bool) -> Result<MeetingStatus, String> {
+    let status = {
+        let session = SESSION.lock().unwrap();
+        let Some(session) = session.as_ref().filter(|s| !s.stopping) else {
+            return Err("No meeting is running".to_string());
+        };
+        if session.paused.swap(paused, Ordering::SeqCst) == paused {
+            return Ok(session.status());
+        }
+        let _ = session.control.send(if paused {
+            Control::Pause
+        } else {
+            Control::Resume
+        });
+        session.status()
+    };
+
+    emit_meeting_progress(app, status.words, paused);
+    update_tray_menu(app, &TrayIconState::Recording, None);
+    Ok(status)
+}
+
+/// Start a meeting, or stop the running one.
+pub fn toggle(app: &AppHandle) {
+    let result = if is_active() {
+        stop(app)
+    } else {
+        start(app).map(|_| ())
+    };
+    if let Err(e) = result {
+        warn!("Meeting toggle ignored: {}", e);
+    }
+}
+
+/// The configured notes folder, or `meetings` in the app data directory.
+fn notes_folder(app: &AppHandle, configured: Option<&str>) -> Result<PathBuf, String> {
+    match configured.filter(|folder| !folder.trim().is_empty()) {
+        Some(folder) => file_output::resolve_path(folder, app.path().home_dir().ok().as_deref()),
+        None => app
+            .path()
+            .app_data_dir()
+            .map(|dir| dir.join("meetings"))
+            .map_err(|e| format!("no app data directory: {}", e)),
+    }
+}
+
+fn session_file_name(started: &DateTime<Local>) -> String {
+    format!("meeting-{}.md", started.format("%Y-%m-%d-%H%M%S"))
+}
+
+fn create_session_file(file: &Path, started: &DateTime<Local>) -> Result<(), String> {
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("could not create {}: {}", parent.display(), e))?;
+    }
+    fs::write(
+        file,
+        format!("# Meeting notes — {}\n\n", started.format("%Y-%m-%d %H:%M")),
+    )
+    .map_err(|e| format!("could not create {}: {}", file.display(), e))
+}
+
+/// One transcript line, stamped with the time the segment started.
+fn format_line(spoken_at: &DateTime<Local>, text: &str) -> String {
+    format!("[{}] {}\n", spoken_at.format("%H:%M:%S"), text.trim())
+}
+
+/// Owns the recording for the whole session: takes segments, transcribes
+/// them and acts on pause/resume/stop.
+struct Worker {
+    app: AppHandle,
+    file: PathBuf,
+    words: Arc<AtomicUsize>,
+    paused: Arc<AtomicBool>,
+    private: bool,
+    transcript: Vec<String>,
+    /// Only the first failed write is reported, not one per segment.
+    write_failed: bool,
+}
+
+impl Worker {
+    fn run(mut self, control: mpsc::Receiver<Control>) {
+        let _span = info_span!("meeting", private = self.private).entered();
+        let _guard = SessionGuard(self.app.clone());
+        let rm = self
+            .app
+            .state::<Arc<AudioRecordingManager>>()
+            .inner()
+            .clone();
+        let tm = self
+            .app
+            .state::<Arc<TranscriptionManager>>()
+            .inner()
+            .clone();
+        // Speech keeps buffering in the recorder while the model loads
+        tm.wait_for_load();
+
+        let mut recording = true;
+        loop {
+            match control.recv_timeout(POLL_INTERVAL) {
+                Ok(Control::Pause) => {
+                    if let Some(samples) = rm.stop_recording(BINDING) {
+                        self.transcribe(&tm, samples);
+                    }
+                    recording = false;
+                    debug!("Meeting paused");
+                    continue;
+                }
+                Ok(Control::Resume) => {
+                    recording = rm.try_start_recording(BINDING);
+                    if !recording {
+                        warn!("Could not resume the meeting recording");
+                    }
+                    continue;
+                }
+                Ok(Control::Stop) | Err(mpsc::RecvTimeoutError::Disconnected) => break,
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+            }
+            if !recording {
+                continue;
+            }
+            if let Some(samples) = rm.take_segment(BINDING, SEGMENT_SILENCE, SEGMENT_MAX) {
+                self.transcribe(&tm, samples);
+            }
+        }
+
+        if let Some(samples) = rm.stop_recording(BINDING) {
+            self.transcribe(&tm, samples);
+        }
+        tm.maybe_unload_immediately("meeting");
+        self.save_history();
+        info!(
+            "Meeting finished: {} words in {}",
+            self.words.load(Ordering::Relaxed),
+            self.file.display()
+        );
+    }
+
+    fn transcribe(&mut self, tm: &TranscriptionManager, samples: Vec<f32>) {
+        let duration = Duration::from_secs_f32(samples.len() as f32 / WHISPER_SAMPLE_RATE as f32);
+        let spoken_at = Local::now() - duration;
+        let text = match tm.transcribe_partial(samples, self.private) {
+            Ok(text) if text.trim().is_empty() => return,
+            Ok(text) => text,
+            Err(e) => {
+                // One lost segment shouldn't end a meeting
+                error!("Meeting segment transcription failed: {}", e);
+                return;
+            }
+        };
+
+        self.append(&format_line(&spoken_at, &text));
+        let count = text.split_whitespace().count();
+        let words = self.words.fetch_add(count, Ordering::Relaxed) + count;
+        emit_meeting_progress(&self.app, words, self.paused.load(Ordering::Relaxed));
+        self.transcript.push(text.trim().to_string());
+    }
+
+    fn append(&mut self, line: &str) {
+        let result = OpenOptions::new()
+            .append(true)
+            .open(&self.file)
+            .and_then(|mut file| file.write_all(line.as_bytes()));
+        match result {
+            Ok(()) => self.write_failed = false,
+            Err(e) if !self.write_failed => {
+                self.write_failed = true;
+                app_error::report(
+                    &self.app,
+                    AppError::OutputFailed {
+                        target: self.file.to_string_lossy().into_owned(),
+                        message: e.to_string(),
+                    },
+                );
+            }
+            Err(e) => debug!("Meeting notes write failed again: {}", e),
+        }
+    }
+
+    fn save_history(&mut self) {
+        if self.private || self.transcript.is_empty() {
+            return;
+        }
+        let hm = self.app.state::<Arc<HistoryManager>>().inner().clone();
+        let text = self.transcript.join("\n");
+        // The meeting's audio isn't kept, only its transcript
+        let result = tauri::async_runtime::block_on(hm.save_transcription(
+            None,
+            text,
+            None,
+            None,
+            Vec::new(),
+            DictationTimings::default(),
+            None,
+        ));
+        if let Err(e) = result {
+            error!("Failed to save meeting transcript to history: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn lines_are_stamped_and_trimmed() {
+        let at = Local.with_ymd_and_hms(2026, 1, 2, 9, 30, 12).unwrap();
+        assert_eq!(
+            format_line(&at, " The beta goes out on Friday. "),
+            "[09:30:12] The beta goes out on Friday.\n"
+        );
+    }
+
+    #[test]
+    fn session_files_are_named_after_their_start() {
+        let at = Local.with_ymd_and_hms(2026, 1, 2, 9, 30, 12).unwrap();
+        assert_eq!(session_file_name(&at), "meeting-2026-01-02-093012.md");
+    }
+}
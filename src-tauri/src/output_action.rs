@@ -0,0 +1,298 @@
+//! Output actions: what happens to a binding's final text. Besides pasting
+//! it, the text can be appended to a file ([`file_output`]), POSTed to a
+//! webhook or piped to a shell command, which is enough to feed note apps,
+//! todo managers and custom automations.
+//!
+//! Webhooks receive a JSON body:
+//!
+//! ```json
+//! { "text": "...", "binding": "transcribe", "app_name": "Slack",
+//!   "language": "en", "timestamp": "2026-01-02T09:30:00+01:00" }
+//! ```
+//!
+//! Commands run through the platform shell with the text on stdin and the
+//! same context in `VOICE_INPUT_BINDING`, `VOICE_INPUT_APP` and
+//! `VOICE_INPUT_LANGUAGE`.
+//!
+//! [`file_output`]: crate::file_output
+
+use crate::app_error::{self, AppError};
+use crate::file_output;
+use crate::settings::{AppSettings, OutputAction};
+use serde::Serialize;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+use tauri::AppHandle;
+use tracing::{debug, info};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+/// Longest stderr excerpt included in a command error, in characters.
+const STDERR_CHARS: usize = 200;
+
+/// Context passed along with the text to webhooks and commands.
+#[derive(Serialize, Debug)]
+pub struct OutputContext {
+    pub binding: String,
+    pub app_name: Option<String>,
+    pub language: String,
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    text: &'a str,
+    #[serde(flatten)]
+    context: &'a OutputContext,
+    timestamp: String,
+}
+
+/// Whether the text is pasted into the focused window.
+pub fn pastes(actions: &[OutputAction]) -> bool {
+    actions.is_empty() || actions.contains(&OutputAction::Paste)
+}
+
+/// Check an action before it is saved.
+pub fn validate(action: &OutputAction) -> Result<(), String> {
+    match action {
+        OutputAction::Paste => Ok(()),
+        OutputAction::AppendToFile { path, .. } if path.trim().is_empty() => {
+            Err("An output file path is required".to_string())
+        }
+        OutputAction::AppendToFile { .. } => Ok(()),
+        OutputAction::Webhook { url }
+            if !(url.starts_with("http://") || url.starts_with("https://")) =>
+        {
+            Err(format!("'{}' is not an http(s) URL", url))
+        }
+        OutputAction::Webhook { .. } => Ok(()),
+        OutputAction::Command { command } if command.trim().is_empty() => {
+            Err("A command is required".to_string())
+        }
+        OutputAction::Command { .. } => Ok(()),
+    }
+}
+
+/// Run every action except pasting, in order. A failed action is reported
+/// and doesn't stop the others. Returns whether all of them succeeded.
+pub async fn run(
+    app: &AppHandle,
+    settings: &AppSettings,
+    actions: &[OutputAction],
+    text: &str,
+    context: &OutputContext,
+) -> bool {
+    let mut all_succeeded = true;
+    for action in actions {
+        let result = match action {
+            OutputAction::Paste => continue,
+            OutputAction::AppendToFile { path, header } => file_output::append(
+                app,
+                settings,
+                path,
+                header,
+                text,
+                context.app_name.as_deref(),
+            )
+            .map(|written| info!("Appended dictation to {}", written.display())),
+            OutputAction::Webhook { url } => post_webhook(url, text, context)
+                .await
+                .map(|()| info!("Sent dictation to webhook {}", url)),
+            OutputAction::Command { command } => {
+                let (command, text) = (command.clone(), text.to_string());
+                let env = command_env(context);
+                tauri::async_runtime::spawn_blocking(move || run_command(&command, &text, &env))
+                    .await
+                    .unwrap_or_else(|e| Err(format!("command task failed: {}", e)))
+                    .map(|()| info!("Piped dictation to command"))
+            }
+        };
+
+        if let Err(message) = result {
+            all_succeeded = false;
+            app_error::report(
+                app,
+                AppError::OutputFailed {
+                    target: target_label(action),
+                    message,
+                },
+            );
+        }
+    }
+    all_succeeded
+}
+
+/// What an action writes to, for error messages.
+fn target_label(action: &OutputAction) -> String {
+    match action {
+        OutputAction::Paste => "the focused window".to_string(),
+        OutputAction::AppendToFile { path, .. } => path.clone(),
+        OutputAction::Webhook { url } => url.clone(),
+        OutputAction::Command { command } => command.clone(),
+    }
+}
+
+async fn post_webhook(url: &str, text: &str, context: &OutputContext) -> Result<(), String> {
+    let payload = WebhookPayload {
+        text,
+        context,
+        timestamp: chrono::Local::now().to_rfc3339(),
+    };
+    let client = reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+    let response = client
+        .post(url)
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+    debug!("Webhook answered {}", status);
+    Ok(())
+}
+
+fn command_env(context: &OutputContext) -> Vec<(&'static str, String)> {
+    vec![
+        ("VOICE_INPUT_BINDING", context.binding.clone()),
+        (
+            "VOICE_INPUT_APP",
+            context.app_name.clone().unwrap_or_default(),
+        ),
+        ("VOICE_INPUT_LANGUAGE", context.language.clone()),
+    ]
+}
+
+fn shell(command: &str) -> Command {
+    #[cfg(windows)]
+    {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    }
+    #[cfg(not(windows))]
+    {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+/// Run `command` with `text` on stdin, killing it after [`COMMAND_TIMEOUT`]
+/// so a hung script can't hold up the dictations queued behind it.
+fn run_command(command: &str, text: &str, env: &[(&str, String)]) -> Result<(), String> {
+    let mut child = shell(command)
+        .envs(env.iter().map(|(key, value)| (*key, value)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("could not start: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that doesn't read its input closes the pipe early;
+        // its exit status is what counts
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    let started = Instant::now();
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if started.elapsed() > COMMAND_TIMEOUT => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(format!("timed out after {}s", COMMAND_TIMEOUT.as_secs()));
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(20)),
+            Err(e) => return Err(format!("could not wait for it: {}", e)),
+        }
+    };
+
+    if status.success() {
+        return Ok(());
+    }
+    let mut stderr = String::new();
+    if let Some(mut pipe) = child.stderr.take() {
+        let _ = pipe.read_to_string(&mut stderr);
+    }
+    let stderr: String = stderr.trim().chars().take(STDERR_CHARS).collect();
+    if stderr.is_empty() {
+        Err(format!("exited with {}", status))
+    } else {
+        Err(format!("exited with {}: {}", status, stderr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context() -> OutputContext {
+        OutputContext {
+            binding: "transcribe".to_string(),
+            app_name: None,
+            language: "en".to_string(),
+        }
+    }
+
+    #[test]
+    fn pastes_unless_configured_otherwise() {
+        let webhook = OutputAction::Webhook {
+            url: "https://example.com/hook".to_string(),
+        };
+        assert!(pastes(&[]));
+        assert!(pastes(&[webhook.clone(), OutputAction::Paste]));
+        assert!(!pastes(&[webhook]));
+    }
+
+    #[test]
+    fn rejects_incomplete_actions() {
+        let webhook = |url: &str| OutputAction::Webhook {
+            url: url.to_string(),
+        };
+        assert!(validate(&webhook("https://example.com/hook")).is_ok());
+        assert!(validate(&webhook("example.com/hook")).is_err());
+        assert!(validate(&OutputAction::Command {
+            command: " ".to_string()
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn webhook_payload_flattens_the_context() {
+        let context = context();
+        let payload = WebhookPayload {
+            text: "hello",
+            context: &context,
+            timestamp: "2026-01-02T09:30:00+01:00".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "text": "hello",
+                "binding": "transcribe",
+                "app_name": null,
+                "language": "en",
+                "timestamp": "2026-01-02T09:30:00+01:00"
+            })
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn commands_get_the_text_on_stdin() {
+        let env = command_env(&context());
+        let check = r#"[ "$(cat)" = "hello" ] && [ "$VOICE_INPUT_BINDING" = transcribe ]"#;
+        assert!(run_command(check, "hello", &env).is_ok());
+
+        let err = run_command("echo oops >&2; exit 3", "hello", &env).unwrap_err();
+        assert!(err.ends_with(": oops"), "{}", err);
+    }
+}
@@ -1,10 +1,14 @@
+use crate::audio_toolkit::audio::InputLevel;
 use crate::input;
 use crate::settings;
-use crate::settings::OverlayPosition;
+use crate::settings::{OverlayLayout, OverlayPosition};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize};
-
-#[cfg(not(target_os = "macos"))]
-use log::debug;
+use tracing::debug;
 
 #[cfg(not(target_os = "macos"))]
 use tauri::WebviewWindowBuilder;
@@ -22,7 +26,9 @@ use gtk_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
 tauri_panel! {
     panel!(RecordingOverlayPanel {
         config: {
-            can_become_key_window: false,
+            // Non-activating, so this only matters while confirmation
+            // mode's held text is being edited in it
+            can_become_key_window: true,
             is_floating_panel: true
         }
     })
@@ -42,23 +48,70 @@ const OVERLAY_BOTTOM_OFFSET: f64 = 15.0;
 #[cfg(any(target_os = "windows", target_os = "linux"))]
 const OVERLAY_BOTTOM_OFFSET: f64 = 40.0;
 
+const OVERLAY_SIDE_OFFSET: f64 = 16.0;
+
+/// Placement shown while the settings UI previews changes; overrides saved settings.
+static PREVIEW_PLACEMENT: Lazy<Mutex<Option<(OverlayPosition, OverlayLayout)>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// How long an error stays in the overlay before it hides itself.
+const ERROR_DISPLAY_MS: u64 = 4000;
+
+/// Bumped whenever the overlay is shown, so a pending error auto-hide does not
+/// hide an overlay that a newer recording is using.
+static OVERLAY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Payload of the `overlay-style` event consumed by the overlay webview.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct OverlayStyle {
+    pub scale: f64,
+    pub opacity: f64,
+}
+
+/// The placement currently in effect: the live preview if one is active, else settings.
+fn effective_placement(app_handle: &AppHandle) -> (OverlayPosition, OverlayLayout) {
+    if let Some(preview) = PREVIEW_PLACEMENT.lock().unwrap().clone() {
+        return preview;
+    }
+    let settings = settings::get_settings(app_handle);
+    (settings.overlay_position, settings.overlay_layout)
+}
+
+/// Default overlay size in logical pixels for the given layout.
+fn base_overlay_size(layout: &OverlayLayout) -> (f64, f64) {
+    (OVERLAY_WIDTH * layout.scale, OVERLAY_HEIGHT * layout.scale)
+}
+
 #[cfg(target_os = "linux")]
 fn update_gtk_layer_shell_anchors(overlay_window: &tauri::webview::WebviewWindow) {
     let window_clone = overlay_window.clone();
     let _ = overlay_window.run_on_main_thread(move || {
         // Try to get the GTK window from the Tauri webview
         if let Ok(gtk_window) = window_clone.gtk_window() {
-            let settings = settings::get_settings(window_clone.app_handle());
-            match settings.overlay_position {
-                OverlayPosition::Top => {
-                    gtk_window.set_anchor(Edge::Top, true);
-                    gtk_window.set_anchor(Edge::Bottom, false);
-                }
-                OverlayPosition::Bottom | OverlayPosition::None => {
-                    gtk_window.set_anchor(Edge::Bottom, true);
-                    gtk_window.set_anchor(Edge::Top, false);
-                }
-            }
+            let (position, layout) = effective_placement(window_clone.app_handle());
+            // (top, bottom, left, right); an edge that is not anchored is centered
+            let anchors = match position {
+                OverlayPosition::Top => (true, false, false, false),
+                OverlayPosition::Bottom | OverlayPosition::None => (false, true, false, false),
+                OverlayPosition::TopLeft | OverlayPosition::Custom => (true, false, true, false),
+                OverlayPosition::TopRight => (true, false, false, true),
+                OverlayPosition::BottomLeft => (false, true, true, false),
+                OverlayPosition::BottomRight => (false, true, false, true),
+                OverlayPosition::Left => (false, false, true, false),
+                OverlayPosition::Right => (false, false, false, true),
+            };
+            gtk_window.set_anchor(Edge::Top, anchors.0);
+            gtk_window.set_anchor(Edge::Bottom, anchors.1);
+            gtk_window.set_anchor(Edge::Left, anchors.2);
+            gtk_window.set_anchor(Edge::Right, anchors.3);
+
+            let (margin_left, margin_top) = if position == OverlayPosition::Custom {
+                (layout.custom_x as i32, layout.custom_y as i32)
+            } else {
+                (0, 0)
+            };
+            gtk_window.set_layer_shell_margin(Edge::Left, margin_left);
+            gtk_window.set_layer_shell_margin(Edge::Top, margin_top);
         }
     });
 }
@@ -149,6 +202,21 @@ fn get_monitor_with_cursor(app_handle: &AppHandle) -> Option<tauri::Monitor> {
     app_handle.primary_monitor().ok().flatten()
 }
 
+/// The monitor chosen in the layout, falling back to the one under the cursor.
+fn get_overlay_monitor(app_handle: &AppHandle, layout: &OverlayLayout) -> Option<tauri::Monitor> {
+    if let Some(index) = layout.monitor {
+        let monitor = app_handle
+            .available_monitors()
+            .ok()
+            .and_then(|monitors| monitors.into_iter().nth(index as usize));
+        if monitor.is_some() {
+            return monitor;
+        }
+        debug!("Overlay monitor {} not available, following cursor", index);
+    }
+    get_monitor_with_cursor(app_handle)
+}
+
 fn is_mouse_within_monitor(
     mouse_pos: (i32, i32),
     monitor_pos: &PhysicalPosition<i32>,
@@ -170,34 +238,77 @@ fn is_mouse_within_monitor(
         && mouse_y < (monitor_y + monitor_height as i32)
 }
 
-fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
-    if let Some(monitor) = get_monitor_with_cursor(app_handle) {
-        let work_area = monitor.work_area();
-        let scale = monitor.scale_factor();
-        let work_area_width = work_area.size.width as f64 / scale;
-        let work_area_height = work_area.size.height as f64 / scale;
-        let work_area_x = work_area.position.x as f64 / scale;
-        let work_area_y = work_area.position.y as f64 / scale;
-
-        let settings = settings::get_settings(app_handle);
-
-        let x = work_area_x + (work_area_width - OVERLAY_WIDTH) / 2.0;
-        let y = match settings.overlay_position {
-            OverlayPosition::Top => work_area_y + OVERLAY_TOP_OFFSET,
-            OverlayPosition::Bottom | OverlayPosition::None => {
-                work_area_y + work_area_height - OVERLAY_HEIGHT - OVERLAY_BOTTOM_OFFSET
-            }
-        };
-
-        return Some((x, y));
+/// Logical position of an overlay of `size` inside `work_area` (x, y, width, height).
+fn overlay_origin(
+    position: OverlayPosition,
+    layout: &OverlayLayout,
+    work_area: (f64, f64, f64, f64),
+    size: (f64, f64),
+) -> (f64, f64) {
+    let (area_x, area_y, area_width, area_height) = work_area;
+    let (width, height) = size;
+
+    let left = area_x + OVERLAY_SIDE_OFFSET;
+    let center_x = area_x + (area_width - width) / 2.0;
+    let right = area_x + area_width - width - OVERLAY_SIDE_OFFSET;
+    let top = area_y + OVERLAY_TOP_OFFSET;
+    let center_y = area_y + (area_height - height) / 2.0;
+    let bottom = area_y + area_height - height - OVERLAY_BOTTOM_OFFSET;
+
+    match position {
+        OverlayPosition::Top => (center_x, top),
+        OverlayPosition::Bottom | OverlayPosition::None => (center_x, bottom),
+        OverlayPosition::TopLeft => (left, top),
+        OverlayPosition::TopRight => (right, top),
+        OverlayPosition::BottomLeft => (left, bottom),
+        OverlayPosition::BottomRight => (right, bottom),
+        OverlayPosition::Left => (left, center_y),
+        OverlayPosition::Right => (right, center_y),
+        OverlayPosition::Custom => {
+            // Keep the overlay fully inside the work area
+            let x = area_x + layout.custom_x.min((area_width - width).max(0.0));
+            let y = area_y + layout.custom_y.min((area_height - height).max(0.0));
+            (x, y)
+        }
     }
-    None
+}
+
+fn calculate_overlay_position_for_size(
+    app_handle: &AppHandle,
+    width: f64,
+    height: f64,
+) -> Option<(f64, f64)> {
+    let (position, layout) = effective_placement(app_handle);
+    let monitor = get_overlay_monitor(app_handle, &layout)?;
+
+    let work_area = monitor.work_area();
+    let scale = monitor.scale_factor();
+    let work_area = (
+        work_area.position.x as f64 / scale,
+        work_area.position.y as f64 / scale,
+        work_area.size.width as f64 / scale,
+        work_area.size.height as f64 / scale,
+    );
+
+    Some(overlay_origin(
+        position,
+        &layout,
+        work_area,
+        (width, height),
+    ))
+}
+
+fn calculate_overlay_position(app_handle: &AppHandle) -> Option<(f64, f64)> {
+    let (_, layout) = effective_placement(app_handle);
+    let (width, height) = base_overlay_size(&layout);
+    calculate_overlay_position_for_size(app_handle, width, height)
 }
 
 /// Creates the recording overlay window and keeps it hidden by default
 #[cfg(not(target_os = "macos"))]
 pub fn create_recording_overlay(app_handle: &AppHandle) {
     let position = calculate_overlay_position(app_handle);
+    let (width, height) = base_overlay_size(&effective_placement(app_handle).1);
 
     // On Linux (Wayland), monitor detection often fails, but we don't need exact coordinates
     // for Layer Shell as we use anchors. On other platforms, we require a position.
@@ -214,7 +325,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
     )
     .title("Recording")
     .resizable(false)
-    .inner_size(OVERLAY_WIDTH, OVERLAY_HEIGHT)
+    .inner_size(width, height)
     .shadow(false)
     .maximizable(false)
     .minimizable(false)
@@ -254,6 +365,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
 /// Creates the recording overlay panel and keeps it hidden by default (macOS)
 #[cfg(target_os = "macos")]
 pub fn create_recording_overlay(app_handle: &AppHandle) {
+    let (width, height) = base_overlay_size(&effective_placement(app_handle).1);
     if let Some((x, y)) = calculate_overlay_position(app_handle) {
         // PanelBuilder creates a Tauri window then converts it to NSPanel.
         // The window remains registered, so get_webview_window() still works.
@@ -262,10 +374,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
             .title("Recording")
             .position(tauri::Position::Logical(tauri::LogicalPosition { x, y }))
             .level(PanelLevel::Status)
-            .size(tauri::Size::Logical(tauri::LogicalSize {
-                width: OVERLAY_WIDTH,
-                height: OVERLAY_HEIGHT,
-            }))
+            .size(tauri::Size::Logical(tauri::LogicalSize { width, height }))
             .has_shadow(false)
             .transparent(true)
             .no_activate(true)
@@ -282,7 +391,7 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
                 let _ = panel.hide();
             }
             Err(e) => {
-                log::error!("Failed to create recording overlay panel: {}", e);
+                tracing::error!("Failed to create recording overlay panel: {}", e);
             }
         }
     }
@@ -290,11 +399,12 @@ pub fn create_recording_overlay(app_handle: &AppHandle) {
 
 fn show_overlay_state(app_handle: &AppHandle, state: &str) {
     // Check if overlay should be shown based on position setting
-    let settings = settings::get_settings(app_handle);
-    if settings.overlay_position == OverlayPosition::None {
+    let (position, _) = effective_placement(app_handle);
+    if position == OverlayPosition::None {
         return;
     }
 
+    OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst);
     update_overlay_position(app_handle);
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
@@ -312,10 +422,50 @@ fn show_overlay_state(app_handle: &AppHandle, state: &str) {
             let _ = overlay_window.show();
         }
 
+        emit_overlay_style(app_handle);
         let _ = overlay_window.emit("show-overlay", state);
     }
 }
 
+/// Send the current scale and opacity to the overlay webview.
+fn emit_overlay_style(app_handle: &AppHandle) {
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let (_, layout) = effective_placement(app_handle);
+        let _ = overlay_window.emit(
+            "overlay-style",
+            OverlayStyle {
+                scale: layout.scale,
+                opacity: layout.opacity,
+            },
+        );
+    }
+}
+
+/// Show the overlay with an unsaved placement so changes can be previewed live.
+/// Calling it again replaces the preview; [`end_overlay_preview`] restores settings.
+pub fn preview_overlay_placement(
+    app_handle: &AppHandle,
+    position: OverlayPosition,
+    layout: OverlayLayout,
+) {
+    // Preview "none" at the default spot so there is something to look at
+    let position = if position == OverlayPosition::None {
+        OverlayPosition::Bottom
+    } else {
+        position
+    };
+    *PREVIEW_PLACEMENT.lock().unwrap() = Some((position, layout.clamped()));
+    reset_overlay_size(app_handle);
+    show_overlay_state(app_handle, "recording");
+}
+
+/// Leave preview mode and hide the overlay.
+pub fn end_overlay_preview(app_handle: &AppHandle) {
+    if PREVIEW_PLACEMENT.lock().unwrap().take().is_some() {
+        hide_recording_overlay(app_handle);
+    }
+}
+
 /// Shows the recording overlay window with fade-in animation
 pub fn show_recording_overlay(app_handle: &AppHandle) {
     show_overlay_state(app_handle, "recording");
@@ -331,6 +481,97 @@ pub fn show_processing_overlay(app_handle: &AppHandle) {
     show_overlay_state(app_handle, "processing");
 }
 
+/// Shows the meeting overlay: levels, word count and pause/stop controls
+pub fn show_meeting_overlay(app_handle: &AppHandle) {
+    show_overlay_state(app_handle, "meeting");
+}
+
+/// Shows finished text held for confirmation, with Accept / Edit / Discard buttons
+pub fn show_confirm_overlay(app_handle: &AppHandle, text: &str) {
+    let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") else {
+        return;
+    };
+
+    let _ = overlay_window.emit("confirm-text", text);
+    show_overlay_state(app_handle, "confirm");
+    resize_overlay_for_text(app_handle, &overlay_window, text);
+}
+
+/// Let the overlay take keyboard focus while held text is edited in it, or
+/// stop it from taking focus again. It never does otherwise, so recording
+/// doesn't steal key events from the focused app.
+pub fn set_overlay_editing(app_handle: &AppHandle, editing: bool) {
+    let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") else {
+        return;
+    };
+
+    // Layer-shell surfaces only get keyboard input when they ask for it
+    #[cfg(target_os = "linux")]
+    {
+        let window = overlay_window.clone();
+        let _ = overlay_window.run_on_main_thread(move || {
+            if let Ok(gtk_window) = window.gtk_window() {
+                if gtk_window.is_layer_window() {
+                    gtk_window.set_keyboard_mode(if editing {
+                        KeyboardMode::OnDemand
+                    } else {
+                        KeyboardMode::None
+                    });
+                }
+            }
+        });
+    }
+
+    if editing {
+        let _ = overlay_window.set_focus();
+    }
+}
+
+/// Payload of the `meeting-progress` event.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct MeetingProgress {
+    pub words: usize,
+    pub paused: bool,
+}
+
+/// Update the word count and paused state shown in the meeting overlay.
+pub fn emit_meeting_progress(app_handle: &AppHandle, words: usize, paused: bool) {
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("meeting-progress", MeetingProgress { words, paused });
+    }
+}
+
+/// Shows an error message in the overlay and hides it after a few seconds
+pub fn show_error_overlay(app_handle: &AppHandle, message: &str) {
+    let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") else {
+        return;
+    };
+
+    let _ = overlay_window.emit("overlay-error", message);
+    show_overlay_state(app_handle, "error");
+    resize_overlay_for_text(app_handle, &overlay_window, message);
+    hide_overlay_later(app_handle);
+}
+
+/// Shows "no speech detected" in place of a dropped transcript, briefly
+pub fn show_no_speech_overlay(app_handle: &AppHandle) {
+    reset_overlay_size(app_handle);
+    show_overlay_state(app_handle, "no-speech");
+    hide_overlay_later(app_handle);
+}
+
+/// Hide the overlay after a few seconds, unless it has been shown again since.
+fn hide_overlay_later(app_handle: &AppHandle) {
+    let generation = OVERLAY_GENERATION.load(Ordering::SeqCst);
+    let app_clone = app_handle.clone();
+    std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(ERROR_DISPLAY_MS));
+        if OVERLAY_GENERATION.load(Ordering::SeqCst) == generation {
+            hide_recording_overlay(&app_clone);
+        }
+    });
+}
+
 /// Updates the overlay window position based on current settings
 pub fn update_overlay_position(app_handle: &AppHandle) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
@@ -370,47 +611,48 @@ pub fn hide_recording_overlay(app_handle: &AppHandle) {
 pub fn emit_streaming_text(app_handle: &AppHandle, text: &str) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
         let _ = overlay_window.emit("streaming-text", text);
+        resize_overlay_for_text(app_handle, &overlay_window, text);
+    }
+}
 
-        // Resize overlay to fit text: estimate width from character count
-        // Use a reasonable max width (e.g., 600px) and let CSS handle wrapping
-        let char_count = text.chars().count();
-        let estimated_width = if char_count == 0 {
-            OVERLAY_WIDTH
-        } else {
-            // ~8px per char + padding for icon + cancel button + margins
-            let text_width = (char_count as f64 * 8.0) + 80.0;
-            text_width.clamp(OVERLAY_WIDTH, 600.0)
-        };
+fn resize_overlay_for_text(
+    app_handle: &AppHandle,
+    overlay_window: &tauri::WebviewWindow,
+    text: &str,
+) {
+    // Resize overlay to fit text: estimate width from character count
+    // Use a reasonable max width (e.g., 600px) and let CSS handle wrapping
+    let char_count = text.chars().count();
+    let estimated_width = if char_count == 0 {
+        OVERLAY_WIDTH
+    } else {
+        // ~8px per char + padding for icon + cancel button + margins
+        let text_width = (char_count as f64 * 8.0) + 80.0;
+        text_width.clamp(OVERLAY_WIDTH, 600.0)
+    };
 
-        // Height grows for multi-line: base 36px + extra for wrapped lines
-        let estimated_lines = if estimated_width >= 600.0 {
-            ((char_count as f64 * 8.0 + 80.0) / 600.0).ceil() as u32
-        } else {
-            1
-        };
-        let estimated_height = if estimated_lines > 1 {
-            36.0 + (estimated_lines - 1) as f64 * 18.0
-        } else {
-            OVERLAY_HEIGHT
-        };
+    // Height grows for multi-line: base 36px + extra for wrapped lines
+    let estimated_lines = if estimated_width >= 600.0 {
+        ((char_count as f64 * 8.0 + 80.0) / 600.0).ceil() as u32
+    } else {
+        1
+    };
+    let estimated_height = if estimated_lines > 1 {
+        36.0 + (estimated_lines - 1) as f64 * 18.0
+    } else {
+        OVERLAY_HEIGHT
+    };
 
-        let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize {
-            width: estimated_width,
-            height: estimated_height,
-        }));
-
-        // Re-center horizontally after resize
-        if let Some((_, y)) = calculate_overlay_position(app_handle) {
-            if let Some(monitor) = get_monitor_with_cursor(app_handle) {
-                let work_area = monitor.work_area();
-                let scale = monitor.scale_factor();
-                let work_area_width = work_area.size.width as f64 / scale;
-                let work_area_x = work_area.position.x as f64 / scale;
-                let x = work_area_x + (work_area_width - estimated_width) / 2.0;
-                let _ = overlay_window
-                    .set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
-            }
-        }
+    let (_, layout) = effective_placement(app_handle);
+    let width = estimated_width * layout.scale;
+    let height = estimated_height * layout.scale;
+
+    let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize { width, height }));
+
+    // Re-anchor after resize so the overlay grows away from its screen edge
+    if let Some((x, y)) = calculate_overlay_position_for_size(app_handle, width, height) {
+        let _ =
+            overlay_window.set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
     }
 }
 
@@ -425,10 +667,8 @@ pub fn emit_overlay_done(app_handle: &AppHandle, final_text: &str) {
 /// Reset overlay to its default size (called when streaming text is cleared)
 pub fn reset_overlay_size(app_handle: &AppHandle) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
-        let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize {
-            width: OVERLAY_WIDTH,
-            height: OVERLAY_HEIGHT,
-        }));
+        let (width, height) = base_overlay_size(&effective_placement(app_handle).1);
+        let _ = overlay_window.set_size(tauri::Size::Logical(tauri::LogicalSize { width, height }));
         // Re-center at default size
         if let Some((x, y)) = calculate_overlay_position(app_handle) {
             let _ = overlay_window
@@ -446,3 +686,59 @@ pub fn emit_levels(app_handle: &AppHandle, levels: &Vec<f32>) {
         let _ = overlay_window.emit("mic-level", levels);
     }
 }
+
+/// Emit the RMS/peak level of the latest input block (`input-level` event).
+pub fn emit_input_level(app_handle: &AppHandle, level: &InputLevel) {
+    let _ = app_handle.emit("input-level", level);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let _ = overlay_window.emit("input-level", level);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WORK_AREA: (f64, f64, f64, f64) = (100.0, 0.0, 1000.0, 800.0);
+    const SIZE: (f64, f64) = (200.0, 40.0);
+
+    #[test]
+    fn test_overlay_origin_anchors() {
+        let layout = OverlayLayout::default();
+        let origin = |position| overlay_origin(position, &layout, WORK_AREA, SIZE);
+
+        assert_eq!(origin(OverlayPosition::Top), (500.0, OVERLAY_TOP_OFFSET));
+        assert_eq!(
+            origin(OverlayPosition::BottomRight),
+            (
+                100.0 + 1000.0 - 200.0 - OVERLAY_SIDE_OFFSET,
+                800.0 - 40.0 - OVERLAY_BOTTOM_OFFSET
+            )
+        );
+        assert_eq!(
+            origin(OverlayPosition::Left),
+            (100.0 + OVERLAY_SIDE_OFFSET, 380.0)
+        );
+    }
+
+    #[test]
+    fn test_overlay_origin_custom_is_kept_on_screen() {
+        let mut layout = OverlayLayout {
+            custom_x: 50.0,
+            custom_y: 60.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            overlay_origin(OverlayPosition::Custom, &layout, WORK_AREA, SIZE),
+            (150.0, 60.0)
+        );
+
+        layout.custom_x = 5000.0;
+        layout.custom_y = 5000.0;
+        assert_eq!(
+            overlay_origin(OverlayPosition::Custom, &layout, WORK_AREA, SIZE),
+            (900.0, 760.0)
+        );
+    }
+}
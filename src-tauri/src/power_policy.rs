@@ -0,0 +1,189 @@
+//! Battery saver. While a laptop runs on battery and `battery_policy` is
+//! enabled, [`PowerProfile::current`] swaps in a smaller model, keeps the
+//! microphone closed between dictations, skips streaming partials and keeps
+//! the model loaded longer instead of reloading it for every dictation.
+//!
+//! The power source is polled in the background and cached, so the
+//! profile is cheap to compute on every dictation.
+
+use crate::helpers::power;
+use crate::managers::audio::{AudioRecordingManager, MicrophoneMode};
+use crate::managers::transcription::TranscriptionManager;
+use crate::settings::{get_settings, AppSettings, ModelUnloadTimeout};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{info, warn};
+
+/// How often the power source is checked.
+const POWER_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+static ON_BATTERY: AtomicBool = AtomicBool::new(false);
+
+/// The settings that depend on the power source, after the battery policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PowerProfile {
+    pub model: String,
+    pub always_on_microphone: bool,
+    pub streaming: bool,
+    pub unload_timeout: ModelUnloadTimeout,
+}
+
+impl PowerProfile {
+    /// Profile for the current power source.
+    pub fn current(settings: &AppSettings) -> Self {
+        Self::resolve(settings, on_battery())
+    }
+
+    fn resolve(settings: &AppSettings, on_battery: bool) -> Self {
+        let profile = Self {
+            model: settings.selected_model.clone(),
+            always_on_microphone: settings.always_on_microphone,
+            streaming: true,
+            unload_timeout: settings.model_unload_timeout,
+        };
+        let policy = &settings.battery_policy;
+        if !on_battery || !policy.enabled {
+            return profile;
+        }
+
+        Self {
+            model: policy
+                .model
+                .clone()
+                .filter(|model| !model.is_empty())
+                .unwrap_or(profile.model),
+            always_on_microphone: profile.always_on_microphone
+                && !policy.disable_always_on_microphone,
+            streaming: !policy.disable_streaming,
+            unload_timeout: longer_timeout(profile.unload_timeout, policy.unload_timeout),
+        }
+    }
+
+    pub fn microphone_mode(&self) -> MicrophoneMode {
+        if self.always_on_microphone {
+            MicrophoneMode::AlwaysOn
+        } else {
+            MicrophoneMode::OnDemand
+        }
+    }
+}
+
+/// Whether the machine was on battery at the last check.
+pub fn on_battery() -> bool {
+    ON_BATTERY.load(Ordering::Relaxed)
+}
+
+/// Read the power source once. Call before the managers are created so
+/// they start with the right profile.
+pub fn init() {
+    ON_BATTERY.store(power::is_on_battery(), Ordering::Relaxed);
+}
+
+/// Poll the power source and re-apply the profile when it changes.
+pub fn start_watcher(app: &AppHandle) {
+    let app = app.clone();
+    thread::spawn(move || loop {
+        thread::sleep(POWER_CHECK_INTERVAL);
+        let on_battery = power::is_on_battery();
+        if ON_BATTERY.swap(on_battery, Ordering::Relaxed) != on_battery {
+            info!(
+                "Power source changed: {}",
+                if on_battery { "battery" } else { "AC" }
+            );
+            let _ = app.emit("power-state-changed", on_battery);
+            apply(&app);
+        }
+    });
+}
+
+/// Bring the microphone and model in line with the current profile, after
+/// the power source or the battery policy changed.
+pub fn apply(app: &AppHandle) {
+    let profile = PowerProfile::current(&get_settings(app));
+
+    let rm = app.state::<Arc<AudioRecordingManager>>();
+    if let Err(e) = rm.update_mode(profile.microphone_mode()) {
+        warn!("Failed to update microphone mode for power source: {}", e);
+    }
+
+    app.state::<Arc<TranscriptionManager>>()
+        .apply_keep_alive_policy();
+}
+
+/// The timeout that keeps the model loaded longer. `Immediately` is the
+/// shortest and `Never` the longest.
+fn longer_timeout(a: ModelUnloadTimeout, b: ModelUnloadTimeout) -> ModelUnloadTimeout {
+    let seconds = |timeout: ModelUnloadTimeout| timeout.to_seconds().unwrap_or(u64::MAX);
+    if seconds(b) > seconds(a) {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{get_default_settings, BatteryPolicy};
+
+    fn settings_with_policy(policy: BatteryPolicy) -> AppSettings {
+        let mut settings = get_default_settings();
+        settings.selected_model = "large".to_string();
+        settings.always_on_microphone = true;
+        settings.model_unload_timeout = ModelUnloadTimeout::Min2;
+        settings.battery_policy = policy;
+        settings
+    }
+
+    #[test]
+    fn battery_policy_applies_only_on_battery() {
+        let settings = settings_with_policy(BatteryPolicy {
+            enabled: true,
+            model: Some("small".to_string()),
+            ..Default::default()
+        });
+
+        let ac = PowerProfile::resolve(&settings, false);
+        assert_eq!(ac.model, "large");
+        assert!(ac.always_on_microphone);
+        assert!(ac.streaming);
+        assert_eq!(ac.unload_timeout, ModelUnloadTimeout::Min2);
+
+        let battery = PowerProfile::resolve(&settings, true);
+        assert_eq!(battery.model, "small");
+        assert!(!battery.always_on_microphone);
+        assert!(!battery.streaming);
+        assert_eq!(battery.unload_timeout, ModelUnloadTimeout::Min15);
+    }
+
+    #[test]
+    fn disabled_policy_changes_nothing() {
+        let settings = settings_with_policy(BatteryPolicy {
+            model: Some("small".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(
+            PowerProfile::resolve(&settings, true),
+            PowerProfile::resolve(&settings, false)
+        );
+    }
+
+    #[test]
+    fn unload_timeout_is_only_lengthened() {
+        assert_eq!(
+            longer_timeout(ModelUnloadTimeout::Hour1, ModelUnloadTimeout::Min15),
+            ModelUnloadTimeout::Hour1
+        );
+        assert_eq!(
+            longer_timeout(ModelUnloadTimeout::Immediately, ModelUnloadTimeout::Min5),
+            ModelUnloadTimeout::Min5
+        );
+        assert_eq!(
+            longer_timeout(ModelUnloadTimeout::Never, ModelUnloadTimeout::Min15),
+            ModelUnloadTimeout::Never
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Optional profanity masking of a binding's output, for dictating in
+//! shared or work settings. Applied to the text before it is shown, pasted
+//! or saved to history.
+//!
+//! List entries match whole words, ignoring case. A `*` at either end
+//! matches the rest of the word, so "fuck*" covers "fucking" and "*пизд*"
+//! covers its many derived forms; entries are kept narrow enough not to
+//! catch innocent words ("ass" would hit "class").
+
+use crate::settings::{AppSettings, ProfanityMask};
+
+/// Built-in lists by language code.
+const LISTS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "*fuck*",
+            "*shit*",
+            "bitch*",
+            "bastard*",
+            "asshole*",
+            "arsehole*",
+            "cunt*",
+            "dick",
+            "dicks",
+            "dickhead*",
+            "whore*",
+            "slut*",
+            "wanker*",
+            "twat*",
+            "bollocks",
+            "goddamn*",
+            "damn",
+            "dammit",
+        ],
+    ),
+    (
+        "ru",
+        &[
+            "хуй*",
+            "хуе*",
+            "хуё*",
+            "хуя*",
+            "нахуй*",
+            "нахуя*",
+            "похуй*",
+            "*пизд*",
+            "еб*",
+            "ёб*",
+            "заеб*",
+            "заёб*",
+            "выеб*",
+            "уеб*",
+            "уёб*",
+            "проеб*",
+            "наеб*",
+            "отъеб*",
+            "съеб*",
+            "разъеб*",
+            "въеб*",
+            "долбоеб*",
+            "долбоёб*",
+            "бля",
+            "бляд*",
+            "блят*",
+            "сука",
+            "суки",
+            "суке",
+            "суку",
+            "сукой",
+            "мудак*",
+            "мудил*",
+            "пидор*",
+            "пидар*",
+            "гандон*",
+            "шлюх*",
+            "залуп*",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "scheiße*",
+            "scheisse*",
+            "scheiß*",
+            "scheiss*",
+            "fick*",
+            "arschloch*",
+            "fotze*",
+            "wichser*",
+            "hurensohn*",
+            "miststück*",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "putain*",
+            "merde*",
+            "connard*",
+            "connasse*",
+            "salope*",
+            "encul*",
+            "pute",
+            "putes",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "mierda*",
+            "joder",
+            "jodido*",
+            "jodida*",
+            "coño",
+            "puta",
+            "putas",
+            "puto",
+            "putos",
+            "cabrón",
+            "cabrona*",
+            "cabrones",
+            "gilipollas",
+            "pendejo*",
+            "pendeja*",
+            "chingar*",
+            "chingada*",
+        ],
+    ),
+];
+
+/// The masking one binding's output gets, with the lists for the
+/// transcription language.
+#[derive(Debug, Clone)]
+pub struct ProfanityFilter {
+    mask: ProfanityMask,
+    patterns: Vec<&'static str>,
+}
+
+impl ProfanityFilter {
+    pub fn for_binding(settings: &AppSettings, binding_id: &str) -> Self {
+        let mask = settings
+            .binding_profanity
+            .get(binding_id)
+            .copied()
+            .unwrap_or_default();
+        let patterns = LISTS
+            .iter()
+            .filter(|(code, _)| settings.may_transcribe_language(code))
+            .flat_map(|(_, list)| list.iter().copied())
+            .collect();
+        Self { mask, patterns }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.mask != ProfanityMask::Keep
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        mask_words(text, self.mask, &self.patterns)
+    }
+}
+
+fn mask_words(text: &str, mask: ProfanityMask, patterns: &[&str]) -> String {
+    if mask == ProfanityMask::Keep {
+        return text.to_string();
+    }
+
+    let mut masked = String::with_capacity(text.len());
+    let mut rest = text;
+    // Capitalize the word after one removed from the start of a sentence
+    let mut capitalize_next = false;
+    while let Some(start) = rest.find(char::is_alphanumeric) {
+        let (before, from_word) = rest.split_at(start);
+        let end = from_word
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(from_word.len());
+        let (word, after) = from_word.split_at(end);
+        masked.push_str(before);
+
+        if !is_profane(word, patterns) {
+            if capitalize_next {
+                push_capitalized(&mut masked, word);
+                capitalize_next = false;
+            } else {
+                masked.push_str(word);
+            }
+            rest = after;
+            continue;
+        }
+
+        match mask {
+            ProfanityMask::Asterisks => {
+                let mut chars = word.chars();
+                masked.extend(chars.next());
+                masked.extend(chars.map(|_| '*'));
+                rest = after;
+            }
+            ProfanityMask::Remove => {
+                let at_sentence_start = match masked.trim_end().chars().next_back() {
+                    Some(c) => ".!?…".contains(c),
+                    None => true,
+                };
+                capitalize_next = at_sentence_start && word.starts_with(char::is_uppercase);
+                // A comma after the word goes with it, and so does the end
+                // of a sentence that was only the word
+                rest = if at_sentence_start {
+                    after.trim_start_matches([',', '.', '!', '?', '…'])
+                } else {
+                    after.strip_prefix(',').unwrap_or(after)
+                };
+            }
+            ProfanityMask::Keep => unreachable!(),
+        }
+    }
+    masked.push_str(rest);
+
+    if mask == ProfanityMask::Remove {
+        tidy_removed(&masked)
+    } else {
+        masked
+    }
+}
+
+fn is_profane(word: &str, patterns: &[&str]) -> bool {
+    let word = word.to_lowercase();
+    patterns.iter().any(
+        |pattern| match (pattern.strip_prefix('*'), pattern.strip_suffix('*')) {
+            (Some(inner), Some(_)) => word.contains(inner.trim_end_matches('*')),
+            (Some(suffix), None) => word.ends_with(suffix),
+            (None, Some(prefix)) => word.starts_with(prefix),
+            (None, None) => word == *pattern,
+        },
+    )
+}
+
+fn push_capitalized(out: &mut String, word: &str) {
+    let mut chars = word.chars();
+    if let Some(first) = chars.next() {
+        out.extend(first.to_uppercase());
+        out.push_str(chars.as_str());
+    }
+}
+
+/// Clean up the spaces left by removed words. Text with no words left is
+/// dropped altogether, rather than pasting a stray "!".
+fn tidy_removed(text: &str) -> String {
+    if !text.chars().any(char::is_alphanumeric) {
+        return String::new();
+    }
+    let mut tidy = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ' ' && tidy.ends_with([' ', '\n']) {
+            continue;
+        }
+        if ",.!?;:".contains(c) && tidy.ends_with(' ') {
+            tidy.pop();
+        }
+        tidy.push(c);
+    }
+    tidy.trim()
+        .trim_start_matches([',', ';', ':', ' '])
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+
+    fn mask(text: &str, mask: ProfanityMask) -> String {
+        let patterns: Vec<&str> = LISTS.iter().flat_map(|(_, l)| l.iter().copied()).collect();
+        mask_words(text, mask, &patterns)
+    }
+
+    #[test]
+    fn masks_with_asterisks() {
+        assert_eq!(
+            mask("This fucking printer is shit.", ProfanityMask::Asterisks),
+            "This f****** printer is s***."
+        );
+        assert_eq!(
+            mask("Ну пиздец, опять сломалось", ProfanityMask::Asterisks),
+            "Ну п*****, опять сломалось"
+        );
+        // Innocent words that contain a listed one are left alone
+        assert_eq!(
+            mask("The class assessment, колебания", ProfanityMask::Asterisks),
+            "The class assessment, колебания"
+        );
+    }
+
+    #[test]
+    fn removes_words_and_tidies_up() {
+        assert_eq!(
+            mask("What the fuck, it's broken again.", ProfanityMask::Remove),
+            "What the it's broken again."
+        );
+        assert_eq!(
+            mask("Shit. That damn build failed!", ProfanityMask::Remove),
+            "That build failed!"
+        );
+        assert_eq!(
+            mask("Fucking hell, ship it", ProfanityMask::Remove),
+            "Hell, ship it"
+        );
+        assert_eq!(mask("Fuck!", ProfanityMask::Remove), "");
+    }
+
+    #[test]
+    fn keep_leaves_text_unchanged() {
+        let text = "Well, shit happens.";
+        assert_eq!(mask(text, ProfanityMask::Keep), text);
+    }
+
+    #[test]
+    fn uses_the_binding_mask_and_language() {
+        let mut settings = get_default_settings();
+        settings.selected_language = "en".to_string();
+        let filter = ProfanityFilter::for_binding(&settings, "transcribe");
+        assert!(!filter.is_active());
+        assert_eq!(filter.apply("damn it"), "damn it");
+
+        settings
+            .binding_profanity
+            .insert("transcribe".to_string(), ProfanityMask::Asterisks);
+        let filter = ProfanityFilter::for_binding(&settings, "transcribe");
+        assert_eq!(filter.apply("damn it, сука"), "d*** it, сука");
+    }
+}
@@ -0,0 +1,171 @@
+//! Template variables for post-processing prompts.
+//!
+//! Supported variables:
+//! - `${output}`       – the transcription being post-processed
+//! - `${language}`     – the selected transcription language
+//! - `${app_name}`     – the application that currently has focus
+//! - `${clipboard}`    – current clipboard text
+//! - `${date}`         – today's date (YYYY-MM-DD)
+//! - `${time}`         – the current time (HH:MM)
+//! - `${history_last}` – the previous dictation from history
+//!
+//! Context that is expensive or privacy-sensitive (focused app, clipboard,
+//! history) is only gathered when the prompt actually references it.
+
+use std::sync::Arc;
+
+use tauri::{AppHandle, Manager};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+use tracing::debug;
+
+use crate::managers::history::HistoryManager;
+use crate::settings::AppSettings;
+
+#[derive(Debug, Default, Clone)]
+pub struct PromptVariables {
+    pub output: String,
+    pub language: String,
+    pub app_name: Option<String>,
+    pub clipboard: Option<String>,
+    pub date: String,
+    pub time: String,
+    pub history_last: Option<String>,
+}
+
+/// Gather the values for every variable referenced in `prompt`.
+pub fn collect(
+    app: &AppHandle,
+    settings: &AppSettings,
+    prompt: &str,
+    output: &str,
+) -> PromptVariables {
+    let uses = |name: &str| prompt.contains(&format!("${{{}}}", name));
+
+    let app_name = if uses("app_name") {
+        crate::utils::get_focused_app_name()
+    } else {
+        None
+    };
+
+    let clipboard = if uses("clipboard") {
+        app.clipboard().read_text().ok()
+    } else {
+        None
+    };
+
+    let history_last = if uses("history_last") {
+        app.try_state::<Arc<HistoryManager>>()
+            .and_then(|hm| hm.get_latest_entry().ok().flatten())
+            .map(|entry| {
+                entry
+                    .post_processed_text
+                    .unwrap_or(entry.transcription_text)
+            })
+    } else {
+        None
+    };
+
+    debug!(
+        "Prompt variables: app_name={:?}, clipboard={}, history_last={}",
+        app_name,
+        clipboard.is_some(),
+        history_last.is_some()
+    );
+
+    let now = chrono::Local::now();
+    PromptVariables {
+        output: output.to_string(),
+        language: settings.selected_language.clone(),
+        app_name,
+        clipboard,
+        date: now.format("%Y-%m-%d").to_string(),
+        time: now.format("%H:%M").to_string(),
+        history_last,
+    }
+}
+
+/// Substitute all `${...}` variables in `prompt` in a single pass, so values
+/// containing `${...}` (dictated text, clipboard) are never expanded again.
+/// Unknown variables are left as-is; known variables without a value become empty.
+pub fn render(prompt: &str, vars: &PromptVariables) -> String {
+    let mut result = String::with_capacity(prompt.len() + vars.output.len());
+    let mut rest = prompt;
+
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let name = &after[..end];
+        let value = match name {
+            "output" => Some(vars.output.as_str()),
+            "language" => Some(vars.language.as_str()),
+            "app_name" => Some(vars.app_name.as_deref().unwrap_or("")),
+            "clipboard" => Some(vars.clipboard.as_deref().unwrap_or("")),
+            "date" => Some(vars.date.as_str()),
+            "time" => Some(vars.time.as_str()),
+            "history_last" => Some(vars.history_last.as_deref().unwrap_or("")),
+            _ => None,
+        };
+        match value {
+            Some(value) => result.push_str(value),
+            None => result.push_str(&rest[start..start + 2 + end + 1]),
+        }
+        rest = &after[end + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars() -> PromptVariables {
+        PromptVariables {
+            output: "hello world".to_string(),
+            language: "en".to_string(),
+            app_name: Some("Code".to_string()),
+            clipboard: None,
+            date: "2026-01-02".to_string(),
+            time: "09:30".to_string(),
+            history_last: Some("previous".to_string()),
+        }
+    }
+
+    #[test]
+    fn renders_all_variables() {
+        let prompt =
+            "[${language}|${app_name}|${clipboard}|${date} ${time}|${history_last}] ${output}";
+        assert_eq!(
+            render(prompt, &vars()),
+            "[en|Code||2026-01-02 09:30|previous] hello world"
+        );
+    }
+
+    #[test]
+    fn does_not_expand_variables_inside_output() {
+        let mut v = vars();
+        v.output = "say ${date}".to_string();
+        assert_eq!(render("${output}", &v), "say ${date}");
+    }
+
+    #[test]
+    fn does_not_expand_variables_inside_clipboard() {
+        let mut v = vars();
+        v.clipboard = Some("${output}".to_string());
+        assert_eq!(render("${clipboard}", &v), "${output}");
+    }
+
+    #[test]
+    fn leaves_unknown_variables_untouched() {
+        assert_eq!(
+            render("${unknown} ${output} ${unterminated", &vars()),
+            "${unknown} hello world ${unterminated"
+        );
+    }
+}
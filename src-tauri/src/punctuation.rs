@@ -0,0 +1,447 @@
+//! Local punctuation and casing restorer for Whisper output that comes back
+//! all lowercase or without sentence punctuation, which happens with some
+//! models and languages. It runs before and independently of LLM
+//! post-processing, so dictations read right with no provider configured.
+//!
+//! The rules capitalize sentences, add the commas a language's grammar
+//! makes predictable (Russian "что", German "dass") and end the text with a
+//! period, or a question mark when it opens like a question. Proper nouns
+//! are beyond rules: they come from the custom words and, optionally, from
+//! a small truecasing model learned from well-formed dictations in history.
+
+use crate::managers::history::HistoryManager;
+use crate::settings::AppSettings;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{AppHandle, Manager};
+use tracing::{debug, warn};
+
+/// Dictations the casing model learns from
+const HISTORY_ENTRIES: usize = 500;
+/// Times a word must be seen with the same capitals to be learned
+const MIN_OCCURRENCES: usize = 2;
+
+const SENTENCE_END: [char; 4] = ['.', '!', '?', '…'];
+
+/// What the restorer knows about one language. Patterns are matched against
+/// the first words of a sentence (openers) or at any word (commas): words
+/// are separated by spaces, `|` separates alternatives, `*` matches any word
+/// and a trailing `*` any ending.
+struct Rules {
+    code: &'static str,
+    /// Sentence openings that make it a question
+    questions: &'static [&'static str],
+    /// Questions also open with "¿"
+    inverted_question: bool,
+    /// Words and phrases a comma goes before
+    commas: &'static [&'static str],
+    /// Words after which no comma is added
+    no_comma_after: &'static [&'static str],
+    /// Prepositions that move the comma before them ("дом, в котором")
+    prepositions: &'static [&'static str],
+    /// Capitalize "i" and its contractions
+    capitalize_i: bool,
+}
+
+const RULES: &[Rules] = &[
+    Rules {
+        code: "en",
+        questions: &[
+            "what|why|how|where|who|when|which|whose is|are|was|were|do|does|did|can|could|will|would|should|has|have|had|isn't|aren't|don't|doesn't|didn't|can't|won't",
+            "is|are|was|were|do|does|did|can|could|will|would|should|has|have|may|shall|isn't|aren't|don't|doesn't|didn't|can't|won't|wouldn't|couldn't|shouldn't i|you|he|she|it|we|they|this|that|there",
+        ],
+        inverted_question: false,
+        commas: &[],
+        no_comma_after: &[],
+        prepositions: &[],
+        capitalize_i: true,
+    },
+    Rules {
+        code: "ru",
+        questions: &[
+            "почему|зачем|где|куда|откуда|сколько|кто|разве|неужели|чей|чья|чьё|чьи",
+            "* ли",
+        ],
+        inverted_question: false,
+        commas: &[
+            "но",
+            "а",
+            "что",
+            "чтобы",
+            "потому что",
+            "так как",
+            "так что",
+            "если",
+            "котор*",
+        ],
+        no_comma_after: &[
+            "и", "или", "да", "а", "но", "что", "чем", "то", "не", "ли", "потому", "так",
+        ],
+        prepositions: &[
+            "в", "во", "на", "с", "со", "о", "об", "за", "из", "у", "к", "по", "для", "про", "от",
+            "до", "при", "над", "под", "без", "через",
+        ],
+        capitalize_i: false,
+    },
+    Rules {
+        code: "de",
+        questions: &[
+            "warum|wieso|weshalb|weswegen|wohin|woher|wann",
+            "wer|was|wo|wie|welche|welcher|welches|welchen ist|sind|war|waren|bist|hast|hat|habt|haben|kann|kannst|können|soll|sollen|sollte|machst|macht|geht|gibt|heißt",
+            "ist|sind|bist|hast|hat|habt|kannst|kann|können|gibt|willst|möchtest|darf|dürfen du|ihr|sie|es|das|man|er|wir|ich",
+        ],
+        inverted_question: false,
+        commas: &["dass", "weil", "obwohl", "ob", "wenn", "sondern"],
+        no_comma_after: &["und", "oder", "als", "so"],
+        prepositions: &[],
+        capitalize_i: false,
+    },
+    Rules {
+        code: "fr",
+        questions: &[
+            "pourquoi|combien|comment|où|quel|quelle|quels|quelles|est-ce|qu'est-ce",
+        ],
+        inverted_question: false,
+        commas: &["mais"],
+        no_comma_after: &["et", "ou"],
+        prepositions: &[],
+        capitalize_i: false,
+    },
+    Rules {
+        code: "es",
+        questions: &[
+            "qué|cómo|dónde|adónde|cuándo|cuánto|cuánta|cuántos|cuántas|quién|quiénes|cuál|cuáles",
+            "por qué",
+        ],
+        inverted_question: true,
+        commas: &["pero", "sino"],
+        no_comma_after: &["y", "o"],
+        prepositions: &[],
+        capitalize_i: false,
+    },
+];
+
+/// Restore casing and punctuation of `text` if it needs it; otherwise, or
+/// when the restorer is off, the output is `verbatim` or there are no rules
+/// for the language, `text` is returned as it is.
+pub fn restore(app: &AppHandle, settings: &AppSettings, text: &str, verbatim: bool) -> String {
+    if !should_restore(settings, text, verbatim) {
+        return text.to_string();
+    }
+    let rules: Vec<&Rules> = RULES
+        .iter()
+        .filter(|rules| settings.may_transcribe_language(rules.code))
+        .collect();
+    if rules.is_empty() {
+        return text.to_string();
+    }
+
+    let mut casing = HashMap::new();
+    if settings.punctuation_restore_casing_model {
+        casing = learn_casing(&recent_dictations(app));
+    }
+    // Custom words are spelled the way the user wants them
+    for word in &settings.custom_words {
+        if !word.contains(char::is_whitespace) && word.contains(char::is_uppercase) {
+            casing.insert(word.to_lowercase(), word.clone());
+        }
+    }
+
+    debug!(
+        "Restoring punctuation and casing ({} learned or custom spellings)",
+        casing.len()
+    );
+    restore_text(text, &rules, &casing)
+}
+
+/// Texts of the most recent dictations, for the casing model.
+fn recent_dictations(app: &AppHandle) -> Vec<String> {
+    let Some(history) = app.try_state::<Arc<HistoryManager>>() else {
+        return Vec::new();
+    };
+    match history.get_recent_entries(HISTORY_ENTRIES) {
+        Ok(entries) => entries
+            .into_iter()
+            .map(|entry| {
+                entry
+                    .post_processed_text
+                    .unwrap_or(entry.transcription_text)
+            })
+            .collect(),
+        Err(e) => {
+            warn!("Couldn't read history for the casing model: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Verbatim output (terminals, code editors) is typed exactly as dictated.
+fn should_restore(settings: &AppSettings, text: &str, verbatim: bool) -> bool {
+    settings.punctuation_restore && !verbatim && needs_restoring(text)
+}
+
+/// Whether `text` has cased letters but no capitals, or no sentence
+/// punctuation at all.
+fn needs_restoring(text: &str) -> bool {
+    if !text.chars().any(char::is_lowercase) {
+        return false;
+    }
+    !text.chars().any(char::is_uppercase) || !text.contains(SENTENCE_END)
+}
+
+fn restore_text(text: &str, rules: &[&Rules], casing: &HashMap<String, String>) -> String {
+    let mut words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+    // Commas are only added when the model left out punctuation altogether;
+    // where it punctuated, its commas are better than the rules
+    if !text.contains(SENTENCE_END) {
+        add_commas(&mut words, rules);
+    }
+
+    // Only when the text is known to be English: with "auto" every language's
+    // rules apply, and "i" is a word of its own in Polish, Czech and others
+    let capitalize_i = rules.iter().all(|r| r.capitalize_i);
+    let mut sentence_start = 0;
+    for (i, word) in words.iter_mut().enumerate() {
+        let (start, end) = core_span(word);
+        let core = &word[start..end];
+        if core.is_empty() {
+            // A dash or a stray mark; the sentence starts at the next word
+            if i == sentence_start {
+                sentence_start = i + 1;
+            }
+            continue;
+        }
+        let key = core.to_lowercase();
+        let mut cased = core.to_string();
+        if core == key {
+            if let Some(spelling) = casing.get(&key) {
+                cased = spelling.clone();
+            } else if capitalize_i && (key == "i" || key.starts_with("i'") || key.starts_with("i’"))
+            {
+                cased = capitalize(core);
+            }
+        }
+        if i == sentence_start {
+            cased = capitalize(&cased);
+        }
+        word.replace_range(start..end, &cased);
+        if word.ends_with(SENTENCE_END) {
+            sentence_start = i + 1;
+        }
+    }
+
+    let ends_in_word = match words.last() {
+        Some(last) => last.ends_with(char::is_alphanumeric),
+        None => false,
+    };
+    if ends_in_word {
+        let keys: Vec<String> = words[sentence_start..].iter().map(|w| key(w)).collect();
+        match rules
+            .iter()
+            .find(|r| r.questions.iter().any(|q| matches_at(q, &keys, 0)))
+        {
+            Some(question) => {
+                if question.inverted_question {
+                    words[sentence_start].insert(0, '¿');
+                }
+                words.last_mut().unwrap().push('?');
+            }
+            None => words.last_mut().unwrap().push('.'),
+        }
+    }
+
+    words.join(" ")
+}
+
+/// Put commas before the words and phrases the rules call for.
+fn add_commas(words: &mut [String], rules: &[&Rules]) {
+    let keys: Vec<String> = words.iter().map(|w| key(w)).collect();
+    let mut comma_before = vec![false; words.len()];
+    for rules in rules {
+        for at in 1..words.len() {
+            if !rules.commas.iter().any(|c| matches_at(c, &keys, at)) {
+                continue;
+            }
+            let mut at = at;
+            if rules.prepositions.contains(&keys[at - 1].as_str()) {
+                at -= 1;
+            }
+            if at == 0 || !words[at - 1].ends_with(char::is_alphanumeric) {
+                continue;
+            }
+            if rules.no_comma_after.contains(&keys[at - 1].as_str()) {
+                continue;
+            }
+            comma_before[at] = true;
+        }
+    }
+    for at in 1..words.len() {
+        if comma_before[at] {
+            words[at - 1].push(',');
+        }
+    }
+}
+
+/// Whether `pattern` matches `keys` starting at word `at`.
+fn matches_at(pattern: &str, keys: &[String], at: usize) -> bool {
+    let parts: Vec<&str> = pattern.split(' ').collect();
+    if at + parts.len() > keys.len() {
+        return false;
+    }
+    parts.iter().zip(&keys[at..]).all(|(part, key)| {
+        part.split('|').any(|alt| match alt.strip_suffix('*') {
+            Some(prefix) => key.starts_with(prefix),
+            None => alt == key,
+        })
+    })
+}
+
+/// Learn how words are capitalized from well-formed texts, skipping the
+/// first word of each sentence. Words mostly written with capitals map from
+/// lowercase to that spelling.
+fn learn_casing(texts: &[String]) -> HashMap<String, String> {
+    let mut seen: HashMap<String, HashMap<String, usize>> = HashMap::new();
+    for text in texts.iter().filter(|t| !needs_restoring(t)) {
+        let mut sentence_start = true;
+        for word in text.split_whitespace() {
+            let (start, end) = core_span(word);
+            let core = &word[start..end];
+            if !sentence_start && core.chars().any(char::is_alphabetic) {
+                *seen
+                    .entry(core.to_lowercase())
+                    .or_default()
+                    .entry(core.to_string())
+                    .or_default() += 1;
+            }
+            sentence_start = word.ends_with(SENTENCE_END);
+        }
+    }
+
+    seen.into_iter()
+        .filter_map(|(key, spellings)| {
+            let total: usize = spellings.values().sum();
+            let (spelling, count) = spellings.into_iter().max_by_key(|(_, count)| *count)?;
+            let learned = spelling != key && count >= MIN_OCCURRENCES && count * 3 >= total * 2;
+            learned.then_some((key, spelling))
+        })
+        .collect()
+}
+
+/// Byte range of the word in `token`, without surrounding punctuation.
+fn core_span(token: &str) -> (usize, usize) {
+    let start = token.find(char::is_alphanumeric).unwrap_or(token.len());
+    let end = token
+        .rfind(char::is_alphanumeric)
+        .map(|i| i + token[i..].chars().next().map_or(1, char::len_utf8))
+        .unwrap_or(start);
+    (start, end.max(start))
+}
+
+fn key(token: &str) -> String {
+    let (start, end) = core_span(token);
+    token[start..end].to_lowercase()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn restore_in(text: &str, code: &str) -> String {
+        let rules: Vec<&Rules> = RULES.iter().filter(|r| r.code == code).collect();
+        restore_text(text, &rules, &HashMap::new())
+    }
+
+    #[test]
+    fn restores_lowercase_english() {
+        assert_eq!(
+            restore_in("i think i'll send it tomorrow", "en"),
+            "I think I'll send it tomorrow."
+        );
+        assert_eq!(
+            restore_in("can you check the build", "en"),
+            "Can you check the build?"
+        );
+        assert_eq!(
+            restore_in("ok. what are we doing next", "en"),
+            "Ok. What are we doing next?"
+        );
+        // Whisper's own capitals are left alone
+        assert_eq!(restore_in("send it to Anna", "en"), "Send it to Anna.");
+    }
+
+    #[test]
+    fn adds_commas_by_language_rules() {
+        assert_eq!(
+            restore_in("я знаю что он придёт но позже", "ru"),
+            "Я знаю, что он придёт, но позже."
+        );
+        assert_eq!(
+            restore_in("это дом в котором я вырос потому что так вышло", "ru"),
+            "Это дом, в котором я вырос, потому что так вышло."
+        );
+        assert_eq!(restore_in("знаешь ли ты его", "ru"), "Знаешь ли ты его?");
+        assert_eq!(
+            restore_in("ich glaube dass es regnet", "de"),
+            "Ich glaube, dass es regnet."
+        );
+        assert_eq!(restore_in("por qué no vienes", "es"), "¿Por qué no vienes?");
+    }
+
+    #[test]
+    fn capitalizes_i_only_for_english() {
+        let all: Vec<&Rules> = RULES.iter().collect();
+        assert_eq!(
+            restore_text("kot i pies", &all, &HashMap::new()),
+            "Kot i pies."
+        );
+    }
+
+    #[test]
+    fn leaves_verbatim_text_unchanged() {
+        let mut settings = crate::settings::get_default_settings();
+        settings.punctuation_restore = true;
+        assert!(should_restore(&settings, "what is ls", false));
+        assert!(!should_restore(&settings, "what is ls", true));
+    }
+
+    #[test]
+    fn only_restores_degraded_text() {
+        assert!(needs_restoring("hello there"));
+        assert!(needs_restoring("hello there. how are you?"));
+        assert!(needs_restoring("Hello there how are you"));
+        assert!(!needs_restoring("Hello there. How are you?"));
+        assert!(!needs_restoring("你好"));
+        assert!(!needs_restoring(""));
+    }
+
+    #[test]
+    fn learns_casing_from_well_formed_text() {
+        let texts = [
+            "I pushed it to GitHub today.",
+            "Open GitHub and check the build.",
+            "Check the GitHub issue, please.",
+            "we ate an apple",
+            "The apple was green. Apple pie is better.",
+        ]
+        .map(str::to_string);
+        let casing = learn_casing(&texts);
+        assert_eq!(casing.get("github").map(String::as_str), Some("GitHub"));
+        // Sentence-initial capitals don't count
+        assert!(!casing.contains_key("apple"));
+        assert!(!casing.contains_key("the"));
+
+        let rules: Vec<&Rules> = RULES.iter().filter(|r| r.code == "en").collect();
+        assert_eq!(
+            restore_text("push it to github", &rules, &casing),
+            "Push it to GitHub."
+        );
+    }
+}
@@ -0,0 +1,222 @@
+//! OS credential storage for post-processing API keys.
+//!
+//! Keys are stored in the platform keychain (macOS Keychain, Windows
+//! Credential Manager, Secret Service on Linux). When no keychain is
+//! available the key stays in the plaintext settings store as before, so
+//! post-processing keeps working on minimal desktops.
+
+use anyhow::Result;
+use once_cell::sync::Lazy;
+use tracing::{debug, warn};
+
+use crate::settings::AppSettings;
+
+const SERVICE_NAME: &str = "com.divnjl2.voice-input";
+
+pub trait SecretStore: Send + Sync {
+    fn get(&self, key: &str) -> Result<Option<String>>;
+    fn set(&self, key: &str, value: &str) -> Result<()>;
+    fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// [`SecretStore`] backed by the OS keychain via the `keyring` crate.
+pub struct KeyringStore {
+    service: String,
+}
+
+impl KeyringStore {
+    pub fn new(service: &str) -> Self {
+        Self {
+            service: service.to_string(),
+        }
+    }
+
+    fn entry(&self, key: &str) -> Result<keyring::Entry> {
+        Ok(keyring::Entry::new(&self.service, key)?)
+    }
+}
+
+impl SecretStore for KeyringStore {
+    fn get(&self, key: &str) -> Result<Option<String>> {
+        match self.entry(key)?.get_password() {
+            Ok(value) => Ok(Some(value)),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<()> {
+        self.entry(key)?.set_password(value)?;
+        Ok(())
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        match self.entry(key)?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+static SECRET_STORE: Lazy<KeyringStore> = Lazy::new(|| KeyringStore::new(SERVICE_NAME));
+
+pub fn secret_store() -> &'static dyn SecretStore {
+    &*SECRET_STORE
+}
+
+fn api_key_name(provider_id: &str) -> String {
+    format!("post_process_api_key.{}", provider_id)
+}
+
+/// Resolve the API key for a provider: keychain first, then plaintext settings.
+pub fn get_api_key(store: &dyn SecretStore, settings: &AppSettings, provider_id: &str) -> String {
+    match store.get(&api_key_name(provider_id)) {
+        Ok(Some(key)) if !key.is_empty() => return key,
+        Ok(_) => {}
+        Err(e) => debug!("Keychain lookup failed for '{}': {}", provider_id, e),
+    }
+
+    settings
+        .post_process_api_keys
+        .get(provider_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Store the API key for a provider. Returns `true` when the key landed in the
+/// keychain, in which case the plaintext copy in settings is cleared.
+pub fn set_api_key(
+    store: &dyn SecretStore,
+    settings: &mut AppSettings,
+    provider_id: &str,
+    api_key: &str,
+) -> bool {
+    let name = api_key_name(provider_id);
+    let result = if api_key.is_empty() {
+        store.delete(&name)
+    } else {
+        store.set(&name, api_key)
+    };
+
+    match result {
+        Ok(()) => {
+            settings
+                .post_process_api_keys
+                .insert(provider_id.to_string(), String::new());
+            true
+        }
+        Err(e) => {
+            warn!(
+                "Keychain unavailable, keeping API key for '{}' in settings: {}",
+                provider_id, e
+            );
+            settings
+                .post_process_api_keys
+                .insert(provider_id.to_string(), api_key.to_string());
+            false
+        }
+    }
+}
+
+/// Move any plaintext API keys from settings into the keychain.
+/// Returns `true` if settings were modified and need to be written back.
+pub fn migrate_plaintext_keys(store: &dyn SecretStore, settings: &mut AppSettings) -> bool {
+    let plaintext: Vec<(String, String)> = settings
+        .post_process_api_keys
+        .iter()
+        .filter(|(_, key)| !key.is_empty())
+        .map(|(id, key)| (id.clone(), key.clone()))
+        .collect();
+
+    let mut changed = false;
+    for (provider_id, key) in plaintext {
+        match store.set(&api_key_name(&provider_id), &key) {
+            Ok(()) => {
+                debug!("Migrated API key for '{}' to keychain", provider_id);
+                settings
+                    .post_process_api_keys
+                    .insert(provider_id, String::new());
+                changed = true;
+            }
+            Err(e) => {
+                warn!(
+                    "Could not migrate API key for '{}' to keychain: {}",
+                    provider_id, e
+                );
+            }
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::get_default_settings;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryStore {
+        values: Mutex<HashMap<String, String>>,
+        fail: bool,
+    }
+
+    impl SecretStore for MemoryStore {
+        fn get(&self, key: &str) -> Result<Option<String>> {
+            if self.fail {
+                anyhow::bail!("no keychain");
+            }
+            Ok(self.values.lock().unwrap().get(key).cloned())
+        }
+
+        fn set(&self, key: &str, value: &str) -> Result<()> {
+            if self.fail {
+                anyhow::bail!("no keychain");
+            }
+            self.values
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn delete(&self, key: &str) -> Result<()> {
+            if self.fail {
+                anyhow::bail!("no keychain");
+            }
+            self.values.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn migrates_plaintext_keys_into_store() {
+        let store = MemoryStore::default();
+        let mut settings = get_default_settings();
+        settings
+            .post_process_api_keys
+            .insert("openai".to_string(), "sk-test".to_string());
+
+        assert!(migrate_plaintext_keys(&store, &mut settings));
+        assert_eq!(settings.post_process_api_keys["openai"], "");
+        assert_eq!(get_api_key(&store, &settings, "openai"), "sk-test");
+
+        // Nothing left to migrate on the second run
+        assert!(!migrate_plaintext_keys(&store, &mut settings));
+    }
+
+    #[test]
+    fn falls_back_to_settings_without_keychain() {
+        let store = MemoryStore {
+            fail: true,
+            ..Default::default()
+        };
+        let mut settings = get_default_settings();
+
+        assert!(!set_api_key(&store, &mut settings, "groq", "gsk-test"));
+        assert_eq!(settings.post_process_api_keys["groq"], "gsk-test");
+        assert_eq!(get_api_key(&store, &settings, "groq"), "gsk-test");
+        assert!(!migrate_plaintext_keys(&store, &mut settings));
+    }
+}
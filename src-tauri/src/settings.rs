@@ -1,13 +1,17 @@
-use log::{debug, warn};
 use serde::de::{self, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use specta::Type;
 use std::collections::HashMap;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 use tauri_plugin_store::StoreExt;
+use tracing::{debug, warn};
 
 pub const APPLE_INTELLIGENCE_PROVIDER_ID: &str = "apple_intelligence";
 pub const APPLE_INTELLIGENCE_DEFAULT_MODEL_ID: &str = "Apple Intelligence";
+pub const LOCAL_GRAMMAR_PROVIDER_ID: &str = "local_grammar";
+pub const LOCAL_GRAMMAR_DEFAULT_MODEL_ID: &str = "Grammar & Spelling";
+/// Azure OpenAI `api-version` used unless a provider sets its own.
+pub const AZURE_OPENAI_API_VERSION: &str = "2024-10-21";
 
 #[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "lowercase")]
@@ -82,6 +86,8 @@ pub struct ShortcutBinding {
     pub name: String,
     pub description: String,
     pub default_binding: String,
+    /// Plus-separated key combination (e.g. "ctrl+space"), or a single mouse
+    /// button / media key name such as "mouse4" or "media_play_pause".
     pub current_binding: String,
 }
 
@@ -90,6 +96,44 @@ pub struct LLMPrompt {
     pub id: String,
     pub name: String,
     pub prompt: String,
+    /// Model to run this prompt on instead of the provider's selected one,
+    /// e.g. a cheap model for cleanup and a stronger one for summaries.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Sampling temperature; `None` leaves it to the provider.
+    #[serde(default)]
+    pub temperature: Option<f32>,
+    /// Cap on the length of the response; `None` leaves it to the provider.
+    #[serde(default)]
+    pub max_tokens: Option<u32>,
+    /// System message sent ahead of the prompt. Supports the same
+    /// `${...}` variables as the prompt.
+    #[serde(default)]
+    pub system_message: Option<String>,
+}
+
+/// Release stream the updater follows.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    #[default]
+    Stable,
+    /// Pre-releases, ahead of stable
+    Beta,
+}
+
+/// Request format a post-processing provider speaks.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ProviderApi {
+    /// OpenAI-compatible `/chat/completions`
+    #[default]
+    OpenAi,
+    /// Azure OpenAI: a URL per deployment, `api-key` header and an
+    /// `api-version` query parameter
+    AzureOpenAi,
+    /// Google Gemini `generateContent`
+    Gemini,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -101,14 +145,83 @@ pub struct PostProcessProvider {
     pub allow_base_url_edit: bool,
     #[serde(default)]
     pub models_endpoint: Option<String>,
+    #[serde(default)]
+    pub api: ProviderApi,
+    /// Azure OpenAI `api-version`; `None` uses [`AZURE_OPENAI_API_VERSION`].
+    #[serde(default)]
+    pub api_version: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum OverlayPosition {
     None,
     Top,
     Bottom,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    Left,
+    Right,
+    /// Placed at `OverlayLayout::custom_x`/`custom_y`
+    Custom,
+}
+
+/// Overlay placement details that complement `overlay_position`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Type)]
+pub struct OverlayLayout {
+    /// Index into the available monitors; `None` follows the mouse cursor.
+    #[serde(default)]
+    pub monitor: Option<u32>,
+    /// Logical offset from the monitor work area's top-left corner (custom position only).
+    #[serde(default)]
+    pub custom_x: f64,
+    #[serde(default)]
+    pub custom_y: f64,
+    /// Size multiplier applied to the overlay window and its content.
+    #[serde(default = "default_overlay_scale")]
+    pub scale: f64,
+    #[serde(default = "default_overlay_opacity")]
+    pub opacity: f64,
+}
+
+pub const OVERLAY_SCALE_RANGE: (f64, f64) = (0.5, 2.0);
+pub const OVERLAY_OPACITY_RANGE: (f64, f64) = (0.2, 1.0);
+
+fn default_overlay_scale() -> f64 {
+    1.0
+}
+
+fn default_overlay_opacity() -> f64 {
+    1.0
+}
+
+impl Default for OverlayLayout {
+    fn default() -> Self {
+        Self {
+            monitor: None,
+            custom_x: 0.0,
+            custom_y: 0.0,
+            scale: default_overlay_scale(),
+            opacity: default_overlay_opacity(),
+        }
+    }
+}
+
+impl OverlayLayout {
+    /// Clamp scale and opacity to supported ranges and offsets to non-negative values.
+    pub fn clamped(mut self) -> Self {
+        self.scale = self
+            .scale
+            .clamp(OVERLAY_SCALE_RANGE.0, OVERLAY_SCALE_RANGE.1);
+        self.opacity = self
+            .opacity
+            .clamp(OVERLAY_OPACITY_RANGE.0, OVERLAY_OPACITY_RANGE.1);
+        self.custom_x = self.custom_x.max(0.0);
+        self.custom_y = self.custom_y.max(0.0);
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -124,6 +237,51 @@ pub enum ModelUnloadTimeout {
     Sec5, // Debug mode only
 }
 
+/// When the transcription model is kept in memory between dictations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelKeepAlive {
+    /// Load on demand and unload according to `model_unload_timeout`
+    #[default]
+    Idle,
+    /// Load at startup and reload whenever the model was dropped
+    AlwaysLoaded,
+    /// Keep the model loaded on AC power, unload it while on battery
+    UnloadOnBattery,
+}
+
+/// Overrides applied while a laptop runs on battery, to save power.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct BatteryPolicy {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Model used instead of `selected_model`, typically a smaller one.
+    /// `None` keeps the selected model.
+    #[serde(default)]
+    pub model: Option<String>,
+    #[serde(default = "default_battery_disable_always_on_microphone")]
+    pub disable_always_on_microphone: bool,
+    /// Skip live partial transcription while recording.
+    #[serde(default = "default_battery_disable_streaming")]
+    pub disable_streaming: bool,
+    /// Used instead of `model_unload_timeout` when it is longer, so the model
+    /// isn't reloaded for every dictation.
+    #[serde(default = "default_battery_unload_timeout")]
+    pub unload_timeout: ModelUnloadTimeout,
+}
+
+impl Default for BatteryPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            model: None,
+            disable_always_on_microphone: default_battery_disable_always_on_microphone(),
+            disable_streaming: default_battery_disable_streaming(),
+            unload_timeout: default_battery_unload_timeout(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum PasteMethod {
@@ -132,6 +290,79 @@ pub enum PasteMethod {
     None,
     ShiftInsert,
     CtrlShiftV,
+    /// Type one character at a time with `per_character_delay_ms` between
+    /// them, so an active IME can commit each one.
+    PerCharacter,
+}
+
+/// How dictated text is formatted and inserted for a given application.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AppOutputBehavior {
+    #[default]
+    Default,
+    /// No post-processing or sentence formatting; typed directly instead of pasted.
+    Verbatim,
+}
+
+/// Input overrides for a single shortcut binding, e.g. a headset for the
+/// meeting binding and the desk microphone for dictation.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Type)]
+pub struct BindingAudioOverride {
+    /// Input device name; `None` uses the globally selected microphone.
+    #[serde(default)]
+    pub microphone: Option<String>,
+    /// Gain applied to the recorded samples, in dB.
+    #[serde(default)]
+    pub gain_db: f32,
+}
+
+impl BindingAudioOverride {
+    pub fn is_noop(&self) -> bool {
+        self.microphone.is_none() && self.gain_db == 0.0
+    }
+}
+
+/// How profanity in a binding's output is masked.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfanityMask {
+    /// Leave the words as they are.
+    #[default]
+    Keep,
+    /// Keep the first letter and star out the rest: "f***".
+    Asterisks,
+    /// Drop the words.
+    Remove,
+}
+
+/// Something done with a binding's final text. Bindings without any
+/// output actions just paste.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum OutputAction {
+    /// Paste into the focused window.
+    Paste,
+    /// Append to a file, e.g. a daily notes page in a Markdown vault.
+    AppendToFile {
+        /// Absolute path, or starting with `~/`. May use `${date}`.
+        path: String,
+        /// Line written above each entry, e.g. `## ${date} ${time}`.
+        /// Empty writes the text alone.
+        #[serde(default = "default_output_file_header")]
+        header: String,
+    },
+    /// POST the text as JSON to a URL.
+    Webhook { url: String },
+    /// Run a shell command with the text on its stdin.
+    Command { command: String },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Type)]
+pub struct AppBehaviorRule {
+    /// Application name, matched case-insensitively. A trailing `*` matches any suffix.
+    pub app_pattern: String,
+    pub behavior: AppOutputBehavior,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
@@ -141,6 +372,35 @@ pub enum ClipboardHandling {
     CopyToClipboard,
 }
 
+/// X11/Wayland selection that clipboard-based paste methods write to (Linux only).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionTarget {
+    #[default]
+    Clipboard,
+    /// The PRIMARY selection, pasted with a middle click
+    Primary,
+    Both,
+}
+
+impl SelectionTarget {
+    pub fn includes_primary(self) -> bool {
+        matches!(self, SelectionTarget::Primary | SelectionTarget::Both)
+    }
+}
+
+/// What a transcribe shortcut does while the previous recording is still
+/// being transcribed.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum BusyBehavior {
+    /// Play an error sound and ignore the shortcut
+    #[default]
+    Reject,
+    /// Start recording right away; transcriptions are processed in order
+    Queue,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum RecordingRetentionPeriod {
@@ -151,6 +411,21 @@ pub enum RecordingRetentionPeriod {
     Months3,
 }
 
+/// Additional limits applied on top of `recording_retention_period`.
+/// Every limit is optional; saved entries are never pruned.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, Type)]
+pub struct HistoryRetentionPolicy {
+    #[serde(default)]
+    pub max_entries: Option<usize>,
+    #[serde(default)]
+    pub max_audio_size_mb: Option<u64>,
+    #[serde(default)]
+    pub max_age_days: Option<u32>,
+    /// When set, pruning deletes only the WAV files and keeps the transcript text.
+    #[serde(default)]
+    pub audio_only_pruning: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum KeyboardImplementation {
@@ -244,8 +519,17 @@ impl SoundTheme {
 /* still handy for composing the initial JSON in the store ------------- */
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
 pub struct AppSettings {
+    /// Schema version, upgraded on load by `settings_migration`.
+    #[serde(default = "default_settings_version")]
+    pub settings_version: u32,
     pub bindings: HashMap<String, ShortcutBinding>,
     pub push_to_talk: bool,
+    /// Recognize tap, double-tap and long-press on the transcribe shortcut
+    /// instead of plain toggle / push-to-talk.
+    #[serde(default)]
+    pub hotkey_gestures: bool,
+    #[serde(default)]
+    pub busy_behavior: BusyBehavior,
     pub audio_feedback: bool,
     #[serde(default = "default_audio_feedback_volume")]
     pub audio_feedback_volume: f32,
@@ -253,10 +537,19 @@ pub struct AppSettings {
     pub sound_theme: SoundTheme,
     #[serde(default = "default_start_hidden")]
     pub start_hidden: bool,
+    /// Run without tray icon, overlay and settings windows (also `--headless`).
+    /// Takes effect on next launch.
+    #[serde(default)]
+    pub headless: bool,
     #[serde(default = "default_autostart_enabled")]
     pub autostart_enabled: bool,
     #[serde(default = "default_update_checks_enabled")]
     pub update_checks_enabled: bool,
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    /// Hours between background update checks.
+    #[serde(default = "default_update_check_interval_hours")]
+    pub update_check_interval_hours: u32,
     #[serde(default = "default_model")]
     pub selected_model: String,
     #[serde(default = "default_always_on_microphone")]
@@ -265,22 +558,49 @@ pub struct AppSettings {
     pub selected_microphone: Option<String>,
     #[serde(default)]
     pub clamshell_microphone: Option<String>,
+    /// Per-binding microphone and gain, keyed by binding id.
+    #[serde(default)]
+    pub binding_audio: HashMap<String, BindingAudioOverride>,
+    /// Per-binding output actions, keyed by binding id, run in order.
+    #[serde(default)]
+    pub binding_output: HashMap<String, Vec<OutputAction>>,
+    /// Per-binding profanity masking, keyed by binding id. Bindings
+    /// without an entry keep the text as it is.
+    #[serde(default)]
+    pub binding_profanity: HashMap<String, ProfanityMask>,
     #[serde(default)]
     pub selected_output_device: Option<String>,
+    /// Set once the first-launch microphone diagnostics got past the
+    /// permission check.
+    #[serde(default)]
+    pub microphone_check_completed: bool,
     #[serde(default = "default_translate_to_english")]
     pub translate_to_english: bool,
     #[serde(default = "default_selected_language")]
     pub selected_language: String,
     #[serde(default = "default_overlay_position")]
     pub overlay_position: OverlayPosition,
+    #[serde(default)]
+    pub overlay_layout: OverlayLayout,
     #[serde(default = "default_debug_mode")]
     pub debug_mode: bool,
     #[serde(default = "default_log_level")]
     pub log_level: LogLevel,
+    /// Write raw transcript, prompt and LLM text to logs instead of redacted summaries.
+    #[serde(default)]
+    pub log_transcripts: bool,
     #[serde(default)]
     pub custom_words: Vec<String>,
     #[serde(default)]
     pub model_unload_timeout: ModelUnloadTimeout,
+    #[serde(default)]
+    pub model_keep_alive: ModelKeepAlive,
+    /// Engines used in parallel for the final pass of long recordings.
+    /// Each extra worker loads another copy of the model; 1 disables it.
+    #[serde(default = "default_transcription_workers")]
+    pub transcription_workers: usize,
+    #[serde(default)]
+    pub battery_policy: BatteryPolicy,
     #[serde(default = "default_word_correction_threshold")]
     pub word_correction_threshold: f64,
     #[serde(default = "default_history_limit")]
@@ -288,9 +608,16 @@ pub struct AppSettings {
     #[serde(default = "default_recording_retention_period")]
     pub recording_retention_period: RecordingRetentionPeriod,
     #[serde(default)]
+    pub history_retention: HistoryRetentionPolicy,
+    #[serde(default)]
     pub paste_method: PasteMethod,
     #[serde(default)]
     pub clipboard_handling: ClipboardHandling,
+    #[serde(default)]
+    pub selection_target: SelectionTarget,
+    /// Paste the PRIMARY selection with a simulated middle click (Linux only).
+    #[serde(default)]
+    pub middle_click_paste: bool,
     #[serde(default = "default_post_process_enabled")]
     pub post_process_enabled: bool,
     #[serde(default = "default_post_process_provider_id")]
@@ -305,6 +632,10 @@ pub struct AppSettings {
     pub post_process_prompts: Vec<LLMPrompt>,
     #[serde(default)]
     pub post_process_selected_prompt_id: Option<String>,
+    /// Ordered prompt ids run as a chain, each stage feeding the next.
+    /// When empty, only the selected prompt is run.
+    #[serde(default)]
+    pub post_process_chain: Vec<String>,
     #[serde(default)]
     pub mute_while_recording: bool,
     #[serde(default)]
@@ -317,8 +648,82 @@ pub struct AppSettings {
     pub keyboard_implementation: KeyboardImplementation,
     #[serde(default = "default_paste_delay_ms")]
     pub paste_delay_ms: u64,
+    #[serde(default = "default_per_character_delay_ms")]
+    pub per_character_delay_ms: u64,
+    /// Type the text per character when a clipboard paste fails, and when
+    /// direct typing would send CJK text through Enigo.
+    #[serde(default = "default_paste_fallback_enabled")]
+    pub paste_fallback_enabled: bool,
     #[serde(default = "default_voice_commands_enabled")]
     pub voice_commands_enabled: bool,
+    /// Treat every dictation as private: nothing is saved to history and
+    /// transcript text is redacted from logs.
+    #[serde(default)]
+    pub private_mode: bool,
+    /// Per-application output behavior, matched against the focused app at record start.
+    #[serde(default = "default_app_behaviors")]
+    pub app_behaviors: Vec<AppBehaviorRule>,
+    /// Convert spoken numbers, currencies, times and dates to digits
+    /// ("twenty three" -> "23") before pasting and voice-command matching.
+    #[serde(default)]
+    pub number_normalization: bool,
+    /// Send a native notification (and copy the text) when a finished
+    /// transcript may not have reached the user: the paste failed, or the
+    /// focused app changed while it was being processed.
+    #[serde(default = "default_completion_notifications")]
+    pub completion_notifications: bool,
+    /// Where meeting mode writes its session files; `None` uses the
+    /// `meetings` folder in the app data directory.
+    #[serde(default)]
+    pub meeting_notes_folder: Option<String>,
+    /// Join a dictation onto the previous one when it starts within
+    /// `continue_dictation_window_secs` in the same app.
+    #[serde(default)]
+    pub continue_dictation: bool,
+    #[serde(default = "default_continue_dictation_window_secs")]
+    pub continue_dictation_window_secs: u32,
+    /// Read the final transcript aloud after it is delivered.
+    #[serde(default)]
+    pub tts_read_transcripts: bool,
+    /// Read aloud the errors that stop a dictation.
+    #[serde(default)]
+    pub tts_read_errors: bool,
+    /// Speech synthesizer voice; `None` uses the system default.
+    #[serde(default)]
+    pub tts_voice: Option<String>,
+    /// Speaking rate as a multiple of the normal speed.
+    #[serde(default = "default_tts_rate")]
+    pub tts_rate: f32,
+    /// Hold each finished dictation in the overlay until it is accepted,
+    /// edited or discarded, instead of pasting it straight away.
+    #[serde(default)]
+    pub confirm_before_paste: bool,
+    /// Drop transcripts of near-silent audio and Whisper's phantom phrases
+    /// ("Thank you for watching") instead of pasting them.
+    #[serde(default = "default_hallucination_filter")]
+    pub hallucination_filter: bool,
+    /// RMS level in dBFS below which a recording counts as silent.
+    #[serde(default = "default_hallucination_energy_floor_db")]
+    pub hallucination_energy_floor_db: f32,
+    /// Share of the audio without speech at which a transcript made up of
+    /// blocklisted phrases is dropped.
+    #[serde(default = "default_hallucination_no_speech_threshold")]
+    pub hallucination_no_speech_threshold: f32,
+    /// Known phantom phrases, by Whisper language code.
+    #[serde(default = "default_hallucination_phrases")]
+    pub hallucination_phrases: HashMap<String, Vec<String>>,
+    /// Restore casing and punctuation locally when the transcript comes
+    /// back all lowercase or unpunctuated, before any LLM post-processing.
+    #[serde(default = "default_punctuation_restore")]
+    pub punctuation_restore: bool,
+    /// Also restore capitals of names from a casing model learned from the
+    /// dictation history.
+    #[serde(default)]
+    pub punctuation_restore_casing_model: bool,
+}
+
+fn default_settings_version() -> u32 {
+    crate::settings_migration::CURRENT_SETTINGS_VERSION
 }
 
 fn default_model() -> String {
@@ -345,6 +750,10 @@ fn default_update_checks_enabled() -> bool {
     true
 }
 
+fn default_update_check_interval_hours() -> u32 {
+    24
+}
+
 fn default_selected_language() -> String {
     "auto".to_string()
 }
@@ -372,14 +781,115 @@ fn default_paste_delay_ms() -> u64 {
     60
 }
 
+fn default_per_character_delay_ms() -> u64 {
+    15
+}
+
+fn default_paste_fallback_enabled() -> bool {
+    true
+}
+
+fn default_completion_notifications() -> bool {
+    true
+}
+
+fn default_continue_dictation_window_secs() -> u32 {
+    20
+}
+
+fn default_tts_rate() -> f32 {
+    1.0
+}
+
+fn default_hallucination_filter() -> bool {
+    true
+}
+
+fn default_hallucination_energy_floor_db() -> f32 {
+    -55.0
+}
+
+fn default_hallucination_no_speech_threshold() -> f32 {
+    0.8
+}
+
+fn default_hallucination_phrases() -> HashMap<String, Vec<String>> {
+    crate::hallucination::default_phrases()
+}
+
+fn default_punctuation_restore() -> bool {
+    true
+}
+
+fn default_output_file_header() -> String {
+    "## ${date} ${time}".to_string()
+}
+
 fn default_voice_commands_enabled() -> bool {
     false
 }
 
+fn default_app_behaviors() -> Vec<AppBehaviorRule> {
+    [
+        // Editors / IDEs
+        "code",
+        "visual studio code",
+        "cursor",
+        "zed",
+        "sublime_text",
+        "sublime text",
+        "vim",
+        "nvim",
+        "emacs",
+        "idea*",
+        "intellij idea*",
+        "pycharm*",
+        "webstorm*",
+        "clion*",
+        "goland*",
+        "rider*",
+        "android studio",
+        "xcode",
+        // Terminals
+        "terminal",
+        "iterm2",
+        "windowsterminal",
+        "alacritty",
+        "kitty",
+        "wezterm*",
+        "gnome-terminal*",
+        "konsole",
+        "xterm",
+        "ghostty",
+    ]
+    .iter()
+    .map(|pattern| AppBehaviorRule {
+        app_pattern: pattern.to_string(),
+        behavior: AppOutputBehavior::Verbatim,
+    })
+    .collect()
+}
+
 fn default_history_limit() -> usize {
     5
 }
 
+fn default_battery_disable_always_on_microphone() -> bool {
+    true
+}
+
+fn default_battery_disable_streaming() -> bool {
+    true
+}
+
+fn default_battery_unload_timeout() -> ModelUnloadTimeout {
+    ModelUnloadTimeout::Min15
+}
+
+fn default_transcription_workers() -> usize {
+    1
+}
+
 fn default_recording_retention_period() -> RecordingRetentionPeriod {
     RecordingRetentionPeriod::PreserveLimit
 }
@@ -414,6 +924,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.openai.com/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
         },
         PostProcessProvider {
             id: "openrouter".to_string(),
@@ -421,6 +933,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://openrouter.ai/api/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
         },
         PostProcessProvider {
             id: "anthropic".to_string(),
@@ -428,6 +942,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.anthropic.com/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
         },
         PostProcessProvider {
             id: "groq".to_string(),
@@ -435,6 +951,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.groq.com/openai/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
         },
         PostProcessProvider {
             id: "cerebras".to_string(),
@@ -442,6 +960,28 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "https://api.cerebras.ai/v1".to_string(),
             allow_base_url_edit: false,
             models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::OpenAi,
+            api_version: None,
+        },
+        PostProcessProvider {
+            id: "gemini".to_string(),
+            label: "Google Gemini".to_string(),
+            base_url: "https://generativelanguage.googleapis.com/v1beta".to_string(),
+            allow_base_url_edit: false,
+            models_endpoint: Some("/models".to_string()),
+            api: ProviderApi::Gemini,
+            api_version: None,
+        },
+        // The base URL names the user's resource, and models are the names
+        // of its deployments
+        PostProcessProvider {
+            id: "azure_openai".to_string(),
+            label: "Azure OpenAI".to_string(),
+            base_url: "https://YOUR-RESOURCE.openai.azure.com/openai".to_string(),
+            allow_base_url_edit: true,
+            models_endpoint: None,
+            api: ProviderApi::AzureOpenAi,
+            api_version: Some(AZURE_OPENAI_API_VERSION.to_string()),
         },
     ];
 
@@ -457,9 +997,22 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
             base_url: "apple-intelligence://local".to_string(),
             allow_base_url_edit: false,
             models_endpoint: None,
+            api: ProviderApi::OpenAi,
+            api_version: None,
         });
     }
 
+    // Offline grammar/spelling cleanup, for users without an API key or local LLM
+    providers.push(PostProcessProvider {
+        id: LOCAL_GRAMMAR_PROVIDER_ID.to_string(),
+        label: "Local Grammar (offline)".to_string(),
+        base_url: "local-grammar://local".to_string(),
+        allow_base_url_edit: false,
+        models_endpoint: None,
+        api: ProviderApi::OpenAi,
+        api_version: None,
+    });
+
     // Custom provider always comes last
     providers.push(PostProcessProvider {
         id: "custom".to_string(),
@@ -467,6 +1020,8 @@ fn default_post_process_providers() -> Vec<PostProcessProvider> {
         base_url: "http://localhost:11434/v1".to_string(),
         allow_base_url_edit: true,
         models_endpoint: Some("/models".to_string()),
+        api: ProviderApi::OpenAi,
+        api_version: None,
     });
 
     providers
@@ -484,6 +1039,9 @@ fn default_model_for_provider(provider_id: &str) -> String {
     if provider_id == APPLE_INTELLIGENCE_PROVIDER_ID {
         return APPLE_INTELLIGENCE_DEFAULT_MODEL_ID.to_string();
     }
+    if provider_id == LOCAL_GRAMMAR_PROVIDER_ID {
+        return LOCAL_GRAMMAR_DEFAULT_MODEL_ID.to_string();
+    }
     String::new()
 }
 
@@ -503,6 +1061,10 @@ fn default_post_process_prompts() -> Vec<LLMPrompt> {
         id: "default_improve_transcriptions".to_string(),
         name: "Improve Transcriptions".to_string(),
         prompt: "Clean this transcript:\n1. Fix spelling, capitalization, and punctuation errors\n2. Convert number words to digits (twenty-five → 25, ten percent → 10%, five dollars → $5)\n3. Replace spoken punctuation with symbols (period → ., comma → ,, question mark → ?)\n4. Remove filler words (um, uh, like as filler)\n5. Keep the language in the original version (if it was french, keep it in french for example)\n\nPreserve exact meaning and word order. Do not paraphrase or reorder content.\n\nReturn only the cleaned transcript.\n\nTranscript:\n${output}".to_string(),
+        model: None,
+        temperature: None,
+        max_tokens: None,
+        system_message: None,
     }]
 }
 
@@ -607,6 +1169,38 @@ pub fn get_default_settings() -> AppSettings {
             current_binding: default_toggle_settings_shortcut.to_string(),
         },
     );
+    #[cfg(target_os = "macos")]
+    let default_private_shortcut = "option+cmd+shift+space";
+    #[cfg(not(target_os = "macos"))]
+    let default_private_shortcut = "ctrl+alt+shift+space";
+
+    bindings.insert(
+        "transcribe_private".to_string(),
+        ShortcutBinding {
+            id: "transcribe_private".to_string(),
+            name: "Private Dictation".to_string(),
+            description: "Converts your speech into text without saving it to history or logs."
+                .to_string(),
+            default_binding: default_private_shortcut.to_string(),
+            current_binding: default_private_shortcut.to_string(),
+        },
+    );
+    #[cfg(target_os = "macos")]
+    let default_meeting_shortcut = "option+cmd+m";
+    #[cfg(not(target_os = "macos"))]
+    let default_meeting_shortcut = "ctrl+alt+m";
+
+    bindings.insert(
+        "meeting".to_string(),
+        ShortcutBinding {
+            id: "meeting".to_string(),
+            name: "Meeting Notes".to_string(),
+            description: "Starts or stops continuous transcription into a meeting notes file."
+                .to_string(),
+            default_binding: default_meeting_shortcut.to_string(),
+            current_binding: default_meeting_shortcut.to_string(),
+        },
+    );
     bindings.insert(
         "cancel".to_string(),
         ShortcutBinding {
@@ -619,31 +1213,49 @@ pub fn get_default_settings() -> AppSettings {
     );
 
     AppSettings {
+        settings_version: default_settings_version(),
         bindings,
         push_to_talk: true,
+        hotkey_gestures: false,
+        busy_behavior: BusyBehavior::default(),
         audio_feedback: false,
         audio_feedback_volume: default_audio_feedback_volume(),
         sound_theme: default_sound_theme(),
         start_hidden: default_start_hidden(),
+        headless: false,
         autostart_enabled: default_autostart_enabled(),
         update_checks_enabled: default_update_checks_enabled(),
+        update_channel: UpdateChannel::Stable,
+        update_check_interval_hours: default_update_check_interval_hours(),
         selected_model: "".to_string(),
         always_on_microphone: false,
         selected_microphone: None,
         clamshell_microphone: None,
+        binding_audio: HashMap::new(),
+        binding_output: HashMap::new(),
+        binding_profanity: HashMap::new(),
         selected_output_device: None,
+        microphone_check_completed: false,
         translate_to_english: false,
         selected_language: "auto".to_string(),
         overlay_position: default_overlay_position(),
+        overlay_layout: OverlayLayout::default(),
         debug_mode: false,
         log_level: default_log_level(),
+        log_transcripts: false,
         custom_words: Vec::new(),
         model_unload_timeout: ModelUnloadTimeout::Never,
+        model_keep_alive: ModelKeepAlive::default(),
+        transcription_workers: default_transcription_workers(),
+        battery_policy: BatteryPolicy::default(),
         word_correction_threshold: default_word_correction_threshold(),
         history_limit: default_history_limit(),
         recording_retention_period: default_recording_retention_period(),
+        history_retention: HistoryRetentionPolicy::default(),
         paste_method: PasteMethod::default(),
         clipboard_handling: ClipboardHandling::default(),
+        selection_target: SelectionTarget::default(),
+        middle_click_paste: false,
         post_process_enabled: default_post_process_enabled(),
         post_process_provider_id: default_post_process_provider_id(),
         post_process_providers: default_post_process_providers(),
@@ -651,17 +1263,46 @@ pub fn get_default_settings() -> AppSettings {
         post_process_models: default_post_process_models(),
         post_process_prompts: default_post_process_prompts(),
         post_process_selected_prompt_id: None,
+        post_process_chain: Vec::new(),
         mute_while_recording: false,
         append_trailing_space: false,
         app_language: default_app_language(),
         experimental_enabled: false,
         keyboard_implementation: KeyboardImplementation::default(),
         paste_delay_ms: default_paste_delay_ms(),
+        per_character_delay_ms: default_per_character_delay_ms(),
+        paste_fallback_enabled: default_paste_fallback_enabled(),
         voice_commands_enabled: default_voice_commands_enabled(),
+        private_mode: false,
+        app_behaviors: default_app_behaviors(),
+        number_normalization: false,
+        completion_notifications: default_completion_notifications(),
+        meeting_notes_folder: None,
+        continue_dictation: false,
+        continue_dictation_window_secs: default_continue_dictation_window_secs(),
+        tts_read_transcripts: false,
+        tts_read_errors: false,
+        tts_voice: None,
+        tts_rate: default_tts_rate(),
+        confirm_before_paste: false,
+        hallucination_filter: default_hallucination_filter(),
+        hallucination_energy_floor_db: default_hallucination_energy_floor_db(),
+        hallucination_no_speech_threshold: default_hallucination_no_speech_threshold(),
+        hallucination_phrases: default_hallucination_phrases(),
+        punctuation_restore: default_punctuation_restore(),
+        punctuation_restore_casing_model: false,
     }
 }
 
 impl AppSettings {
+    /// Whether transcripts can come out in language `code`: any language
+    /// with auto-detect, and English too when translating.
+    pub fn may_transcribe_language(&self, code: &str) -> bool {
+        // "zh-Hans" transcribes as "zh"
+        let selected = self.selected_language.split('-').next().unwrap_or("");
+        selected == "auto" || code == selected || (self.translate_to_english && code == "en")
+    }
+
     pub fn active_post_process_provider(&self) -> Option<&PostProcessProvider> {
         self.post_process_providers
             .iter()
@@ -674,6 +1315,26 @@ impl AppSettings {
             .find(|provider| provider.id == provider_id)
     }
 
+    /// API key for a provider, read from the OS keychain with a fallback to
+    /// the plaintext `post_process_api_keys` map.
+    pub fn post_process_api_key(&self, provider_id: &str) -> String {
+        crate::secret_store::get_api_key(crate::secret_store::secret_store(), self, provider_id)
+    }
+
+    /// Prompts to run for LLM post-processing, in order: the configured chain,
+    /// or just the selected prompt.
+    pub fn post_process_prompt_chain(&self) -> Vec<&LLMPrompt> {
+        let ids: Vec<&String> = if self.post_process_chain.is_empty() {
+            self.post_process_selected_prompt_id.iter().collect()
+        } else {
+            self.post_process_chain.iter().collect()
+        };
+
+        ids.into_iter()
+            .filter_map(|id| self.post_process_prompts.iter().find(|p| &p.id == id))
+            .collect()
+    }
+
     pub fn post_process_provider_mut(
         &mut self,
         provider_id: &str,
@@ -691,44 +1352,39 @@ pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
         .expect("Failed to initialize store");
 
     let mut settings = if let Some(settings_value) = store.get("settings") {
-        // Parse the entire settings object
-        match serde_json::from_value::<AppSettings>(settings_value) {
-            Ok(mut settings) => {
-                debug!("Found existing settings: {:?}", settings);
-                let default_settings = get_default_settings();
-                let mut updated = false;
-
-                // Merge default bindings into existing settings
-                for (key, value) in default_settings.bindings {
-                    if !settings.bindings.contains_key(&key) {
-                        debug!("Adding missing binding: {}", key);
-                        settings.bindings.insert(key, value);
-                        updated = true;
-                    }
-                }
-
-                if updated {
-                    debug!("Settings updated with new bindings");
-                    store.set("settings", serde_json::to_value(&settings).unwrap());
-                }
-
-                settings
-            }
-            Err(e) => {
-                warn!("Failed to parse settings: {}", e);
-                // Fall back to default settings if parsing fails
-                let default_settings = get_default_settings();
-                store.set("settings", serde_json::to_value(&default_settings).unwrap());
-                default_settings
+        let loaded = load_stored_settings(&store, settings_value);
+        let mut settings = loaded.settings;
+        debug!("Found existing settings: {:?}", settings);
+        let default_settings = get_default_settings();
+        let mut updated = loaded.changed;
+
+        // Merge default bindings into existing settings
+        for (key, value) in default_settings.bindings {
+            if !settings.bindings.contains_key(&key) {
+                debug!("Adding missing binding: {}", key);
+                settings.bindings.insert(key, value);
+                updated = true;
             }
         }
+
+        if updated {
+            debug!("Settings updated after migration or with new bindings");
+            store.set("settings", serde_json::to_value(&settings).unwrap());
+        }
+
+        settings
     } else {
         let default_settings = get_default_settings();
         store.set("settings", serde_json::to_value(&default_settings).unwrap());
         default_settings
     };
 
-    if ensure_post_process_defaults(&mut settings) {
+    let mut changed = ensure_post_process_defaults(&mut settings);
+    changed |= crate::secret_store::migrate_plaintext_keys(
+        crate::secret_store::secret_store(),
+        &mut settings,
+    );
+    if changed {
         store.set("settings", serde_json::to_value(&settings).unwrap());
     }
 
@@ -741,11 +1397,11 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
         .expect("Failed to initialize store");
 
     let mut settings = if let Some(settings_value) = store.get("settings") {
-        serde_json::from_value::<AppSettings>(settings_value).unwrap_or_else(|_| {
-            let default_settings = get_default_settings();
-            store.set("settings", serde_json::to_value(&default_settings).unwrap());
-            default_settings
-        })
+        let loaded = load_stored_settings(&store, settings_value);
+        if loaded.changed {
+            store.set("settings", serde_json::to_value(&loaded.settings).unwrap());
+        }
+        loaded.settings
     } else {
         let default_settings = get_default_settings();
         store.set("settings", serde_json::to_value(&default_settings).unwrap());
@@ -759,6 +1415,63 @@ pub fn get_settings(app: &AppHandle) -> AppSettings {
     settings
 }
 
+/// Migrate and parse the stored settings. Fields that fail to parse are reset
+/// individually; the original object is kept under `settings_backup` and
+/// the errors are recorded for the frontend.
+fn load_stored_settings<R: tauri::Runtime>(
+    store: &tauri_plugin_store::Store<R>,
+    settings_value: serde_json::Value,
+) -> crate::settings_migration::LoadedSettings {
+    let loaded = crate::settings_migration::load(settings_value.clone());
+    if !loaded.errors.is_empty() {
+        warn!(
+            "Reset {} invalid setting(s) to defaults, original kept in settings_backup",
+            loaded.errors.len()
+        );
+        store.set("settings_backup", settings_value);
+        crate::settings_migration::record_load_errors(&loaded.errors);
+    }
+    loaded
+}
+
+/// Read the settings file on disk, bypassing the store's in-memory copy,
+/// e.g. after it was edited by hand. Nothing is written back.
+pub fn read_settings_from_disk(app: &AppHandle) -> Result<AppSettings, String> {
+    let path = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("No app data directory: {}", e))?
+        .join(SETTINGS_STORE_PATH);
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let mut settings = parse_settings_file(&contents)?;
+    ensure_post_process_defaults(&mut settings);
+    crate::secret_store::migrate_plaintext_keys(crate::secret_store::secret_store(), &mut settings);
+    Ok(settings)
+}
+
+/// Settings from the contents of the store file. Invalid fields are reset
+/// like on a normal load; an unreadable file is an error.
+fn parse_settings_file(contents: &str) -> Result<AppSettings, String> {
+    let file: serde_json::Value =
+        serde_json::from_str(contents).map_err(|e| format!("Invalid settings file: {}", e))?;
+    let value = file
+        .get("settings")
+        .cloned()
+        .ok_or_else(|| "Settings file has no settings".to_string())?;
+
+    let loaded = crate::settings_migration::load(value);
+    if !loaded.errors.is_empty() {
+        warn!(
+            "Reset {} invalid setting(s) to defaults while reloading",
+            loaded.errors.len()
+        );
+        crate::settings_migration::record_load_errors(&loaded.errors);
+    }
+    Ok(loaded.settings)
+}
+
 pub fn write_settings(app: &AppHandle, settings: AppSettings) {
     let store = app
         .store(SETTINGS_STORE_PATH)
@@ -767,6 +1480,38 @@ pub fn write_settings(app: &AppHandle, settings: AppSettings) {
     store.set("settings", serde_json::to_value(&settings).unwrap());
 }
 
+/// A random id for this install, created on first use. It is kept in the
+/// store next to the settings rather than in them, so exported profiles
+/// don't carry it to other machines.
+pub fn install_id(app: &AppHandle) -> String {
+    let store = app
+        .store(SETTINGS_STORE_PATH)
+        .expect("Failed to initialize store");
+
+    if let Some(id) = store
+        .get("install_id")
+        .and_then(|id| id.as_str().map(str::to_owned))
+    {
+        return id;
+    }
+    let id = random_id();
+    store.set("install_id", id.clone());
+    id
+}
+
+/// 64 random bits as hex. `RandomState` is seeded from the OS random source,
+/// which is enough here without pulling in `rand`.
+fn random_id() -> String {
+    use std::hash::{BuildHasher, Hasher};
+
+    let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos());
+    hasher.write_u128(nanos);
+    format!("{:016x}", hasher.finish())
+}
+
 pub fn get_bindings(app: &AppHandle) -> HashMap<String, ShortcutBinding> {
     let settings = get_settings(app);
 
@@ -791,6 +1536,11 @@ pub fn get_recording_retention_period(app: &AppHandle) -> RecordingRetentionPeri
     settings.recording_retention_period
 }
 
+pub fn get_history_retention_policy(app: &AppHandle) -> HistoryRetentionPolicy {
+    let settings = get_settings(app);
+    settings.history_retention
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -805,6 +1555,8 @@ mod tests {
             .bindings
             .contains_key("transcribe_with_post_process"));
         assert!(settings.bindings.contains_key("toggle_settings"));
+        assert!(settings.bindings.contains_key("transcribe_private"));
+        assert!(settings.bindings.contains_key("meeting"));
         assert!(settings.bindings.contains_key("cancel"));
     }
 
@@ -834,6 +1586,8 @@ mod tests {
         assert!(!settings.start_hidden);
         assert!(!settings.autostart_enabled);
         assert!(settings.update_checks_enabled);
+        assert_eq!(settings.update_channel, UpdateChannel::Stable);
+        assert_eq!(settings.update_check_interval_hours, 24);
         assert_eq!(settings.selected_model, "");
         assert!(!settings.always_on_microphone);
         assert!(!settings.translate_to_english);
@@ -845,6 +1599,38 @@ mod tests {
         assert!(!settings.append_trailing_space);
         assert!(!settings.experimental_enabled);
         assert!(!settings.voice_commands_enabled);
+        assert!(!settings.private_mode);
+        assert!(!settings.log_transcripts);
+        assert!(!settings.number_normalization);
+        assert!(settings.completion_notifications);
+        assert!(settings.meeting_notes_folder.is_none());
+        assert!(!settings.continue_dictation);
+        assert_eq!(settings.continue_dictation_window_secs, 20);
+        assert!(!settings.tts_read_transcripts);
+        assert!(!settings.tts_read_errors);
+        assert!(settings.tts_voice.is_none());
+        assert_eq!(settings.tts_rate, 1.0);
+        assert!(!settings.confirm_before_paste);
+        assert!(settings.hallucination_filter);
+        assert_eq!(settings.hallucination_energy_floor_db, -55.0);
+        assert_eq!(settings.hallucination_no_speech_threshold, 0.8);
+        assert!(settings.hallucination_phrases.contains_key("en"));
+        assert!(settings.punctuation_restore);
+        assert!(!settings.punctuation_restore_casing_model);
+        assert!(!settings.headless);
+        assert!(!settings.microphone_check_completed);
+        assert!(!settings.hotkey_gestures);
+        assert_eq!(settings.busy_behavior, BusyBehavior::Reject);
+        assert!(settings.binding_audio.is_empty());
+        assert!(settings.binding_output.is_empty());
+        assert!(settings.binding_profanity.is_empty());
+        assert_eq!(settings.model_keep_alive, ModelKeepAlive::Idle);
+        assert_eq!(settings.transcription_workers, 1);
+        assert!(!settings.battery_policy.enabled);
+        assert_eq!(
+            settings.settings_version,
+            crate::settings_migration::CURRENT_SETTINGS_VERSION
+        );
     }
 
     #[test]
@@ -863,6 +1649,8 @@ mod tests {
     fn test_default_paste_delay_ms() {
         let settings = get_default_settings();
         assert_eq!(settings.paste_delay_ms, 60);
+        assert_eq!(settings.per_character_delay_ms, 15);
+        assert!(settings.paste_fallback_enabled);
     }
 
     // ── Serialization / Deserialization ──────────────────────────────
@@ -988,6 +1776,27 @@ mod tests {
         let providers = default_post_process_providers();
         assert!(providers.iter().any(|p| p.id == "openai"));
         assert!(providers.iter().any(|p| p.id == "custom"));
+        assert!(providers.iter().any(|p| p.id == LOCAL_GRAMMAR_PROVIDER_ID));
+        assert_eq!(providers.last().unwrap().id, "custom");
+    }
+
+    #[test]
+    fn test_providers_declare_their_api() {
+        let providers = default_post_process_providers();
+        let api = |id: &str| providers.iter().find(|p| p.id == id).unwrap().api;
+        assert_eq!(api("openai"), ProviderApi::OpenAi);
+        assert_eq!(api("azure_openai"), ProviderApi::AzureOpenAi);
+        assert_eq!(api("gemini"), ProviderApi::Gemini);
+
+        // Providers saved before the field existed speak the OpenAI format
+        let saved: PostProcessProvider = serde_json::from_value(serde_json::json!({
+            "id": "groq",
+            "label": "Groq",
+            "base_url": "https://api.groq.com/openai/v1"
+        }))
+        .unwrap();
+        assert_eq!(saved.api, ProviderApi::OpenAi);
+        assert!(saved.api_version.is_none());
     }
 
     #[test]
@@ -1071,6 +1880,28 @@ mod tests {
         let deserialized: OverlayPosition =
             serde_json::from_value(serde_json::json!("bottom")).unwrap();
         assert_eq!(deserialized, OverlayPosition::Bottom);
+
+        let json = serde_json::to_value(OverlayPosition::TopLeft).unwrap();
+        assert_eq!(json, serde_json::json!("top_left"));
+    }
+
+    #[test]
+    fn test_overlay_layout_defaults_and_clamping() {
+        let layout: OverlayLayout = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(layout, OverlayLayout::default());
+
+        let layout = OverlayLayout {
+            monitor: Some(1),
+            custom_x: -10.0,
+            custom_y: 40.0,
+            scale: 5.0,
+            opacity: 0.0,
+        }
+        .clamped();
+        assert_eq!(layout.custom_x, 0.0);
+        assert_eq!(layout.custom_y, 40.0);
+        assert_eq!(layout.scale, OVERLAY_SCALE_RANGE.1);
+        assert_eq!(layout.opacity, OVERLAY_OPACITY_RANGE.0);
     }
 
     // ── RecordingRetentionPeriod ────────────────────────────────────
@@ -1085,6 +1916,66 @@ mod tests {
         assert_eq!(deserialized, RecordingRetentionPeriod::Weeks2);
     }
 
+    // ── Post-processing chains ──────────────────────────────────────
+
+    #[test]
+    fn test_prompt_chain_falls_back_to_selected_prompt() {
+        let mut settings = get_default_settings();
+        settings.post_process_selected_prompt_id = Some("default_improve_transcriptions".into());
+        let chain = settings.post_process_prompt_chain();
+        assert_eq!(chain.len(), 1);
+        assert_eq!(chain[0].id, "default_improve_transcriptions");
+    }
+
+    #[test]
+    fn test_prompt_chain_preserves_order_and_skips_unknown_ids() {
+        let mut settings = get_default_settings();
+        settings.post_process_prompts.push(LLMPrompt {
+            id: "bullets".into(),
+            name: "Bullets".into(),
+            prompt: "Format as bullets: ${output}".into(),
+            model: None,
+            temperature: None,
+            max_tokens: None,
+            system_message: None,
+        });
+        settings.post_process_chain = vec![
+            "default_improve_transcriptions".into(),
+            "missing".into(),
+            "bullets".into(),
+        ];
+        let ids: Vec<&str> = settings
+            .post_process_prompt_chain()
+            .iter()
+            .map(|p| p.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["default_improve_transcriptions", "bullets"]);
+    }
+
+    // ── HistoryRetentionPolicy ──────────────────────────────────────
+
+    #[test]
+    fn test_history_retention_policy_default_has_no_limits() {
+        let settings = get_default_settings();
+        assert_eq!(
+            settings.history_retention,
+            HistoryRetentionPolicy::default()
+        );
+        assert!(settings.history_retention.max_entries.is_none());
+        assert!(settings.history_retention.max_audio_size_mb.is_none());
+        assert!(settings.history_retention.max_age_days.is_none());
+        assert!(!settings.history_retention.audio_only_pruning);
+    }
+
+    #[test]
+    fn test_history_retention_policy_partial_json() {
+        let json = serde_json::json!({ "max_age_days": 30 });
+        let policy: HistoryRetentionPolicy = serde_json::from_value(json).unwrap();
+        assert_eq!(policy.max_age_days, Some(30));
+        assert!(policy.max_entries.is_none());
+        assert!(!policy.audio_only_pruning);
+    }
+
     // ── ClipboardHandling ───────────────────────────────────────────
 
     #[test]
@@ -1097,4 +1988,70 @@ mod tests {
         let json = serde_json::to_value(ClipboardHandling::CopyToClipboard).unwrap();
         assert_eq!(json, serde_json::json!("copy_to_clipboard"));
     }
+
+    // ── SelectionTarget ─────────────────────────────────────────────
+
+    #[test]
+    fn test_selection_target_defaults_to_clipboard() {
+        let settings = get_default_settings();
+        assert_eq!(settings.selection_target, SelectionTarget::Clipboard);
+        assert!(!settings.selection_target.includes_primary());
+        assert!(!settings.middle_click_paste);
+        assert!(SelectionTarget::Both.includes_primary());
+    }
+
+    // ── BindingAudioOverride ────────────────────────────────────────
+
+    #[test]
+    fn test_binding_audio_override_partial_json() {
+        let json = serde_json::json!({ "microphone": "USB Headset" });
+        let o: BindingAudioOverride = serde_json::from_value(json).unwrap();
+        assert_eq!(o.microphone.as_deref(), Some("USB Headset"));
+        assert_eq!(o.gain_db, 0.0);
+        assert!(!o.is_noop());
+        assert!(BindingAudioOverride::default().is_noop());
+    }
+
+    // ── OutputAction ────────────────────────────────────────────────
+
+    #[test]
+    fn test_output_actions_deserialize_by_kind() {
+        let json = serde_json::json!([
+            { "kind": "paste" },
+            { "kind": "append_to_file", "path": "~/notes.md" },
+            { "kind": "webhook", "url": "http://localhost:8080/inbox" },
+        ]);
+        let actions: Vec<OutputAction> = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            actions,
+            vec![
+                OutputAction::Paste,
+                OutputAction::AppendToFile {
+                    path: "~/notes.md".to_string(),
+                    header: "## ${date} ${time}".to_string(),
+                },
+                OutputAction::Webhook {
+                    url: "http://localhost:8080/inbox".to_string(),
+                },
+            ]
+        );
+    }
+
+    // ── Reloading from disk ─────────────────────────────────────────
+
+    #[test]
+    fn test_parse_settings_file_reads_settings_key() {
+        let mut stored = serde_json::to_value(get_default_settings()).unwrap();
+        stored["push_to_talk"] = serde_json::json!(false);
+        let file = serde_json::json!({ "settings": stored, "settings_backup": {} }).to_string();
+
+        let settings = parse_settings_file(&file).unwrap();
+        assert!(!settings.push_to_talk);
+    }
+
+    #[test]
+    fn test_parse_settings_file_rejects_unreadable_files() {
+        assert!(parse_settings_file("{ not json").is_err());
+        assert!(parse_settings_file("{}").is_err());
+    }
 }
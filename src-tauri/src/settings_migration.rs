@@ -0,0 +1,253 @@
+//! Versioned settings schema.
+//!
+//! The stored settings object carries a `settings_version`. On load it is
+//! upgraded step by step to [`CURRENT_SETTINGS_VERSION`], then parsed. If a
+//! field still doesn't parse, only that field is reset to its default and
+//! the failure is recorded for the frontend, instead of resetting everything.
+
+use crate::settings::{get_default_settings, AppSettings};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use specta::Type;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+/// Schema version written by this build. Stores without a version are v1.
+pub const CURRENT_SETTINGS_VERSION: u32 = 3;
+
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` upgrades version `n + 1` to `n + 2`. Append only; a
+/// migration may run again after a downgrade, so each must be idempotent.
+const MIGRATIONS: &[Migration] = &[v1_to_v2, v2_to_v3];
+
+/// A stored setting that could not be used and was reset to its default.
+#[derive(Serialize, Debug, Clone, PartialEq, Type)]
+pub struct SettingsFieldError {
+    pub field: String,
+    pub message: String,
+}
+
+pub struct LoadedSettings {
+    pub settings: AppSettings,
+    pub errors: Vec<SettingsFieldError>,
+    /// Migrations ran or fields were reset, so the store needs rewriting.
+    pub changed: bool,
+}
+
+/// Field errors from the last load, kept until the frontend asks for them.
+static LOAD_ERRORS: Lazy<Mutex<Vec<SettingsFieldError>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Migrate and parse a stored settings object.
+pub fn load(value: Value) -> LoadedSettings {
+    let Value::Object(mut map) = value else {
+        return LoadedSettings {
+            settings: get_default_settings(),
+            errors: vec![SettingsFieldError {
+                field: "settings".to_string(),
+                message: "expected an object".to_string(),
+            }],
+            changed: true,
+        };
+    };
+
+    let migrated = migrate(&mut map);
+
+    if let Ok(settings) = serde_json::from_value::<AppSettings>(Value::Object(map.clone())) {
+        return LoadedSettings {
+            settings,
+            errors: Vec::new(),
+            changed: migrated,
+        };
+    }
+
+    let (settings, errors) = recover_fields(map);
+    LoadedSettings {
+        settings,
+        errors,
+        changed: true,
+    }
+}
+
+/// Run the migrations from the stored version up to the current one.
+/// Returns true if the object was changed.
+pub fn migrate(map: &mut Map<String, Value>) -> bool {
+    let stored = map
+        .get("settings_version")
+        .and_then(Value::as_u64)
+        .map_or(1, |v| v as u32);
+
+    if stored == CURRENT_SETTINGS_VERSION {
+        return false;
+    }
+    if stored > CURRENT_SETTINGS_VERSION {
+        // Written by a newer build: unknown fields are dropped on the next
+        // write, and the migrations rerun if that build is started again.
+        warn!(
+            "Settings version {} is newer than supported version {}",
+            stored, CURRENT_SETTINGS_VERSION
+        );
+    } else {
+        for from in stored.max(1)..CURRENT_SETTINGS_VERSION {
+            info!("Migrating settings from v{} to v{}", from, from + 1);
+            MIGRATIONS[from as usize - 1](map);
+        }
+    }
+
+    map.insert(
+        "settings_version".to_string(),
+        Value::from(CURRENT_SETTINGS_VERSION),
+    );
+    true
+}
+
+/// Start from the defaults and take each stored field that parses,
+/// recording the ones that don't.
+fn recover_fields(stored: Map<String, Value>) -> (AppSettings, Vec<SettingsFieldError>) {
+    let defaults = get_default_settings();
+    let Ok(Value::Object(mut merged)) = serde_json::to_value(&defaults) else {
+        return (defaults, Vec::new());
+    };
+
+    let mut errors = Vec::new();
+    for (field, value) in stored {
+        let previous = merged.insert(field.clone(), value);
+        if let Err(e) = serde_json::from_value::<AppSettings>(Value::Object(merged.clone())) {
+            warn!("Resetting setting '{}' to default: {}", field, e);
+            match previous {
+                Some(previous) => merged.insert(field.clone(), previous),
+                None => merged.remove(&field),
+            };
+            errors.push(SettingsFieldError {
+                field,
+                message: e.to_string(),
+            });
+        }
+    }
+
+    let settings = serde_json::from_value(Value::Object(merged)).unwrap_or(defaults);
+    (settings, errors)
+}
+
+/// Remember field errors from a load so the frontend can report them.
+pub fn record_load_errors(errors: &[SettingsFieldError]) {
+    if errors.is_empty() {
+        return;
+    }
+    LOAD_ERRORS.lock().unwrap().extend_from_slice(errors);
+}
+
+/// Field errors recorded since the last call.
+pub fn take_load_errors() -> Vec<SettingsFieldError> {
+    std::mem::take(&mut *LOAD_ERRORS.lock().unwrap())
+}
+
+/// v2 stores `log_level` as a name; v1 also allowed the numbers 1-5.
+fn v1_to_v2(map: &mut Map<String, Value>) {
+    let Some(level) = map.get("log_level").and_then(Value::as_u64) else {
+        return;
+    };
+    let name = match level {
+        1 => "trace",
+        2 => "debug",
+        3 => "info",
+        4 => "warn",
+        5 => "error",
+        _ => return,
+    };
+    map.insert("log_level".to_string(), Value::from(name));
+}
+
+/// v3 keeps a list of output actions per binding; v2 kept a single target.
+fn v2_to_v3(map: &mut Map<String, Value>) {
+    let Some(Value::Object(outputs)) = map.get_mut("binding_output") else {
+        return;
+    };
+    for output in outputs.values_mut() {
+        if output.is_object() {
+            *output = Value::Array(vec![output.take()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{LogLevel, OutputAction};
+    use serde_json::json;
+
+    fn stored(value: Value) -> Value {
+        let mut base = serde_json::to_value(get_default_settings()).unwrap();
+        let base_map = base.as_object_mut().unwrap();
+        base_map.remove("settings_version");
+        for (key, value) in value.as_object().unwrap() {
+            base_map.insert(key.clone(), value.clone());
+        }
+        base
+    }
+
+    #[test]
+    fn unversioned_store_is_migrated() {
+        let loaded = load(stored(json!({ "log_level": 4 })));
+        assert!(loaded.changed);
+        assert!(loaded.errors.is_empty());
+        assert_eq!(loaded.settings.settings_version, CURRENT_SETTINGS_VERSION);
+        assert_eq!(loaded.settings.log_level, LogLevel::Warn);
+    }
+
+    #[test]
+    fn single_output_targets_become_lists() {
+        let loaded = load(stored(json!({
+            "settings_version": 2,
+            "binding_output": {
+                "transcribe": { "kind": "append_to_file", "path": "~/notes.md" },
+            },
+        })));
+        assert!(loaded.changed);
+        assert!(loaded.errors.is_empty());
+        assert_eq!(
+            loaded.settings.binding_output["transcribe"],
+            vec![OutputAction::AppendToFile {
+                path: "~/notes.md".to_string(),
+                header: "## ${date} ${time}".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn current_store_is_unchanged() {
+        let value = serde_json::to_value(get_default_settings()).unwrap();
+        let loaded = load(value);
+        assert!(!loaded.changed);
+        assert!(loaded.errors.is_empty());
+    }
+
+    #[test]
+    fn invalid_field_resets_only_that_field() {
+        let loaded = load(stored(json!({
+            "push_to_talk": false,
+            "history_limit": "lots",
+            "selected_language": "de",
+        })));
+        assert!(loaded.changed);
+        assert_eq!(loaded.errors.len(), 1);
+        assert_eq!(loaded.errors[0].field, "history_limit");
+        assert_eq!(loaded.settings.history_limit, 5);
+        // Valid fields survive
+        assert!(!loaded.settings.push_to_talk);
+        assert_eq!(loaded.settings.selected_language, "de");
+    }
+
+    #[test]
+    fn non_object_falls_back_to_defaults() {
+        let loaded = load(json!("garbage"));
+        assert!(loaded.changed);
+        assert_eq!(loaded.errors[0].field, "settings");
+    }
+
+    #[test]
+    fn migrations_cover_every_version() {
+        assert_eq!(MIGRATIONS.len() as u32, CURRENT_SETTINGS_VERSION - 1);
+    }
+}
@@ -0,0 +1,313 @@
+//! Portable settings profiles: the configuration exported to a single JSON
+//! file and merged back in on another machine.
+
+use crate::settings::AppSettings;
+use crate::settings_migration::{self, SettingsFieldError};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use specta::Type;
+
+const PROFILE_FORMAT: &str = "voice-input-profile";
+
+/// Left out of profiles: secrets, and settings that only make sense on the
+/// machine they were made on.
+const LOCAL_FIELDS: &[&str] = &[
+    "settings_version",
+    "post_process_api_keys",
+    "selected_model",
+    "selected_microphone",
+    "clamshell_microphone",
+    "selected_output_device",
+    "binding_audio",
+    "binding_output",
+    "meeting_notes_folder",
+    "tts_voice",
+    "microphone_check_completed",
+    "keyboard_implementation",
+    "autostart_enabled",
+];
+
+/// List fields merged entry by entry, with the key that identifies an entry.
+const KEYED_LISTS: &[(&str, &str)] = &[
+    ("post_process_prompts", "id"),
+    ("post_process_providers", "id"),
+    ("app_behaviors", "app_pattern"),
+];
+
+#[derive(Serialize, Deserialize)]
+struct Profile {
+    format: String,
+    settings_version: u32,
+    settings: Map<String, Value>,
+}
+
+/// What to do when an imported setting differs from the current one.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictResolution {
+    KeepExisting,
+    UseImported,
+}
+
+/// Settings touched by an import. Entries of merged collections are
+/// reported as `field/key`, e.g. `post_process_prompts/default_improve`.
+#[derive(Serialize, Debug, Default, Type)]
+pub struct ImportReport {
+    pub added: Vec<String>,
+    /// Conflicts resolved in favor of the imported value
+    pub replaced: Vec<String>,
+    /// Conflicts resolved in favor of the current value
+    pub kept: Vec<String>,
+    /// Imported fields that were invalid and skipped
+    pub errors: Vec<SettingsFieldError>,
+}
+
+/// Serialize the portable part of the settings as a profile file.
+pub fn export_profile(settings: &AppSettings) -> Result<String, String> {
+    let Value::Object(mut map) = serde_json::to_value(settings).map_err(|e| e.to_string())? else {
+        return Err("Settings are not an object".to_string());
+    };
+    map.retain(|field, _| !LOCAL_FIELDS.contains(&field.as_str()));
+
+    let profile = Profile {
+        format: PROFILE_FORMAT.to_string(),
+        settings_version: settings_migration::CURRENT_SETTINGS_VERSION,
+        settings: map,
+    };
+    serde_json::to_string_pretty(&profile).map_err(|e| e.to_string())
+}
+
+/// Merge a profile file into the current settings. Fields and entries that
+/// exist only in the profile are added; ones that differ are resolved
+/// with `resolution`. Local fields are never touched.
+pub fn import_profile(
+    current: &AppSettings,
+    contents: &str,
+    resolution: ConflictResolution,
+) -> Result<(AppSettings, ImportReport), String> {
+    let profile: Profile =
+        serde_json::from_str(contents).map_err(|e| format!("Invalid settings profile: {}", e))?;
+    if profile.format != PROFILE_FORMAT {
+        return Err(format!("Not a settings profile: {}", profile.format));
+    }
+
+    // Bring the profile up to this build's schema and validate it
+    let mut imported = profile.settings;
+    imported.insert(
+        "settings_version".to_string(),
+        Value::from(profile.settings_version),
+    );
+    settings_migration::migrate(&mut imported);
+    let loaded = settings_migration::load(Value::Object(imported.clone()));
+    let Value::Object(valid) = serde_json::to_value(&loaded.settings).map_err(|e| e.to_string())?
+    else {
+        return Err("Settings are not an object".to_string());
+    };
+
+    let Value::Object(mut merged) = serde_json::to_value(current).map_err(|e| e.to_string())?
+    else {
+        return Err("Settings are not an object".to_string());
+    };
+
+    let mut report = ImportReport {
+        errors: loaded.errors,
+        ..Default::default()
+    };
+    for field in imported.keys() {
+        if LOCAL_FIELDS.contains(&field.as_str()) || report.errors.iter().any(|e| &e.field == field)
+        {
+            continue;
+        }
+        let (Some(value), Some(existing)) = (valid.get(field), merged.get_mut(field)) else {
+            continue;
+        };
+
+        match field.as_str() {
+            // Only bindings this build knows about
+            "bindings" => merge_map(field, existing, value, false, resolution, &mut report),
+            "post_process_models" => {
+                merge_map(field, existing, value, true, resolution, &mut report)
+            }
+            "custom_words" => merge_words(field, existing, value, &mut report),
+            _ => match KEYED_LISTS.iter().find(|(name, _)| *name == field.as_str()) {
+                Some((_, key)) => merge_list(field, key, existing, value, resolution, &mut report),
+                None => {
+                    if *existing != *value {
+                        resolve(field.clone(), existing, value, resolution, &mut report)
+                    }
+                }
+            },
+        }
+    }
+
+    let settings = serde_json::from_value(Value::Object(merged)).map_err(|e| e.to_string())?;
+    Ok((settings, report))
+}
+
+fn resolve(
+    name: String,
+    existing: &mut Value,
+    imported: &Value,
+    resolution: ConflictResolution,
+    report: &mut ImportReport,
+) {
+    match resolution {
+        ConflictResolution::UseImported => {
+            *existing = imported.clone();
+            report.replaced.push(name);
+        }
+        ConflictResolution::KeepExisting => report.kept.push(name),
+    }
+}
+
+fn merge_map(
+    field: &str,
+    existing: &mut Value,
+    imported: &Value,
+    allow_new: bool,
+    resolution: ConflictResolution,
+    report: &mut ImportReport,
+) {
+    let (Some(existing), Some(imported)) = (existing.as_object_mut(), imported.as_object()) else {
+        return;
+    };
+    for (key, value) in imported {
+        let name = format!("{}/{}", field, key);
+        if let Some(current) = existing.get_mut(key) {
+            if *current != *value {
+                resolve(name, current, value, resolution, report);
+            }
+        } else if allow_new {
+            existing.insert(key.clone(), value.clone());
+            report.added.push(name);
+        }
+    }
+}
+
+fn merge_list(
+    field: &str,
+    key: &str,
+    existing: &mut Value,
+    imported: &Value,
+    resolution: ConflictResolution,
+    report: &mut ImportReport,
+) {
+    let (Some(existing), Some(imported)) = (existing.as_array_mut(), imported.as_array()) else {
+        return;
+    };
+    for entry in imported {
+        let Some(id) = entry.get(key).and_then(Value::as_str) else {
+            continue;
+        };
+        let name = format!("{}/{}", field, id);
+        match existing
+            .iter()
+            .position(|e| e.get(key).and_then(Value::as_str) == Some(id))
+        {
+            Some(i) if existing[i] != *entry => {
+                resolve(name, &mut existing[i], entry, resolution, report)
+            }
+            Some(_) => {}
+            None => {
+                existing.push(entry.clone());
+                report.added.push(name);
+            }
+        }
+    }
+}
+
+/// Custom words can't conflict, the lists are simply combined.
+fn merge_words(field: &str, existing: &mut Value, imported: &Value, report: &mut ImportReport) {
+    let (Some(existing), Some(imported)) = (existing.as_array_mut(), imported.as_array()) else {
+        return;
+    };
+    for word in imported {
+        if !existing.contains(word) {
+            existing.push(word.clone());
+            if let Some(word) = word.as_str() {
+                report.added.push(format!("{}/{}", field, word));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::{get_default_settings, LLMPrompt};
+
+    fn exported(edit: impl FnOnce(&mut AppSettings)) -> String {
+        let mut settings = get_default_settings();
+        edit(&mut settings);
+        export_profile(&settings).unwrap()
+    }
+
+    #[test]
+    fn export_leaves_out_secrets_and_local_fields() {
+        let contents = exported(|s| {
+            s.post_process_api_keys
+                .insert("openai".to_string(), "sk-secret".to_string());
+            s.selected_microphone = Some("USB Mic".to_string());
+        });
+        assert!(!contents.contains("sk-secret"));
+        assert!(!contents.contains("USB Mic"));
+        assert!(contents.contains("\"bindings\""));
+        assert!(contents.contains(PROFILE_FORMAT));
+    }
+
+    #[test]
+    fn import_adds_new_entries_and_resolves_conflicts() {
+        let contents = exported(|s| {
+            s.selected_language = "de".to_string();
+            s.custom_words = vec!["Tauri".to_string()];
+            s.post_process_prompts.push(LLMPrompt {
+                id: "imported".to_string(),
+                name: "Imported".to_string(),
+                prompt: "${output}".to_string(),
+                model: None,
+                temperature: None,
+                max_tokens: None,
+                system_message: None,
+            });
+        });
+        let mut current = get_default_settings();
+        current.selected_language = "fr".to_string();
+        current.custom_words = vec!["Rust".to_string()];
+        current.selected_microphone = Some("Built-in".to_string());
+
+        let (kept, report) =
+            import_profile(&current, &contents, ConflictResolution::KeepExisting).unwrap();
+        assert_eq!(kept.selected_language, "fr");
+        assert_eq!(report.kept, vec!["selected_language".to_string()]);
+        assert!(report
+            .added
+            .contains(&"post_process_prompts/imported".to_string()));
+        assert_eq!(kept.custom_words, vec!["Rust", "Tauri"]);
+
+        let (replaced, report) =
+            import_profile(&current, &contents, ConflictResolution::UseImported).unwrap();
+        assert_eq!(replaced.selected_language, "de");
+        assert_eq!(report.replaced, vec!["selected_language".to_string()]);
+        // Local fields stay as they are
+        assert_eq!(replaced.selected_microphone.as_deref(), Some("Built-in"));
+    }
+
+    #[test]
+    fn import_skips_invalid_fields() {
+        let contents = exported(|_| {}).replace("\"history_limit\": 5", "\"history_limit\": \"x\"");
+        let current = get_default_settings();
+        let (settings, report) =
+            import_profile(&current, &contents, ConflictResolution::UseImported).unwrap();
+        assert_eq!(settings.history_limit, current.history_limit);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].field, "history_limit");
+    }
+
+    #[test]
+    fn import_rejects_other_files() {
+        let current = get_default_settings();
+        assert!(import_profile(&current, "{}", ConflictResolution::UseImported).is_err());
+        let other = r#"{"format": "other", "settings_version": 2, "settings": {}}"#;
+        assert!(import_profile(&current, other, ConflictResolution::UseImported).is_err());
+    }
+}
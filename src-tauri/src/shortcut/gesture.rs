@@ -0,0 +1,203 @@
+//! Tap / double-tap / long-press recognition for a single hotkey.
+//!
+//! The detector only sees press and release events plus the passage of time;
+//! the caller polls [`GestureDetector::tick`] once [`GestureDetector::deadline`]
+//! is reached. A single tap is only reported after the double-tap window has
+//! closed, so it costs `DOUBLE_TAP_WINDOW` of latency.
+
+use std::time::{Duration, Instant};
+
+/// Maximum gap between the first release and the second press of a double-tap.
+pub const DOUBLE_TAP_WINDOW: Duration = Duration::from_millis(300);
+/// How long the key has to be held before it counts as a long press.
+pub const LONG_PRESS_THRESHOLD: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gesture {
+    SinglePress,
+    DoubleTap,
+    /// The key has been held past the threshold and is still down
+    LongPressStart,
+    /// The key of a long press was released
+    LongPressEnd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Idle,
+    /// First press, not yet released
+    Down {
+        since: Instant,
+    },
+    /// Released after a short press; waiting for a possible second press
+    Released {
+        at: Instant,
+    },
+    /// Held past the long-press threshold
+    Held,
+    /// Ignore everything until the key is released (second tap, or a press
+    /// the caller already consumed)
+    Swallow,
+}
+
+#[derive(Debug)]
+pub struct GestureDetector {
+    state: State,
+}
+
+impl Default for GestureDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GestureDetector {
+    pub fn new() -> Self {
+        Self { state: State::Idle }
+    }
+
+    /// Feed a key press. Repeated presses while the key is down (key repeat)
+    /// are ignored.
+    pub fn press(&mut self, now: Instant) -> Option<Gesture> {
+        match self.state {
+            State::Idle => {
+                self.state = State::Down { since: now };
+                None
+            }
+            State::Released { at } if now.duration_since(at) <= DOUBLE_TAP_WINDOW => {
+                self.state = State::Swallow;
+                Some(Gesture::DoubleTap)
+            }
+            State::Released { .. } => {
+                // The window expired without a tick; report the pending tap
+                // and treat this press as the start of a new gesture
+                self.state = State::Down { since: now };
+                Some(Gesture::SinglePress)
+            }
+            State::Down { .. } | State::Held | State::Swallow => None,
+        }
+    }
+
+    /// Feed a key release.
+    pub fn release(&mut self, now: Instant) -> Option<Gesture> {
+        match self.state {
+            State::Down { since } if now.duration_since(since) >= LONG_PRESS_THRESHOLD => {
+                // Released before a tick promoted it; start and end at once
+                self.state = State::Idle;
+                Some(Gesture::LongPressEnd)
+            }
+            State::Down { .. } => {
+                self.state = State::Released { at: now };
+                None
+            }
+            State::Held => {
+                self.state = State::Idle;
+                Some(Gesture::LongPressEnd)
+            }
+            State::Swallow => {
+                self.state = State::Idle;
+                None
+            }
+            State::Idle | State::Released { .. } => None,
+        }
+    }
+
+    /// Resolve timeouts: a press held past the threshold becomes a long press,
+    /// and a tap with no second press within the window becomes a single press.
+    pub fn tick(&mut self, now: Instant) -> Option<Gesture> {
+        match self.state {
+            State::Down { since } if now.duration_since(since) >= LONG_PRESS_THRESHOLD => {
+                self.state = State::Held;
+                Some(Gesture::LongPressStart)
+            }
+            State::Released { at } if now.duration_since(at) > DOUBLE_TAP_WINDOW => {
+                self.state = State::Idle;
+                Some(Gesture::SinglePress)
+            }
+            _ => None,
+        }
+    }
+
+    /// When [`tick`](Self::tick) should next be called, if a timeout is pending.
+    pub fn deadline(&self) -> Option<Instant> {
+        match self.state {
+            State::Down { since } => Some(since + LONG_PRESS_THRESHOLD),
+            // Strictly after the window so the tick sees it as expired
+            State::Released { at } => Some(at + DOUBLE_TAP_WINDOW + Duration::from_millis(1)),
+            _ => None,
+        }
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.state == State::Held
+    }
+
+    /// Ignore events until the next release, for a press the caller handled
+    /// itself (e.g. stopping a toggled recording).
+    pub fn swallow_until_release(&mut self) {
+        self.state = State::Swallow;
+    }
+
+    pub fn reset(&mut self) {
+        self.state = State::Idle;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn short_tap_becomes_single_press_after_window() {
+        let t0 = Instant::now();
+        let mut d = GestureDetector::new();
+        assert_eq!(d.press(t0), None);
+        assert_eq!(d.release(t0 + ms(80)), None);
+        assert_eq!(d.tick(t0 + ms(200)), None);
+
+        let deadline = d.deadline().unwrap();
+        assert_eq!(d.tick(deadline), Some(Gesture::SinglePress));
+        assert_eq!(d.deadline(), None);
+    }
+
+    #[test]
+    fn two_quick_taps_are_a_double_tap() {
+        let t0 = Instant::now();
+        let mut d = GestureDetector::new();
+        d.press(t0);
+        d.release(t0 + ms(80));
+        assert_eq!(d.press(t0 + ms(250)), Some(Gesture::DoubleTap));
+        // Second release and any late tick produce nothing
+        assert_eq!(d.release(t0 + ms(320)), None);
+        assert_eq!(d.tick(t0 + ms(1000)), None);
+    }
+
+    #[test]
+    fn held_key_is_a_long_press_until_release() {
+        let t0 = Instant::now();
+        let mut d = GestureDetector::new();
+        d.press(t0);
+        assert_eq!(d.deadline(), Some(t0 + LONG_PRESS_THRESHOLD));
+        assert_eq!(d.tick(t0 + ms(450)), Some(Gesture::LongPressStart));
+        assert!(d.is_held());
+        // Key repeat while held is ignored
+        assert_eq!(d.press(t0 + ms(500)), None);
+        assert_eq!(d.release(t0 + ms(2000)), Some(Gesture::LongPressEnd));
+        assert!(!d.is_held());
+    }
+
+    #[test]
+    fn swallowed_press_ignores_its_release() {
+        let t0 = Instant::now();
+        let mut d = GestureDetector::new();
+        d.swallow_until_release();
+        assert_eq!(d.tick(t0 + ms(1000)), None);
+        assert_eq!(d.release(t0 + ms(1000)), None);
+        assert_eq!(d.press(t0 + ms(1100)), None);
+        assert_eq!(d.deadline(), Some(t0 + ms(1100) + LONG_PRESS_THRESHOLD));
+    }
+}
@@ -3,23 +3,26 @@
 //! This module contains the common logic for handling shortcut events,
 //! used by both the Tauri and handy-keys implementations.
 
-use log::warn;
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
+use tracing::warn;
 
 use crate::actions::ACTION_MAP;
 use crate::managers::audio::AudioRecordingManager;
+use crate::meeting;
 use crate::settings::get_settings;
-use crate::transcription_coordinator::is_transcribe_binding;
+use crate::transcription_coordinator::{input_mode, is_transcribe_binding};
 use crate::TranscriptionCoordinator;
 
 /// Handle a shortcut event from either implementation.
 ///
 /// This function contains the shared logic for:
 /// - Looking up the action in ACTION_MAP
-/// - Routing transcribe bindings through the TranscriptionCoordinator
+/// - Routing transcribe bindings through the TranscriptionCoordinator, which
+///   also recognizes tap / double-tap / long-press on "transcribe" when
+///   `hotkey_gestures` is enabled
 /// - Handling the cancel binding (only fires when recording)
-/// - Handling toggle_settings (fires on press only)
+/// - Handling toggle_settings and meeting (fire on press only)
 /// - Handling remaining bindings with simple start/stop
 ///
 /// # Arguments
@@ -38,7 +41,8 @@ pub fn handle_shortcut_event(
     // Transcribe bindings are handled by the coordinator.
     if is_transcribe_binding(binding_id) {
         if let Some(coordinator) = app.try_state::<TranscriptionCoordinator>() {
-            coordinator.send_input(binding_id, hotkey_string, is_pressed, settings.push_to_talk);
+            let mode = input_mode(binding_id, settings.push_to_talk, settings.hotkey_gestures);
+            coordinator.send_input(binding_id, hotkey_string, is_pressed, mode);
         } else {
             warn!("TranscriptionCoordinator is not initialized");
         }
@@ -62,8 +66,8 @@ pub fn handle_shortcut_event(
         return;
     }
 
-    // Toggle settings: fire on press only, ignore PTT mode
-    if binding_id == "toggle_settings" {
+    // Toggle settings and meeting mode: fire on press only, ignore PTT mode
+    if binding_id == "toggle_settings" || binding_id == meeting::BINDING {
         if is_pressed {
             action.start(app, binding_id, hotkey_string);
         }
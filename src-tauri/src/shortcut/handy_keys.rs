@@ -28,7 +28,6 @@
 //! via Tauri's event system.
 
 use handy_keys::{Hotkey, HotkeyId, HotkeyManager, HotkeyState, KeyboardListener};
-use log::{debug, error, info};
 use serde::Serialize;
 use specta::Type;
 use std::collections::HashMap;
@@ -37,10 +36,12 @@ use std::sync::mpsc::{self, Receiver, Sender};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 use tauri::{AppHandle, Emitter, Manager};
+use tracing::{debug, error, info};
 
 use crate::settings::{self, get_settings, ShortcutBinding};
 
 use super::handler::handle_shortcut_event;
+use super::special_input;
 
 /// Commands that can be sent to the hotkey manager thread
 enum ManagerCommand {
@@ -444,7 +445,12 @@ pub fn init_shortcuts(app: &AppHandle) -> Result<(), String> {
             .cloned()
             .unwrap_or(default_binding);
 
-        if let Err(e) = state.register(&binding) {
+        let result = if special_input::is_special(&binding.current_binding) {
+            special_input::register_shortcut(app, binding)
+        } else {
+            state.register(&binding)
+        };
+        if let Err(e) = result {
             error!(
                 "Failed to register handy-keys shortcut {} during init: {}",
                 id, e
@@ -7,32 +7,38 @@
 //! - `handy_keys`: Uses the handy-keys library for more control
 //!
 //! The active implementation is determined by the `keyboard_implementation`
-//! setting and can be changed at runtime.
+//! setting and can be changed at runtime. Mouse-button and media-key bindings
+//! are handled by `special_input` regardless of the implementation.
 
+pub mod gesture;
 mod handler;
 pub mod handy_keys;
+mod special_input;
 mod tauri_impl;
 
-use log::{error, info, warn};
 use serde::Serialize;
 use specta::Type;
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_autostart::ManagerExt;
 use tauri_plugin_global_shortcut::GlobalShortcutExt;
+use tracing::{error, info, warn};
 
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::settings::APPLE_INTELLIGENCE_DEFAULT_MODEL_ID;
 use crate::settings::{
-    self, get_settings, ClipboardHandling, KeyboardImplementation, LLMPrompt, OverlayPosition,
-    PasteMethod, ShortcutBinding, SoundTheme, APPLE_INTELLIGENCE_PROVIDER_ID,
+    self, get_settings, BusyBehavior, ClipboardHandling, KeyboardImplementation, LLMPrompt,
+    OverlayPosition, PasteMethod, SelectionTarget, ShortcutBinding, SoundTheme, UpdateChannel,
+    APPLE_INTELLIGENCE_PROVIDER_ID, LOCAL_GRAMMAR_DEFAULT_MODEL_ID, LOCAL_GRAMMAR_PROVIDER_ID,
 };
 use crate::tray;
+use crate::updater;
 
 // Note: Commands are accessed via shortcut::handy_keys:: in lib.rs
 
 /// Initialize shortcuts using the configured implementation
 pub fn init_shortcuts(app: &AppHandle) {
     let user_settings = settings::load_or_create_app_settings(app);
+    app.manage(special_input::SpecialInputState::default());
 
     // Check which implementation to use
     match user_settings.keyboard_implementation {
@@ -59,6 +65,12 @@ pub fn init_shortcuts(app: &AppHandle) {
 /// Register the cancel shortcut (called when recording starts)
 pub fn register_cancel_shortcut(app: &AppHandle) {
     let settings = get_settings(app);
+    if let Some(cancel) = special_cancel_binding(&settings) {
+        if let Err(e) = special_input::register_shortcut(app, cancel) {
+            error!("Failed to register cancel shortcut: {}", e);
+        }
+        return;
+    }
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::register_cancel_shortcut(app),
         KeyboardImplementation::HandyKeys => handy_keys::register_cancel_shortcut(app),
@@ -68,14 +80,30 @@ pub fn register_cancel_shortcut(app: &AppHandle) {
 /// Unregister the cancel shortcut (called when recording stops)
 pub fn unregister_cancel_shortcut(app: &AppHandle) {
     let settings = get_settings(app);
+    if let Some(cancel) = special_cancel_binding(&settings) {
+        let _ = special_input::unregister_shortcut(app, cancel);
+        return;
+    }
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::unregister_cancel_shortcut(app),
         KeyboardImplementation::HandyKeys => handy_keys::unregister_cancel_shortcut(app),
     }
 }
 
+/// The cancel binding, if it is bound to a mouse button or media key
+fn special_cancel_binding(settings: &settings::AppSettings) -> Option<ShortcutBinding> {
+    settings
+        .bindings
+        .get("cancel")
+        .filter(|b| special_input::is_special(&b.current_binding))
+        .cloned()
+}
+
 /// Register a shortcut using the appropriate implementation
 pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    if special_input::is_special(&binding.current_binding) {
+        return special_input::register_shortcut(app, binding);
+    }
     let settings = get_settings(app);
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::register_shortcut(app, binding),
@@ -85,6 +113,9 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
 
 /// Unregister a shortcut using the appropriate implementation
 pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    if special_input::is_special(&binding.current_binding) {
+        return special_input::unregister_shortcut(app, binding);
+    }
     let settings = get_settings(app);
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::unregister_shortcut(app, binding),
@@ -335,6 +366,9 @@ fn validate_shortcut_for_implementation(
     raw: &str,
     implementation: KeyboardImplementation,
 ) -> Result<(), String> {
+    if special_input::is_special(raw) {
+        return special_input::validate(raw);
+    }
     match implementation {
         KeyboardImplementation::Tauri => tauri_impl::validate_shortcut(raw),
         KeyboardImplementation::HandyKeys => handy_keys::validate_shortcut(raw),
@@ -365,6 +399,10 @@ fn unregister_all_shortcuts(app: &AppHandle, implementation: KeyboardImplementat
         if id == "cancel" {
             continue;
         }
+        // Mouse and media bindings don't belong to either implementation
+        if special_input::is_special(&binding.current_binding) {
+            continue;
+        }
 
         let result = match implementation {
             KeyboardImplementation::Tauri => tauri_impl::unregister_shortcut(app, binding),
@@ -423,6 +461,11 @@ fn register_all_shortcuts_for_implementation(
             reset_bindings.push(id.clone());
         }
 
+        // Mouse and media bindings stay registered across the switch
+        if special_input::is_special(&binding.current_binding) {
+            continue;
+        }
+
         // Register with the appropriate implementation
         let result = match implementation {
             KeyboardImplementation::Tauri => tauri_impl::register_shortcut(app, binding),
@@ -445,6 +488,40 @@ fn register_all_shortcuts_for_implementation(
     reset_bindings
 }
 
+/// Replace the stored settings (e.g. from an imported profile) and move the
+/// shortcut registrations over to the new bindings. Returns the ids of
+/// bindings that had to be reset to their defaults.
+pub fn replace_settings(app: &AppHandle, new_settings: settings::AppSettings) -> Vec<String> {
+    let implementation = get_settings(app).keyboard_implementation;
+
+    let special_bindings = |app: &AppHandle| -> Vec<ShortcutBinding> {
+        let settings = get_settings(app);
+        settings
+            .bindings
+            .into_values()
+            .filter(|b| b.id != "cancel" && special_input::is_special(&b.current_binding))
+            .filter(|b| b.id != "transcribe_with_post_process" || settings.post_process_enabled)
+            .collect()
+    };
+
+    for binding in special_bindings(app) {
+        let _ = special_input::unregister_shortcut(app, binding);
+    }
+    unregister_all_shortcuts(app, implementation);
+
+    settings::write_settings(app, new_settings);
+
+    let reset_bindings = register_all_shortcuts_for_implementation(app, implementation);
+    for binding in special_bindings(app) {
+        let id = binding.id.clone();
+        if let Err(e) = special_input::register_shortcut(app, binding) {
+            error!("Failed to register shortcut '{}': {}", id, e);
+        }
+    }
+
+    reset_bindings
+}
+
 /// Initialize HandyKeys if not already initialized, with rollback on failure
 fn initialize_handy_keys_with_rollback(app: &AppHandle) -> Result<bool, String> {
     if app.try_state::<handy_keys::HandyKeysState>().is_some() {
@@ -481,6 +558,32 @@ pub fn change_ptt_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_hotkey_gestures_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.hotkey_gestures = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_busy_behavior_setting(app: AppHandle, behavior: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match behavior.as_str() {
+        "reject" => BusyBehavior::Reject,
+        "queue" => BusyBehavior::Queue,
+        other => {
+            warn!("Invalid busy behavior '{}', defaulting to reject", other);
+            BusyBehavior::Reject
+        }
+    };
+    settings.busy_behavior = parsed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_audio_feedback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -543,6 +646,13 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
         "none" => OverlayPosition::None,
         "top" => OverlayPosition::Top,
         "bottom" => OverlayPosition::Bottom,
+        "top_left" => OverlayPosition::TopLeft,
+        "top_right" => OverlayPosition::TopRight,
+        "bottom_left" => OverlayPosition::BottomLeft,
+        "bottom_right" => OverlayPosition::BottomRight,
+        "left" => OverlayPosition::Left,
+        "right" => OverlayPosition::Right,
+        "custom" => OverlayPosition::Custom,
         other => {
             warn!("Invalid overlay position '{}', defaulting to bottom", other);
             OverlayPosition::Bottom
@@ -557,6 +667,22 @@ pub fn change_overlay_position_setting(app: AppHandle, position: String) -> Resu
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_overlay_layout_setting(
+    app: AppHandle,
+    layout: settings::OverlayLayout,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.overlay_layout = layout.clamped();
+    settings::write_settings(&app, settings);
+
+    crate::utils::update_overlay_position(&app);
+    crate::utils::reset_overlay_size(&app);
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_debug_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -595,6 +721,15 @@ pub fn change_start_hidden_setting(app: AppHandle, enabled: bool) -> Result<(),
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_headless_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.headless = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -640,6 +775,43 @@ pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(),
     Ok(())
 }
 
+/// Switch release channel and look for an update on the new one.
+#[tauri::command]
+#[specta::specta]
+pub fn change_update_channel_setting(app: AppHandle, channel: UpdateChannel) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.update_channel = channel;
+    let checks_enabled = settings.update_checks_enabled;
+    settings::write_settings(&app, settings);
+
+    if checks_enabled {
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = updater::check(&app, false).await {
+                warn!("Update check after channel change failed: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+/// Longest gap between background update checks: a week.
+const MAX_UPDATE_CHECK_INTERVAL_HOURS: u32 = 7 * 24;
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_update_check_interval_setting(app: AppHandle, hours: u32) -> Result<(), String> {
+    if !(1..=MAX_UPDATE_CHECK_INTERVAL_HOURS).contains(&hours) {
+        return Err(format!(
+            "The update check interval must be between 1 and {} hours",
+            MAX_UPDATE_CHECK_INTERVAL_HOURS
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.update_check_interval_hours = hours;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn update_custom_words(app: AppHandle, words: Vec<String>) -> Result<(), String> {
@@ -671,6 +843,7 @@ pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(),
         "none" => PasteMethod::None,
         "shift_insert" => PasteMethod::ShiftInsert,
         "ctrl_shift_v" => PasteMethod::CtrlShiftV,
+        "per_character" => PasteMethod::PerCharacter,
         other => {
             warn!("Invalid paste method '{}', defaulting to ctrl_v", other);
             PasteMethod::CtrlV
@@ -681,6 +854,24 @@ pub fn change_paste_method_setting(app: AppHandle, method: String) -> Result<(),
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_per_character_delay_setting(app: AppHandle, delay_ms: u64) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.per_character_delay_ms = delay_ms;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_paste_fallback_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.paste_fallback_enabled = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Result<(), String> {
@@ -701,6 +892,36 @@ pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_selection_target_setting(app: AppHandle, target: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match target.as_str() {
+        "clipboard" => SelectionTarget::Clipboard,
+        "primary" => SelectionTarget::Primary,
+        "both" => SelectionTarget::Both,
+        other => {
+            warn!(
+                "Invalid selection target '{}', defaulting to clipboard",
+                other
+            );
+            SelectionTarget::Clipboard
+        }
+    };
+    settings.selection_target = parsed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_middle_click_paste_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.middle_click_paste = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -742,6 +963,231 @@ pub fn change_voice_commands_setting(app: AppHandle, enabled: bool) -> Result<()
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_private_mode_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.private_mode = enabled;
+    settings::write_settings(&app, settings);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "private_mode",
+            "value": enabled
+        }),
+    );
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_number_normalization_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.number_normalization = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_completion_notifications_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.completion_notifications = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_continue_dictation_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.continue_dictation = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_continue_dictation_window_setting(
+    app: AppHandle,
+    seconds: u32,
+) -> Result<(), String> {
+    if !(1..=300).contains(&seconds) {
+        return Err("The continue window must be between 1 and 300 seconds".to_string());
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.continue_dictation_window_secs = seconds;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_tts_read_transcripts_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.tts_read_transcripts = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_tts_read_errors_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.tts_read_errors = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_tts_voice_setting(app: AppHandle, voice: Option<String>) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.tts_voice = voice.filter(|v| !v.trim().is_empty());
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_tts_rate_setting(app: AppHandle, rate: f32) -> Result<(), String> {
+    if !(crate::tts::MIN_RATE..=crate::tts::MAX_RATE).contains(&rate) {
+        return Err(format!(
+            "The speaking rate must be between {} and {}",
+            crate::tts::MIN_RATE,
+            crate::tts::MAX_RATE
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.tts_rate = rate;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_confirm_before_paste_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.confirm_before_paste = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_hallucination_filter_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.hallucination_filter = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_hallucination_energy_floor_setting(app: AppHandle, db: f32) -> Result<(), String> {
+    use crate::hallucination::{MAX_ENERGY_FLOOR_DB, MIN_ENERGY_FLOOR_DB};
+    if !(MIN_ENERGY_FLOOR_DB..=MAX_ENERGY_FLOOR_DB).contains(&db) {
+        return Err(format!(
+            "The energy floor must be between {} and {} dB",
+            MIN_ENERGY_FLOOR_DB, MAX_ENERGY_FLOOR_DB
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.hallucination_energy_floor_db = db;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_hallucination_no_speech_threshold_setting(
+    app: AppHandle,
+    threshold: f32,
+) -> Result<(), String> {
+    use crate::hallucination::{MAX_NO_SPEECH_THRESHOLD, MIN_NO_SPEECH_THRESHOLD};
+    if !(MIN_NO_SPEECH_THRESHOLD..=MAX_NO_SPEECH_THRESHOLD).contains(&threshold) {
+        return Err(format!(
+            "The no-speech threshold must be between {} and {}",
+            MIN_NO_SPEECH_THRESHOLD, MAX_NO_SPEECH_THRESHOLD
+        ));
+    }
+    let mut settings = settings::get_settings(&app);
+    settings.hallucination_no_speech_threshold = threshold;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+/// Replace the hallucination blocklist of one language; an empty list
+/// removes the language.
+#[tauri::command]
+#[specta::specta]
+pub fn update_hallucination_phrases(
+    app: AppHandle,
+    language: String,
+    phrases: Vec<String>,
+) -> Result<(), String> {
+    let language = language.trim().to_lowercase();
+    if language.is_empty() {
+        return Err("A language code is required".to_string());
+    }
+    let phrases: Vec<String> = phrases
+        .iter()
+        .map(|p| p.trim().to_string())
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    let mut settings = settings::get_settings(&app);
+    if phrases.is_empty() {
+        settings.hallucination_phrases.remove(&language);
+    } else {
+        settings.hallucination_phrases.insert(language, phrases);
+    }
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_punctuation_restore_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.punctuation_restore = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_punctuation_restore_casing_model_setting(
+    app: AppHandle,
+    enabled: bool,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.punctuation_restore_casing_model = enabled;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_app_behaviors_setting(
+    app: AppHandle,
+    rules: Vec<settings::AppBehaviorRule>,
+) -> Result<(), String> {
+    if let Some(rule) = rules.iter().find(|r| r.app_pattern.trim().is_empty()) {
+        return Err(format!("Invalid app pattern: '{}'", rule.app_pattern));
+    }
+
+    let mut settings = settings::get_settings(&app);
+    settings.app_behaviors = rules;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_post_process_base_url_setting(
@@ -759,7 +1205,7 @@ pub fn change_post_process_base_url_setting(
         .post_process_provider_mut(&provider_id)
         .expect("Provider looked up above must exist");
 
-    if provider.id != "custom" {
+    if !provider.allow_base_url_edit {
         return Err(format!(
             "Provider '{}' does not allow editing the base URL",
             label
@@ -771,6 +1217,36 @@ pub fn change_post_process_base_url_setting(
     Ok(())
 }
 
+/// Set the `api-version` of an Azure OpenAI provider; empty restores the
+/// default.
+#[tauri::command]
+#[specta::specta]
+pub fn change_post_process_api_version_setting(
+    app: AppHandle,
+    provider_id: String,
+    api_version: String,
+) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let provider = settings
+        .post_process_provider_mut(&provider_id)
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+    if provider.api != settings::ProviderApi::AzureOpenAi {
+        return Err(format!(
+            "Provider '{}' does not use an API version",
+            provider.label
+        ));
+    }
+
+    let api_version = api_version.trim();
+    provider.api_version = Some(if api_version.is_empty() {
+        settings::AZURE_OPENAI_API_VERSION.to_string()
+    } else {
+        api_version.to_string()
+    });
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 /// Generic helper to validate provider exists
 fn validate_provider_exists(
     settings: &settings::AppSettings,
@@ -795,11 +1271,24 @@ pub fn change_post_process_api_key_setting(
 ) -> Result<(), String> {
     let mut settings = settings::get_settings(&app);
     validate_provider_exists(&settings, &provider_id)?;
-    settings.post_process_api_keys.insert(provider_id, api_key);
+    crate::secret_store::set_api_key(
+        crate::secret_store::secret_store(),
+        &mut settings,
+        &provider_id,
+        &api_key,
+    );
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn get_post_process_api_key(app: AppHandle, provider_id: String) -> Result<String, String> {
+    let settings = settings::get_settings(&app);
+    validate_provider_exists(&settings, &provider_id)?;
+    Ok(settings.post_process_api_key(&provider_id))
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_post_process_model_setting(
@@ -840,6 +1329,10 @@ pub fn add_post_process_prompt(
         id: id.clone(),
         name,
         prompt,
+        model: None,
+        temperature: None,
+        max_tokens: None,
+        system_message: None,
     };
 
     settings.post_process_prompts.push(new_prompt.clone());
@@ -872,6 +1365,49 @@ pub fn update_post_process_prompt(
     }
 }
 
+/// Set a prompt's overrides of the provider's model and request parameters;
+/// `None` or an empty string clears an override.
+#[tauri::command]
+#[specta::specta]
+pub fn update_post_process_prompt_options(
+    app: AppHandle,
+    id: String,
+    model: Option<String>,
+    temperature: Option<f32>,
+    max_tokens: Option<u32>,
+    system_message: Option<String>,
+) -> Result<(), String> {
+    if let Some(temperature) = temperature {
+        if !(0.0..=2.0).contains(&temperature) {
+            return Err(format!(
+                "Temperature must be between 0 and 2, got {}",
+                temperature
+            ));
+        }
+    }
+    if max_tokens == Some(0) {
+        return Err("Max tokens must be at least 1".to_string());
+    }
+    let non_empty = |value: Option<String>| {
+        value
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    let mut settings = settings::get_settings(&app);
+    let prompt = settings
+        .post_process_prompts
+        .iter_mut()
+        .find(|p| p.id == id)
+        .ok_or_else(|| format!("Prompt with id '{}' not found", id))?;
+    prompt.model = non_empty(model);
+    prompt.temperature = temperature;
+    prompt.max_tokens = max_tokens;
+    prompt.system_message = non_empty(system_message);
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), String> {
@@ -895,11 +1431,46 @@ pub fn delete_post_process_prompt(app: AppHandle, id: String) -> Result<(), Stri
         settings.post_process_selected_prompt_id =
             settings.post_process_prompts.first().map(|p| p.id.clone());
     }
+    settings
+        .post_process_chain
+        .retain(|chain_id| chain_id != &id);
 
     settings::write_settings(&app, settings);
     Ok(())
 }
 
+/// Validate a provider's base URL, API key and model without running a dictation.
+#[tauri::command]
+#[specta::specta]
+pub async fn check_post_process_provider(
+    app: AppHandle,
+    provider_id: String,
+    model: Option<String>,
+) -> Result<crate::llm_client::ProviderHealthReport, String> {
+    let settings = settings::get_settings(&app);
+    let provider = settings
+        .post_process_provider(&provider_id)
+        .ok_or_else(|| format!("Provider '{}' not found", provider_id))?;
+
+    if provider.id == APPLE_INTELLIGENCE_PROVIDER_ID || provider.id == LOCAL_GRAMMAR_PROVIDER_ID {
+        return Err(format!(
+            "Health checks are not available for {}.",
+            provider.label
+        ));
+    }
+
+    let model = model.unwrap_or_else(|| {
+        settings
+            .post_process_models
+            .get(&provider_id)
+            .cloned()
+            .unwrap_or_default()
+    });
+    let api_key = settings.post_process_api_key(&provider_id);
+
+    Ok(crate::llm_client::check_provider_health(provider, &api_key, &model).await)
+}
+
 #[tauri::command]
 #[specta::specta]
 pub async fn fetch_post_process_models(
@@ -927,12 +1498,12 @@ pub async fn fetch_post_process_models(
         }
     }
 
+    if provider.id == LOCAL_GRAMMAR_PROVIDER_ID {
+        return Ok(vec![LOCAL_GRAMMAR_DEFAULT_MODEL_ID.to_string()]);
+    }
+
     // Get API key
-    let api_key = settings
-        .post_process_api_keys
-        .get(&provider_id)
-        .cloned()
-        .unwrap_or_default();
+    let api_key = settings.post_process_api_key(&provider_id);
 
     // Skip fetching if no API key for providers that typically need one
     if api_key.trim().is_empty() && provider.id != "custom" {
@@ -960,6 +1531,24 @@ pub fn set_post_process_selected_prompt(app: AppHandle, id: String) -> Result<()
     Ok(())
 }
 
+/// Set the ordered list of prompts run as a post-processing chain.
+/// An empty list runs only the selected prompt.
+#[tauri::command]
+#[specta::specta]
+pub fn set_post_process_chain(app: AppHandle, prompt_ids: Vec<String>) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+
+    for id in &prompt_ids {
+        if !settings.post_process_prompts.iter().any(|p| &p.id == id) {
+            return Err(format!("Prompt with id '{}' not found", id));
+        }
+    }
+
+    settings.post_process_chain = prompt_ids;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_mute_while_recording_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
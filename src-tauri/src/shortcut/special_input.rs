@@ -0,0 +1,294 @@
+//! Mouse-button and media-key shortcuts
+//!
+//! Neither the Tauri global-shortcut plugin nor handy-keys can bind the extra
+//! mouse buttons, and media keys are not reliably reported by either. Bindings
+//! whose `current_binding` is one of the names below are therefore handled here,
+//! independent of the selected keyboard implementation:
+//!
+//! - `mouse4`, `mouse5` (back / forward side buttons)
+//! - `media_play_pause`, `media_next`, `media_previous`, `media_stop`
+//! - `volume_mute`, `volume_down`, `volume_up`
+//!
+//! They are bound on their own, without modifiers. Events come from an `rdev`
+//! listener thread that is started on the first registration. The listener
+//! only observes input, so the button or key keeps its normal effect in the
+//! focused application. Media keys are not delivered as key events on macOS,
+//! and on Wayland only XWayland windows are observed.
+
+use rdev::{Button, EventType, Key};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tauri::{AppHandle, Manager};
+use tracing::{debug, error, info};
+
+use crate::settings::ShortcutBinding;
+
+use super::handler::handle_shortcut_event;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialTrigger {
+    Mouse4,
+    Mouse5,
+    MediaPlayPause,
+    MediaNext,
+    MediaPrevious,
+    MediaStop,
+    VolumeMute,
+    VolumeDown,
+    VolumeUp,
+}
+
+const ALL_TRIGGERS: [SpecialTrigger; 9] = [
+    SpecialTrigger::Mouse4,
+    SpecialTrigger::Mouse5,
+    SpecialTrigger::MediaPlayPause,
+    SpecialTrigger::MediaNext,
+    SpecialTrigger::MediaPrevious,
+    SpecialTrigger::MediaStop,
+    SpecialTrigger::VolumeMute,
+    SpecialTrigger::VolumeDown,
+    SpecialTrigger::VolumeUp,
+];
+
+impl SpecialTrigger {
+    /// Parse a binding string, returning `None` for regular keyboard shortcuts.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim().to_lowercase();
+        ALL_TRIGGERS.into_iter().find(|t| t.as_str() == raw)
+    }
+
+    /// The name stored in `ShortcutBinding::current_binding`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SpecialTrigger::Mouse4 => "mouse4",
+            SpecialTrigger::Mouse5 => "mouse5",
+            SpecialTrigger::MediaPlayPause => "media_play_pause",
+            SpecialTrigger::MediaNext => "media_next",
+            SpecialTrigger::MediaPrevious => "media_previous",
+            SpecialTrigger::MediaStop => "media_stop",
+            SpecialTrigger::VolumeMute => "volume_mute",
+            SpecialTrigger::VolumeDown => "volume_down",
+            SpecialTrigger::VolumeUp => "volume_up",
+        }
+    }
+
+    fn is_mouse_button(self) -> bool {
+        matches!(self, SpecialTrigger::Mouse4 | SpecialTrigger::Mouse5)
+    }
+
+    /// Raw key code the listener reports for a media key on this platform.
+    /// rdev has no named variants for these keys, so they arrive as `Key::Unknown`.
+    #[cfg(target_os = "windows")]
+    fn key_code(self) -> Option<u32> {
+        // Virtual-key codes (VK_MEDIA_*, VK_VOLUME_*)
+        match self {
+            SpecialTrigger::MediaPlayPause => Some(0xB3),
+            SpecialTrigger::MediaNext => Some(0xB0),
+            SpecialTrigger::MediaPrevious => Some(0xB1),
+            SpecialTrigger::MediaStop => Some(0xB2),
+            SpecialTrigger::VolumeMute => Some(0xAD),
+            SpecialTrigger::VolumeDown => Some(0xAE),
+            SpecialTrigger::VolumeUp => Some(0xAF),
+            SpecialTrigger::Mouse4 | SpecialTrigger::Mouse5 => None,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn key_code(self) -> Option<u32> {
+        // X11 keycodes (evdev + 8) of the XF86Audio* keys
+        match self {
+            SpecialTrigger::MediaPlayPause => Some(172),
+            SpecialTrigger::MediaNext => Some(171),
+            SpecialTrigger::MediaPrevious => Some(173),
+            SpecialTrigger::MediaStop => Some(174),
+            SpecialTrigger::VolumeMute => Some(121),
+            SpecialTrigger::VolumeDown => Some(122),
+            SpecialTrigger::VolumeUp => Some(123),
+            SpecialTrigger::Mouse4 | SpecialTrigger::Mouse5 => None,
+        }
+    }
+
+    #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+    fn key_code(self) -> Option<u32> {
+        None
+    }
+
+    fn from_key(key: Key) -> Option<Self> {
+        let Key::Unknown(code) = key else {
+            return None;
+        };
+        ALL_TRIGGERS
+            .into_iter()
+            .find(|t| t.key_code() == Some(code))
+    }
+
+    fn from_button(button: Button) -> Option<Self> {
+        // Side buttons are reported by number, which differs per platform
+        #[cfg(target_os = "linux")]
+        const SIDE_BUTTONS: (u8, u8) = (8, 9);
+        #[cfg(target_os = "windows")]
+        const SIDE_BUTTONS: (u8, u8) = (1, 2);
+        #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+        const SIDE_BUTTONS: (u8, u8) = (3, 4);
+
+        match button {
+            Button::Unknown(n) if n == SIDE_BUTTONS.0 => Some(SpecialTrigger::Mouse4),
+            Button::Unknown(n) if n == SIDE_BUTTONS.1 => Some(SpecialTrigger::Mouse5),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a binding string names a mouse button or media key.
+pub fn is_special(raw: &str) -> bool {
+    SpecialTrigger::parse(raw).is_some()
+}
+
+/// Check that the trigger can be observed on this platform.
+pub fn validate(raw: &str) -> Result<(), String> {
+    let trigger = SpecialTrigger::parse(raw)
+        .ok_or_else(|| format!("'{}' is not a mouse or media key", raw))?;
+    if trigger.is_mouse_button() || trigger.key_code().is_some() {
+        Ok(())
+    } else {
+        Err(format!(
+            "'{}' can't be used as a shortcut on this platform",
+            trigger.as_str()
+        ))
+    }
+}
+
+/// Registered special bindings, shared with the listener thread
+#[derive(Default)]
+pub struct SpecialInputState {
+    bindings: Arc<Mutex<HashMap<SpecialTrigger, String>>>,
+    listener_started: AtomicBool,
+}
+
+impl SpecialInputState {
+    fn register(&self, app: &AppHandle, binding: &ShortcutBinding) -> Result<(), String> {
+        validate(&binding.current_binding)?;
+        let trigger = SpecialTrigger::parse(&binding.current_binding)
+            .ok_or("Not a mouse or media key binding")?;
+
+        {
+            let mut bindings = self
+                .bindings
+                .lock()
+                .map_err(|_| "Failed to lock special bindings")?;
+            if let Some(existing) = bindings.get(&trigger) {
+                if existing != &binding.id {
+                    return Err(format!("Shortcut '{}' is already in use", trigger.as_str()));
+                }
+            }
+            bindings.insert(trigger, binding.id.clone());
+        }
+
+        if !self.listener_started.swap(true, Ordering::SeqCst) {
+            Self::start_listener(app.clone(), Arc::clone(&self.bindings));
+        }
+
+        debug!(
+            "Registered special shortcut: {} -> {}",
+            binding.id,
+            trigger.as_str()
+        );
+        Ok(())
+    }
+
+    fn unregister(&self, binding: &ShortcutBinding) -> Result<(), String> {
+        let mut bindings = self
+            .bindings
+            .lock()
+            .map_err(|_| "Failed to lock special bindings")?;
+        bindings.retain(|_, id| id != &binding.id);
+        Ok(())
+    }
+
+    /// Start the global input listener. `rdev::listen` blocks for the lifetime
+    /// of the process, so this runs at most once.
+    fn start_listener(app: AppHandle, bindings: Arc<Mutex<HashMap<SpecialTrigger, String>>>) {
+        thread::spawn(move || {
+            info!("Special input listener started");
+            // Media keys auto-repeat while held; only forward the first press
+            let mut held: HashSet<SpecialTrigger> = HashSet::new();
+
+            let result = rdev::listen(move |event| {
+                let (trigger, is_pressed) = match event.event_type {
+                    EventType::ButtonPress(b) => (SpecialTrigger::from_button(b), true),
+                    EventType::ButtonRelease(b) => (SpecialTrigger::from_button(b), false),
+                    EventType::KeyPress(k) => (SpecialTrigger::from_key(k), true),
+                    EventType::KeyRelease(k) => (SpecialTrigger::from_key(k), false),
+                    _ => return,
+                };
+                let Some(trigger) = trigger else {
+                    return;
+                };
+
+                if is_pressed && !held.insert(trigger) {
+                    return;
+                }
+                if !is_pressed {
+                    held.remove(&trigger);
+                }
+
+                let binding_id = bindings.lock().ok().and_then(|b| b.get(&trigger).cloned());
+                if let Some(binding_id) = binding_id {
+                    handle_shortcut_event(&app, &binding_id, trigger.as_str(), is_pressed);
+                }
+            });
+
+            if let Err(e) = result {
+                error!("Special input listener failed: {:?}", e);
+            }
+        });
+    }
+}
+
+/// Register a mouse-button or media-key binding
+pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<SpecialInputState>()
+        .ok_or("SpecialInputState not initialized")?;
+    state.register(app, &binding)
+}
+
+/// Unregister a mouse-button or media-key binding
+pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<SpecialInputState>()
+        .ok_or("SpecialInputState not initialized")?;
+    state.unregister(&binding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_special_binding_names() {
+        assert_eq!(
+            SpecialTrigger::parse("mouse4"),
+            Some(SpecialTrigger::Mouse4)
+        );
+        assert_eq!(
+            SpecialTrigger::parse(" Media_Play_Pause "),
+            Some(SpecialTrigger::MediaPlayPause)
+        );
+        assert_eq!(SpecialTrigger::parse("ctrl+mouse4"), None);
+        assert_eq!(SpecialTrigger::parse("ctrl+space"), None);
+
+        for trigger in ALL_TRIGGERS {
+            assert_eq!(SpecialTrigger::parse(trigger.as_str()), Some(trigger));
+        }
+    }
+
+    #[test]
+    fn mouse_buttons_are_always_valid() {
+        assert!(validate("mouse4").is_ok());
+        assert!(validate("mouse5").is_ok());
+        assert!(validate("space").is_err());
+    }
+}
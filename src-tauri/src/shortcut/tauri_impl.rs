@@ -3,13 +3,14 @@
 //! This module provides shortcut functionality using Tauri's built-in
 //! global-shortcut plugin.
 
-use log::{error, warn};
 use tauri::AppHandle;
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+use tracing::{error, warn};
 
 use crate::settings::{self, get_settings, ShortcutBinding};
 
 use super::handler::handle_shortcut_event;
+use super::special_input;
 
 /// Initialize shortcuts using Tauri's global-shortcut plugin
 pub fn init_shortcuts(app: &AppHandle) {
@@ -31,7 +32,12 @@ pub fn init_shortcuts(app: &AppHandle) {
             .cloned()
             .unwrap_or(default_binding);
 
-        if let Err(e) = register_shortcut(app, binding) {
+        let result = if special_input::is_special(&binding.current_binding) {
+            special_input::register_shortcut(app, binding)
+        } else {
+            register_shortcut(app, binding)
+        };
+        if let Err(e) = result {
             error!("Failed to register shortcut {} during init: {}", id, e);
         }
     }
@@ -1,32 +1,80 @@
+//! Control from scripts and window managers through Unix signals:
+//!
+//! - `SIGUSR2` toggles transcription, `SIGUSR1` toggles it with post-processing
+//! - `SIGHUP` reloads the settings file from disk and re-registers shortcuts
+//! - `SIGRTMIN+1` (Linux only; macOS has no realtime signals) cancels the
+//!   current recording or transcription
+
+#[cfg(unix)]
+use crate::settings;
+#[cfg(unix)]
+use crate::shortcut;
 #[cfg(unix)]
-use crate::TranscriptionCoordinator;
+use crate::transcription_coordinator::InputMode;
 #[cfg(unix)]
-use log::{debug, info, warn};
+use crate::utils::cancel_current_operation;
+#[cfg(unix)]
+use crate::{commands, TranscriptionCoordinator};
 #[cfg(unix)]
 use std::thread;
 #[cfg(unix)]
 use tauri::{AppHandle, Manager};
+#[cfg(unix)]
+use tracing::{debug, error, info, warn};
 
 #[cfg(unix)]
-use signal_hook::consts::{SIGUSR1, SIGUSR2};
+use signal_hook::consts::{SIGHUP, SIGUSR1, SIGUSR2};
 #[cfg(unix)]
 use signal_hook::iterator::Signals;
 
+/// Signal that cancels the current operation, if the platform has one.
+#[cfg(target_os = "linux")]
+fn cancel_signal() -> Option<i32> {
+    Some(libc::SIGRTMIN() + 1)
+}
+
+#[cfg(all(unix, not(target_os = "linux")))]
+fn cancel_signal() -> Option<i32> {
+    None
+}
+
 #[cfg(unix)]
-pub fn setup_signal_handler(app_handle: AppHandle, mut signals: Signals) {
-    debug!("Signal handler registered for SIGUSR1 and SIGUSR2");
+pub fn setup_signal_handler(app_handle: AppHandle) {
+    let cancel_signal = cancel_signal();
+    let mut handled = vec![SIGUSR1, SIGUSR2, SIGHUP];
+    handled.extend(cancel_signal);
+
+    let mut signals = match Signals::new(&handled) {
+        Ok(signals) => signals,
+        Err(e) => {
+            error!("Failed to register signal handlers: {}", e);
+            return;
+        }
+    };
+    debug!("Signal handler registered for {:?}", handled);
+
     thread::spawn(move || {
         debug!("Signal handler thread started");
         for sig in signals.forever() {
             let (binding_id, signal_name) = match sig {
                 SIGUSR2 => ("transcribe", "SIGUSR2"),
                 SIGUSR1 => ("transcribe_with_post_process", "SIGUSR1"),
+                SIGHUP => {
+                    info!("Received SIGHUP, reloading settings");
+                    reload_settings(&app_handle);
+                    continue;
+                }
+                sig if Some(sig) == cancel_signal => {
+                    info!("Received SIGRTMIN+1, cancelling current operation");
+                    cancel_current_operation(&app_handle);
+                    continue;
+                }
                 _ => continue,
             };
             debug!("Received {signal_name} signal");
 
             if let Some(coordinator) = app_handle.try_state::<TranscriptionCoordinator>() {
-                coordinator.send_input(binding_id, signal_name, true, false);
+                coordinator.send_input(binding_id, signal_name, true, InputMode::Toggle);
                 info!("{signal_name}: sent toggle to coordinator for '{binding_id}'");
             } else {
                 warn!("TranscriptionCoordinator is not initialized");
@@ -34,3 +82,36 @@ pub fn setup_signal_handler(app_handle: AppHandle, mut signals: Signals) {
         }
     });
 }
+
+/// Apply the settings file as if it had been imported: swap the shortcut
+/// registrations, then bring logging, the power policy (microphone mode and
+/// model keep-alive), the tray and the settings window in line with it.
+#[cfg(unix)]
+fn reload_settings(app: &AppHandle) {
+    let settings = match settings::read_settings_from_disk(app) {
+        Ok(settings) => settings,
+        Err(e) => {
+            warn!("SIGHUP: keeping current settings: {}", e);
+            return;
+        }
+    };
+
+    // Shortcut registration has to happen on the main thread
+    let app_handle = app.clone();
+    let result = app.run_on_main_thread(move || {
+        let app = &app_handle;
+        let reset_bindings = shortcut::replace_settings(app, settings.clone());
+        if !reset_bindings.is_empty() {
+            warn!(
+                "Reloaded shortcuts reset to defaults: {}",
+                reset_bindings.join(", ")
+            );
+        }
+
+        commands::apply_reloaded_settings(app, "sighup");
+        info!("Settings reloaded from disk");
+    });
+    if let Err(e) = result {
+        error!("SIGHUP: could not reload settings on main thread: {}", e);
+    }
+}
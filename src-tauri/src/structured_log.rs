@@ -0,0 +1,312 @@
+//! Structured logging for the dictation pipeline.
+//!
+//! Recording, transcription, post-processing and paste emit `tracing` events
+//! inside spans. The global subscriber installed by [`init`]:
+//! - writes them as JSON lines to `pipeline.<date>.jsonl` in the log dir,
+//!   rotated daily, including a timing event when each span closes,
+//! - keeps the most recent ones in memory for the in-app log viewer,
+//! - forwards them to `log`, so they still reach the console and text log.
+//!
+//! Only events from this crate are captured, at the user's file log level.
+
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Write as _};
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+use std::time::Instant;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::fmt::format::FmtSpan;
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Events kept in memory for the log viewer.
+const RECENT_CAPACITY: usize = 1000;
+/// Daily JSON log files kept on disk.
+const MAX_LOG_FILES: usize = 7;
+const CRATE_TARGET: &str = env!("CARGO_CRATE_NAME");
+
+static RECENT: Lazy<Mutex<VecDeque<LogEvent>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(RECENT_CAPACITY)));
+
+/// One captured event, as shown in the log viewer.
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct LogEvent {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: i64,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    /// Enclosing spans, outermost first, e.g. `dictation{binding=transcribe}`
+    pub spans: Vec<String>,
+    pub fields: BTreeMap<String, String>,
+}
+
+/// Install the global subscriber. Call once, after the log plugin is set up.
+pub fn init(log_dir: &Path) {
+    let file_layer = match RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix("pipeline")
+        .filename_suffix("jsonl")
+        .max_log_files(MAX_LOG_FILES)
+        .build(log_dir)
+    {
+        Ok(appender) => Some(
+            tracing_subscriber::fmt::layer()
+                .json()
+                .with_writer(appender)
+                .with_ansi(false)
+                .with_current_span(true)
+                .with_span_list(true)
+                .with_span_events(FmtSpan::CLOSE)
+                .with_filter(filter_fn(captured)),
+        ),
+        Err(e) => {
+            log::warn!("Failed to open pipeline log in {:?}: {}", log_dir, e);
+            None
+        }
+    };
+
+    let subscriber = tracing_subscriber::registry()
+        .with(RecentEvents.with_filter(filter_fn(captured)))
+        .with(LogBridge.with_filter(filter_fn(is_ours)))
+        .with(file_layer);
+
+    if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
+        log::warn!("Failed to install tracing subscriber: {}", e);
+    }
+}
+
+/// The last `limit` captured events, oldest first.
+pub fn recent_events(limit: usize) -> Vec<LogEvent> {
+    let recent = RECENT.lock().unwrap();
+    recent
+        .iter()
+        .skip(recent.len().saturating_sub(limit))
+        .cloned()
+        .collect()
+}
+
+fn is_ours(metadata: &Metadata<'_>) -> bool {
+    metadata.target().starts_with(CRATE_TARGET)
+}
+
+/// Our events at or above the file log level chosen in settings.
+fn captured(metadata: &Metadata<'_>) -> bool {
+    let file_level = crate::level_filter_from_u8(crate::FILE_LOG_LEVEL.load(Ordering::Relaxed));
+    is_ours(metadata) && to_log_level(metadata.level()) <= file_level
+}
+
+fn to_log_level(level: &Level) -> log::Level {
+    match *level {
+        Level::ERROR => log::Level::Error,
+        Level::WARN => log::Level::Warn,
+        Level::INFO => log::Level::Info,
+        Level::DEBUG => log::Level::Debug,
+        Level::TRACE => log::Level::Trace,
+    }
+}
+
+fn push_recent(event: LogEvent) {
+    let mut recent = RECENT.lock().unwrap();
+    if recent.len() == RECENT_CAPACITY {
+        recent.pop_front();
+    }
+    recent.push_back(event);
+}
+
+/// Splits an event or span into its message and the remaining fields.
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl FieldVisitor {
+    fn insert(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = value;
+        } else {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+
+    /// `message key=value ...`
+    fn into_line(self) -> String {
+        let mut line = self.message;
+        for (key, value) in self.fields {
+            let _ = write!(line, " {}={}", key, value);
+        }
+        line
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{:?}", value));
+    }
+}
+
+/// Stored in span extensions by [`RecentEvents`].
+struct SpanData {
+    /// `name{key=value,...}`
+    label: String,
+    opened: Instant,
+}
+
+/// Keeps recent events, and span durations, for the log viewer.
+struct RecentEvents;
+
+impl RecentEvents {
+    fn labels<S>(ctx: &Context<'_, S>, leaf: Option<&Id>) -> Vec<String>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let Some(span) = leaf.and_then(|id| ctx.span(id)) else {
+            return Vec::new();
+        };
+        span.scope()
+            .from_root()
+            .filter_map(|s| s.extensions().get::<SpanData>().map(|d| d.label.clone()))
+            .collect()
+    }
+}
+
+impl<S> Layer<S> for RecentEvents
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        let fields: Vec<String> = visitor
+            .fields
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect();
+        let label = if fields.is_empty() {
+            span.name().to_string()
+        } else {
+            format!("{}{{{}}}", span.name(), fields.join(","))
+        };
+        span.extensions_mut().insert(SpanData {
+            label,
+            opened: Instant::now(),
+        });
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let leaf = ctx.event_span(event).map(|span| span.id());
+
+        push_recent(LogEvent {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            spans: Self::labels(&ctx, leaf.as_ref()),
+            fields: visitor.fields,
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        let Some(elapsed_ms) = span
+            .extensions()
+            .get::<SpanData>()
+            .map(|d| d.opened.elapsed().as_millis())
+        else {
+            return;
+        };
+        let metadata = span.metadata();
+        let parent = span.parent().map(|p| p.id());
+
+        push_recent(LogEvent {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: format!("{} finished", span.name()),
+            spans: Self::labels(&ctx, parent.as_ref()),
+            fields: BTreeMap::from([("elapsed_ms".to_string(), elapsed_ms.to_string())]),
+        });
+    }
+}
+
+/// Forwards events to the `log` logger (console and text log file).
+struct LogBridge;
+
+impl<S: Subscriber> Layer<S> for LogBridge {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let log_metadata = log::Metadata::builder()
+            .level(to_log_level(metadata.level()))
+            .target(metadata.target())
+            .build();
+        let logger = log::logger();
+        if !logger.enabled(&log_metadata) {
+            return;
+        }
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        logger.log(
+            &log::Record::builder()
+                .metadata(log_metadata)
+                .args(format_args!("{}", visitor.into_line()))
+                .module_path(metadata.module_path())
+                .file(metadata.file())
+                .line(metadata.line())
+                .build(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing::{info, info_span};
+
+    #[test]
+    fn records_events_with_span_labels_and_durations() {
+        let subscriber = tracing_subscriber::registry().with(RecentEvents);
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("dictation", binding = "transcribe");
+            let _entered = span.enter();
+            info!(samples = 16000, "Recording stopped");
+            info_span!("paste").in_scope(|| info!("Text pasted"));
+        });
+
+        let events = recent_events(RECENT_CAPACITY);
+        let stopped = events
+            .iter()
+            .find(|e| e.message == "Recording stopped")
+            .unwrap();
+        assert_eq!(stopped.spans, vec!["dictation{binding=transcribe}"]);
+        assert_eq!(stopped.fields["samples"], "16000");
+
+        let pasted = events.iter().find(|e| e.message == "Text pasted").unwrap();
+        assert_eq!(pasted.spans, vec!["dictation{binding=transcribe}", "paste"]);
+        assert!(events
+            .iter()
+            .any(|e| e.message == "paste finished" && e.fields.contains_key("elapsed_ms")));
+    }
+}
@@ -0,0 +1,349 @@
+//! Corrections dictated against text held for confirmation: "replace X
+//! with Y", "delete last sentence", "capitalize that". They edit the held
+//! text in the overlay, never the target app.
+//!
+//! "That" is the text the last dictation added, as in other dictation
+//! software; the rest of the held text isn't touched by it.
+
+use std::ops::Range;
+
+/// A parsed correction command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EditCommand {
+    /// Replace the last occurrence of `target` (whole words, any case)
+    Replace {
+        target: String,
+        replacement: String,
+    },
+    DeleteLastSentence,
+    DeleteLastWord,
+    /// Change the case of "that"
+    Recase(Case),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// First letter of every word
+    Capitalize,
+    Upper,
+    Lower,
+}
+
+/// "replace X with Y" phrasings: (verb, separator).
+const REPLACE_FORMS: &[(&str, &str)] = &[
+    ("replace", " with "),
+    ("change", " to "),
+    ("замени", " на "),
+    ("заменить", " на "),
+    ("исправь", " на "),
+];
+
+const FIXED_COMMANDS: &[(&[&str], EditCommand)] = &[
+    (
+        &[
+            "delete last sentence",
+            "delete the last sentence",
+            "удали последнее предложение",
+        ],
+        EditCommand::DeleteLastSentence,
+    ),
+    (
+        &[
+            "delete last word",
+            "delete the last word",
+            "удали последнее слово",
+        ],
+        EditCommand::DeleteLastWord,
+    ),
+    (
+        &["capitalize that", "cap that", "с большой буквы"],
+        EditCommand::Recase(Case::Capitalize),
+    ),
+    (
+        &["all caps that", "uppercase that", "заглавными"],
+        EditCommand::Recase(Case::Upper),
+    ),
+    (
+        &["lowercase that", "no caps that", "строчными"],
+        EditCommand::Recase(Case::Lower),
+    ),
+];
+
+/// The correction the whole of `text` asks for, if it is one.
+pub fn parse(text: &str) -> Option<EditCommand> {
+    let text = text.trim().trim_end_matches(['.', '!', '?']).trim_end();
+    let lower = text.to_lowercase();
+    // Offsets found in `lower` index `text` only if lowercasing kept every
+    // byte length, as it does for Latin and Cyrillic
+    let source = if lower.len() == text.len() {
+        text
+    } else {
+        &lower
+    };
+
+    for (verb, separator) in REPLACE_FORMS {
+        // Transcription may put a comma after the verb
+        let Some(rest) = lower
+            .strip_prefix(verb)
+            .filter(|rest| rest.starts_with([' ', ',']))
+        else {
+            continue;
+        };
+        let Some(split) = rest.find(separator) else {
+            continue;
+        };
+        let target_start = verb.len();
+        let target_end = target_start + split;
+        let target = clean_phrase(&source[target_start..target_end]);
+        let replacement = clean_phrase(&source[target_end + separator.len()..]);
+        if !target.is_empty() {
+            return Some(EditCommand::Replace {
+                target: target.to_string(),
+                replacement: replacement.to_string(),
+            });
+        }
+    }
+
+    let normalized = lower.trim_end_matches(',');
+    FIXED_COMMANDS
+        .iter()
+        .find(|(phrases, _)| phrases.contains(&normalized))
+        .map(|(_, command)| command.clone())
+}
+
+/// Strip the commas and quotes transcription leaves around a spoken phrase.
+fn clean_phrase(phrase: &str) -> &str {
+    phrase.trim_matches(|c: char| c.is_whitespace() || ",\"'«»“”".contains(c))
+}
+
+/// Apply `command` to `text`, where "that" starts at byte `that_start`.
+/// Returns `None` if it doesn't apply, e.g. the word to replace isn't there.
+pub fn apply(text: &str, that_start: usize, command: &EditCommand) -> Option<String> {
+    match command {
+        EditCommand::Replace {
+            target,
+            replacement,
+        } => {
+            let range = rfind_words(text, target)?;
+            let replacement = match_case(&text[range.clone()], replacement);
+            let mut edited = String::with_capacity(text.len() + replacement.len());
+            edited.push_str(&text[..range.start]);
+            edited.push_str(&replacement);
+            edited.push_str(&text[range.end..]);
+            Some(tidy_spaces(&edited))
+        }
+        EditCommand::DeleteLastSentence => {
+            let trimmed = text.trim_end();
+            if trimmed.is_empty() {
+                return None;
+            }
+            // The end of the sentence before the last one
+            let body = trimmed.trim_end_matches(is_sentence_end);
+            let keep = body
+                .char_indices()
+                .rev()
+                .find(|&(_, c)| is_sentence_end(c))
+                .map_or(0, |(i, c)| i + c.len_utf8());
+            Some(text[..keep].to_string())
+        }
+        EditCommand::DeleteLastWord => {
+            let trimmed = text.trim_end();
+            if trimmed.is_empty() {
+                return None;
+            }
+            let keep = trimmed
+                .char_indices()
+                .rev()
+                .find(|(_, c)| c.is_whitespace())
+                .map_or(0, |(i, _)| i);
+            Some(text[..keep].trim_end().to_string())
+        }
+        EditCommand::Recase(case) => {
+            let start = floor_char_boundary(text, that_start);
+            let that = &text[start..];
+            if that.trim().is_empty() {
+                return None;
+            }
+            let recased = match case {
+                Case::Capitalize => capitalize_words(that),
+                Case::Upper => that.to_uppercase(),
+                Case::Lower => that.to_lowercase(),
+            };
+            Some(format!("{}{}", &text[..start], recased))
+        }
+    }
+}
+
+fn is_sentence_end(c: char) -> bool {
+    ".!?…".contains(c)
+}
+
+/// Largest char boundary at or before `index`.
+pub fn floor_char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+/// Last case-insensitive occurrence of `needle` in `haystack` that starts
+/// and ends on word boundaries.
+fn rfind_words(haystack: &str, needle: &str) -> Option<Range<usize>> {
+    haystack.char_indices().rev().find_map(|(start, _)| {
+        let end = start + match_len(&haystack[start..], needle)?;
+        let starts_word = !haystack[..start]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric);
+        let ends_word = !haystack[end..]
+            .chars()
+            .next()
+            .is_some_and(char::is_alphanumeric);
+        (starts_word && ends_word).then_some(start..end)
+    })
+}
+
+/// Byte length of the prefix of `haystack` equal to `needle` ignoring case.
+fn match_len(haystack: &str, needle: &str) -> Option<usize> {
+    let mut chars = haystack.char_indices();
+    for expected in needle.chars() {
+        let (_, c) = chars.next()?;
+        if !c.to_lowercase().eq(expected.to_lowercase()) {
+            return None;
+        }
+    }
+    Some(chars.next().map_or(haystack.len(), |(i, _)| i))
+}
+
+/// `replacement` capitalized if the text it replaces was, so a replaced
+/// sentence start stays capitalized.
+fn match_case(replaced: &str, replacement: &str) -> String {
+    let starts_upper = replaced.chars().next().is_some_and(char::is_uppercase);
+    let mut chars = replacement.chars();
+    match chars.next() {
+        Some(first) if starts_upper && first.is_lowercase() => {
+            first.to_uppercase().chain(chars).collect()
+        }
+        _ => replacement.to_string(),
+    }
+}
+
+/// Collapse the double spaces left by replacing a word with nothing.
+fn tidy_spaces(text: &str) -> String {
+    let mut tidy = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c == ' ' && tidy.ends_with(' ') {
+            continue;
+        }
+        tidy.push(c);
+    }
+    tidy.trim().to_string()
+}
+
+fn capitalize_words(text: &str) -> String {
+    let mut at_word_start = true;
+    text.chars()
+        .flat_map(|c| {
+            let recased: Vec<char> = if at_word_start {
+                c.to_uppercase().collect()
+            } else {
+                vec![c]
+            };
+            at_word_start = c.is_whitespace();
+            recased
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_replace_commands() {
+        assert_eq!(
+            parse("Replace Tuesday with Wednesday."),
+            Some(EditCommand::Replace {
+                target: "Tuesday".to_string(),
+                replacement: "Wednesday".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("Замени, кот, на собака"),
+            Some(EditCommand::Replace {
+                target: "кот".to_string(),
+                replacement: "собака".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("Delete the last sentence."),
+            Some(EditCommand::DeleteLastSentence)
+        );
+        assert_eq!(
+            parse("Cap that!"),
+            Some(EditCommand::Recase(Case::Capitalize))
+        );
+        assert_eq!(parse("replace the filter tomorrow"), None);
+        assert_eq!(parse("we should capitalize that"), None);
+    }
+
+    #[test]
+    fn replaces_the_last_whole_word_match() {
+        let replace = |target: &str, replacement: &str| EditCommand::Replace {
+            target: target.to_string(),
+            replacement: replacement.to_string(),
+        };
+        assert_eq!(
+            apply("Cat and cat food for the cats.", 0, &replace("cat", "dog")),
+            Some("Cat and dog food for the cats.".to_string())
+        );
+        assert_eq!(
+            apply("Tuesday works.", 0, &replace("tuesday", "wednesday")),
+            Some("Wednesday works.".to_string())
+        );
+        assert_eq!(
+            apply("See you very soon.", 0, &replace("very", "")),
+            Some("See you soon.".to_string())
+        );
+        assert_eq!(apply("Hello.", 0, &replace("bye", "hi")), None);
+    }
+
+    #[test]
+    fn deletes_the_last_sentence_or_word() {
+        assert_eq!(
+            apply(
+                "Hi there. How are you? ",
+                0,
+                &EditCommand::DeleteLastSentence
+            ),
+            Some("Hi there.".to_string())
+        );
+        assert_eq!(
+            apply("Just one", 0, &EditCommand::DeleteLastSentence),
+            Some(String::new())
+        );
+        assert_eq!(
+            apply("See you soon.", 0, &EditCommand::DeleteLastWord),
+            Some("See you".to_string())
+        );
+    }
+
+    #[test]
+    fn recases_only_the_last_dictation() {
+        let text = "Meet at the hilton hotel";
+        let that = "Meet at the ".len();
+        assert_eq!(
+            apply(text, that, &EditCommand::Recase(Case::Capitalize)),
+            Some("Meet at the Hilton Hotel".to_string())
+        );
+        assert_eq!(
+            apply(text, that, &EditCommand::Recase(Case::Upper)),
+            Some("Meet at the HILTON HOTEL".to_string())
+        );
+        assert_eq!(
+            apply("привет мир", 3, &EditCommand::Recase(Case::Upper)),
+            Some("пРИВЕТ МИР".to_string())
+        );
+    }
+}
@@ -1,13 +1,37 @@
 use crate::actions::ACTION_MAP;
+use crate::audio_feedback::{play_alert_sound, SoundType};
 use crate::managers::audio::AudioRecordingManager;
-use log::{debug, error, warn};
-use std::sync::mpsc::{self, Sender};
-use std::sync::Arc;
+use crate::meeting;
+use crate::settings::{get_settings, BusyBehavior};
+use crate::shortcut::gesture::{Gesture, GestureDetector};
+use crate::tray::{change_tray_icon, TrayIconState};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
 use tauri::{AppHandle, Manager};
+use tracing::{debug, error, warn};
 
 const DEBOUNCE: Duration = Duration::from_millis(30);
+/// How long a queued start waits for the previous pipeline to release the
+/// recorder (it stops recording right after the stop shortcut).
+const QUEUE_WAIT: Duration = Duration::from_millis(500);
+
+/// Binding whose shortcut gets gesture recognition.
+const GESTURE_BINDING: &str = "transcribe";
+/// Binding started by a double-tap in gesture mode.
+const DOUBLE_TAP_BINDING: &str = "transcribe_with_post_process";
+
+/// How press/release events of a binding drive recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// Press starts, next press stops
+    Toggle,
+    /// Press starts, release stops
+    PushToTalk,
+    /// Tap toggles, double-tap toggles with post-processing, hold is push-to-talk
+    Gestures,
+}
 
 /// Commands processed sequentially by the coordinator thread.
 enum Command {
@@ -15,7 +39,7 @@ enum Command {
         binding_id: String,
         hotkey_string: String,
         is_pressed: bool,
-        push_to_talk: bool,
+        mode: InputMode,
     },
     Cancel {
         recording_was_active: bool,
@@ -23,8 +47,12 @@ enum Command {
     ProcessingFinished,
 }
 
-/// Pipeline lifecycle, owned exclusively by the coordinator thread.
-enum Stage {
+/// Pipeline lifecycle, owned by the coordinator thread; a copy is published
+/// after every event for [`TranscriptionCoordinator::stage`].
+/// With `BusyBehavior::Queue` a new recording can start while earlier ones
+/// are still processing; those are counted separately as in flight.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stage {
     Idle,
     Recording(String), // binding_id
     Processing,
@@ -35,28 +63,79 @@ enum Stage {
 /// the async transcribe-paste pipeline.
 pub struct TranscriptionCoordinator {
     tx: Sender<Command>,
+    stage: Arc<Mutex<Stage>>,
 }
 
 pub fn is_transcribe_binding(id: &str) -> bool {
-    id == "transcribe" || id == "transcribe_with_post_process"
+    id == "transcribe" || id == "transcribe_with_post_process" || id == "transcribe_private"
+}
+
+/// Input mode for a transcribe binding given the current settings.
+pub fn input_mode(binding_id: &str, push_to_talk: bool, hotkey_gestures: bool) -> InputMode {
+    if hotkey_gestures && binding_id == GESTURE_BINDING {
+        InputMode::Gestures
+    } else if push_to_talk {
+        InputMode::PushToTalk
+    } else {
+        InputMode::Toggle
+    }
+}
+
+fn is_gesture_recording(stage: &Stage) -> bool {
+    matches!(stage, Stage::Recording(id) if id == GESTURE_BINDING || id == DOUBLE_TAP_BINDING)
 }
 
 impl TranscriptionCoordinator {
     pub fn new(app: AppHandle) -> Self {
         let (tx, rx) = mpsc::channel();
+        let published = Arc::new(Mutex::new(Stage::Idle));
+        let stage_snapshot = Arc::clone(&published);
 
         thread::spawn(move || {
+            let publish = |stage: &Stage| *published.lock().unwrap() = stage.clone();
             let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
                 let mut stage = Stage::Idle;
+                let mut in_flight: usize = 0;
                 let mut last_press: Option<Instant> = None;
+                let mut gestures = GestureDetector::new();
+                let mut gesture_hotkey = String::new();
+
+                loop {
+                    // Wake up for pending gesture timeouts (long press, double-tap window)
+                    let cmd = match gestures.deadline() {
+                        Some(deadline) => {
+                            match rx
+                                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                            {
+                                Ok(cmd) => cmd,
+                                Err(RecvTimeoutError::Timeout) => {
+                                    if let Some(gesture) = gestures.tick(Instant::now()) {
+                                        on_gesture(
+                                            &app,
+                                            &mut stage,
+                                            &mut in_flight,
+                                            gesture,
+                                            &gesture_hotkey,
+                                        );
+                                        publish(&stage);
+                                    }
+                                    continue;
+                                }
+                                Err(RecvTimeoutError::Disconnected) => break,
+                            }
+                        }
+                        None => match rx.recv() {
+                            Ok(cmd) => cmd,
+                            Err(_) => break,
+                        },
+                    };
 
-                while let Ok(cmd) = rx.recv() {
                     match cmd {
                         Command::Input {
                             binding_id,
                             hotkey_string,
                             is_pressed,
-                            push_to_talk,
+                            mode,
                         } => {
                             // Debounce rapid-fire press events (key repeat / double-tap).
                             // Releases always pass through for push-to-talk.
@@ -69,24 +148,77 @@ impl TranscriptionCoordinator {
                                 last_press = Some(now);
                             }
 
-                            if push_to_talk {
-                                if is_pressed && matches!(stage, Stage::Idle) {
-                                    start(&app, &mut stage, &binding_id, &hotkey_string);
+                            if mode == InputMode::Gestures {
+                                gesture_hotkey = hotkey_string;
+                                let now = Instant::now();
+                                let gesture = if is_pressed
+                                    && is_gesture_recording(&stage)
+                                    && !gestures.is_held()
+                                {
+                                    // A tap while a toggled recording runs stops it right away
+                                    if let Stage::Recording(id) = &stage {
+                                        let id = id.clone();
+                                        stop(
+                                            &app,
+                                            &mut stage,
+                                            &mut in_flight,
+                                            &id,
+                                            &gesture_hotkey,
+                                        );
+                                    }
+                                    gestures.swallow_until_release();
+                                    None
+                                } else if is_pressed {
+                                    gestures.press(now)
+                                } else {
+                                    gestures.release(now)
+                                };
+                                if let Some(gesture) = gesture {
+                                    on_gesture(
+                                        &app,
+                                        &mut stage,
+                                        &mut in_flight,
+                                        gesture,
+                                        &gesture_hotkey,
+                                    );
+                                }
+                            } else if mode == InputMode::PushToTalk {
+                                if is_pressed && !matches!(stage, Stage::Recording(_)) {
+                                    start_or_queue(&app, &mut stage, &binding_id, &hotkey_string);
                                 } else if !is_pressed
                                     && matches!(&stage, Stage::Recording(id) if id == &binding_id)
                                 {
-                                    stop(&app, &mut stage, &binding_id, &hotkey_string);
+                                    stop(
+                                        &app,
+                                        &mut stage,
+                                        &mut in_flight,
+                                        &binding_id,
+                                        &hotkey_string,
+                                    );
                                 }
                             } else if is_pressed {
                                 match &stage {
-                                    Stage::Idle => {
-                                        start(&app, &mut stage, &binding_id, &hotkey_string);
+                                    Stage::Idle | Stage::Processing => {
+                                        start_or_queue(
+                                            &app,
+                                            &mut stage,
+                                            &binding_id,
+                                            &hotkey_string,
+                                        );
                                     }
                                     Stage::Recording(id) if id == &binding_id => {
-                                        stop(&app, &mut stage, &binding_id, &hotkey_string);
+                                        stop(
+                                            &app,
+                                            &mut stage,
+                                            &mut in_flight,
+                                            &binding_id,
+                                            &hotkey_string,
+                                        );
                                     }
                                     _ => {
-                                        debug!("Ignoring press for '{binding_id}': pipeline busy")
+                                        debug!(
+                                            "Ignoring press for '{binding_id}': already recording"
+                                        )
                                     }
                                 }
                             }
@@ -100,11 +232,22 @@ impl TranscriptionCoordinator {
                             {
                                 stage = Stage::Idle;
                             }
+                            gestures.reset();
                         }
                         Command::ProcessingFinished => {
-                            stage = Stage::Idle;
+                            in_flight = in_flight.saturating_sub(1);
+                            match stage {
+                                Stage::Processing if in_flight == 0 => stage = Stage::Idle,
+                                // A queued recording is running; the finished pipeline
+                                // reset the tray icon, so put it back
+                                Stage::Recording(_) => {
+                                    change_tray_icon(&app, TrayIconState::Recording)
+                                }
+                                _ => {}
+                            }
                         }
                     }
+                    publish(&stage);
                 }
                 debug!("Transcription coordinator exited");
             }));
@@ -113,25 +256,38 @@ impl TranscriptionCoordinator {
             }
         });
 
-        Self { tx }
+        Self {
+            tx,
+            stage: stage_snapshot,
+        }
+    }
+
+    /// The pipeline stage after the last processed event.
+    pub fn stage(&self) -> Stage {
+        self.stage.lock().unwrap().clone()
     }
 
     /// Send a keyboard/signal input event for a transcribe binding.
-    /// For signal-based toggles, use `is_pressed: true` and `push_to_talk: false`.
+    /// For signal-based toggles, use `is_pressed: true` and `InputMode::Toggle`.
     pub fn send_input(
         &self,
         binding_id: &str,
         hotkey_string: &str,
         is_pressed: bool,
-        push_to_talk: bool,
+        mode: InputMode,
     ) {
+        // The microphone belongs to the meeting until it ends
+        if meeting::is_active() {
+            debug!("Ignoring '{}' input during a meeting", binding_id);
+            return;
+        }
         if self
             .tx
             .send(Command::Input {
                 binding_id: binding_id.to_string(),
                 hotkey_string: hotkey_string.to_string(),
                 is_pressed,
-                push_to_talk,
+                mode,
             })
             .is_err()
         {
@@ -158,6 +314,72 @@ impl TranscriptionCoordinator {
     }
 }
 
+fn on_gesture(
+    app: &AppHandle,
+    stage: &mut Stage,
+    in_flight: &mut usize,
+    gesture: Gesture,
+    hotkey_string: &str,
+) {
+    debug!("Gesture {gesture:?} on '{GESTURE_BINDING}'");
+    match gesture {
+        Gesture::SinglePress | Gesture::LongPressStart => {
+            if !matches!(stage, Stage::Recording(_)) {
+                start_or_queue(app, stage, GESTURE_BINDING, hotkey_string);
+            }
+        }
+        Gesture::DoubleTap => {
+            if !matches!(stage, Stage::Recording(_)) {
+                start_or_queue(app, stage, DOUBLE_TAP_BINDING, hotkey_string);
+            }
+        }
+        Gesture::LongPressEnd => {
+            if let Stage::Recording(id) = &*stage {
+                if id == GESTURE_BINDING {
+                    stop(app, stage, in_flight, GESTURE_BINDING, hotkey_string);
+                }
+            }
+        }
+    }
+}
+
+/// Start recording, or apply the busy behavior if a transcription is still running.
+fn start_or_queue(app: &AppHandle, stage: &mut Stage, binding_id: &str, hotkey_string: &str) {
+    if matches!(stage, Stage::Processing) {
+        match get_settings(app).busy_behavior {
+            BusyBehavior::Reject => {
+                debug!("Rejecting press for '{binding_id}': pipeline busy");
+                play_alert_sound(app, SoundType::Busy);
+                return;
+            }
+            BusyBehavior::Queue => {
+                if !wait_for_recorder(app) {
+                    warn!("Recorder still busy, rejecting press for '{binding_id}'");
+                    play_alert_sound(app, SoundType::Busy);
+                    return;
+                }
+                debug!("Queueing recording for '{binding_id}' behind running transcription");
+            }
+        }
+    }
+    start(app, stage, binding_id, hotkey_string);
+}
+
+/// Wait until the previous pipeline has taken its samples from the recorder.
+fn wait_for_recorder(app: &AppHandle) -> bool {
+    let Some(rm) = app.try_state::<Arc<AudioRecordingManager>>() else {
+        return false;
+    };
+    let deadline = Instant::now() + QUEUE_WAIT;
+    while rm.is_recording() {
+        if Instant::now() >= deadline {
+            return false;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    true
+}
+
 fn start(app: &AppHandle, stage: &mut Stage, binding_id: &str, hotkey_string: &str) {
     let Some(action) = ACTION_MAP.get(binding_id) else {
         warn!("No action in ACTION_MAP for '{binding_id}'");
@@ -174,11 +396,18 @@ fn start(app: &AppHandle, stage: &mut Stage, binding_id: &str, hotkey_string: &s
     }
 }
 
-fn stop(app: &AppHandle, stage: &mut Stage, binding_id: &str, hotkey_string: &str) {
+fn stop(
+    app: &AppHandle,
+    stage: &mut Stage,
+    in_flight: &mut usize,
+    binding_id: &str,
+    hotkey_string: &str,
+) {
     let Some(action) = ACTION_MAP.get(binding_id) else {
         warn!("No action in ACTION_MAP for '{binding_id}'");
         return;
     };
     action.stop(app, binding_id, hotkey_string);
     *stage = Stage::Processing;
+    *in_flight += 1;
 }
@@ -1,14 +1,32 @@
+use crate::audio_toolkit::list_input_devices;
+use crate::completion_notice::first_line;
 use crate::managers::history::{HistoryEntry, HistoryManager};
 use crate::managers::transcription::TranscriptionManager;
-use crate::settings;
-use crate::tray_i18n::get_tray_translations;
-use log::{error, info, warn};
-use std::sync::Arc;
+use crate::settings::{self, AppSettings};
+use crate::transcription_coordinator::{Stage, TranscriptionCoordinator};
+use crate::tray_i18n::{get_tray_translations, TrayStrings};
+use crate::{commands, meeting, shortcut, updater};
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use tauri::image::Image;
-use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::menu::{CheckMenuItem, IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
 use tauri::tray::TrayIcon;
-use tauri::{AppHandle, Manager, Theme};
+use tauri::{AppHandle, Emitter, Manager, Theme};
 use tauri_plugin_clipboard_manager::ClipboardExt;
+use tracing::{error, info, warn};
+
+/// Transcriptions listed in the "Recent Transcripts" submenu.
+const RECENT_TRANSCRIPTS: usize = 5;
+/// Longest transcript preview in the recent submenu, in characters.
+const RECENT_LABEL_CHARS: usize = 40;
+
+const RECENT_TRANSCRIPT_PREFIX: &str = "recent_transcript:";
+const MICROPHONE_PREFIX: &str = "microphone:";
+
+/// Input device names for the microphone picker. Listing devices can be
+/// slow, so it's done off the main thread and cached between menu rebuilds.
+static INPUT_DEVICES: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum TrayIconState {
@@ -61,8 +79,25 @@ pub fn get_icon_path(theme: AppTheme, state: TrayIconState) -> &'static str {
     }
 }
 
+/// The state the tray should show right now, for rebuilding it outside the
+/// pipeline, e.g. after a settings reload in the middle of a recording.
+pub fn current_state(app: &AppHandle) -> TrayIconState {
+    let stage = app
+        .try_state::<TranscriptionCoordinator>()
+        .map(|coordinator| coordinator.stage());
+    match stage {
+        Some(Stage::Recording(_)) => TrayIconState::Recording,
+        Some(Stage::Processing) => TrayIconState::Transcribing,
+        _ if meeting::is_active() => TrayIconState::Recording,
+        _ => TrayIconState::Idle,
+    }
+}
+
 pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
-    let tray = app.state::<TrayIcon>();
+    // No tray in headless mode
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let theme = get_current_theme(app);
 
     let icon_path = get_icon_path(theme, icon.clone());
@@ -78,6 +113,9 @@ pub fn change_tray_icon(app: &AppHandle, icon: TrayIconState) {
 
     // Update menu based on state
     update_tray_menu(app, &icon, None);
+    if icon == TrayIconState::Idle {
+        refresh_input_devices(app);
+    }
 }
 
 pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&str>) {
@@ -108,13 +146,23 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         settings_accelerator,
     )
     .expect("failed to create settings item");
-    let check_updates_i = MenuItem::with_id(
-        app,
-        "check_updates",
-        &strings.check_updates,
-        settings.update_checks_enabled,
-        None::<&str>,
-    )
+    // Once an update is downloaded, the item installs it instead
+    let check_updates_i = match updater::ready_version() {
+        Some(version) => MenuItem::with_id(
+            app,
+            "apply_update",
+            format!("{} (v{})", strings.restart_to_update, version),
+            true,
+            None::<&str>,
+        ),
+        None => MenuItem::with_id(
+            app,
+            "check_updates",
+            &strings.check_updates,
+            settings.update_checks_enabled,
+            None::<&str>,
+        ),
+    }
     .expect("failed to create check updates item");
     let copy_last_transcript_i = MenuItem::with_id(
         app,
@@ -137,29 +185,65 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         .expect("failed to create quit item");
     let show_hide_i = MenuItem::with_id(app, "show_hide", &strings.show_hide, true, None::<&str>)
         .expect("failed to create show/hide item");
+    let recent_i = build_recent_submenu(app, &strings);
+    let start_meeting_i = MenuItem::with_id(
+        app,
+        "start_meeting",
+        &strings.start_meeting,
+        true,
+        None::<&str>,
+    )
+    .expect("failed to create start meeting item");
     let separator = || PredefinedMenuItem::separator(app).expect("failed to create separator");
 
     let menu = match state {
         TrayIconState::Recording | TrayIconState::Transcribing => {
-            let cancel_i = MenuItem::with_id(app, "cancel", &strings.cancel, true, None::<&str>)
-                .expect("failed to create cancel item");
-            Menu::with_items(
-                app,
-                &[
-                    &version_i,
-                    &separator(),
-                    &show_hide_i,
-                    &cancel_i,
-                    &separator(),
-                    &copy_last_transcript_i,
-                    &separator(),
-                    &settings_i,
-                    &check_updates_i,
-                    &separator(),
-                    &quit_i,
+            // A meeting is paused or stopped rather than cancelled
+            let session_items = match meeting::status() {
+                Some(status) => {
+                    let (pause_id, pause_label) = if status.paused {
+                        ("resume_meeting", &strings.resume_meeting)
+                    } else {
+                        ("pause_meeting", &strings.pause_meeting)
+                    };
+                    vec![
+                        MenuItem::with_id(app, pause_id, pause_label, true, None::<&str>)
+                            .expect("failed to create pause meeting item"),
+                        MenuItem::with_id(
+                            app,
+                            "stop_meeting",
+                            &strings.stop_meeting,
+                            true,
+                            None::<&str>,
+                        )
+                        .expect("failed to create stop meeting item"),
+                    ]
+                }
+                None => vec![
+                    MenuItem::with_id(app, "cancel", &strings.cancel, true, None::<&str>)
+                        .expect("failed to create cancel item"),
                 ],
-            )
-            .expect("failed to create menu")
+            };
+            let separators = [separator(), separator(), separator(), separator()];
+
+            let mut items: Vec<&dyn IsMenuItem<tauri::Wry>> =
+                vec![&version_i, &separators[0], &show_hide_i];
+            items.extend(
+                session_items
+                    .iter()
+                    .map(|item| item as &dyn IsMenuItem<tauri::Wry>),
+            );
+            items.extend::<[&dyn IsMenuItem<tauri::Wry>; 8]>([
+                &separators[1],
+                &copy_last_transcript_i,
+                &recent_i,
+                &separators[2],
+                &settings_i,
+                &check_updates_i,
+                &separators[3],
+                &quit_i,
+            ]);
+            Menu::with_items(app, &items).expect("failed to create menu")
         }
         TrayIconState::Idle => Menu::with_items(
             app,
@@ -167,10 +251,32 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
                 &version_i,
                 &separator(),
                 &show_hide_i,
+                &start_meeting_i,
                 &separator(),
                 &copy_last_transcript_i,
+                &recent_i,
                 &unload_model_i,
                 &separator(),
+                &build_toggle_item(
+                    app,
+                    "toggle_push_to_talk",
+                    &strings.push_to_talk,
+                    settings.push_to_talk,
+                ),
+                &build_toggle_item(
+                    app,
+                    "toggle_post_processing",
+                    &strings.post_processing,
+                    settings.post_process_enabled,
+                ),
+                &build_toggle_item(
+                    app,
+                    "toggle_voice_commands",
+                    &strings.voice_commands,
+                    settings.voice_commands_enabled,
+                ),
+                &build_microphone_submenu(app, &strings, &settings),
+                &separator(),
                 &settings_i,
                 &check_updates_i,
                 &separator(),
@@ -180,13 +286,250 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         .expect("failed to create menu"),
     };
 
-    let tray = app.state::<TrayIcon>();
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     let _ = tray.set_menu(Some(menu));
     let _ = tray.set_icon_as_template(true);
 }
 
+fn build_toggle_item(
+    app: &AppHandle,
+    id: &str,
+    label: &str,
+    checked: bool,
+) -> CheckMenuItem<tauri::Wry> {
+    CheckMenuItem::with_id(app, id, label, true, checked, None::<&str>)
+        .expect("failed to create toggle item")
+}
+
+/// The last few transcriptions; clicking one copies it again.
+fn build_recent_submenu(app: &AppHandle, strings: &TrayStrings) -> Submenu<tauri::Wry> {
+    let submenu = Submenu::with_id(app, "recent_transcripts", &strings.recent_transcripts, true)
+        .expect("failed to create recent transcripts submenu");
+
+    let entries = match app
+        .state::<Arc<HistoryManager>>()
+        .get_recent_entries(RECENT_TRANSCRIPTS)
+    {
+        Ok(entries) => entries,
+        Err(err) => {
+            error!("Failed to fetch recent transcripts for tray: {}", err);
+            Vec::new()
+        }
+    };
+
+    let items: Vec<MenuItem<tauri::Wry>> = entries
+        .iter()
+        .filter_map(|entry| {
+            let label = recent_entry_label(entry)?;
+            MenuItem::with_id(
+                app,
+                format!("{}{}", RECENT_TRANSCRIPT_PREFIX, entry.id),
+                label,
+                true,
+                None::<&str>,
+            )
+            .ok()
+        })
+        .collect();
+
+    if items.is_empty() {
+        let empty_i = MenuItem::with_id(
+            app,
+            "no_recent_transcripts",
+            &strings.no_recent_transcripts,
+            false,
+            None::<&str>,
+        )
+        .expect("failed to create empty recent transcripts item");
+        let _ = submenu.append(&empty_i);
+    }
+    for item in &items {
+        let _ = submenu.append(item);
+    }
+    submenu
+}
+
+/// Input device picker, with "Default" first. Uses the cached device list.
+fn build_microphone_submenu(
+    app: &AppHandle,
+    strings: &TrayStrings,
+    settings: &AppSettings,
+) -> Submenu<tauri::Wry> {
+    let submenu = Submenu::with_id(app, "microphone", &strings.microphone, true)
+        .expect("failed to create microphone submenu");
+
+    let selected = settings.selected_microphone.as_deref();
+    let default_i = CheckMenuItem::with_id(
+        app,
+        format!("{}default", MICROPHONE_PREFIX),
+        &strings.default_microphone,
+        true,
+        selected.is_none(),
+        None::<&str>,
+    )
+    .expect("failed to create default microphone item");
+    let _ = submenu.append(&default_i);
+
+    let devices = INPUT_DEVICES.lock().unwrap().clone();
+    for name in devices {
+        let checked = selected == Some(name.as_str());
+        if let Ok(item) = CheckMenuItem::with_id(
+            app,
+            format!("{}{}", MICROPHONE_PREFIX, name),
+            escape_menu_label(&name),
+            true,
+            checked,
+            None::<&str>,
+        ) {
+            let _ = submenu.append(&item);
+        }
+    }
+    submenu
+}
+
+/// Re-list the input devices in the background, and rebuild the menu if
+/// they changed. Skipped until onboarding has been through the microphone
+/// permission check, so the tray never triggers the macOS permission prompt.
+pub fn refresh_input_devices(app: &AppHandle) {
+    if !settings::get_settings(app).microphone_check_completed {
+        return;
+    }
+
+    let app = app.clone();
+    thread::spawn(move || {
+        let names: Vec<String> = match list_input_devices() {
+            Ok(devices) => devices.into_iter().map(|device| device.name).collect(),
+            Err(err) => {
+                warn!("Failed to list input devices for tray: {}", err);
+                return;
+            }
+        };
+
+        let changed = {
+            let mut cached = INPUT_DEVICES.lock().unwrap();
+            if *cached == names {
+                false
+            } else {
+                *cached = names;
+                true
+            }
+        };
+        if changed {
+            update_tray_menu(&app, &TrayIconState::Idle, None);
+        }
+    });
+}
+
+/// Handle the quick actions: recent transcripts, meeting controls, setting
+/// toggles and the microphone picker. Other ids are ignored.
+pub fn handle_quick_action(app: &AppHandle, id: &str) {
+    let meeting_result = match id {
+        "start_meeting" => Some(meeting::start(app).map(|_| ())),
+        "pause_meeting" => Some(meeting::set_paused(app, true).map(|_| ())),
+        "resume_meeting" => Some(meeting::set_paused(app, false).map(|_| ())),
+        "stop_meeting" => Some(meeting::stop(app)),
+        _ => None,
+    };
+    if let Some(result) = meeting_result {
+        if let Err(err) = result {
+            warn!("Tray meeting action '{}' failed: {}", id, err);
+        }
+        return;
+    }
+
+    if let Some(entry_id) = id.strip_prefix(RECENT_TRANSCRIPT_PREFIX) {
+        if let Ok(entry_id) = entry_id.parse::<i64>() {
+            copy_transcript(app, entry_id);
+        }
+        return;
+    }
+
+    if let Some(device_name) = id.strip_prefix(MICROPHONE_PREFIX) {
+        match commands::audio::set_selected_microphone(app.clone(), device_name.to_string()) {
+            Ok(()) => {
+                info!("Microphone set to '{}' via tray.", device_name);
+                notify_setting_changed(app, "selected_microphone");
+            }
+            Err(err) => error!("Failed to set microphone via tray: {}", err),
+        }
+        return;
+    }
+
+    let settings = settings::get_settings(app);
+    let (setting, result) = match id {
+        "toggle_push_to_talk" => (
+            "push_to_talk",
+            shortcut::change_ptt_setting(app.clone(), !settings.push_to_talk),
+        ),
+        "toggle_post_processing" => (
+            "post_process_enabled",
+            shortcut::change_post_process_enabled_setting(
+                app.clone(),
+                !settings.post_process_enabled,
+            ),
+        ),
+        "toggle_voice_commands" => (
+            "voice_commands_enabled",
+            shortcut::change_voice_commands_setting(app.clone(), !settings.voice_commands_enabled),
+        ),
+        _ => return,
+    };
+    match result {
+        Ok(()) => notify_setting_changed(app, setting),
+        Err(err) => error!("Failed to toggle {} via tray: {}", setting, err),
+    }
+}
+
+/// Let the settings window reload, and rebuild the menu so check marks match.
+fn notify_setting_changed(app: &AppHandle, setting: &str) {
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({ "setting": setting, "source": "tray" }),
+    );
+    update_tray_menu(app, &TrayIconState::Idle, None);
+}
+
+fn copy_transcript(app: &AppHandle, entry_id: i64) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let history_manager = app.state::<Arc<HistoryManager>>();
+        let entry = match history_manager.get_entry_by_id(entry_id).await {
+            Ok(Some(entry)) => entry,
+            Ok(None) => {
+                warn!("Transcript {} is no longer in history.", entry_id);
+                return;
+            }
+            Err(err) => {
+                error!("Failed to fetch transcript {}: {}", entry_id, err);
+                return;
+            }
+        };
+
+        if let Err(err) = app.clipboard().write_text(last_transcript_text(&entry)) {
+            error!("Failed to copy transcript to clipboard: {}", err);
+            return;
+        }
+        info!("Copied transcript {} to clipboard via tray.", entry_id);
+    });
+}
+
+/// Menu label for a history entry, or `None` if it has no text.
+fn recent_entry_label(entry: &HistoryEntry) -> Option<String> {
+    let preview = first_line(last_transcript_text(entry), RECENT_LABEL_CHARS);
+    (!preview.is_empty()).then(|| escape_menu_label(&preview))
+}
+
+/// `&` marks a mnemonic in menu labels; double it to show it literally.
+fn escape_menu_label(label: &str) -> String {
+    label.replace('&', "&&")
+}
+
 pub fn set_tray_visibility(app: &AppHandle, visible: bool) {
-    let tray = app.state::<TrayIcon>();
+    let Some(tray) = app.try_state::<TrayIcon>() else {
+        return;
+    };
     if let Err(e) = tray.set_visible(visible) {
         error!("Failed to set tray visibility: {}", e);
     } else {
@@ -225,7 +568,7 @@ pub fn copy_last_transcript(app: &AppHandle) {
 
 #[cfg(test)]
 mod tests {
-    use super::last_transcript_text;
+    use super::{last_transcript_text, recent_entry_label};
     use crate::managers::history::HistoryEntry;
 
     fn build_entry(transcription: &str, post_processed: Option<&str>) -> HistoryEntry {
@@ -238,6 +581,10 @@ mod tests {
             transcription_text: transcription.to_string(),
             post_processed_text: post_processed.map(|text| text.to_string()),
             post_process_prompt: None,
+            audio_pruned: false,
+            post_process_steps: Vec::new(),
+            timings: None,
+            continued_from: None,
         }
     }
 
@@ -252,4 +599,11 @@ mod tests {
         let entry = build_entry("raw", None);
         assert_eq!(last_transcript_text(&entry), "raw");
     }
+
+    #[test]
+    fn recent_label_previews_first_line_and_escapes_mnemonics() {
+        let entry = build_entry("raw", Some("Tom & Jerry\nsecond line"));
+        assert_eq!(recent_entry_label(&entry).as_deref(), Some("Tom && Jerry…"));
+        assert_eq!(recent_entry_label(&build_entry("  ", None)), None);
+    }
 }
@@ -1,4 +1,5 @@
-//! Tray menu internationalization
+//! Tray menu internationalization (also used for the completion
+//! notifications in completion_notice.rs)
 //!
 //! Everything is auto-generated at compile time by build.rs from the
 //! frontend locale files (src/i18n/locales/*/translation.json).
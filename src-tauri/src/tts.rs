@@ -0,0 +1,403 @@
+//! Spoken feedback for eyes-free use: reads the final transcript, or the
+//! error that stopped a dictation, aloud through the OS speech synthesizer.
+//!
+//! Each platform's synthesizer is driven through its command-line front end,
+//! with the text written to stdin: `say` on macOS, speech-dispatcher
+//! (`spd-say`) or espeak on Linux, and System.Speech via PowerShell on
+//! Windows. A new utterance interrupts the previous one, and starting a
+//! recording silences it so it isn't transcribed.
+
+use crate::settings::{get_settings, AppSettings};
+use once_cell::sync::Lazy;
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use tauri::AppHandle;
+use tracing::{debug, warn};
+
+/// Slowest and fastest `tts_rate`, as a multiple of the normal speed.
+pub const MIN_RATE: f32 = 0.5;
+pub const MAX_RATE: f32 = 2.0;
+
+/// A speech synthesizer.
+trait SpeechBackend: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Command that speaks what is written to its stdin, in `voice` (the
+    /// system default if `None`) at `rate` times the normal speed.
+    fn command(&self, voice: Option<&str>, rate: f32) -> Command;
+
+    /// Names of the installed voices, as accepted by [`Self::command`].
+    fn voices(&self) -> Result<Vec<String>, String>;
+}
+
+/// The utterance being spoken, killed when the next one starts.
+static SPEAKING: Lazy<Mutex<Option<Child>>> = Lazy::new(|| Mutex::new(None));
+
+/// Read back a transcript that was just delivered. Private dictations are
+/// not read aloud, since anyone nearby would hear them.
+pub fn speak_transcript(app: &AppHandle, text: &str, private: bool) {
+    let settings = get_settings(app);
+    if !settings.tts_read_transcripts || private {
+        return;
+    }
+    speak_logged(&settings, text);
+}
+
+/// Read out an error that stopped a dictation.
+pub fn speak_error(app: &AppHandle, message: &str) {
+    let settings = get_settings(app);
+    if !settings.tts_read_errors {
+        return;
+    }
+    speak_logged(&settings, message);
+}
+
+fn speak_logged(settings: &AppSettings, text: &str) {
+    if let Err(e) = speak(settings, text) {
+        // Not reported as an app error: that would be read out in turn
+        warn!("Text-to-speech failed: {}", e);
+    }
+}
+
+/// Speak `text` with the configured voice and rate, interrupting anything
+/// still being spoken. Returns once the synthesizer has started.
+pub fn speak(settings: &AppSettings, text: &str) -> Result<(), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(());
+    }
+    let backend = select_backend().ok_or("No speech synthesizer is installed")?;
+
+    stop();
+    let rate = settings.tts_rate.clamp(MIN_RATE, MAX_RATE);
+    let mut child = backend
+        .command(settings.tts_voice.as_deref(), rate)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| format!("Failed to start {}: {}", backend.name(), e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // Dropping stdin closes it, which ends the utterance
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| format!("Failed to send text to {}: {}", backend.name(), e))?;
+    }
+    debug!("Speaking {} characters with {}", text.len(), backend.name());
+    *SPEAKING.lock().unwrap() = Some(child);
+    Ok(())
+}
+
+/// Silence the current utterance, if any.
+pub fn stop() {
+    if let Some(mut child) = SPEAKING.lock().unwrap().take() {
+        if let Ok(None) = child.try_wait() {
+            let _ = child.kill();
+        }
+        let _ = child.wait();
+    }
+}
+
+/// Voices the synthesizer offers, for the voice picker.
+pub fn list_voices() -> Result<Vec<String>, String> {
+    let backend = select_backend().ok_or("No speech synthesizer is installed")?;
+    let mut voices = backend.voices()?;
+    voices.sort();
+    voices.dedup();
+    Ok(voices)
+}
+
+fn select_backend() -> Option<Box<dyn SpeechBackend>> {
+    #[cfg(target_os = "macos")]
+    {
+        Some(Box::new(macos::Say))
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        linux::select()
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        Some(Box::new(windows::SystemSpeech))
+    }
+}
+
+/// Speaking rate in words per minute; 175 is the normal speed of both `say`
+/// and espeak.
+#[cfg_attr(target_os = "windows", allow(dead_code))]
+fn words_per_minute(rate: f32) -> u32 {
+    (175.0 * rate).round() as u32
+}
+
+/// `rate` on a scale of `-span..=span` around a normal speed of 0, as used
+/// by speech-dispatcher (±100) and System.Speech (±10).
+#[cfg_attr(target_os = "macos", allow(dead_code))]
+fn rate_offset(rate: f32, span: i32) -> i32 {
+    (((rate - 1.0) * span as f32).round() as i32).clamp(-span, span)
+}
+
+/// Output of a voice-listing command, or its stderr as the error.
+fn command_output(command: &mut Command, name: &str) -> Result<String, String> {
+    let output = command
+        .output()
+        .map_err(|e| format!("Failed to execute {}: {}", name, e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "{} failed: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Voice names from `say -v '?'`, whose lines look like
+/// `Eddy (English (US))    en_US    # Hello! My name is Eddy.`
+#[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+fn parse_say_voices(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let description = line.split('#').next()?.trim_end();
+            // The locale is the last column; the name before it may contain spaces
+            let (name, _locale) = description.rsplit_once(char::is_whitespace)?;
+            let name = name.trim();
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Voice names from `spd-say -L`: a header, then `NAME LANGUAGE VARIANT`
+/// where the name may contain spaces.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_spd_voices(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            (columns.len() > 2).then(|| columns[..columns.len() - 2].join(" "))
+        })
+        .collect()
+}
+
+/// Voice names from `espeak --voices`: a header, then columns of which the
+/// second, the language, is what `-v` takes.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_espeak_voices(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .skip(1)
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use super::{command_output, parse_say_voices, words_per_minute, SpeechBackend};
+    use std::process::Command;
+
+    pub struct Say;
+
+    impl SpeechBackend for Say {
+        fn name(&self) -> &'static str {
+            "say"
+        }
+
+        fn command(&self, voice: Option<&str>, rate: f32) -> Command {
+            let mut command = Command::new("say");
+            if let Some(voice) = voice {
+                command.args(["-v", voice]);
+            }
+            command.args(["-r", &words_per_minute(rate).to_string()]);
+            command
+        }
+
+        fn voices(&self) -> Result<Vec<String>, String> {
+            let output = command_output(Command::new("say").args(["-v", "?"]), "say")?;
+            Ok(parse_say_voices(&output))
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::{
+        command_output, parse_espeak_voices, parse_spd_voices, rate_offset, words_per_minute,
+        SpeechBackend,
+    };
+    use crate::input_backend::is_command_available;
+    use std::process::Command;
+
+    /// Speech-dispatcher if it is installed, as desktop screen readers share
+    /// it and its voice configuration, then espeak-ng and espeak.
+    pub fn select() -> Option<Box<dyn SpeechBackend>> {
+        if is_command_available("spd-say") {
+            return Some(Box::new(SpeechDispatcher));
+        }
+        ["espeak-ng", "espeak"]
+            .into_iter()
+            .find(|command| is_command_available(command))
+            .map(|command| Box::new(Espeak { command }) as Box<dyn SpeechBackend>)
+    }
+
+    pub struct SpeechDispatcher;
+
+    impl SpeechBackend for SpeechDispatcher {
+        fn name(&self) -> &'static str {
+            "spd-say"
+        }
+
+        fn command(&self, voice: Option<&str>, rate: f32) -> Command {
+            let mut command = Command::new("spd-say");
+            // Pipe mode reads the text from stdin; wait keeps the process
+            // alive until it's spoken, so killing it interrupts the speech
+            command.args(["--pipe-mode", "--wait"]);
+            if let Some(voice) = voice {
+                command.args(["--synthesis-voice", voice]);
+            }
+            command.args(["--rate", &rate_offset(rate, 100).to_string()]);
+            command
+        }
+
+        fn voices(&self) -> Result<Vec<String>, String> {
+            let output = command_output(
+                Command::new("spd-say").arg("--list-synthesis-voices"),
+                "spd-say",
+            )?;
+            Ok(parse_spd_voices(&output))
+        }
+    }
+
+    pub struct Espeak {
+        command: &'static str,
+    }
+
+    impl SpeechBackend for Espeak {
+        fn name(&self) -> &'static str {
+            self.command
+        }
+
+        fn command(&self, voice: Option<&str>, rate: f32) -> Command {
+            let mut command = Command::new(self.command);
+            command.arg("--stdin");
+            if let Some(voice) = voice {
+                command.args(["-v", voice]);
+            }
+            command.args(["-s", &words_per_minute(rate).to_string()]);
+            command
+        }
+
+        fn voices(&self) -> Result<Vec<String>, String> {
+            let output = command_output(Command::new(self.command).arg("--voices"), self.command)?;
+            Ok(parse_espeak_voices(&output))
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod windows {
+    use super::{command_output, rate_offset, SpeechBackend};
+    use std::os::windows::process::CommandExt;
+    use std::process::Command;
+
+    /// Keeps PowerShell from flashing a console window.
+    const CREATE_NO_WINDOW: u32 = 0x0800_0000;
+
+    /// The voice is passed in the environment rather than spliced into the
+    /// script, so its name needs no quoting.
+    const VOICE_ENV: &str = "VOICE_INPUT_TTS_VOICE";
+
+    pub struct SystemSpeech;
+
+    fn powershell(script: &str) -> Command {
+        let mut command = Command::new("powershell");
+        command
+            .args(["-NoProfile", "-NonInteractive", "-Command", script])
+            .creation_flags(CREATE_NO_WINDOW);
+        command
+    }
+
+    impl SpeechBackend for SystemSpeech {
+        fn name(&self) -> &'static str {
+            "System.Speech"
+        }
+
+        fn command(&self, voice: Option<&str>, rate: f32) -> Command {
+            let script = format!(
+                "Add-Type -AssemblyName System.Speech; \
+                 $s = New-Object System.Speech.Synthesis.SpeechSynthesizer; \
+                 $s.Rate = {}; \
+                 if ($env:{env}) {{ $s.SelectVoice($env:{env}) }}; \
+                 $s.Speak([Console]::In.ReadToEnd())",
+                rate_offset(rate, 10),
+                env = VOICE_ENV,
+            );
+            let mut command = powershell(&script);
+            if let Some(voice) = voice {
+                command.env(VOICE_ENV, voice);
+            }
+            command
+        }
+
+        fn voices(&self) -> Result<Vec<String>, String> {
+            let output = command_output(
+                &mut powershell(
+                    "Add-Type -AssemblyName System.Speech; \
+                     (New-Object System.Speech.Synthesis.SpeechSynthesizer).GetInstalledVoices() \
+                     | ForEach-Object { $_.VoiceInfo.Name }",
+                ),
+                "powershell",
+            )?;
+            Ok(output
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(str::to_string)
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_rate_to_each_scale() {
+        assert_eq!(words_per_minute(1.0), 175);
+        assert_eq!(words_per_minute(2.0), 350);
+        assert_eq!(rate_offset(1.0, 100), 0);
+        assert_eq!(rate_offset(0.5, 100), -50);
+        assert_eq!(rate_offset(1.5, 10), 5);
+        assert_eq!(rate_offset(3.0, 10), 10);
+    }
+
+    #[test]
+    fn parses_say_voices() {
+        let output = "Alex                en_US    # Most people recognize me by my voice.\n\
+                      Bad News            en_US    # The light you see at the end of the tunnel.\n\
+                      Eddy (English (US)) en_US    # Hello! My name is Eddy.\n";
+        assert_eq!(
+            parse_say_voices(output),
+            vec!["Alex", "Bad News", "Eddy (English (US))"]
+        );
+    }
+
+    #[test]
+    fn parses_linux_voice_lists() {
+        let spd = "NAME                 LANGUAGE    VARIANT\n\
+                   English (America)    en-US       none\n\
+                   Russian              ru          none\n";
+        assert_eq!(parse_spd_voices(spd), vec!["English (America)", "Russian"]);
+
+        let espeak = "Pty Language       Age/Gender VoiceName          File                 Other Languages\n \
+                      5  af              --/M      Afrikaans          gmw/af\n \
+                      5  en-us           --/M      English_(America)  gmw/en-US            (en 3)\n";
+        assert_eq!(parse_espeak_voices(espeak), vec!["af", "en-us"]);
+    }
+}
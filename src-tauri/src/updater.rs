@@ -0,0 +1,381 @@
+//! Self-update. Releases are published on two channels, each with its own
+//! `latest.json` manifest. A background task checks the selected channel
+//! every few hours (see `update_check_interval_hours`) and downloads what
+//! it finds; the download is installed when the user picks "Restart to
+//! update", or when the app quits.
+//!
+//! A manifest can hold an update back from part of the users with a
+//! `rollout` percentage. Every install has a fixed bucket from 0 to 99 and
+//! background checks only see the update once the rollout covers it;
+//! checking by hand always does.
+//!
+//! Every change is broadcast as an [`UpdateStatus`] in [`STATUS_EVENT`]
+//! events, including the release notes of an available update.
+
+use crate::settings::{get_settings, UpdateChannel};
+use crate::tray::{self, TrayIconState};
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use specta::Type;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Url};
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tracing::{debug, error, info, warn};
+
+/// Event carrying the current [`UpdateStatus`].
+pub const STATUS_EVENT: &str = "update-status";
+
+const STABLE_MANIFEST: &str =
+    "https://github.com/divnjl2/voice-input/releases/latest/download/latest.json";
+const BETA_MANIFEST: &str =
+    "https://github.com/divnjl2/voice-input/releases/download/beta/latest.json";
+
+/// Wait after launch before the first background check.
+const FIRST_CHECK_DELAY: Duration = Duration::from_secs(30);
+/// How often the background task looks at the schedule, so interval
+/// changes apply without a restart.
+const SCHEDULE_TICK: Duration = Duration::from_secs(60);
+
+/// A release newer than the running build.
+#[derive(Serialize, Clone, Debug, Type)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    pub channel: UpdateChannel,
+    /// Publication date, as given in the manifest.
+    pub date: Option<String>,
+    /// Release notes.
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Clone, Debug, Type)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum UpdateStatus {
+    Idle,
+    Checking,
+    UpToDate,
+    Available {
+        update: UpdateInfo,
+    },
+    Downloading {
+        update: UpdateInfo,
+        downloaded: u64,
+        total: Option<u64>,
+    },
+    /// Downloaded; installed on restart or quit.
+    Ready {
+        update: UpdateInfo,
+    },
+    Failed {
+        message: String,
+    },
+}
+
+struct Updater {
+    status: UpdateStatus,
+    /// The update offered by the last check.
+    update: Option<Update>,
+    /// Its downloaded package, once complete.
+    package: Option<Vec<u8>>,
+}
+
+static UPDATER: Lazy<Mutex<Updater>> = Lazy::new(|| {
+    Mutex::new(Updater {
+        status: UpdateStatus::Idle,
+        update: None,
+        package: None,
+    })
+});
+
+pub fn status() -> UpdateStatus {
+    UPDATER.lock().unwrap().status.clone()
+}
+
+/// Version of the update waiting to be installed, if any.
+pub fn ready_version() -> Option<String> {
+    match &UPDATER.lock().unwrap().status {
+        UpdateStatus::Ready { update } => Some(update.version.clone()),
+        _ => None,
+    }
+}
+
+fn set_status(app: &AppHandle, status: UpdateStatus) {
+    UPDATER.lock().unwrap().status = status.clone();
+    let _ = app.emit(STATUS_EVENT, status);
+}
+
+fn manifest_url(channel: UpdateChannel) -> &'static str {
+    match channel {
+        UpdateChannel::Stable => STABLE_MANIFEST,
+        UpdateChannel::Beta => BETA_MANIFEST,
+    }
+}
+
+/// Start the background checks.
+pub fn start(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(FIRST_CHECK_DELAY).await;
+        let mut last_check: Option<Instant> = None;
+        loop {
+            let settings = get_settings(&app);
+            let interval =
+                Duration::from_secs(u64::from(settings.update_check_interval_hours.max(1)) * 3600);
+            let due = match last_check {
+                Some(at) => at.elapsed() >= interval,
+                None => true,
+            };
+            if settings.update_checks_enabled && due {
+                last_check = Some(Instant::now());
+                check_in_background(&app).await;
+            }
+            tokio::time::sleep(SCHEDULE_TICK).await;
+        }
+    });
+}
+
+/// Check, and download whatever is found.
+async fn check_in_background(app: &AppHandle) {
+    match check(app, false).await {
+        Ok(Some(_)) => {
+            if let Err(e) = download(app).await {
+                warn!("Update download failed: {}", e);
+            }
+        }
+        Ok(None) => {}
+        Err(e) => warn!("Update check failed: {}", e),
+    }
+}
+
+/// Ask the selected channel for a newer release. `manual` checks ignore
+/// the staged rollout.
+pub async fn check(app: &AppHandle, manual: bool) -> Result<Option<UpdateInfo>, String> {
+    let settings = get_settings(app);
+    if !settings.update_checks_enabled {
+        return Err("Update checks are disabled".to_string());
+    }
+    // A download in progress or done stays until it is installed
+    match status() {
+        UpdateStatus::Downloading { update, .. } | UpdateStatus::Ready { update } => {
+            return Ok(Some(update))
+        }
+        _ => {}
+    }
+
+    set_status(app, UpdateStatus::Checking);
+    let channel = settings.update_channel;
+    let found = match find_update(app, channel).await {
+        Ok(found) => found,
+        Err(e) => {
+            set_status(app, UpdateStatus::Failed { message: e.clone() });
+            return Err(e);
+        }
+    };
+
+    let held_back = found.as_ref().is_some_and(|update| {
+        let bucket = rollout_bucket(app);
+        let rollout = rollout_percent(&update.raw_json);
+        let held_back = !manual && bucket >= rollout;
+        if held_back {
+            debug!(
+                "Update {} is rolled out to {}% of installs, not bucket {} yet",
+                update.version, rollout, bucket
+            );
+        }
+        held_back
+    });
+    let update = found.filter(|_| !held_back);
+
+    let info = update.as_ref().map(|update| UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        channel,
+        date: update
+            .raw_json
+            .get("pub_date")
+            .and_then(|date| date.as_str())
+            .map(str::to_string),
+        notes: update.body.clone().filter(|notes| !notes.trim().is_empty()),
+    });
+    {
+        let mut updater = UPDATER.lock().unwrap();
+        updater.update = update;
+        updater.package = None;
+    }
+    match &info {
+        Some(info) => {
+            info!(
+                "Update {} available on the {:?} channel",
+                info.version, channel
+            );
+            set_status(
+                app,
+                UpdateStatus::Available {
+                    update: info.clone(),
+                },
+            );
+        }
+        None => set_status(app, UpdateStatus::UpToDate),
+    }
+    Ok(info)
+}
+
+async fn find_update(app: &AppHandle, channel: UpdateChannel) -> Result<Option<Update>, String> {
+    let url = Url::parse(manifest_url(channel)).map_err(|e| e.to_string())?;
+    app.updater_builder()
+        .endpoints(vec![url])
+        .and_then(|builder| builder.build())
+        .map_err(|e| format!("Could not set up the updater: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Could not check for updates: {}", e))
+}
+
+/// Download the update found by the last check, with progress in
+/// [`STATUS_EVENT`] events.
+pub async fn download(app: &AppHandle) -> Result<(), String> {
+    let (update, info) = {
+        let updater = UPDATER.lock().unwrap();
+        match (&updater.update, &updater.status) {
+            (Some(update), UpdateStatus::Available { update: info }) => {
+                (update.clone(), info.clone())
+            }
+            (_, UpdateStatus::Downloading { .. }) | (_, UpdateStatus::Ready { .. }) => {
+                return Ok(())
+            }
+            _ => return Err("No update to download".to_string()),
+        }
+    };
+
+    set_status(
+        app,
+        UpdateStatus::Downloading {
+            update: info.clone(),
+            downloaded: 0,
+            total: None,
+        },
+    );
+    let mut downloaded = 0u64;
+    let mut reported_percent = 0;
+    let result = update
+        .download(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                // One event per percent is plenty for a progress bar
+                let percent = total.map_or(0, |total| downloaded * 100 / total.max(1));
+                if percent != reported_percent {
+                    reported_percent = percent;
+                    set_status(
+                        app,
+                        UpdateStatus::Downloading {
+                            update: info.clone(),
+                            downloaded,
+                            total,
+                        },
+                    );
+                }
+            },
+            || {},
+        )
+        .await;
+
+    match result {
+        Ok(package) => {
+            UPDATER.lock().unwrap().package = Some(package);
+            info!("Update {} downloaded; it installs on restart", info.version);
+            set_status(app, UpdateStatus::Ready { update: info });
+            tray::update_tray_menu(app, &TrayIconState::Idle, None);
+            Ok(())
+        }
+        Err(e) => {
+            let message = format!("Could not download the update: {}", e);
+            // Back to available, so the download can be retried
+            set_status(app, UpdateStatus::Available { update: info });
+            Err(message)
+        }
+    }
+}
+
+/// Install the downloaded update and restart into it.
+pub fn apply(app: &AppHandle) -> Result<(), String> {
+    if !install()? {
+        return Err("No update has been downloaded".to_string());
+    }
+    app.restart();
+}
+
+/// Install a downloaded update before the app exits.
+pub fn install_on_exit() {
+    if let Err(e) = install() {
+        error!("{}", e);
+    }
+}
+
+/// Install the downloaded update, if there is one.
+fn install() -> Result<bool, String> {
+    let (update, package) = {
+        let mut updater = UPDATER.lock().unwrap();
+        match (updater.update.clone(), updater.package.take()) {
+            (Some(update), Some(package)) => (update, package),
+            _ => return Ok(false),
+        }
+    };
+    info!("Installing update {}", update.version);
+    update
+        .install(package)
+        .map_err(|e| format!("Could not install update {}: {}", update.version, e))?;
+    Ok(true)
+}
+
+/// Share of installs, in percent, that a manifest offers its update to.
+/// Without a `rollout` field it goes to everyone.
+fn rollout_percent(manifest: &serde_json::Value) -> u64 {
+    manifest
+        .get("rollout")
+        .and_then(|rollout| rollout.as_u64())
+        .map_or(100, |rollout| rollout.min(100))
+}
+
+/// This install's place in a staged rollout, from 0 to 99. Derived from the
+/// random install id, which stays put across updates and app data moves.
+fn rollout_bucket(app: &AppHandle) -> u64 {
+    fnv1a(crate::settings::install_id(app).as_bytes()) % 100
+}
+
+/// FNV-1a; unlike the std hasher its output never changes between builds,
+/// so an install keeps its bucket across updates.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn rollout_defaults_to_everyone() {
+        assert_eq!(rollout_percent(&json!({ "version": "1.2.0" })), 100);
+        assert_eq!(rollout_percent(&json!({ "rollout": 25 })), 25);
+        assert_eq!(rollout_percent(&json!({ "rollout": 250 })), 100);
+        assert_eq!(rollout_percent(&json!({ "rollout": "half" })), 100);
+    }
+
+    #[test]
+    fn buckets_are_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(fnv1a(b"3f2a9c0d41b7e856"), fnv1a(b"3f2a9c0d41b7e856"));
+    }
+
+    #[test]
+    fn channels_have_their_own_manifest() {
+        assert_ne!(
+            manifest_url(UpdateChannel::Stable),
+            manifest_url(UpdateChannel::Beta)
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::transcription::TranscriptionManager;
+use crate::meeting;
 use crate::shortcut;
 use crate::TranscriptionCoordinator;
-use log::info;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tauri::{AppHandle, Manager};
+use tracing::info;
 
 // Re-export all utility modules for easy access
 // pub use crate::audio_feedback::*;
@@ -12,9 +14,27 @@ pub use crate::clipboard::*;
 pub use crate::overlay::*;
 pub use crate::tray::*;
 
+static HEADLESS: AtomicBool = AtomicBool::new(false);
+
+/// Whether the app runs without tray icon, overlay and settings windows.
+pub fn is_headless() -> bool {
+    HEADLESS.load(Ordering::Relaxed)
+}
+
+pub fn set_headless(headless: bool) {
+    HEADLESS.store(headless, Ordering::Relaxed);
+}
+
 /// Centralized cancellation function that can be called from anywhere in the app.
 /// Handles cancelling both recording and transcription operations and updates UI state.
 pub fn cancel_current_operation(app: &AppHandle) {
+    // A meeting is ended rather than thrown away; its notes are kept
+    if meeting::is_active() {
+        info!("Cancel requested during a meeting, stopping it");
+        let _ = meeting::stop(app);
+        return;
+    }
+
     info!("Initiating operation cancellation...");
 
     // Unregister the cancel shortcut asynchronously
@@ -25,6 +45,9 @@ pub fn cancel_current_operation(app: &AppHandle) {
     let recording_was_active = audio_manager.is_recording();
     audio_manager.cancel_recording();
 
+    // Text held for confirmation is thrown away along with it
+    crate::confirmation::discard();
+
     // Update tray icon and hide overlay
     change_tray_icon(app, crate::tray::TrayIconState::Idle);
     hide_recording_overlay(app);
@@ -64,3 +87,78 @@ pub fn is_kde_plasma() -> bool {
 pub fn is_kde_wayland() -> bool {
     is_wayland() && is_kde_plasma()
 }
+
+/// Name of the application that currently has keyboard focus, if it can be determined.
+#[cfg(target_os = "macos")]
+pub fn get_focused_app_name() -> Option<String> {
+    let output = std::process::Command::new("osascript")
+        .args([
+            "-e",
+            "tell application \"System Events\" to get name of first application process whose frontmost is true",
+        ])
+        .output()
+        .ok()?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
+
+/// Name of the application that currently has keyboard focus, if it can be determined.
+#[cfg(target_os = "windows")]
+pub fn get_focused_app_name() -> Option<String> {
+    use windows::core::PWSTR;
+    use windows::Win32::Foundation::CloseHandle;
+    use windows::Win32::System::Threading::{
+        OpenProcess, QueryFullProcessImageNameW, PROCESS_NAME_WIN32,
+        PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    unsafe {
+        let hwnd = GetForegroundWindow();
+        let mut pid = 0u32;
+        GetWindowThreadProcessId(hwnd, Some(&mut pid));
+        if pid == 0 {
+            return None;
+        }
+        let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+        let mut buffer = [0u16; 1024];
+        let mut len = buffer.len() as u32;
+        let result = QueryFullProcessImageNameW(
+            process,
+            PROCESS_NAME_WIN32,
+            PWSTR(buffer.as_mut_ptr()),
+            &mut len,
+        );
+        let _ = CloseHandle(process);
+        result.ok()?;
+        let path = String::from_utf16_lossy(&buffer[..len as usize]);
+        std::path::Path::new(&path)
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+    }
+}
+
+/// Name of the application that currently has keyboard focus, if it can be determined.
+/// Only X11 is supported; Wayland compositors don't expose the focused window.
+#[cfg(target_os = "linux")]
+pub fn get_focused_app_name() -> Option<String> {
+    if is_wayland() {
+        return None;
+    }
+    let output = std::process::Command::new("xdotool")
+        .args(["getactivewindow", "getwindowpid"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let pid = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let name = std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
+    let name = name.trim().to_string();
+    (!name.is_empty()).then_some(name)
+}
+
+#[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+pub fn get_focused_app_name() -> Option<String> {
+    None
+}
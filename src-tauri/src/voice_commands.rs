@@ -6,9 +6,10 @@
 //! Instead of pasting text, voice commands execute keyboard actions like
 //! pressing Enter, deleting text, selecting all, etc.
 
-use log::debug;
+use crate::log_redaction;
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use tracing::debug;
 
 /// Represents a keyboard action to execute
 #[derive(Debug, Clone, PartialEq)]
@@ -84,6 +85,41 @@ fn normalize(text: &str) -> String {
     result
 }
 
+/// Phrases of the "new paragraph" command, which also works with voice
+/// commands off while continue-dictation is on.
+const NEW_PARAGRAPH: &[&str] = &[
+    "new paragraph",
+    "next paragraph",
+    "новый абзац",
+    "с нового абзаца",
+];
+
+/// Answers to a dictation held for confirmation, checked before the
+/// command map so "send it" sends the held text instead of pressing Enter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmCommand {
+    Send,
+    Discard,
+}
+
+const CONFIRM_SEND: &[&str] = &[
+    "send it",
+    "send",
+    "accept",
+    "отправить",
+    "отправь",
+    "отправляй",
+];
+
+const CONFIRM_DISCARD: &[&str] = &[
+    "discard",
+    "discard it",
+    "cancel",
+    "отмена",
+    "отменить",
+    "удалить",
+];
+
 /// Static command map: normalized phrase -> VoiceCommand
 static COMMAND_MAP: Lazy<HashMap<String, VoiceCommand>> = Lazy::new(|| {
     let mut map = HashMap::new();
@@ -127,6 +163,13 @@ static COMMAND_MAP: Lazy<HashMap<String, VoiceCommand>> = Lazy::new(|| {
         "Press Enter",
     );
 
+    // ── New paragraph (also ends a continued dictation block) ─────────
+    add(
+        NEW_PARAGRAPH,
+        VoiceAction::TypeText("\n\n".to_string()),
+        "New Paragraph",
+    );
+
     // ── Backspace ─────────────────────────────────────────────────────
     add(
         &[
@@ -352,7 +395,8 @@ static COMMAND_MAP: Lazy<HashMap<String, VoiceCommand>> = Lazy::new(|| {
 /// The matching is exact (after normalization): the entire transcribed text
 /// must match a known command phrase. This prevents false positives when
 /// the user is dictating normal text that happens to contain command words.
-pub fn check_voice_command(text: &str) -> VoiceCommandResult {
+/// In a `private` session the text is redacted from logs.
+pub fn check_voice_command(text: &str, private: bool) -> VoiceCommandResult {
     let normalized = normalize(text);
 
     // Strip trailing period/comma that Whisper sometimes adds
@@ -363,8 +407,9 @@ pub fn check_voice_command(text: &str) -> VoiceCommandResult {
 
     if let Some(cmd) = COMMAND_MAP.get(stripped) {
         debug!(
-            "Voice command recognized: '{}' -> {}",
-            text, cmd.description
+            "Voice command recognized: {} -> {}",
+            log_redaction::session_text(text, private),
+            cmd.description
         );
         return VoiceCommandResult::Command(cmd.clone());
     }
@@ -373,8 +418,9 @@ pub fn check_voice_command(text: &str) -> VoiceCommandResult {
     if stripped != normalized {
         if let Some(cmd) = COMMAND_MAP.get(normalized.as_str()) {
             debug!(
-                "Voice command recognized (with punctuation): '{}' -> {}",
-                text, cmd.description
+                "Voice command recognized (with punctuation): {} -> {}",
+                log_redaction::session_text(text, private),
+                cmd.description
             );
             return VoiceCommandResult::Command(cmd.clone());
         }
@@ -383,6 +429,30 @@ pub fn check_voice_command(text: &str) -> VoiceCommandResult {
     VoiceCommandResult::Text(text.to_string())
 }
 
+/// Whether the whole text is one of `phrases`, ignoring case and a
+/// trailing period or comma.
+fn is_phrase(text: &str, phrases: &[&str]) -> bool {
+    let normalized = normalize(text);
+    let stripped = normalized.trim_end_matches(['.', ',', '!']).trim();
+    phrases.iter().any(|phrase| normalize(phrase) == stripped)
+}
+
+/// Whether the text is just the "new paragraph" command.
+pub fn is_new_paragraph(text: &str) -> bool {
+    is_phrase(text, NEW_PARAGRAPH)
+}
+
+/// The confirmation answer the text is, if any.
+pub fn check_confirm_command(text: &str) -> Option<ConfirmCommand> {
+    if is_phrase(text, CONFIRM_SEND) {
+        Some(ConfirmCommand::Send)
+    } else if is_phrase(text, CONFIRM_DISCARD) {
+        Some(ConfirmCommand::Discard)
+    } else {
+        None
+    }
+}
+
 /// Get a list of all available voice commands with descriptions.
 /// Useful for UI display / help.
 pub fn list_commands() -> Vec<(String, &'static str)> {
@@ -403,7 +473,7 @@ mod tests {
 
     #[test]
     fn test_enter_command_english() {
-        match check_voice_command("press enter") {
+        match check_voice_command("press enter", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -413,7 +483,7 @@ mod tests {
 
     #[test]
     fn test_enter_command_russian() {
-        match check_voice_command("нажми ввод") {
+        match check_voice_command("нажми ввод", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -423,7 +493,7 @@ mod tests {
 
     #[test]
     fn test_new_line() {
-        match check_voice_command("new line") {
+        match check_voice_command("new line", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -433,7 +503,7 @@ mod tests {
 
     #[test]
     fn test_delete_all_english() {
-        match check_voice_command("delete all") {
+        match check_voice_command("delete all", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(
                     cmd.action,
@@ -450,7 +520,7 @@ mod tests {
 
     #[test]
     fn test_delete_all_russian() {
-        match check_voice_command("сотри все") {
+        match check_voice_command("сотри все", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Delete All (Ctrl+A, Delete)");
             }
@@ -461,7 +531,7 @@ mod tests {
     #[test]
     fn test_delete_all_russian_yo() {
         // Test with ё variant
-        match check_voice_command("сотри всё") {
+        match check_voice_command("сотри всё", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Delete All (Ctrl+A, Delete)");
             }
@@ -473,7 +543,7 @@ mod tests {
 
     #[test]
     fn test_case_insensitive() {
-        match check_voice_command("Press Enter") {
+        match check_voice_command("Press Enter", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -483,7 +553,7 @@ mod tests {
 
     #[test]
     fn test_all_caps() {
-        match check_voice_command("SELECT ALL") {
+        match check_voice_command("SELECT ALL", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(
                     cmd.action,
@@ -498,7 +568,7 @@ mod tests {
 
     #[test]
     fn test_extra_whitespace() {
-        match check_voice_command("  press   enter  ") {
+        match check_voice_command("  press   enter  ", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -511,7 +581,7 @@ mod tests {
     #[test]
     fn test_trailing_period() {
         // Whisper sometimes adds a period at the end
-        match check_voice_command("press enter.") {
+        match check_voice_command("press enter.", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -521,7 +591,7 @@ mod tests {
 
     #[test]
     fn test_trailing_comma() {
-        match check_voice_command("undo,") {
+        match check_voice_command("undo,", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(
                     cmd.action,
@@ -536,7 +606,7 @@ mod tests {
 
     #[test]
     fn test_normal_text_not_command() {
-        match check_voice_command("I want to press enter to continue") {
+        match check_voice_command("I want to press enter to continue", false) {
             VoiceCommandResult::Text(text) => {
                 assert_eq!(text, "I want to press enter to continue");
             }
@@ -546,7 +616,7 @@ mod tests {
 
     #[test]
     fn test_empty_text() {
-        match check_voice_command("") {
+        match check_voice_command("", false) {
             VoiceCommandResult::Text(text) => {
                 assert_eq!(text, "");
             }
@@ -557,7 +627,7 @@ mod tests {
     #[test]
     fn test_partial_match_not_command() {
         // "delete" alone is a command, but "delete the file" is not
-        match check_voice_command("delete the file") {
+        match check_voice_command("delete the file", false) {
             VoiceCommandResult::Text(_) => {} // expected
             VoiceCommandResult::Command(_) => panic!("Should not match partial text"),
         }
@@ -567,7 +637,7 @@ mod tests {
 
     #[test]
     fn test_undo_command() {
-        match check_voice_command("undo") {
+        match check_voice_command("undo", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(
                     cmd.action,
@@ -580,7 +650,7 @@ mod tests {
 
     #[test]
     fn test_redo_command() {
-        match check_voice_command("redo") {
+        match check_voice_command("redo", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(
                     cmd.action,
@@ -593,7 +663,7 @@ mod tests {
 
     #[test]
     fn test_copy_command() {
-        match check_voice_command("copy") {
+        match check_voice_command("copy", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Copy (Ctrl+C)");
             }
@@ -603,7 +673,7 @@ mod tests {
 
     #[test]
     fn test_save_command() {
-        match check_voice_command("save") {
+        match check_voice_command("save", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Save (Ctrl+S)");
             }
@@ -613,7 +683,7 @@ mod tests {
 
     #[test]
     fn test_tab_command() {
-        match check_voice_command("tab") {
+        match check_voice_command("tab", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Tab));
             }
@@ -623,7 +693,7 @@ mod tests {
 
     #[test]
     fn test_backspace_command() {
-        match check_voice_command("backspace") {
+        match check_voice_command("backspace", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Backspace));
             }
@@ -633,7 +703,7 @@ mod tests {
 
     #[test]
     fn test_delete_word_russian() {
-        match check_voice_command("удали слово") {
+        match check_voice_command("удали слово", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Delete Word (Ctrl+Backspace)");
             }
@@ -643,14 +713,14 @@ mod tests {
 
     #[test]
     fn test_arrow_keys() {
-        match check_voice_command("up") {
+        match check_voice_command("up", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Up));
             }
             VoiceCommandResult::Text(_) => panic!("Expected command"),
         }
 
-        match check_voice_command("вниз") {
+        match check_voice_command("вниз", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Down));
             }
@@ -658,23 +728,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_new_paragraph() {
+        match check_voice_command("New paragraph.", false) {
+            VoiceCommandResult::Command(cmd) => {
+                assert_eq!(cmd.action, VoiceAction::TypeText("\n\n".to_string()));
+            }
+            VoiceCommandResult::Text(_) => panic!("Expected command"),
+        }
+        assert!(is_new_paragraph("Новый абзац"));
+        assert!(!is_new_paragraph("a new paragraph about cats"));
+    }
+
+    #[test]
+    fn test_confirm_commands() {
+        assert_eq!(
+            check_confirm_command("Send it!"),
+            Some(ConfirmCommand::Send)
+        );
+        assert_eq!(
+            check_confirm_command("Отправь."),
+            Some(ConfirmCommand::Send)
+        );
+        assert_eq!(
+            check_confirm_command("Discard."),
+            Some(ConfirmCommand::Discard)
+        );
+        assert_eq!(check_confirm_command("send it to Anna"), None);
+    }
+
     #[test]
     fn test_punctuation_commands() {
-        match check_voice_command("period") {
+        match check_voice_command("period", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::TypeText(".".to_string()));
             }
             VoiceCommandResult::Text(_) => panic!("Expected command"),
         }
 
-        match check_voice_command("запятая") {
+        match check_voice_command("запятая", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::TypeText(",".to_string()));
             }
             VoiceCommandResult::Text(_) => panic!("Expected command"),
         }
 
-        match check_voice_command("question mark") {
+        match check_voice_command("question mark", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::TypeText("?".to_string()));
             }
@@ -686,7 +785,7 @@ mod tests {
 
     #[test]
     fn test_russian_new_line() {
-        match check_voice_command("новая строка") {
+        match check_voice_command("новая строка", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.action, VoiceAction::KeyPress(KeyAction::Enter));
             }
@@ -696,7 +795,7 @@ mod tests {
 
     #[test]
     fn test_russian_select_all() {
-        match check_voice_command("выдели все") {
+        match check_voice_command("выдели все", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Select All (Ctrl+A)");
             }
@@ -706,7 +805,7 @@ mod tests {
 
     #[test]
     fn test_russian_undo() {
-        match check_voice_command("отмени") {
+        match check_voice_command("отмени", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Undo (Ctrl+Z)");
             }
@@ -716,7 +815,7 @@ mod tests {
 
     #[test]
     fn test_russian_copy() {
-        match check_voice_command("скопируй") {
+        match check_voice_command("скопируй", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Copy (Ctrl+C)");
             }
@@ -726,7 +825,7 @@ mod tests {
 
     #[test]
     fn test_russian_paste() {
-        match check_voice_command("вставь") {
+        match check_voice_command("вставь", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Paste (Ctrl+V)");
             }
@@ -736,7 +835,7 @@ mod tests {
 
     #[test]
     fn test_russian_save() {
-        match check_voice_command("сохрани") {
+        match check_voice_command("сохрани", false) {
             VoiceCommandResult::Command(cmd) => {
                 assert_eq!(cmd.description, "Save (Ctrl+S)");
             }
@@ -1,5 +1,6 @@
 import { useEffect, useState, useRef } from "react";
-import { Toaster } from "sonner";
+import { Toaster, toast } from "sonner";
+import { listen } from "@tauri-apps/api/event";
 import { useTranslation } from "react-i18next";
 import { platform } from "@tauri-apps/plugin-os";
 import {
@@ -18,6 +19,16 @@ import { getLanguageDirection, initializeRTL } from "@/lib/utils/rtl";
 
 type OnboardingStep = "accessibility" | "model" | "done";
 
+interface AppErrorEvent {
+  kind: string;
+  message: string;
+  [field: string]: unknown;
+}
+
+// "paste_failed" -> "pasteFailed"
+const errorKey = (kind: string) =>
+  kind.replace(/_([a-z])/g, (_, c: string) => c.toUpperCase());
+
 const renderSettingsContent = (section: SidebarSection) => {
   const ActiveComponent =
     SECTIONS_CONFIG[section]?.component || SECTIONS_CONFIG.general.component;
@@ -25,7 +36,7 @@ const renderSettingsContent = (section: SidebarSection) => {
 };
 
 function App() {
-  const { i18n } = useTranslation();
+  const { t, i18n } = useTranslation();
   const [onboardingStep, setOnboardingStep] = useState<OnboardingStep | null>(
     null,
   );
@@ -65,8 +76,33 @@ function App() {
       });
       refreshAudioDevices();
       refreshOutputDevices();
+      commands.getSettingsLoadErrors().then((result) => {
+        if (result.status === "ok" && result.data.length > 0) {
+          toast.warning(
+            t("errors.settingsReset", {
+              fields: result.data.map((e) => e.field).join(", "),
+            }),
+          );
+        }
+      });
     }
-  }, [onboardingStep, refreshAudioDevices, refreshOutputDevices]);
+  }, [onboardingStep, refreshAudioDevices, refreshOutputDevices, t]);
+
+  // Surface pipeline errors (model, microphone, paste, LLM) as toasts
+  useEffect(() => {
+    const unlisten = listen<AppErrorEvent>("app-error", (event) => {
+      const { kind, message } = event.payload;
+      toast.error(
+        t(`errors.${errorKey(kind)}`, {
+          ...event.payload,
+          defaultValue: message,
+        }),
+      );
+    });
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [t]);
 
   // Handle keyboard shortcuts for debug mode toggle
   useEffect(() => {
@@ -29,6 +29,22 @@ async changePttSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changeHotkeyGesturesSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_hotkey_gestures_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeBusyBehaviorSetting(behavior: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_busy_behavior_setting", { behavior }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAudioFeedbackSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_audio_feedback_setting", { enabled }) };
@@ -61,6 +77,14 @@ async changeStartHiddenSetting(enabled: boolean) : Promise<Result<null, string>>
     else return { status: "error", error: e  as any };
 }
 },
+async changeHeadlessSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_headless_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeAutostartSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_autostart_setting", { enabled }) };
@@ -93,6 +117,14 @@ async changeOverlayPositionSetting(position: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
+async changeOverlayLayoutSetting(layout: OverlayLayout) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_overlay_layout_setting", { layout }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeDebugModeSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_debug_mode_setting", { enabled }) };
@@ -117,6 +149,22 @@ async changePasteMethodSetting(method: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changePerCharacterDelaySetting(delayMs: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_per_character_delay_setting", { delayMs }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changePasteFallbackSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_paste_fallback_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_clipboard_handling_setting", { handling }) };
@@ -125,6 +173,22 @@ async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, st
     else return { status: "error", error: e  as any };
 }
 },
+async changeSelectionTargetSetting(target: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_selection_target_setting", { target }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeMiddleClickPasteSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_middle_click_paste_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_enabled_setting", { enabled }) };
@@ -149,6 +213,18 @@ async changePostProcessBaseUrlSetting(providerId: string, baseUrl: string) : Pro
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set the `api-version` of an Azure OpenAI provider; empty restores the
+ * default.
+ */
+async changePostProcessApiVersionSetting(providerId: string, apiVersion: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_post_process_api_version_setting", { providerId, apiVersion }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_api_key_setting", { providerId, apiKey }) };
@@ -157,6 +233,25 @@ async changePostProcessApiKeySetting(providerId: string, apiKey: string) : Promi
     else return { status: "error", error: e  as any };
 }
 },
+async getPostProcessApiKey(providerId: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_post_process_api_key", { providerId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Validate a provider's base URL, API key and model without running a dictation.
+ */
+async checkPostProcessProvider(providerId: string, model: string | null) : Promise<Result<ProviderHealthReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_post_process_provider", { providerId, model }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessModelSetting(providerId: string, model: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_model_setting", { providerId, model }) };
@@ -197,6 +292,18 @@ async updatePostProcessPrompt(id: string, name: string, prompt: string) : Promis
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set a prompt's overrides of the provider's model and request parameters;
+ * `None` or an empty string clears an override.
+ */
+async updatePostProcessPromptOptions(id: string, model: string | null, temperature: number | null, maxTokens: number | null, systemMessage: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_post_process_prompt_options", { id, model, temperature, maxTokens, systemMessage }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async deletePostProcessPrompt(id: string) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("delete_post_process_prompt", { id }) };
@@ -213,6 +320,18 @@ async setPostProcessSelectedPrompt(id: string) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Set the ordered list of prompts run as a post-processing chain.
+ * An empty list runs only the selected prompt.
+ */
+async setPostProcessChain(promptIds: string[]) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_post_process_chain", { promptIds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async updateCustomWords(words: string[]) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("update_custom_words", { words }) };
@@ -260,340 +379,658 @@ async changeAppendTrailingSpaceSetting(enabled: boolean) : Promise<Result<null,
     else return { status: "error", error: e  as any };
 }
 },
-async changeAppLanguageSetting(language: string) : Promise<Result<null, string>> {
+async changeCompletionNotificationsSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_app_language_setting", { language }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_completion_notifications_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeContinueDictationSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_update_checks_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_continue_dictation_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Change the keyboard implementation with runtime switching.
- * This will unregister all shortcuts from the old implementation,
- * validate shortcuts for the new implementation (resetting invalid ones to defaults),
- * and register them with the new implementation.
- */
-async changeKeyboardImplementationSetting(implementation: string) : Promise<Result<ImplementationChangeResult, string>> {
+async changeContinueDictationWindowSetting(seconds: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_keyboard_implementation_setting", { implementation }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_continue_dictation_window_setting", { seconds }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Get the current keyboard implementation
- */
-async getKeyboardImplementation() : Promise<string> {
-    return await TAURI_INVOKE("get_keyboard_implementation");
-},
-async changeVoiceCommandsSetting(enabled: boolean) : Promise<Result<null, string>> {
+async changeTtsReadTranscriptsSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_setting", { enabled }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_read_transcripts_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Start key recording mode
- */
-async startHandyKeysRecording(bindingId: string) : Promise<Result<null, string>> {
+async changeTtsReadErrorsSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("start_handy_keys_recording", { bindingId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_read_errors_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Stop key recording mode
- */
-async stopHandyKeysRecording() : Promise<Result<null, string>> {
+async changeTtsVoiceSetting(voice: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("stop_handy_keys_recording") };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_voice_setting", { voice }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async triggerUpdateCheck() : Promise<Result<null, string>> {
+async changeTtsRateSetting(rate: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("trigger_update_check") };
+    return { status: "ok", data: await TAURI_INVOKE("change_tts_rate_setting", { rate }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelOperation() : Promise<void> {
-    await TAURI_INVOKE("cancel_operation");
-},
-async getAppDirPath() : Promise<Result<string, string>> {
+async changeConfirmBeforePasteSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_dir_path") };
+    return { status: "ok", data: await TAURI_INVOKE("change_confirm_before_paste_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAppSettings() : Promise<Result<AppSettings, string>> {
+async changeHallucinationFilterSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("change_hallucination_filter_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getDefaultSettings() : Promise<Result<AppSettings, string>> {
+async changeHallucinationEnergyFloorSetting(db: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_default_settings") };
+    return { status: "ok", data: await TAURI_INVOKE("change_hallucination_energy_floor_setting", { db }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getLogDirPath() : Promise<Result<string, string>> {
+async changeHallucinationNoSpeechThresholdSetting(threshold: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_log_dir_path") };
+    return { status: "ok", data: await TAURI_INVOKE("change_hallucination_no_speech_threshold_setting", { threshold }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setLogLevel(level: LogLevel) : Promise<Result<null, string>> {
+/**
+ * Replace the hallucination blocklist of one language; an empty list
+ * removes the language.
+ */
+async updateHallucinationPhrases(language: string, phrases: string[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_log_level", { level }) };
+    return { status: "ok", data: await TAURI_INVOKE("update_hallucination_phrases", { language, phrases }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openRecordingsFolder() : Promise<Result<null, string>> {
+async changePunctuationRestoreSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
+    return { status: "ok", data: await TAURI_INVOKE("change_punctuation_restore_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openLogDir() : Promise<Result<null, string>> {
+async changePunctuationRestoreCasingModelSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_log_dir") };
+    return { status: "ok", data: await TAURI_INVOKE("change_punctuation_restore_casing_model_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async openAppDataDir() : Promise<Result<null, string>> {
+async changeAppLanguageSetting(language: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
+    return { status: "ok", data: await TAURI_INVOKE("change_app_language_setting", { language }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-/**
- * Check if Apple Intelligence is available on this device.
- * Called by the frontend when the user selects Apple Intelligence provider.
- */
-async checkAppleIntelligenceAvailable() : Promise<boolean> {
-    return await TAURI_INVOKE("check_apple_intelligence_available");
-},
-/**
- * Try to initialize Enigo (keyboard/mouse simulation).
- * On macOS, this will return an error if accessibility permissions are not granted.
- */
-async initializeEnigo() : Promise<Result<null, string>> {
+async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("initialize_enigo") };
+    return { status: "ok", data: await TAURI_INVOKE("change_update_checks_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
 /**
- * Initialize keyboard shortcuts.
- * On macOS, this should be called after accessibility permissions are granted.
- * This is idempotent - calling it multiple times is safe.
+ * Switch release channel and look for an update on the new one.
  */
-async initializeShortcuts() : Promise<Result<null, string>> {
+async changeUpdateChannelSetting(channel: UpdateChannel) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("initialize_shortcuts") };
+    return { status: "ok", data: await TAURI_INVOKE("change_update_channel_setting", { channel }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
+async changeUpdateCheckIntervalSetting(hours: number) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
+    return { status: "ok", data: await TAURI_INVOKE("change_update_check_interval_setting", { hours }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, string>> {
+/**
+ * Change the keyboard implementation with runtime switching.
+ * This will unregister all shortcuts from the old implementation,
+ * validate shortcuts for the new implementation (resetting invalid ones to defaults),
+ * and register them with the new implementation.
+ */
+async changeKeyboardImplementationSetting(implementation: string) : Promise<Result<ImplementationChangeResult, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_keyboard_implementation_setting", { implementation }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async downloadModel(modelId: string) : Promise<Result<null, string>> {
+/**
+ * Get the current keyboard implementation
+ */
+async getKeyboardImplementation() : Promise<string> {
+    return await TAURI_INVOKE("get_keyboard_implementation");
+},
+async changeVoiceCommandsSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_voice_commands_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteModel(modelId: string) : Promise<Result<null, string>> {
+async changePrivateModeSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_private_mode_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async cancelDownload(modelId: string) : Promise<Result<null, string>> {
+async changeAppBehaviorsSetting(rules: AppBehaviorRule[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_app_behaviors_setting", { rules }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setActiveModel(modelId: string) : Promise<Result<null, string>> {
+async changeNumberNormalizationSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
+    return { status: "ok", data: await TAURI_INVOKE("change_number_normalization_setting", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getCurrentModel() : Promise<Result<string, string>> {
+/**
+ * Start key recording mode
+ */
+async startHandyKeysRecording(bindingId: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+    return { status: "ok", data: await TAURI_INVOKE("start_handy_keys_recording", { bindingId }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+/**
+ * Stop key recording mode
+ */
+async stopHandyKeysRecording() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+    return { status: "ok", data: await TAURI_INVOKE("stop_handy_keys_recording") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async isModelLoading() : Promise<Result<boolean, string>> {
+async cancelOperation() : Promise<void> {
+    await TAURI_INVOKE("cancel_operation");
+},
+async getAppDirPath() : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+    return { status: "ok", data: await TAURI_INVOKE("get_app_dir_path") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+async getAppSettings() : Promise<Result<AppSettings, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+    return { status: "ok", data: await TAURI_INVOKE("get_app_settings") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+async getDefaultSettings() : Promise<Result<AppSettings, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+    return { status: "ok", data: await TAURI_INVOKE("get_default_settings") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+/**
+ * Settings that failed to load and were reset to defaults since the last call.
+ */
+async getSettingsLoadErrors() : Promise<Result<SettingsFieldError[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_settings_load_errors") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getMicrophoneMode() : Promise<Result<boolean, string>> {
+/**
+ * Write the portable part of the settings to a profile file. API keys and
+ * device choices are left out.
+ */
+async exportSettings(path: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+    return { status: "ok", data: await TAURI_INVOKE("export_settings", { path }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+/**
+ * Merge a profile file into the settings. With `dry_run` nothing is saved,
+ * so the frontend can look at the conflicts before choosing a resolution.
+ */
+async importSettings(path: string, resolution: ConflictResolution, dryRun: boolean) : Promise<Result<ImportReport, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+    return { status: "ok", data: await TAURI_INVOKE("import_settings", { path, resolution, dryRun }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
+/**
+ * The most recent structured pipeline log events, oldest first, for the
+ * log viewer and bug reports.
+ */
+async getRecentLogEvents(limit: number) : Promise<Result<LogEvent[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("get_recent_log_events", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSelectedMicrophone() : Promise<Result<string, string>> {
+async getLogDirPath() : Promise<Result<string, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_selected_microphone") };
+    return { status: "ok", data: await TAURI_INVOKE("get_log_dir_path") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAvailableOutputDevices() : Promise<Result<AudioDevice[], string>> {
+async setLogLevel(level: LogLevel) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
+    return { status: "ok", data: await TAURI_INVOKE("set_log_level", { level }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async setSelectedOutputDevice(deviceName: string) : Promise<Result<null, string>> {
+async setLogTranscripts(enabled: boolean) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_selected_output_device", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_log_transcripts", { enabled }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getSelectedOutputDevice() : Promise<Result<string, string>> {
+/**
+ * Set what happens to a binding's final text. An empty list, or pasting
+ * alone, goes back to the default.
+ */
+async setBindingOutputActions(bindingId: string, actions: OutputAction[]) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_selected_output_device") };
+    return { status: "ok", data: await TAURI_INVOKE("set_binding_output_actions", { bindingId, actions }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async playTestSound(soundType: string) : Promise<void> {
-    await TAURI_INVOKE("play_test_sound", { soundType });
-},
-async checkCustomSounds() : Promise<CustomSounds> {
-    return await TAURI_INVOKE("check_custom_sounds");
-},
-async setClamshellMicrophone(deviceName: string) : Promise<Result<null, string>> {
+/**
+ * Set how profanity in a binding's output is masked.
+ */
+async setBindingProfanityMask(bindingId: string, mask: ProfanityMask) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("set_clamshell_microphone", { deviceName }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_binding_profanity_mask", { bindingId, mask }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openRecordingsFolder() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openLogDir() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_log_dir") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async openAppDataDir() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check whether the app may send keystrokes (macOS Accessibility permission).
+ * Always true on other platforms.
+ */
+async checkAccessibilityPermission() : Promise<boolean> {
+    return await TAURI_INVOKE("check_accessibility_permission");
+},
+/**
+ * Open the Accessibility pane of System Settings.
+ */
+async openAccessibilitySettings() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("open_accessibility_settings") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Check if Apple Intelligence is available on this device.
+ * Called by the frontend when the user selects Apple Intelligence provider.
+ */
+async checkAppleIntelligenceAvailable() : Promise<boolean> {
+    return await TAURI_INVOKE("check_apple_intelligence_available");
+},
+/**
+ * Try to initialize Enigo (keyboard/mouse simulation).
+ * On macOS, this will return an error if accessibility permissions are not granted.
+ */
+async initializeEnigo() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("initialize_enigo") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Initialize keyboard shortcuts.
+ * On macOS, this should be called after accessibility permissions are granted.
+ * This is idempotent - calling it multiple times is safe.
+ */
+async initializeShortcuts() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("initialize_shortcuts") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableModels() : Promise<Result<ModelInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_models") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getModelInfo(modelId: string) : Promise<Result<ModelInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_model_info", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async downloadModel(modelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("download_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteModel(modelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async cancelDownload(modelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("cancel_download", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setActiveModel(modelId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_active_model", { modelId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getCurrentModel() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_current_model") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getTranscriptionModelStatus() : Promise<Result<string | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_transcription_model_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async isModelLoading() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("is_model_loading") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsAvailable() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_available") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async hasAnyModelsOrDownloads() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("has_any_models_or_downloads") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async listOverlayMonitors() : Promise<Result<OverlayMonitor[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("list_overlay_monitors") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Show the overlay with unsaved placement settings. Call repeatedly while the
+ * user adjusts controls, then `end_overlay_preview` when done.
+ */
+async previewOverlayLayout(position: OverlayPosition, layout: OverlayLayout) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("preview_overlay_layout", { position, layout }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async endOverlayPreview() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("end_overlay_preview") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Send the text held by confirmation mode, with `text` replacing it if the
+ * user edited it.
+ */
+async sendPendingText(text: string | null) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("send_pending_text", { text }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async discardPendingText() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("discard_pending_text") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Give the overlay keyboard focus so the held text can be edited in place.
+ */
+async editPendingText() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("edit_pending_text") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateMicrophoneMode(alwaysOn: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_microphone_mode", { alwaysOn }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getMicrophoneMode() : Promise<Result<boolean, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_microphone_mode") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableMicrophones() : Promise<Result<AudioDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_microphones") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSelectedMicrophone(deviceName: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_microphone", { deviceName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Set the microphone and gain used by a single binding. A `None` microphone
+ * and 0 dB gain remove the override.
+ */
+async setBindingAudioOverride(bindingId: string, microphone: string | null, gainDb: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_binding_audio_override", { bindingId, microphone, gainDb }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSelectedMicrophone() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_microphone") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAvailableOutputDevices() : Promise<Result<AudioDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_available_output_devices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async setSelectedOutputDevice(deviceName: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_selected_output_device", { deviceName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getSelectedOutputDevice() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_selected_output_device") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async playTestSound(soundType: string) : Promise<void> {
+    await TAURI_INVOKE("play_test_sound", { soundType });
+},
+async checkCustomSounds() : Promise<CustomSounds> {
+    return await TAURI_INVOKE("check_custom_sounds");
+},
+async setClamshellMicrophone(deviceName: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_clamshell_microphone", { deviceName }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -610,68 +1047,226 @@ async getClamshellMicrophone() : Promise<Result<string, string>> {
 async isRecording() : Promise<boolean> {
     return await TAURI_INVOKE("is_recording");
 },
+async runMicrophoneDiagnostics() : Promise<Result<MicrophoneDiagnostics, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_microphone_diagnostics") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async setModelUnloadTimeout(timeout: ModelUnloadTimeout) : Promise<void> {
     await TAURI_INVOKE("set_model_unload_timeout", { timeout });
 },
+async setModelKeepAlive(policy: ModelKeepAlive) : Promise<void> {
+    await TAURI_INVOKE("set_model_keep_alive", { policy });
+},
+async setBatteryPolicy(policy: BatteryPolicy) : Promise<void> {
+    await TAURI_INVOKE("set_battery_policy", { policy });
+},
+async setTranscriptionWorkers(workers: number) : Promise<void> {
+    await TAURI_INVOKE("set_transcription_workers", { workers });
+},
+async getModelMemoryUsage() : Promise<Result<ModelMemoryUsage, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_model_memory_usage") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
+    return { status: "ok", data: await TAURI_INVOKE("get_model_load_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async unloadModelManually() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Transcribe an audio file (wav, mp3, m4a or ogg) like a dictation, with
+ * progress in `file-transcription-progress` events.
+ */
+async transcribeAudioFile(path: string, postProcess: boolean) : Promise<Result<FileTranscription, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("transcribe_audio_file", { path, postProcess }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getUpdateStatus() : Promise<UpdateStatus> {
+    return await TAURI_INVOKE("get_update_status");
+},
+/**
+ * Check the selected channel now, regardless of the staged rollout.
+ */
+async checkForUpdates() : Promise<Result<UpdateInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_for_updates") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async downloadUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("download_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Install the downloaded update and restart into it.
+ */
+async applyUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("apply_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateHistoryRetentionPolicy(policy: HistoryRetentionPolicy) : Promise<Result<HistoryCleanupReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_history_retention_policy", { policy }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Run the retention cleanup immediately instead of waiting for the background task.
+ */
+async runHistoryCleanup() : Promise<Result<HistoryCleanupReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("run_history_cleanup") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async unloadModelManually() : Promise<Result<null, string>> {
+/**
+ * Per-stage latency over the `limit` most recent timed dictations.
+ */
+async getLatencyReport(limit: number) : Promise<Result<LatencyReport, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
+    return { status: "ok", data: await TAURI_INVOKE("get_latency_report", { limit }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getHistoryEntries() : Promise<Result<HistoryEntry[], string>> {
+async startMeeting() : Promise<Result<MeetingStatus, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_history_entries") };
+    return { status: "ok", data: await TAURI_INVOKE("start_meeting") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async toggleHistoryEntrySaved(id: number) : Promise<Result<null, string>> {
+async stopMeeting() : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("toggle_history_entry_saved", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("stop_meeting") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async getAudioFilePath(fileName: string) : Promise<Result<string, string>> {
+async setMeetingPaused(paused: boolean) : Promise<Result<MeetingStatus, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_audio_file_path", { fileName }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_meeting_paused", { paused }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async deleteHistoryEntry(id: number) : Promise<Result<null, string>> {
+async getMeetingStatus() : Promise<MeetingStatus | null> {
+    return await TAURI_INVOKE("get_meeting_status");
+},
+/**
+ * Set the folder for meeting notes; an empty or missing folder goes back
+ * to the default in the app data directory.
+ */
+async setMeetingNotesFolder(folder: string | null) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("delete_history_entry", { id }) };
+    return { status: "ok", data: await TAURI_INVOKE("set_meeting_notes_folder", { folder }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateHistoryLimit(limit: number) : Promise<Result<null, string>> {
+async listTtsVoices() : Promise<Result<string[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_history_limit", { limit }) };
+    return { status: "ok", data: await TAURI_INVOKE("list_tts_voices") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
 },
-async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, string>> {
+/**
+ * Speak `text` with the current voice and rate, so they can be tried out.
+ */
+async previewTts(text: string) : Promise<Result<null, string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("update_recording_retention_period", { period }) };
+    return { status: "ok", data: await TAURI_INVOKE("preview_tts", { text }) };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -701,13 +1296,285 @@ async isLaptop() : Promise<Result<boolean, string>> {
 
 /** user-defined types **/
 
-export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; experimental_enabled?: boolean; keyboard_implementation?: KeyboardImplementation; paste_delay_ms?: number; voice_commands_enabled?: boolean }
+export type AppBehaviorRule = { 
+/**
+ * Application name, matched case-insensitively. A trailing `*` matches any suffix.
+ */
+app_pattern: string; behavior: AppOutputBehavior }
+/**
+ * How dictated text is formatted and inserted for a given application.
+ */
+export type AppOutputBehavior = "default" | 
+/**
+ * No post-processing or sentence formatting; typed directly instead of pasted.
+ */
+"verbatim"
+export type AppSettings = { settings_version?: number; bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; 
+/**
+ * Recognize tap, double-tap and long-press on the transcribe shortcut
+ * instead of plain toggle / push-to-talk.
+ */
+hotkey_gestures?: boolean; busy_behavior?: BusyBehavior; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; 
+/**
+ * Run without tray icon, overlay and settings windows (also `--headless`).
+ * Takes effect on next launch.
+ */
+headless?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; update_channel?: UpdateChannel; 
+/**
+ * Hours between background update checks.
+ */
+update_check_interval_hours?: number; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; 
+/**
+ * Per-binding microphone and gain, keyed by binding id.
+ */
+binding_audio?: Partial<{ [key in string]: BindingAudioOverride }>; selected_output_device?: string | null; 
+/**
+ * Set once the first-launch microphone diagnostics got past the
+ * permission check.
+ */
+microphone_check_completed?: boolean; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; overlay_layout?: OverlayLayout; debug_mode?: boolean; log_level?: LogLevel; 
+/**
+ * Write raw transcript, prompt and LLM text to logs instead of redacted summaries.
+ */
+log_transcripts?: boolean; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; model_keep_alive?: ModelKeepAlive; 
+/**
+ * Engines used in parallel for the final pass of long recordings.
+ * Each extra worker loads another copy of the model; 1 disables it.
+ */
+transcription_workers?: number; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; history_retention?: HistoryRetentionPolicy; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; selection_target?: SelectionTarget; 
+/**
+ * Paste the PRIMARY selection with a simulated middle click (Linux only).
+ */
+middle_click_paste?: boolean; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; 
+/**
+ * Ordered prompt ids run as a chain, each stage feeding the next.
+ * When empty, only the selected prompt is run.
+ */
+post_process_chain?: string[]; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; experimental_enabled?: boolean; keyboard_implementation?: KeyboardImplementation; paste_delay_ms?: number; per_character_delay_ms?: number; 
+/**
+ * Type the text per character when a clipboard paste fails, and when
+ * direct typing would send CJK text through Enigo.
+ */
+paste_fallback_enabled?: boolean; voice_commands_enabled?: boolean; 
+/**
+ * Treat every dictation as private: nothing is saved to history and
+ * transcript text is redacted from logs.
+ */
+private_mode?: boolean; 
+/**
+ * Per-application output behavior, matched against the focused app at record start.
+ */
+app_behaviors?: AppBehaviorRule[]; 
+/**
+ * Convert spoken numbers, currencies, times and dates to digits
+ * ("twenty three" -> "23") before pasting and voice-command matching.
+ */
+number_normalization?: boolean; battery_policy?: BatteryPolicy; 
+/**
+ * Send a native notification (and copy the text) when a finished
+ * transcript may not have reached the user: the paste failed, or the
+ * focused app changed while it was being processed.
+ */
+completion_notifications?: boolean; 
+/**
+ * Per-binding output actions, keyed by binding id, run in order.
+ */
+binding_output?: Partial<{ [key in string]: OutputAction[] }>; 
+/**
+ * Per-binding profanity masking, keyed by binding id. Bindings
+ * without an entry keep the text as it is.
+ */
+binding_profanity?: Partial<{ [key in string]: ProfanityMask }>; 
+/**
+ * Where meeting mode writes its session files; `None` uses the
+ * `meetings` folder in the app data directory.
+ */
+meeting_notes_folder?: string | null; 
+/**
+ * Join a dictation onto the previous one when it starts within
+ * `continue_dictation_window_secs` in the same app.
+ */
+continue_dictation?: boolean; continue_dictation_window_secs?: number; 
+/**
+ * Read the final transcript aloud after it is delivered.
+ */
+tts_read_transcripts?: boolean; 
+/**
+ * Read aloud the errors that stop a dictation.
+ */
+tts_read_errors?: boolean; 
+/**
+ * Speech synthesizer voice; `None` uses the system default.
+ */
+tts_voice?: string | null; 
+/**
+ * Speaking rate as a multiple of the normal speed.
+ */
+tts_rate?: number; 
+/**
+ * Hold each finished dictation in the overlay until it is accepted,
+ * edited or discarded, instead of pasting it straight away.
+ */
+confirm_before_paste?: boolean; 
+/**
+ * Drop transcripts of near-silent audio and Whisper's phantom phrases
+ * ("Thank you for watching") instead of pasting them.
+ */
+hallucination_filter?: boolean; 
+/**
+ * RMS level in dBFS below which a recording counts as silent.
+ */
+hallucination_energy_floor_db?: number; 
+/**
+ * Share of the audio without speech at which a transcript made up of
+ * blocklisted phrases is dropped.
+ */
+hallucination_no_speech_threshold?: number; 
+/**
+ * Known phantom phrases, by Whisper language code.
+ */
+hallucination_phrases?: Partial<{ [key in string]: string[] }>; 
+/**
+ * Restore casing and punctuation locally when the transcript comes
+ * back all lowercase or unpunctuated, before any LLM post-processing.
+ */
+punctuation_restore?: boolean; 
+/**
+ * Also restore capitals of names from a casing model learned from the
+ * dictation history.
+ */
+punctuation_restore_casing_model?: boolean }
 export type AudioDevice = { index: string; name: string; is_default: boolean }
+/**
+ * Overrides applied while a laptop runs on battery, to save power.
+ */
+export type BatteryPolicy = { enabled?: boolean; 
+/**
+ * Model used instead of `selected_model`, typically a smaller one.
+ * `None` keeps the selected model.
+ */
+model?: string | null; disable_always_on_microphone?: boolean; 
+/**
+ * Skip live partial transcription while recording.
+ */
+disable_streaming?: boolean; 
+/**
+ * Used instead of `model_unload_timeout` when it is longer, so the model
+ * isn't reloaded for every dictation.
+ */
+unload_timeout?: ModelUnloadTimeout }
+/**
+ * Input overrides for a single shortcut binding, e.g. a headset for the
+ * meeting binding and the desk microphone for dictation.
+ */
+export type BindingAudioOverride = { 
+/**
+ * Input device name; `None` uses the globally selected microphone.
+ */
+microphone?: string | null; 
+/**
+ * Gain applied to the recorded samples, in dB.
+ */
+gain_db?: number }
 export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
+/**
+ * What a transcribe shortcut does while the previous recording is still
+ * being transcribed.
+ */
+export type BusyBehavior = 
+/**
+ * Play an error sound and ignore the shortcut
+ */
+"reject" | 
+/**
+ * Start recording right away; transcriptions are processed in order
+ */
+"queue"
 export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
+/**
+ * What to do when an imported setting differs from the current one.
+ */
+export type ConflictResolution = "keep_existing" | "use_imported"
 export type CustomSounds = { start: boolean; stop: boolean }
+/**
+ * Where the time went in one dictation, in milliseconds. A stage that
+ * didn't run (e.g. no post-processing) is `None`.
+ */
+export type DictationTimings = { 
+/**
+ * Opening the microphone stream on key press
+ */
+mic_start_ms: number | null; 
+/**
+ * Waiting for the model after key press; `None` if it was already loaded
+ */
+model_load_ms: number | null; 
+/**
+ * Key press until the first streaming partial was shown
+ */
+first_partial_ms: number | null; 
+/**
+ * Final transcription pass after key release
+ */
+transcribe_ms: number | null; post_process_ms: number | null; 
+/**
+ * Pasting the text, or running the voice command
+ */
+paste_ms: number | null; 
+/**
+ * Key release until the text was pasted
+ */
+total_ms: number | null }
 export type EngineType = "Whisper" | "Parakeet" | "Moonshine"
-export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null }
+/**
+ * The outcome of transcribing a file.
+ */
+export type FileTranscription = { 
+/**
+ * The text as it would have been pasted.
+ */
+text: string; duration_secs: number; 
+/**
+ * The history entry, unless private mode kept it out.
+ */
+history_id: number | null }
+export type HealthCheckStatus = "ok" | "failed" | "skipped"
+/**
+ * Result of a single stage of a provider health check.
+ */
+export type HealthCheckStep = { name: string; status: HealthCheckStatus; detail: string | null }
+/**
+ * Summary of a retention pass, returned to the frontend by `run_history_cleanup`.
+ */
+export type HistoryCleanupReport = { entries_deleted: number; audio_files_deleted: number; bytes_freed: number }
+export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; 
+/**
+ * True when the entry has no WAV file: it was removed by audio-only
+ * pruning, or none was saved (meetings, long audio files).
+ */
+audio_pruned: boolean; 
+/**
+ * Intermediate results of a post-processing chain, in execution order.
+ */
+post_process_steps: PostProcessStep[]; 
+/**
+ * Pipeline timings; `None` for entries recorded before they were tracked.
+ */
+timings: DictationTimings | null; 
+/**
+ * The entry this dictation continued when continue-dictation joined
+ * them into one block of text.
+ */
+continued_from: number | null }
+/**
+ * Additional limits applied on top of `recording_retention_period`.
+ * Every limit is optional; saved entries are never pruned.
+ */
+export type HistoryRetentionPolicy = { max_entries?: number | null; max_audio_size_mb?: number | null; max_age_days?: number | null; 
+/**
+ * When set, pruning deletes only the WAV files and keeps the transcript text.
+ */
+audio_only_pruning?: boolean }
 /**
  * Result of changing keyboard implementation
  */
@@ -716,18 +1583,250 @@ export type ImplementationChangeResult = { success: boolean;
  * List of binding IDs that were reset to defaults due to incompatibility
  */
 reset_bindings: string[] }
+/**
+ * Settings touched by an import. Entries of merged collections are
+ * reported as `field/key`, e.g. `post_process_prompts/default_improve`.
+ */
+export type ImportReport = { added: string[]; 
+/**
+ * Conflicts resolved in favor of the imported value
+ */
+replaced: string[]; 
+/**
+ * Conflicts resolved in favor of the current value
+ */
+kept: string[]; 
+/**
+ * Imported fields that were invalid and skipped
+ */
+errors: SettingsFieldError[] }
 export type KeyboardImplementation = "tauri" | "handy_keys"
-export type LLMPrompt = { id: string; name: string; prompt: string }
+export type LLMPrompt = { id: string; name: string; prompt: string; 
+/**
+ * Model to run this prompt on instead of the provider's selected one,
+ * e.g. a cheap model for cleanup and a stronger one for summaries.
+ */
+model?: string | null; 
+/**
+ * Sampling temperature; `None` leaves it to the provider.
+ */
+temperature?: number | null; 
+/**
+ * Cap on the length of the response; `None` leaves it to the provider.
+ */
+max_tokens?: number | null; 
+/**
+ * System message sent ahead of the prompt. Supports the same
+ * `${...}` variables as the prompt.
+ */
+system_message?: string | null }
+export type LatencyReport = { 
+/**
+ * Dictations with recorded timings
+ */
+dictations: number; 
+/**
+ * Stages in pipeline order; stages that never ran are left out
+ */
+stages: StageLatency[] }
+/**
+ * One captured event, as shown in the log viewer.
+ */
+export type LogEvent = { 
+/**
+ * Milliseconds since the Unix epoch
+ */
+timestamp: number; level: string; target: string; message: string; 
+/**
+ * Enclosing spans, outermost first, e.g. `dictation{binding=transcribe}`
+ */
+spans: string[]; fields: Partial<{ [key in string]: string }> }
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
+/**
+ * A running meeting, as shown in the overlay and settings.
+ */
+export type MeetingStatus = { 
+/**
+ * The session file the transcript is appended to.
+ */
+file: string; words: number; paused: boolean; 
+/**
+ * Unix timestamp, in seconds.
+ */
+started_at: number }
+export type MicrophoneDiagnostics = { verdict: MicrophoneVerdict; permission: PermissionStatus; device_name: string | null; sample_rate: number | null; channels: number | null; samples_received: number; rms_db: number | null; peak: number | null; error: string | null }
+export type MicrophoneVerdict = "ok" | "permission_denied" | "no_device" | "stream_failed" | "no_samples" | "silent" | "clipping"
 export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number; supports_translation: boolean; is_recommended: boolean; supported_languages: string[] }
+/**
+ * When the transcription model is kept in memory between dictations.
+ */
+export type ModelKeepAlive = 
+/**
+ * Load on demand and unload according to `model_unload_timeout`
+ */
+"idle" | 
+/**
+ * Load at startup and reload whenever the model was dropped
+ */
+"always_loaded" | 
+/**
+ * Keep the model loaded on AC power, unload it while on battery
+ */
+"unload_on_battery"
 export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
+export type ModelMemoryUsage = { current_model: string | null; 
+/**
+ * Approximate memory held by the loaded model
+ */
+model_size_bytes: number | null; 
+/**
+ * Resident memory of the whole app
+ */
+process_resident_bytes: number | null; on_battery: boolean }
 export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
-export type OverlayPosition = "none" | "top" | "bottom"
-export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
-export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
+/**
+ * Something done with a binding's final text. Bindings without any
+ * output actions just paste.
+ */
+export type OutputAction = 
+/**
+ * Paste into the focused window.
+ */
+{ kind: "paste" } | 
+/**
+ * Append to a file, e.g. a daily notes page in a Markdown vault.
+ */
+{ kind: "append_to_file"; 
+/**
+ * Absolute path, or starting with `~/`. May use `${date}`.
+ */
+path: string; 
+/**
+ * Line written above each entry, e.g. `## ${date} ${time}`.
+ * Empty writes the text alone.
+ */
+header?: string } | 
+/**
+ * POST the text as JSON to a URL.
+ */
+{ kind: "webhook"; url: string } | 
+/**
+ * Run a shell command with the text on its stdin.
+ */
+{ kind: "command"; command: string }
+/**
+ * Overlay placement details that complement `overlay_position`.
+ */
+export type OverlayLayout = { 
+/**
+ * Index into the available monitors; `None` follows the mouse cursor.
+ */
+monitor?: number | null; 
+/**
+ * Logical offset from the monitor work area's top-left corner (custom position only).
+ */
+custom_x?: number; custom_y?: number; 
+/**
+ * Size multiplier applied to the overlay window and its content.
+ */
+scale?: number; opacity?: number }
+export type OverlayMonitor = { 
+/**
+ * Index to store in `OverlayLayout::monitor`
+ */
+index: number; name: string; 
+/**
+ * Logical work-area size, for bounding custom coordinates
+ */
+width: number; height: number; is_primary: boolean }
+export type OverlayPosition = "none" | "top" | "bottom" | "top_left" | "top_right" | "bottom_left" | "bottom_right" | "left" | "right" | 
+/**
+ * Placed at `OverlayLayout::custom_x`/`custom_y`
+ */
+"custom"
+export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v" | "per_character"
+export type PermissionStatus = "granted" | "denied" | "unknown" | "not_required"
+/**
+ * How profanity in a binding's output is masked.
+ */
+export type ProfanityMask = 
+/**
+ * Leave the words as they are.
+ */
+"keep" | 
+/**
+ * Keep the first letter and star out the rest: "f***".
+ */
+"asterisks" | 
+/**
+ * Drop the words.
+ */
+"remove"
+export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null; api?: ProviderApi; 
+/**
+ * Azure OpenAI `api-version`; `None` uses [`AZURE_OPENAI_API_VERSION`].
+ */
+api_version?: string | null }
+/**
+ * Request format a post-processing provider speaks.
+ */
+export type ProviderApi = "open_ai" | "azure_open_ai" | "gemini"
+/**
+ * Structured diagnosis of a post-processing provider configuration.
+ */
+export type ProviderHealthReport = { provider_id: string; model: string; healthy: boolean; steps: HealthCheckStep[]; 
+/**
+ * Round-trip time of the test completion, if it was attempted.
+ */
+completion_latency_ms: number | null }
 export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
+/**
+ * X11/Wayland selection that clipboard-based paste methods write to (Linux only).
+ */
+export type SelectionTarget = "clipboard" | 
+/**
+ * The PRIMARY selection, pasted with a middle click
+ */
+"primary" | "both"
+/**
+ * A stored setting that could not be used and was reset to its default.
+ */
+export type SettingsFieldError = { field: string; message: string }
 export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
 export type SoundTheme = "marimba" | "pop" | "custom"
+/**
+ * Latency of one stage across the dictations it ran in.
+ */
+export type StageLatency = { stage: string; 
+/**
+ * Dictations in which the stage ran
+ */
+count: number; median_ms: number; p90_ms: number; max_ms: number }
+/**
+ * Release stream the updater follows.
+ */
+export type UpdateChannel = "stable" | 
+/**
+ * Pre-releases, ahead of stable
+ */
+"beta"
+/**
+ * A release newer than the running build.
+ */
+export type UpdateInfo = { version: string; current_version: string; channel: UpdateChannel; 
+/**
+ * Publication date, as given in the manifest.
+ */
+date: string | null; 
+/**
+ * Release notes.
+ */
+notes: string | null }
+export type UpdateStatus = { state: "idle" } | { state: "checking" } | { state: "up_to_date" } | { state: "available"; update: UpdateInfo } | { state: "downloading"; update: UpdateInfo; downloaded: number; total: number | null } | 
+/**
+ * Downloaded; installed on restart or quit.
+ */
+{ state: "ready"; update: UpdateInfo } | { state: "failed"; message: string }
 
 /** tauri-specta globals **/
 
@@ -0,0 +1,126 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { X } from "lucide-react";
+import type { AppBehaviorRule, AppOutputBehavior } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Button } from "../ui/Button";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface AppBehaviorsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const AppBehaviors: React.FC<AppBehaviorsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [newPattern, setNewPattern] = useState("");
+    const rules = getSetting("app_behaviors") || [];
+    const updating = isUpdating("app_behaviors");
+
+    const behaviorOptions = [
+      {
+        value: "default",
+        label: t("settings.advanced.appBehaviors.behaviors.default"),
+      },
+      {
+        value: "verbatim",
+        label: t("settings.advanced.appBehaviors.behaviors.verbatim"),
+      },
+    ];
+
+    const save = (next: AppBehaviorRule[]) =>
+      updateSetting("app_behaviors", next);
+
+    const handleAddRule = () => {
+      const pattern = newPattern.trim();
+      if (!pattern || rules.some((rule) => rule.app_pattern === pattern)) {
+        return;
+      }
+      save([...rules, { app_pattern: pattern, behavior: "verbatim" }]);
+      setNewPattern("");
+    };
+
+    const handleBehaviorChange = (index: number, behavior: string) => {
+      save(
+        rules.map((rule, i) =>
+          i === index
+            ? { ...rule, behavior: behavior as AppOutputBehavior }
+            : rule,
+        ),
+      );
+    };
+
+    const handleKeyPress = (e: React.KeyboardEvent) => {
+      if (e.key === "Enter") {
+        e.preventDefault();
+        handleAddRule();
+      }
+    };
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.appBehaviors.title")}
+          description={t("settings.advanced.appBehaviors.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            <Input
+              type="text"
+              className="max-w-40"
+              value={newPattern}
+              onChange={(e) => setNewPattern(e.target.value)}
+              onKeyDown={handleKeyPress}
+              placeholder={t("settings.advanced.appBehaviors.placeholder")}
+              variant="compact"
+              disabled={updating}
+            />
+            <Button
+              onClick={handleAddRule}
+              disabled={!newPattern.trim() || updating}
+              variant="primary"
+              size="md"
+            >
+              {t("settings.advanced.appBehaviors.add")}
+            </Button>
+          </div>
+        </SettingContainer>
+        {rules.length > 0 && (
+          <div
+            className={`px-4 p-2 ${grouped ? "" : "rounded-lg border border-mid-gray/20"} space-y-1`}
+          >
+            {rules.map((rule, index) => (
+              <div
+                key={`${rule.app_pattern}-${index}`}
+                className="flex items-center gap-2 text-sm"
+              >
+                <span className="flex-1 truncate">{rule.app_pattern}</span>
+                <Dropdown
+                  options={behaviorOptions}
+                  selectedValue={rule.behavior}
+                  onSelect={(value) => handleBehaviorChange(index, value)}
+                  disabled={updating}
+                />
+                <button
+                  onClick={() => save(rules.filter((_, i) => i !== index))}
+                  disabled={updating}
+                  className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer disabled:opacity-30"
+                  aria-label={t("settings.advanced.appBehaviors.remove", {
+                    pattern: rule.app_pattern,
+                  })}
+                >
+                  <X width={16} height={16} />
+                </button>
+              </div>
+            ))}
+          </div>
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,82 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import { useModelStore } from "../../stores/modelStore";
+import { commands, type BatteryPolicy } from "@/bindings";
+import { getTranslatedModelName } from "../../lib/utils/modelTranslation";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+
+interface BatterySaverProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const SELECTED_MODEL = "__selected__";
+
+export const BatterySaver: React.FC<BatterySaverProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting } = useSettings();
+  const { models } = useModelStore();
+  const policy = getSetting("battery_policy");
+
+  if (!policy) return null;
+
+  const save = async (changes: Partial<BatteryPolicy>) => {
+    const updated = { ...policy, ...changes };
+    try {
+      await commands.setBatteryPolicy(updated);
+      updateSetting("battery_policy", updated);
+    } catch (error) {
+      console.error("Failed to update battery policy:", error);
+    }
+  };
+
+  // Smallest first, since the point is to use a lighter model
+  const modelOptions = [
+    {
+      value: SELECTED_MODEL,
+      label: t("settings.advanced.batterySaver.keepModel"),
+    },
+    ...models
+      .filter((model) => model.is_downloaded)
+      .sort((a, b) => a.size_mb - b.size_mb)
+      .map((model) => ({
+        value: model.id,
+        label: `${getTranslatedModelName(model, t)} (${model.size_mb} MB)`,
+      })),
+  ];
+
+  return (
+    <>
+      <ToggleSwitch
+        checked={policy.enabled ?? false}
+        onChange={(enabled) => save({ enabled })}
+        label={t("settings.advanced.batterySaver.title")}
+        description={t("settings.advanced.batterySaver.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+      {policy.enabled && (
+        <SettingContainer
+          title={t("settings.advanced.batterySaver.model.title")}
+          description={t("settings.advanced.batterySaver.model.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <Dropdown
+            options={modelOptions}
+            selectedValue={policy.model ?? SELECTED_MODEL}
+            onSelect={(value) =>
+              save({ model: value === SELECTED_MODEL ? null : value })
+            }
+          />
+        </SettingContainer>
+      )}
+    </>
+  );
+};
@@ -0,0 +1,96 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+
+interface BindingAudioProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+// Sent as the microphone to fall back to the globally selected one
+const GLOBAL_MICROPHONE = "default";
+
+export const BindingAudio: React.FC<BindingAudioProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings, audioDevices, refreshAudioDevices } =
+      useSettings();
+    const bindings = getSetting("bindings") || {};
+    const overrides = getSetting("binding_audio") || {};
+
+    const bindingIds = Object.keys(bindings);
+    const [bindingId, setBindingId] = useState(bindingIds[0] ?? "transcribe");
+    const current = overrides[bindingId];
+    const microphone = current?.microphone ?? GLOBAL_MICROPHONE;
+    const gainDb = current?.gain_db ?? 0;
+
+    const save = async (nextMicrophone: string, nextGainDb: number) => {
+      const result = await commands.setBindingAudioOverride(
+        bindingId,
+        nextMicrophone === GLOBAL_MICROPHONE ? null : nextMicrophone,
+        nextGainDb,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    const bindingOptions = bindingIds.map((id) => ({
+      value: id,
+      label: bindings[id]?.name ?? id,
+    }));
+
+    const microphoneOptions = [
+      {
+        value: GLOBAL_MICROPHONE,
+        label: t("settings.sound.bindingAudio.globalMicrophone"),
+      },
+      ...audioDevices
+        .filter((device) => device.index !== "default")
+        .map((device) => ({ value: device.name, label: device.name })),
+    ];
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.sound.bindingAudio.title")}
+          description={t("settings.sound.bindingAudio.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <div className="flex items-center gap-2">
+            <Dropdown
+              options={bindingOptions}
+              selectedValue={bindingId}
+              onSelect={setBindingId}
+            />
+            <Dropdown
+              options={microphoneOptions}
+              selectedValue={microphone}
+              onSelect={(value) => save(value, gainDb)}
+              onRefresh={refreshAudioDevices}
+            />
+          </div>
+        </SettingContainer>
+        <Slider
+          value={gainDb}
+          onChange={(value) => save(microphone, value)}
+          min={-20}
+          max={20}
+          step={1}
+          label={t("settings.sound.bindingAudio.gain.title")}
+          description={t("settings.sound.bindingAudio.gain.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          formatValue={(value) => `${value > 0 ? "+" : ""}${value} dB`}
+        />
+      </>
+    );
+  },
+);
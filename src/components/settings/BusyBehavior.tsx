@@ -0,0 +1,50 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { BusyBehavior } from "@/bindings";
+
+interface BusyBehaviorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const BusyBehaviorSetting: React.FC<BusyBehaviorProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const busyBehaviorOptions = [
+      {
+        value: "reject",
+        label: t("settings.general.busyBehavior.options.reject"),
+      },
+      {
+        value: "queue",
+        label: t("settings.general.busyBehavior.options.queue"),
+      },
+    ];
+
+    const selectedBehavior = (getSetting("busy_behavior") ||
+      "reject") as BusyBehavior;
+
+    return (
+      <SettingContainer
+        title={t("settings.general.busyBehavior.title")}
+        description={t("settings.general.busyBehavior.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={busyBehaviorOptions}
+          selectedValue={selectedBehavior}
+          onSelect={(value) =>
+            updateSetting("busy_behavior", value as BusyBehavior)
+          }
+          disabled={isUpdating("busy_behavior")}
+        />
+      </SettingContainer>
+    );
+  },
+);
@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface ContinueDictationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const ContinueDictation: React.FC<ContinueDictationProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("continue_dictation") ?? false;
+    const windowSecs = getSetting("continue_dictation_window_secs") ?? 20;
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(enabled) => updateSetting("continue_dictation", enabled)}
+          isUpdating={isUpdating("continue_dictation")}
+          label={t("settings.advanced.continueDictation.label")}
+          description={t("settings.advanced.continueDictation.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <Slider
+            value={windowSecs}
+            onChange={(value) =>
+              updateSetting("continue_dictation_window_secs", value)
+            }
+            min={5}
+            max={120}
+            step={5}
+            label={t("settings.advanced.continueDictation.window.title")}
+            description={t(
+              "settings.advanced.continueDictation.window.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+            formatValue={(v) => `${v}s`}
+          />
+        )}
+      </>
+    );
+  },
+);
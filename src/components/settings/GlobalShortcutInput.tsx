@@ -2,8 +2,10 @@ import React, { useEffect, useState, useRef } from "react";
 import { useTranslation } from "react-i18next";
 import {
   getKeyName,
+  getMouseButtonName,
   formatKeyCombination,
   normalizeKey,
+  SPECIAL_BINDINGS,
 } from "../../lib/utils/keyboard";
 import { ResetButton } from "../ui/ResetButton";
 import { SettingContainer } from "../ui/SettingContainer";
@@ -45,6 +47,37 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
 
     let cleanup = false;
 
+    const commitShortcut = async (newShortcut: string) => {
+      if (editingShortcutId && bindings[editingShortcutId]) {
+        try {
+          await updateBinding(editingShortcutId, newShortcut);
+        } catch (error) {
+          console.error("Failed to change binding:", error);
+          toast.error(
+            t("settings.general.shortcut.errors.set", {
+              error: String(error),
+            }),
+          );
+
+          // Reset to original binding on error
+          if (originalBinding) {
+            try {
+              await updateBinding(editingShortcutId, originalBinding);
+            } catch (resetError) {
+              console.error("Failed to reset binding:", resetError);
+              toast.error(t("settings.general.shortcut.errors.reset"));
+            }
+          }
+        }
+
+        // Exit editing mode and reset states
+        setEditingShortcutId(null);
+        setKeyPressed([]);
+        setRecordedKeys([]);
+        setOriginalBinding("");
+      }
+    };
+
     // Keyboard event listeners
     const handleKeyDown = async (e: KeyboardEvent) => {
       if (cleanup) return;
@@ -118,39 +151,21 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
           if (!aIsModifier && bIsModifier) return 1;
           return 0;
         });
-        const newShortcut = sortedKeys.join("+");
-
-        if (editingShortcutId && bindings[editingShortcutId]) {
-          try {
-            await updateBinding(editingShortcutId, newShortcut);
-          } catch (error) {
-            console.error("Failed to change binding:", error);
-            toast.error(
-              t("settings.general.shortcut.errors.set", {
-                error: String(error),
-              }),
-            );
-
-            // Reset to original binding on error
-            if (originalBinding) {
-              try {
-                await updateBinding(editingShortcutId, originalBinding);
-              } catch (resetError) {
-                console.error("Failed to reset binding:", resetError);
-                toast.error(t("settings.general.shortcut.errors.reset"));
-              }
-            }
-          }
-
-          // Exit editing mode and reset states
-          setEditingShortcutId(null);
-          setKeyPressed([]);
-          setRecordedKeys([]);
-          setOriginalBinding("");
-        }
+        // Media keys can't be combined with modifiers
+        const special = sortedKeys.find((k) => SPECIAL_BINDINGS.includes(k));
+        await commitShortcut(special ?? sortedKeys.join("+"));
       }
     };
 
+    // Extra mouse buttons are bound on press
+    const handleMouseDown = async (e: MouseEvent) => {
+      if (cleanup) return;
+      const button = getMouseButtonName(e);
+      if (!button) return;
+      e.preventDefault();
+      await commitShortcut(button);
+    };
+
     // Add click outside handler
     const handleClickOutside = async (e: MouseEvent) => {
       if (cleanup) return;
@@ -176,12 +191,14 @@ export const GlobalShortcutInput: React.FC<GlobalShortcutInputProps> = ({
 
     window.addEventListener("keydown", handleKeyDown);
     window.addEventListener("keyup", handleKeyUp);
+    window.addEventListener("mousedown", handleMouseDown);
     window.addEventListener("click", handleClickOutside);
 
     return () => {
       cleanup = true;
       window.removeEventListener("keydown", handleKeyDown);
       window.removeEventListener("keyup", handleKeyUp);
+      window.removeEventListener("mousedown", handleMouseDown);
       window.removeEventListener("click", handleClickOutside);
     };
   }, [
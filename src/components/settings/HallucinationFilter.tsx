@@ -0,0 +1,137 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+import { Textarea } from "../ui/Textarea";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface HallucinationFilterProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const HallucinationFilter: React.FC<HallucinationFilterProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating, refreshSettings } =
+      useSettings();
+
+    const enabled = getSetting("hallucination_filter") ?? true;
+    const energyFloor = getSetting("hallucination_energy_floor_db") ?? -55;
+    const noSpeech = getSetting("hallucination_no_speech_threshold") ?? 0.8;
+    const phrases = getSetting("hallucination_phrases") ?? {};
+    // "zh-Hans" transcribes as "zh"
+    const selectedLanguage = (getSetting("selected_language") ?? "auto").split(
+      "-",
+    )[0];
+
+    const languages = Object.keys(phrases).sort();
+    if (selectedLanguage !== "auto" && !languages.includes(selectedLanguage)) {
+      languages.push(selectedLanguage);
+    }
+    const [language, setLanguage] = useState(
+      languages.includes(selectedLanguage) ? selectedLanguage : languages[0],
+    );
+    const saved = (language && phrases[language]) || [];
+    const [draft, setDraft] = useState(saved.join("\n"));
+
+    useEffect(() => {
+      setDraft(saved.join("\n"));
+    }, [language, JSON.stringify(saved)]);
+
+    const savePhrases = async () => {
+      if (!language || draft === saved.join("\n")) return;
+      const result = await commands.updateHallucinationPhrases(
+        language,
+        draft.split("\n"),
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(enabled) => updateSetting("hallucination_filter", enabled)}
+          isUpdating={isUpdating("hallucination_filter")}
+          label={t("settings.advanced.hallucinationFilter.label")}
+          description={t("settings.advanced.hallucinationFilter.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <>
+            <Slider
+              value={energyFloor}
+              onChange={(value) =>
+                updateSetting("hallucination_energy_floor_db", value)
+              }
+              min={-80}
+              max={-30}
+              step={1}
+              label={t("settings.advanced.hallucinationFilter.energy.title")}
+              description={t(
+                "settings.advanced.hallucinationFilter.energy.description",
+              )}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+              formatValue={(v) => `${v.toFixed(0)} dB`}
+            />
+            <Slider
+              value={noSpeech}
+              onChange={(value) =>
+                updateSetting("hallucination_no_speech_threshold", value)
+              }
+              min={0.5}
+              max={1}
+              step={0.05}
+              label={t("settings.advanced.hallucinationFilter.noSpeech.title")}
+              description={t(
+                "settings.advanced.hallucinationFilter.noSpeech.description",
+              )}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+              formatValue={(v) => `${Math.round(v * 100)}%`}
+            />
+            <SettingContainer
+              title={t("settings.advanced.hallucinationFilter.phrases.title")}
+              description={t(
+                "settings.advanced.hallucinationFilter.phrases.description",
+              )}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+              layout="stacked"
+            >
+              <div className="flex flex-col gap-2">
+                <Dropdown
+                  options={languages.map((code) => ({
+                    value: code,
+                    label: code,
+                  }))}
+                  selectedValue={language ?? null}
+                  onSelect={setLanguage}
+                />
+                <Textarea
+                  value={draft}
+                  onChange={(e) => setDraft(e.target.value)}
+                  onBlur={savePhrases}
+                  placeholder={t(
+                    "settings.advanced.hallucinationFilter.phrases.placeholder",
+                  )}
+                  variant="compact"
+                  disabled={!language}
+                />
+              </div>
+            </SettingContainer>
+          </>
+        )}
+      </>
+    );
+  });
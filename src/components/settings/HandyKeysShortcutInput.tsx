@@ -1,7 +1,12 @@
 import React, { useEffect, useState, useRef, useCallback } from "react";
 import { useTranslation } from "react-i18next";
 import { listen } from "@tauri-apps/api/event";
-import { formatKeyCombination } from "../../lib/utils/keyboard";
+import {
+  formatKeyCombination,
+  getKeyName,
+  getMouseButtonName,
+  SPECIAL_BINDINGS,
+} from "../../lib/utils/keyboard";
 import { ResetButton } from "../ui/ResetButton";
 import { SettingContainer } from "../ui/SettingContainer";
 import { useSettings } from "../../hooks/useSettings";
@@ -78,6 +83,40 @@ export const HandyKeysShortcutInput: React.FC<HandyKeysShortcutInputProps> = ({
 
     let cleanup = false;
 
+    const commitShortcut = async (keysToCommit: string) => {
+      try {
+        await updateBinding(shortcutId, keysToCommit);
+      } catch (error) {
+        console.error("Failed to change binding:", error);
+        toast.error(
+          t("settings.general.shortcut.errors.set", {
+            error: String(error),
+          }),
+        );
+
+        // Reset to original binding on error
+        if (originalBinding) {
+          try {
+            await updateBinding(shortcutId, originalBinding);
+          } catch (resetError) {
+            console.error("Failed to reset binding:", resetError);
+            toast.error(t("settings.general.shortcut.errors.reset"));
+          }
+        }
+      }
+
+      // Stop recording
+      if (unlistenRef.current) {
+        unlistenRef.current();
+        unlistenRef.current = null;
+      }
+      await commands.stopHandyKeysRecording().catch(console.error);
+      setIsRecording(false);
+      setCurrentKeys("");
+      currentKeysRef.current = "";
+      setOriginalBinding("");
+    };
+
     const setupListener = async () => {
       // Listen for key events from backend
       const unlisten = await listen<HandyKeysEvent>(
@@ -93,38 +132,7 @@ export const HandyKeysShortcutInput: React.FC<HandyKeysShortcutInputProps> = ({
             setCurrentKeys(hotkey_string);
           } else if (!is_key_down && currentKeysRef.current) {
             // Key released - commit the shortcut using the ref value
-            const keysToCommit = currentKeysRef.current;
-            try {
-              await updateBinding(shortcutId, keysToCommit);
-            } catch (error) {
-              console.error("Failed to change binding:", error);
-              toast.error(
-                t("settings.general.shortcut.errors.set", {
-                  error: String(error),
-                }),
-              );
-
-              // Reset to original binding on error
-              if (originalBinding) {
-                try {
-                  await updateBinding(shortcutId, originalBinding);
-                } catch (resetError) {
-                  console.error("Failed to reset binding:", resetError);
-                  toast.error(t("settings.general.shortcut.errors.reset"));
-                }
-              }
-            }
-
-            // Stop recording
-            if (unlistenRef.current) {
-              unlistenRef.current();
-              unlistenRef.current = null;
-            }
-            await commands.stopHandyKeysRecording().catch(console.error);
-            setIsRecording(false);
-            setCurrentKeys("");
-            currentKeysRef.current = "";
-            setOriginalBinding("");
+            await commitShortcut(currentKeysRef.current);
           }
         },
       );
@@ -134,19 +142,36 @@ export const HandyKeysShortcutInput: React.FC<HandyKeysShortcutInputProps> = ({
 
     setupListener();
 
-    // Handle escape key to cancel
+    // Handle escape key to cancel. Media keys and extra mouse buttons aren't
+    // reported by handy-keys, so they are picked up from the webview instead.
     const handleKeyDown = (e: KeyboardEvent) => {
       if (e.key === "Escape") {
         e.preventDefault();
         cancelRecording();
+        return;
+      }
+      const key = getKeyName(e);
+      if (SPECIAL_BINDINGS.includes(key)) {
+        e.preventDefault();
+        commitShortcut(key);
+      }
+    };
+
+    const handleMouseDown = (e: MouseEvent) => {
+      const button = getMouseButtonName(e);
+      if (button) {
+        e.preventDefault();
+        commitShortcut(button);
       }
     };
 
     window.addEventListener("keydown", handleKeyDown);
+    window.addEventListener("mousedown", handleMouseDown);
 
     return () => {
       cleanup = true;
       window.removeEventListener("keydown", handleKeyDown);
+      window.removeEventListener("mousedown", handleMouseDown);
       if (unlistenRef.current) {
         unlistenRef.current();
         unlistenRef.current = null;
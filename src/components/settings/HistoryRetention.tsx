@@ -0,0 +1,119 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, type HistoryRetentionPolicy } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Button } from "../ui/Button";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+
+interface HistoryRetentionProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+type LimitKey = "max_entries" | "max_audio_size_mb" | "max_age_days";
+
+const LIMITS: { key: LimitKey; unit: string }[] = [
+  { key: "max_entries", unit: "entries" },
+  { key: "max_audio_size_mb", unit: "megabytes" },
+  { key: "max_age_days", unit: "days" },
+];
+
+export const HistoryRetention: React.FC<HistoryRetentionProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const policy = getSetting("history_retention") ?? {};
+  const updating = isUpdating("history_retention");
+
+  const save = (changes: Partial<HistoryRetentionPolicy>) =>
+    updateSetting("history_retention", { ...policy, ...changes });
+
+  // An empty field lifts the limit
+  const handleLimitChange = (key: LimitKey, raw: string) => {
+    if (raw.trim() === "") {
+      save({ [key]: null });
+      return;
+    }
+    const value = parseInt(raw, 10);
+    if (!isNaN(value) && value > 0) {
+      save({ [key]: value });
+    }
+  };
+
+  const cleanUpNow = async () => {
+    const result = await commands.runHistoryCleanup();
+    if (result.status === "ok") {
+      const { entries_deleted, audio_files_deleted, bytes_freed } =
+        result.data;
+      toast.success(
+        t("settings.advanced.historyRetention.cleanupDone", {
+          entries: entries_deleted,
+          files: audio_files_deleted,
+          megabytes: (bytes_freed / (1024 * 1024)).toFixed(1),
+        }),
+      );
+    } else {
+      toast.error(result.error);
+    }
+  };
+
+  return (
+    <>
+      {LIMITS.map(({ key, unit }) => (
+        <SettingContainer
+          key={key}
+          title={t(`settings.advanced.historyRetention.${key}.title`)}
+          description={t(
+            `settings.advanced.historyRetention.${key}.description`,
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          layout="horizontal"
+        >
+          <div className="flex items-center space-x-2">
+            <Input
+              type="number"
+              min="1"
+              value={policy[key] ?? ""}
+              placeholder={t("settings.advanced.historyRetention.unlimited")}
+              onChange={(event) => handleLimitChange(key, event.target.value)}
+              disabled={updating}
+              className="w-24"
+            />
+            <span className="text-sm text-text">
+              {t(`settings.advanced.historyRetention.units.${unit}`)}
+            </span>
+          </div>
+        </SettingContainer>
+      ))}
+      <ToggleSwitch
+        checked={policy.audio_only_pruning ?? false}
+        onChange={(enabled) => save({ audio_only_pruning: enabled })}
+        isUpdating={updating}
+        label={t("settings.advanced.historyRetention.audioOnly.title")}
+        description={t(
+          "settings.advanced.historyRetention.audioOnly.description",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+      <SettingContainer
+        title={t("settings.advanced.historyRetention.cleanup.title")}
+        description={t(
+          "settings.advanced.historyRetention.cleanup.description",
+        )}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Button variant="secondary" size="sm" onClick={cleanUpNow}>
+          {t("settings.advanced.historyRetention.cleanup.button")}
+        </Button>
+      </SettingContainer>
+    </>
+  );
+};
@@ -0,0 +1,55 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { useSettings } from "../../hooks/useSettings";
+import { commands } from "@/bindings";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface MeetingNotesFolderProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const MeetingNotesFolder: React.FC<MeetingNotesFolderProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const saved = getSetting("meeting_notes_folder") ?? "";
+    const [folder, setFolder] = useState(saved);
+
+    useEffect(() => {
+      setFolder(saved);
+    }, [saved]);
+
+    const save = async () => {
+      if (folder.trim() === saved) return;
+      const result = await commands.setMeetingNotesFolder(
+        folder.trim() || null,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+        setFolder(saved);
+      }
+      await refreshSettings();
+    };
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.meetingNotesFolder.title")}
+        description={t("settings.advanced.meetingNotesFolder.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Input
+          type="text"
+          className="w-64"
+          value={folder}
+          onChange={(e) => setFolder(e.target.value)}
+          onBlur={save}
+          placeholder={t("settings.advanced.meetingNotesFolder.placeholder")}
+          variant="compact"
+        />
+      </SettingContainer>
+    );
+  });
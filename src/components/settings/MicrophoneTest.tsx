@@ -0,0 +1,84 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, type MicrophoneDiagnostics } from "@/bindings";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Button } from "../ui/Button";
+
+interface MicrophoneTestProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const MicrophoneTest: React.FC<MicrophoneTestProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const [isTesting, setIsTesting] = useState(false);
+    const [result, setResult] = useState<MicrophoneDiagnostics | null>(null);
+    const [error, setError] = useState<string | null>(null);
+
+    const handleTest = async () => {
+      setIsTesting(true);
+      setError(null);
+      try {
+        const response = await commands.runMicrophoneDiagnostics();
+        if (response.status === "ok") {
+          setResult(response.data);
+        } else {
+          setResult(null);
+          setError(response.error);
+        }
+      } finally {
+        setIsTesting(false);
+      }
+    };
+
+    const isOk = result?.verdict === "ok";
+
+    return (
+      <SettingContainer
+        title={t("settings.sound.microphoneTest.title")}
+        description={t("settings.sound.microphoneTest.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+        layout="stacked"
+      >
+        <div className="flex items-center gap-3">
+          <Button
+            variant="secondary"
+            size="sm"
+            onClick={handleTest}
+            disabled={isTesting}
+          >
+            {isTesting
+              ? t("settings.sound.microphoneTest.testing")
+              : t("settings.sound.microphoneTest.button")}
+          </Button>
+          {result && !isTesting && (
+            <div
+              className={`text-sm ${isOk ? "text-green-500" : "text-red-400"}`}
+            >
+              <div>
+                {t(`settings.sound.microphoneTest.verdict.${result.verdict}`)}
+              </div>
+              {result.device_name && (
+                <div className="text-xs text-mid-gray">
+                  {result.device_name}
+                  {result.sample_rate &&
+                    ` · ${result.sample_rate} Hz · ${result.channels} ch`}
+                  {result.rms_db !== null &&
+                    ` · ${result.rms_db.toFixed(1)} dBFS`}
+                </div>
+              )}
+              {result.error && (
+                <div className="text-xs text-mid-gray">{result.error}</div>
+              )}
+            </div>
+          )}
+          {error && !isTesting && (
+            <div className="text-sm text-red-400">{error}</div>
+          )}
+        </div>
+      </SettingContainer>
+    );
+  },
+);
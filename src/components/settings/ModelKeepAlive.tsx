@@ -0,0 +1,100 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { useSettings } from "../../hooks/useSettings";
+import {
+  commands,
+  type ModelKeepAlive,
+  type ModelMemoryUsage,
+} from "@/bindings";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface ModelKeepAliveProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const MEMORY_POLL_INTERVAL_MS = 5000;
+
+const formatMegabytes = (bytes: number) => `${Math.round(bytes / 1048576)} MB`;
+
+export const ModelKeepAliveSetting: React.FC<ModelKeepAliveProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting } = useSettings();
+  const [usage, setUsage] = useState<ModelMemoryUsage | null>(null);
+
+  const options = [
+    {
+      value: "idle" as ModelKeepAlive,
+      label: t("settings.advanced.modelKeepAlive.options.idle"),
+    },
+    {
+      value: "always_loaded" as ModelKeepAlive,
+      label: t("settings.advanced.modelKeepAlive.options.alwaysLoaded"),
+    },
+    {
+      value: "unload_on_battery" as ModelKeepAlive,
+      label: t("settings.advanced.modelKeepAlive.options.unloadOnBattery"),
+    },
+  ];
+
+  useEffect(() => {
+    const refresh = async () => {
+      const response = await commands.getModelMemoryUsage();
+      if (response.status === "ok") {
+        setUsage(response.data);
+      }
+    };
+    refresh();
+    const interval = setInterval(refresh, MEMORY_POLL_INTERVAL_MS);
+    return () => clearInterval(interval);
+  }, []);
+
+  const handleSelect = async (value: string) => {
+    const policy = value as ModelKeepAlive;
+    try {
+      await commands.setModelKeepAlive(policy);
+      updateSetting("model_keep_alive", policy);
+    } catch (error) {
+      console.error("Failed to update model keep-alive policy:", error);
+    }
+  };
+
+  const currentValue = getSetting("model_keep_alive") ?? "idle";
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.modelKeepAlive.title")}
+      description={t("settings.advanced.modelKeepAlive.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <div className="flex flex-col items-end gap-1">
+        <Dropdown
+          options={options}
+          selectedValue={currentValue}
+          onSelect={handleSelect}
+          disabled={false}
+        />
+        {usage && (
+          <div className="text-xs text-mid-gray">
+            {usage.model_size_bytes != null
+              ? t("settings.advanced.modelKeepAlive.memory.loaded", {
+                  model: formatMegabytes(usage.model_size_bytes),
+                })
+              : t("settings.advanced.modelKeepAlive.memory.unloaded")}
+            {usage.process_resident_bytes != null &&
+              ` · ${t("settings.advanced.modelKeepAlive.memory.app", {
+                app: formatMegabytes(usage.process_resident_bytes),
+              })}`}
+            {usage.on_battery &&
+              ` · ${t("settings.advanced.modelKeepAlive.memory.onBattery")}`}
+          </div>
+        )}
+      </div>
+    </SettingContainer>
+  );
+};
@@ -0,0 +1,29 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface NumberNormalizationProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const NumberNormalization: React.FC<NumberNormalizationProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("number_normalization") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(enabled) => updateSetting("number_normalization", enabled)}
+        isUpdating={isUpdating("number_normalization")}
+        label={t("settings.advanced.numberNormalization.label")}
+        description={t("settings.advanced.numberNormalization.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
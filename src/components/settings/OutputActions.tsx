@@ -0,0 +1,183 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { useSettings } from "../../hooks/useSettings";
+import { commands, type OutputAction } from "@/bindings";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+
+interface OutputActionsProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const DEFAULT_HEADER = "## ${date} ${time}";
+
+interface OutputForm {
+  paste: boolean;
+  path: string;
+  header: string;
+  url: string;
+  command: string;
+}
+
+// The form edits one action of each kind; the settings file allows more
+const toForm = (actions: OutputAction[]): OutputForm => {
+  const find = <K extends OutputAction["kind"]>(kind: K) =>
+    actions.find(
+      (a): a is Extract<OutputAction, { kind: K }> => a.kind === kind,
+    );
+  const file = find("append_to_file");
+  return {
+    paste: actions.length === 0 || find("paste") !== undefined,
+    path: file?.path ?? "",
+    header: file?.header ?? DEFAULT_HEADER,
+    url: find("webhook")?.url ?? "",
+    command: find("command")?.command ?? "",
+  };
+};
+
+const toActions = (form: OutputForm): OutputAction[] => {
+  const actions: OutputAction[] = [];
+  if (form.paste) actions.push({ kind: "paste" });
+  if (form.path.trim()) {
+    actions.push({
+      kind: "append_to_file",
+      path: form.path.trim(),
+      header: form.header,
+    });
+  }
+  if (form.url.trim()) actions.push({ kind: "webhook", url: form.url.trim() });
+  if (form.command.trim()) {
+    actions.push({ kind: "command", command: form.command.trim() });
+  }
+  return actions;
+};
+
+export const OutputActions: React.FC<OutputActionsProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { settings, getSetting, refreshSettings } = useSettings();
+    const bindings = getSetting("bindings") || {};
+    const outputs = getSetting("binding_output") || {};
+
+    const bindingIds = Object.keys(bindings);
+    const [bindingId, setBindingId] = useState(bindingIds[0] ?? "transcribe");
+    const saved = outputs[bindingId] ?? [];
+    const [form, setForm] = useState<OutputForm>(() => toForm(saved));
+
+    useEffect(() => {
+      setForm(toForm(saved));
+    }, [bindingId, JSON.stringify(saved)]);
+
+    if (!settings) return null;
+
+    const save = async (next: OutputForm) => {
+      setForm(next);
+      const result = await commands.setBindingOutputActions(
+        bindingId,
+        toActions(next),
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+    };
+
+    const edit = (changes: Partial<OutputForm>) =>
+      setForm((current) => ({ ...current, ...changes }));
+
+    const bindingOptions = bindingIds.map((id) => ({
+      value: id,
+      label: bindings[id]?.name ?? id,
+    }));
+
+    const textField = (
+      field: "path" | "header" | "url" | "command",
+      placeholder?: string,
+    ) => (
+      <Input
+        type="text"
+        className="w-64"
+        value={form[field]}
+        onChange={(e) => edit({ [field]: e.target.value })}
+        onBlur={() => save(form)}
+        placeholder={placeholder}
+        variant="compact"
+      />
+    );
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.outputActions.title")}
+          description={t("settings.advanced.outputActions.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <Dropdown
+            options={bindingOptions}
+            selectedValue={bindingId}
+            onSelect={setBindingId}
+          />
+        </SettingContainer>
+        <ToggleSwitch
+          checked={form.paste}
+          onChange={(paste) => save({ ...form, paste })}
+          label={t("settings.advanced.outputActions.paste.title")}
+          description={t("settings.advanced.outputActions.paste.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <SettingContainer
+          title={t("settings.advanced.outputActions.path.title")}
+          description={t("settings.advanced.outputActions.path.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          {textField(
+            "path",
+            t("settings.advanced.outputActions.path.placeholder"),
+          )}
+        </SettingContainer>
+        {form.path.trim() && (
+          <SettingContainer
+            title={t("settings.advanced.outputActions.header.title")}
+            description={t(
+              "settings.advanced.outputActions.header.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          >
+            {textField("header")}
+          </SettingContainer>
+        )}
+        <SettingContainer
+          title={t("settings.advanced.outputActions.webhook.title")}
+          description={t(
+            "settings.advanced.outputActions.webhook.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          {textField("url", "https://")}
+        </SettingContainer>
+        <SettingContainer
+          title={t("settings.advanced.outputActions.command.title")}
+          description={t(
+            "settings.advanced.outputActions.command.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          {textField(
+            "command",
+            t("settings.advanced.outputActions.command.placeholder"),
+          )}
+        </SettingContainer>
+      </>
+    );
+  },
+);
@@ -0,0 +1,155 @@
+import React, { useEffect, useRef, useState } from "react";
+import { useTranslation } from "react-i18next";
+import {
+  commands,
+  type OverlayLayout,
+  type OverlayMonitor,
+  type OverlayPosition,
+} from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Dropdown } from "../ui/Dropdown";
+import { Input } from "../ui/Input";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+
+interface OverlayLayoutSettingsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const DEFAULT_LAYOUT: OverlayLayout = {
+  monitor: null,
+  custom_x: 0,
+  custom_y: 0,
+  scale: 1,
+  opacity: 1,
+};
+
+// How long the preview stays up after the last change
+const PREVIEW_HIDE_DELAY_MS = 2000;
+const FOLLOW_CURSOR = "cursor";
+
+export const OverlayLayoutSettings: React.FC<OverlayLayoutSettingsProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting } = useSettings();
+    const [monitors, setMonitors] = useState<OverlayMonitor[]>([]);
+    const hideTimer = useRef<ReturnType<typeof setTimeout> | null>(null);
+
+    const position = (getSetting("overlay_position") ||
+      "bottom") as OverlayPosition;
+    const layout = { ...DEFAULT_LAYOUT, ...getSetting("overlay_layout") };
+
+    useEffect(() => {
+      commands.listOverlayMonitors().then((result) => {
+        if (result.status === "ok") setMonitors(result.data);
+      });
+      return () => {
+        if (hideTimer.current) clearTimeout(hideTimer.current);
+        commands.endOverlayPreview();
+      };
+    }, []);
+
+    if (position === "none") return null;
+
+    const update = (changes: Partial<OverlayLayout>) => {
+      const next = { ...layout, ...changes };
+      updateSetting("overlay_layout", next);
+      commands.previewOverlayLayout(position, next);
+      if (hideTimer.current) clearTimeout(hideTimer.current);
+      hideTimer.current = setTimeout(
+        () => commands.endOverlayPreview(),
+        PREVIEW_HIDE_DELAY_MS,
+      );
+    };
+
+    const monitorOptions = [
+      {
+        value: FOLLOW_CURSOR,
+        label: t("settings.advanced.overlayLayout.monitor.followCursor"),
+      },
+      ...monitors.map((monitor) => ({
+        value: monitor.index.toString(),
+        label: monitor.is_primary
+          ? t("settings.advanced.overlayLayout.monitor.primary", {
+              name: monitor.name,
+            })
+          : monitor.name,
+      })),
+    ];
+    const selectedMonitor = monitors.find((m) => m.index === layout.monitor);
+
+    const coordinate = (field: "custom_x" | "custom_y", max?: number) => (
+      <Input
+        type="number"
+        className="w-24"
+        min={0}
+        max={max}
+        value={Math.round(layout[field] ?? 0)}
+        onChange={(e) => update({ [field]: Number(e.target.value) || 0 })}
+        variant="compact"
+      />
+    );
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.overlayLayout.monitor.title")}
+          description={t("settings.advanced.overlayLayout.monitor.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <Dropdown
+            options={monitorOptions}
+            selectedValue={layout.monitor?.toString() ?? FOLLOW_CURSOR}
+            onSelect={(value) =>
+              update({
+                monitor: value === FOLLOW_CURSOR ? null : Number(value),
+              })
+            }
+          />
+        </SettingContainer>
+        {position === "custom" && (
+          <SettingContainer
+            title={t("settings.advanced.overlayLayout.custom.title")}
+            description={t(
+              "settings.advanced.overlayLayout.custom.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          >
+            <div className="flex items-center gap-2">
+              {coordinate("custom_x", selectedMonitor?.width)}
+              {coordinate("custom_y", selectedMonitor?.height)}
+            </div>
+          </SettingContainer>
+        )}
+        <Slider
+          value={layout.scale ?? 1}
+          onChange={(scale) => update({ scale })}
+          min={0.5}
+          max={2}
+          step={0.1}
+          label={t("settings.advanced.overlayLayout.scale.title")}
+          description={t("settings.advanced.overlayLayout.scale.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          formatValue={(value) => `${Math.round(value * 100)}%`}
+        />
+        <Slider
+          value={layout.opacity ?? 1}
+          onChange={(opacity) => update({ opacity })}
+          min={0.2}
+          max={1}
+          step={0.05}
+          label={t("settings.advanced.overlayLayout.opacity.title")}
+          description={t(
+            "settings.advanced.overlayLayout.opacity.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+          formatValue={(value) => `${Math.round(value * 100)}%`}
+        />
+      </>
+    );
+  });
@@ -31,6 +31,10 @@ export const PasteMethodSetting: React.FC<PasteMethodProps> = React.memo(
           value: "direct",
           label: t("settings.advanced.pasteMethod.options.direct"),
         },
+        {
+          value: "per_character",
+          label: t("settings.advanced.pasteMethod.options.perCharacter"),
+        },
         {
           value: "none",
           label: t("settings.advanced.pasteMethod.options.none"),
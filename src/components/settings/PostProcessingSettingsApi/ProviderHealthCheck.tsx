@@ -0,0 +1,102 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { CheckCircle2, CircleSlash, XCircle } from "lucide-react";
+import {
+  commands,
+  type HealthCheckStatus,
+  type ProviderHealthReport,
+} from "@/bindings";
+import { Button } from "../../ui/Button";
+
+interface ProviderHealthCheckProps {
+  providerId: string;
+  model: string;
+}
+
+const STATUS_ICONS: Record<HealthCheckStatus, React.ReactNode> = {
+  ok: <CheckCircle2 className="w-4 h-4 text-green-500" />,
+  failed: <XCircle className="w-4 h-4 text-red-500" />,
+  skipped: <CircleSlash className="w-4 h-4 text-mid-gray" />,
+};
+
+/**
+ * Checks the provider's base URL, API key and model with a test request and
+ * lists the result of each step.
+ */
+export const ProviderHealthCheck: React.FC<ProviderHealthCheckProps> = ({
+  providerId,
+  model,
+}) => {
+  const { t } = useTranslation();
+  const [checking, setChecking] = useState(false);
+  const [report, setReport] = useState<ProviderHealthReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+
+  // A result only describes the configuration it was made for
+  useEffect(() => {
+    setReport(null);
+    setError(null);
+  }, [providerId, model]);
+
+  const runCheck = async () => {
+    setChecking(true);
+    setError(null);
+    try {
+      const result = await commands.checkPostProcessProvider(providerId, null);
+      if (result.status === "ok") {
+        setReport(result.data);
+      } else {
+        setReport(null);
+        setError(result.error);
+      }
+    } finally {
+      setChecking(false);
+    }
+  };
+
+  return (
+    <div className="space-y-2">
+      <div className="flex items-center gap-3">
+        <Button
+          variant="secondary"
+          size="sm"
+          onClick={runCheck}
+          disabled={checking}
+        >
+          {checking
+            ? t("settings.postProcessing.api.healthCheck.checking")
+            : t("settings.postProcessing.api.healthCheck.button")}
+        </Button>
+        {report && (
+          <span
+            className={`text-sm ${report.healthy ? "text-green-500" : "text-red-500"}`}
+          >
+            {report.healthy
+              ? t("settings.postProcessing.api.healthCheck.healthy", {
+                  latency: report.completion_latency_ms ?? 0,
+                })
+              : t("settings.postProcessing.api.healthCheck.unhealthy")}
+          </span>
+        )}
+      </div>
+      {error && <p className="text-sm text-red-500">{error}</p>}
+      {report && (
+        <ul className="space-y-1">
+          {report.steps.map((step) => (
+            <li key={step.name} className="flex items-start gap-2 text-sm">
+              {STATUS_ICONS[step.status]}
+              <span className="font-medium">
+                {t(
+                  `settings.postProcessing.api.healthCheck.steps.${step.name}`,
+                )}
+              </span>
+              {step.detail && (
+                <span className="text-mid-gray break-all">{step.detail}</span>
+              )}
+            </li>
+          ))}
+        </ul>
+      )}
+    </div>
+  );
+};
@@ -1,4 +1,4 @@
-import { useCallback, useMemo, useState } from "react";
+import { useCallback, useEffect, useMemo, useState } from "react";
 import { useSettings } from "../../../hooks/useSettings";
 import { commands, type PostProcessProvider } from "@/bindings";
 import type { ModelOption } from "./types";
@@ -14,6 +14,10 @@ type PostProcessProviderState = {
   baseUrl: string;
   handleBaseUrlChange: (value: string) => void;
   isBaseUrlUpdating: boolean;
+  isAzureProvider: boolean;
+  apiVersion: string;
+  handleApiVersionChange: (value: string) => void;
+  isApiVersionUpdating: boolean;
   apiKey: string;
   handleApiKeyChange: (value: string) => void;
   isApiKeyUpdating: boolean;
@@ -36,6 +40,7 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
     isUpdating,
     setPostProcessProvider,
     updatePostProcessBaseUrl,
+    updatePostProcessApiVersion,
     updatePostProcessApiKey,
     updatePostProcessModel,
     fetchPostProcessModels,
@@ -62,9 +67,23 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
 
   // Use settings directly as single source of truth
   const baseUrl = selectedProvider?.base_url ?? "";
-  const apiKey = settings?.post_process_api_keys?.[selectedProviderId] ?? "";
+  const apiVersion = selectedProvider?.api_version ?? "";
   const model = settings?.post_process_models?.[selectedProviderId] ?? "";
 
+  // API keys are kept in the OS keychain, not in the settings
+  const [apiKey, setApiKey] = useState("");
+  useEffect(() => {
+    let cancelled = false;
+    void commands.getPostProcessApiKey(selectedProviderId).then((result) => {
+      if (!cancelled) {
+        setApiKey(result.status === "ok" ? result.data : "");
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [selectedProviderId]);
+
   const providerOptions = useMemo<DropdownOption[]>(() => {
     return providers.map((provider) => ({
       value: provider.id,
@@ -96,7 +115,7 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
 
   const handleBaseUrlChange = useCallback(
     (value: string) => {
-      if (!selectedProvider || selectedProvider.id !== "custom") {
+      if (!selectedProvider?.allow_base_url_edit) {
         return;
       }
       const trimmed = value.trim();
@@ -107,10 +126,24 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
     [selectedProvider, baseUrl, updatePostProcessBaseUrl],
   );
 
+  const handleApiVersionChange = useCallback(
+    (value: string) => {
+      if (selectedProvider?.api !== "azure_open_ai") {
+        return;
+      }
+      const trimmed = value.trim();
+      if (trimmed !== apiVersion) {
+        void updatePostProcessApiVersion(selectedProvider.id, trimmed);
+      }
+    },
+    [selectedProvider, apiVersion, updatePostProcessApiVersion],
+  );
+
   const handleApiKeyChange = useCallback(
     (value: string) => {
       const trimmed = value.trim();
       if (trimmed !== apiKey) {
+        setApiKey(trimmed);
         void updatePostProcessApiKey(selectedProviderId, trimmed);
       }
     },
@@ -173,6 +206,9 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
   const isBaseUrlUpdating = isUpdating(
     `post_process_base_url:${selectedProviderId}`,
   );
+  const isApiVersionUpdating = isUpdating(
+    `post_process_api_version:${selectedProviderId}`,
+  );
   const isApiKeyUpdating = isUpdating(
     `post_process_api_key:${selectedProviderId}`,
   );
@@ -184,6 +220,7 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
   );
 
   const isCustomProvider = selectedProvider?.id === "custom";
+  const isAzureProvider = selectedProvider?.api === "azure_open_ai";
 
   // No automatic fetching - user must click refresh button
 
@@ -197,6 +234,10 @@ export const usePostProcessProviderState = (): PostProcessProviderState => {
     baseUrl,
     handleBaseUrlChange,
     isBaseUrlUpdating,
+    isAzureProvider,
+    apiVersion,
+    handleApiVersionChange,
+    isApiVersionUpdating,
     apiKey,
     handleApiKeyChange,
     isApiKeyUpdating,
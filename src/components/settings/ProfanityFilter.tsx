@@ -0,0 +1,73 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, type ProfanityMask } from "@/bindings";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface ProfanityFilterProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const ProfanityFilter: React.FC<ProfanityFilterProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, refreshSettings } = useSettings();
+    const bindings = getSetting("bindings") || {};
+    const masks = getSetting("binding_profanity") || {};
+
+    const bindingIds = Object.keys(bindings);
+    const [bindingId, setBindingId] = useState(bindingIds[0] ?? "transcribe");
+    const [saving, setSaving] = useState(false);
+    const mask = masks[bindingId] ?? "keep";
+
+    const handleSelect = async (value: string) => {
+      setSaving(true);
+      const result = await commands.setBindingProfanityMask(
+        bindingId,
+        value as ProfanityMask,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
+      await refreshSettings();
+      setSaving(false);
+    };
+
+    const bindingOptions = bindingIds.map((id) => ({
+      value: id,
+      label: bindings[id]?.name ?? id,
+    }));
+    const maskOptions = (["keep", "asterisks", "remove"] as const).map(
+      (value) => ({
+        value,
+        label: t(`settings.advanced.profanityFilter.options.${value}`),
+      }),
+    );
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.profanityFilter.title")}
+        description={t("settings.advanced.profanityFilter.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center gap-2">
+          <Dropdown
+            options={bindingOptions}
+            selectedValue={bindingId}
+            onSelect={setBindingId}
+          />
+          <Dropdown
+            options={maskOptions}
+            selectedValue={mask}
+            onSelect={handleSelect}
+            disabled={saving}
+          />
+        </div>
+      </SettingContainer>
+    );
+  },
+);
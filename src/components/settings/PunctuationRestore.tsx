@@ -0,0 +1,48 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface PunctuationRestoreProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const PunctuationRestore: React.FC<PunctuationRestoreProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const enabled = getSetting("punctuation_restore") ?? true;
+    const casingModel = getSetting("punctuation_restore_casing_model") ?? false;
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={enabled}
+          onChange={(enabled) => updateSetting("punctuation_restore", enabled)}
+          isUpdating={isUpdating("punctuation_restore")}
+          label={t("settings.advanced.punctuationRestore.label")}
+          description={t("settings.advanced.punctuationRestore.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <ToggleSwitch
+            checked={casingModel}
+            onChange={(enabled) =>
+              updateSetting("punctuation_restore_casing_model", enabled)
+            }
+            isUpdating={isUpdating("punctuation_restore_casing_model")}
+            label={t("settings.advanced.punctuationRestore.casingModel.label")}
+            description={t(
+              "settings.advanced.punctuationRestore.casingModel.description",
+            )}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+      </>
+    );
+  },
+);
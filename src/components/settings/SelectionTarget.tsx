@@ -0,0 +1,73 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+import { useOsType } from "../../hooks/useOsType";
+import type { SelectionTarget } from "@/bindings";
+
+interface SelectionTargetProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SelectionTargetSetting: React.FC<SelectionTargetProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const osType = useOsType();
+
+    // The PRIMARY selection only exists on X11/Wayland
+    if (osType !== "linux") return null;
+
+    const selectionTargetOptions = [
+      {
+        value: "clipboard",
+        label: t("settings.advanced.selectionTarget.options.clipboard"),
+      },
+      {
+        value: "primary",
+        label: t("settings.advanced.selectionTarget.options.primary"),
+      },
+      {
+        value: "both",
+        label: t("settings.advanced.selectionTarget.options.both"),
+      },
+    ];
+
+    const selectedTarget = (getSetting("selection_target") ||
+      "clipboard") as SelectionTarget;
+    const middleClickPaste = getSetting("middle_click_paste") ?? false;
+
+    return (
+      <>
+        <SettingContainer
+          title={t("settings.advanced.selectionTarget.title")}
+          description={t("settings.advanced.selectionTarget.description")}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        >
+          <Dropdown
+            options={selectionTargetOptions}
+            selectedValue={selectedTarget}
+            onSelect={(value) =>
+              updateSetting("selection_target", value as SelectionTarget)
+            }
+            disabled={isUpdating("selection_target")}
+          />
+        </SettingContainer>
+        {selectedTarget === "primary" && (
+          <ToggleSwitch
+            checked={middleClickPaste}
+            onChange={(enabled) => updateSetting("middle_click_paste", enabled)}
+            isUpdating={isUpdating("middle_click_paste")}
+            label={t("settings.advanced.middleClickPaste.label")}
+            description={t("settings.advanced.middleClickPaste.description")}
+            descriptionMode={descriptionMode}
+            grouped={grouped}
+          />
+        )}
+      </>
+    );
+  });
@@ -0,0 +1,129 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { ask, open, save } from "@tauri-apps/plugin-dialog";
+import { toast } from "sonner";
+import { commands, type ConflictResolution } from "@/bindings";
+import { useSettings } from "../../hooks/useSettings";
+import { Button } from "../ui/Button";
+import { SettingContainer } from "../ui/SettingContainer";
+
+interface SettingsProfileProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const PROFILE_FILTERS = [{ name: "JSON", extensions: ["json"] }];
+
+export const SettingsProfile: React.FC<SettingsProfileProps> = ({
+  descriptionMode = "inline",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { refreshSettings } = useSettings();
+  const [busy, setBusy] = useState(false);
+
+  const handleExport = async () => {
+    const path = await save({
+      defaultPath: "voice-input-settings.json",
+      filters: PROFILE_FILTERS,
+    });
+    if (!path) return;
+
+    setBusy(true);
+    try {
+      const result = await commands.exportSettings(path);
+      if (result.status === "ok") {
+        toast.success(t("settings.advanced.profile.exported"));
+      } else {
+        toast.error(result.error);
+      }
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  const handleImport = async () => {
+    const path = await open({ multiple: false, filters: PROFILE_FILTERS });
+    if (!path) return;
+
+    setBusy(true);
+    try {
+      // Dry run first to find out whether anything conflicts
+      const preview = await commands.importSettings(path, "use_imported", true);
+      if (preview.status === "error") {
+        toast.error(preview.error);
+        return;
+      }
+
+      let resolution: ConflictResolution = "use_imported";
+      const conflicts = preview.data.replaced;
+      if (conflicts.length > 0) {
+        const useImported = await ask(
+          t("settings.advanced.profile.conflicts", {
+            count: conflicts.length,
+            fields: conflicts.join(", "),
+          }),
+          {
+            title: t("settings.advanced.profile.conflictsTitle"),
+            kind: "warning",
+            okLabel: t("settings.advanced.profile.useImported"),
+            cancelLabel: t("settings.advanced.profile.keepExisting"),
+          },
+        );
+        resolution = useImported ? "use_imported" : "keep_existing";
+      }
+
+      const result = await commands.importSettings(path, resolution, false);
+      if (result.status === "error") {
+        toast.error(result.error);
+        return;
+      }
+      await refreshSettings();
+
+      const { added, replaced, errors } = result.data;
+      toast.success(
+        t("settings.advanced.profile.imported", {
+          added: added.length,
+          replaced: replaced.length,
+        }),
+      );
+      if (errors.length > 0) {
+        toast.warning(
+          t("settings.advanced.profile.invalid", {
+            fields: errors.map((e) => e.field).join(", "),
+          }),
+        );
+      }
+    } finally {
+      setBusy(false);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.profile.title")}
+      description={t("settings.advanced.profile.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <div className="flex gap-2">
+        <Button
+          onClick={handleImport}
+          disabled={busy}
+          variant="secondary"
+          size="sm"
+        >
+          {t("settings.advanced.profile.import")}
+        </Button>
+        <Button
+          onClick={handleExport}
+          disabled={busy}
+          variant="secondary"
+          size="sm"
+        >
+          {t("settings.advanced.profile.export")}
+        </Button>
+      </div>
+    </SettingContainer>
+  );
+};
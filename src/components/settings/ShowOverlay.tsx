@@ -19,6 +19,25 @@ export const ShowOverlay: React.FC<ShowOverlayProps> = React.memo(
       { value: "none", label: t("settings.advanced.overlay.options.none") },
       { value: "bottom", label: t("settings.advanced.overlay.options.bottom") },
       { value: "top", label: t("settings.advanced.overlay.options.top") },
+      {
+        value: "top_left",
+        label: t("settings.advanced.overlay.options.topLeft"),
+      },
+      {
+        value: "top_right",
+        label: t("settings.advanced.overlay.options.topRight"),
+      },
+      {
+        value: "bottom_left",
+        label: t("settings.advanced.overlay.options.bottomLeft"),
+      },
+      {
+        value: "bottom_right",
+        label: t("settings.advanced.overlay.options.bottomRight"),
+      },
+      { value: "left", label: t("settings.advanced.overlay.options.left") },
+      { value: "right", label: t("settings.advanced.overlay.options.right") },
+      { value: "custom", label: t("settings.advanced.overlay.options.custom") },
     ];
 
     const selectedPosition = (getSetting("overlay_position") ||
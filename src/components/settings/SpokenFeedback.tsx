@@ -0,0 +1,125 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands } from "@/bindings";
+import { Button } from "../ui/Button";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { Slider } from "../ui/Slider";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface SpokenFeedbackProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SpokenFeedback: React.FC<SpokenFeedbackProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+    const [voices, setVoices] = useState<string[]>([]);
+    const [error, setError] = useState<string | null>(null);
+
+    const readTranscripts = getSetting("tts_read_transcripts") ?? false;
+    const readErrors = getSetting("tts_read_errors") ?? false;
+    const voice = getSetting("tts_voice") ?? "";
+    const rate = getSetting("tts_rate") ?? 1;
+    const enabled = readTranscripts || readErrors;
+
+    const refreshVoices = useCallback(async () => {
+      const result = await commands.listTtsVoices();
+      if (result.status === "ok") {
+        setVoices(result.data);
+        setError(null);
+      } else {
+        setVoices([]);
+        setError(result.error);
+      }
+    }, []);
+
+    useEffect(() => {
+      if (enabled) {
+        refreshVoices();
+      }
+    }, [enabled, refreshVoices]);
+
+    const handlePreview = async () => {
+      const result = await commands.previewTts(
+        t("settings.sound.spokenFeedback.previewText"),
+      );
+      setError(result.status === "error" ? result.error : null);
+    };
+
+    const voiceOptions = [
+      { value: "", label: t("settings.sound.spokenFeedback.voice.default") },
+      ...voices.map((name) => ({ value: name, label: name })),
+    ];
+
+    return (
+      <>
+        <ToggleSwitch
+          checked={readTranscripts}
+          onChange={(enabled) => updateSetting("tts_read_transcripts", enabled)}
+          isUpdating={isUpdating("tts_read_transcripts")}
+          label={t("settings.sound.spokenFeedback.readTranscripts.label")}
+          description={t(
+            "settings.sound.spokenFeedback.readTranscripts.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        <ToggleSwitch
+          checked={readErrors}
+          onChange={(enabled) => updateSetting("tts_read_errors", enabled)}
+          isUpdating={isUpdating("tts_read_errors")}
+          label={t("settings.sound.spokenFeedback.readErrors.label")}
+          description={t(
+            "settings.sound.spokenFeedback.readErrors.description",
+          )}
+          descriptionMode={descriptionMode}
+          grouped={grouped}
+        />
+        {enabled && (
+          <>
+            <SettingContainer
+              title={t("settings.sound.spokenFeedback.voice.title")}
+              description={t("settings.sound.spokenFeedback.voice.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+            >
+              <div className="flex items-center gap-2">
+                <Dropdown
+                  options={voiceOptions}
+                  selectedValue={voice}
+                  onSelect={(value) =>
+                    updateSetting("tts_voice", value === "" ? null : value)
+                  }
+                  disabled={isUpdating("tts_voice")}
+                  onRefresh={refreshVoices}
+                />
+                <Button variant="secondary" size="sm" onClick={handlePreview}>
+                  {t("settings.sound.spokenFeedback.preview")}
+                </Button>
+              </div>
+            </SettingContainer>
+            <Slider
+              value={rate}
+              onChange={(value) => updateSetting("tts_rate", value)}
+              min={0.5}
+              max={2}
+              step={0.1}
+              label={t("settings.sound.spokenFeedback.rate.title")}
+              description={t("settings.sound.spokenFeedback.rate.description")}
+              descriptionMode={descriptionMode}
+              grouped={grouped}
+              formatValue={(v) => `${v.toFixed(1)}×`}
+            />
+            {error && (
+              <div className="px-4 pb-2 text-sm text-red-400">{error}</div>
+            )}
+          </>
+        )}
+      </>
+    );
+  },
+);
@@ -0,0 +1,32 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Slider } from "../ui/Slider";
+import { useSettings } from "../../hooks/useSettings";
+
+interface TranscriptionWorkersProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const TranscriptionWorkers: React.FC<TranscriptionWorkersProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting } = useSettings();
+
+  return (
+    <Slider
+      value={getSetting("transcription_workers") ?? 1}
+      onChange={(value) => updateSetting("transcription_workers", value)}
+      min={1}
+      max={8}
+      step={1}
+      label={t("settings.advanced.transcriptionWorkers.title")}
+      description={t("settings.advanced.transcriptionWorkers.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      formatValue={(value) => value.toString()}
+    />
+  );
+};
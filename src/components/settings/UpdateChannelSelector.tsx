@@ -0,0 +1,48 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import { UpdateChannel } from "@/bindings";
+
+interface UpdateChannelSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const UpdateChannelSelector: React.FC<UpdateChannelSelectorProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const selectedChannel = getSetting("update_channel") || "stable";
+    const updateChecksEnabled = getSetting("update_checks_enabled") ?? true;
+
+    const channelOptions = [
+      {
+        value: "stable",
+        label: t("settings.about.updates.channel.stable"),
+      },
+      { value: "beta", label: t("settings.about.updates.channel.beta") },
+    ];
+
+    return (
+      <SettingContainer
+        title={t("settings.about.updates.channel.title")}
+        description={t("settings.about.updates.channel.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={channelOptions}
+          selectedValue={selectedChannel}
+          onSelect={(channel) =>
+            updateSetting("update_channel", channel as UpdateChannel)
+          }
+          disabled={!updateChecksEnabled || isUpdating("update_channel")}
+        />
+      </SettingContainer>
+    );
+  });
+
+UpdateChannelSelector.displayName = "UpdateChannelSelector";
@@ -0,0 +1,56 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+
+interface UpdateCheckIntervalProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+const INTERVAL_HOURS = [6, 12, 24, 72, 168];
+
+export const UpdateCheckInterval: React.FC<UpdateCheckIntervalProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const selectedHours = getSetting("update_check_interval_hours") ?? 24;
+    const updateChecksEnabled = getSetting("update_checks_enabled") ?? true;
+
+    // Keep a value set outside the presets selectable
+    const choices = INTERVAL_HOURS.includes(selectedHours)
+      ? INTERVAL_HOURS
+      : [...INTERVAL_HOURS, selectedHours].sort((a, b) => a - b);
+
+    const intervalOptions = choices.map((hours) => ({
+      value: hours.toString(),
+      label:
+        hours % 24 === 0
+          ? t("settings.about.updates.interval.days", { count: hours / 24 })
+          : t("settings.about.updates.interval.hours", { count: hours }),
+    }));
+
+    return (
+      <SettingContainer
+        title={t("settings.about.updates.interval.title")}
+        description={t("settings.about.updates.interval.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={intervalOptions}
+          selectedValue={selectedHours.toString()}
+          onSelect={(hours) =>
+            updateSetting("update_check_interval_hours", Number(hours))
+          }
+          disabled={
+            !updateChecksEnabled || isUpdating("update_check_interval_hours")
+          }
+        />
+      </SettingContainer>
+    );
+  });
+
+UpdateCheckInterval.displayName = "UpdateCheckInterval";
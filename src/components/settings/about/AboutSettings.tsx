@@ -8,10 +8,22 @@ import { Button } from "../../ui/Button";
 import { AppDataDirectory } from "../AppDataDirectory";
 import { AppLanguageSelector } from "../AppLanguageSelector";
 import { LogDirectory } from "../debug";
+import { UpdateChecksToggle } from "../UpdateChecksToggle";
+import { UpdateChannelSelector } from "../UpdateChannelSelector";
+import { UpdateCheckInterval } from "../UpdateCheckInterval";
+import UpdateChecker from "../../update-checker";
+import { useUpdateStatus } from "../../../hooks/useUpdateStatus";
 
 export const AboutSettings: React.FC = () => {
   const { t } = useTranslation();
   const [version, setVersion] = useState("");
+  const updateStatus = useUpdateStatus();
+  const pendingUpdate =
+    updateStatus.state === "available" ||
+    updateStatus.state === "downloading" ||
+    updateStatus.state === "ready"
+      ? updateStatus.update
+      : null;
 
   useEffect(() => {
     const fetchVersion = async () => {
@@ -76,6 +88,33 @@ export const AboutSettings: React.FC = () => {
         <LogDirectory grouped={true} />
       </SettingsGroup>
 
+      <SettingsGroup title={t("settings.about.updates.title")}>
+        <UpdateChecksToggle descriptionMode="tooltip" grouped={true} />
+        <UpdateChannelSelector descriptionMode="tooltip" grouped={true} />
+        <UpdateCheckInterval descriptionMode="tooltip" grouped={true} />
+        <SettingContainer
+          title={t("settings.about.updates.status.title")}
+          description={t("settings.about.updates.status.description")}
+          grouped={true}
+        >
+          <UpdateChecker className="text-sm" />
+        </SettingContainer>
+        {pendingUpdate && (
+          <SettingContainer
+            title={t("settings.about.updates.notes.title", {
+              version: pendingUpdate.version,
+            })}
+            description={t("settings.about.updates.notes.description")}
+            grouped={true}
+            layout="stacked"
+          >
+            <div className="text-sm text-mid-gray whitespace-pre-wrap">
+              {pendingUpdate.notes ?? t("settings.about.updates.notes.empty")}
+            </div>
+          </SettingContainer>
+        )}
+      </SettingsGroup>
+
       <SettingsGroup title={t("settings.about.acknowledgments.title")}>
         <SettingContainer
           title={t("settings.about.acknowledgments.whisper.title")}
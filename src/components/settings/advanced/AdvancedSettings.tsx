@@ -1,17 +1,37 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
 import { ShowOverlay } from "../ShowOverlay";
+import { OverlayLayoutSettings } from "../OverlayLayoutSettings";
 import { ModelUnloadTimeoutSetting } from "../ModelUnloadTimeout";
+import { ModelKeepAliveSetting } from "../ModelKeepAlive";
+import { TranscriptionWorkers } from "../TranscriptionWorkers";
+import { BatterySaver } from "../BatterySaver";
+import { SettingsProfile } from "../SettingsProfile";
 import { CustomWords } from "../CustomWords";
+import { HallucinationFilter } from "../HallucinationFilter";
+import { PunctuationRestore } from "../PunctuationRestore";
+import { NumberNormalization } from "../NumberNormalization";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { StartHidden } from "../StartHidden";
+import { HeadlessMode } from "../HeadlessMode";
 import { AutostartToggle } from "../AutostartToggle";
 import { PasteMethodSetting } from "../PasteMethod";
+import { PasteFallback } from "../PasteFallback";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
+import { SelectionTargetSetting } from "../SelectionTarget";
+import { CompletionNotifications } from "../CompletionNotifications";
+import { ConfirmBeforePaste } from "../ConfirmBeforePaste";
+import { OutputActions } from "../OutputActions";
+import { AppBehaviors } from "../AppBehaviors";
+import { ProfanityFilter } from "../ProfanityFilter";
+import { MeetingNotesFolder } from "../MeetingNotesFolder";
 import { PostProcessingToggle } from "../PostProcessingToggle";
 import { AppendTrailingSpace } from "../AppendTrailingSpace";
+import { ContinueDictation } from "../ContinueDictation";
 import { HistoryLimit } from "../HistoryLimit";
 import { RecordingRetentionPeriodSelector } from "../RecordingRetentionPeriod";
+import { HistoryRetention } from "../HistoryRetention";
+import { PrivateMode } from "../PrivateMode";
 import { ExperimentalToggle } from "../ExperimentalToggle";
 import { useSettings } from "../../../hooks/useSettings";
 import { KeyboardImplementationSelector } from "../debug/KeyboardImplementationSelector";
@@ -25,28 +45,48 @@ export const AdvancedSettings: React.FC = () => {
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title={t("settings.advanced.groups.app")}>
         <StartHidden descriptionMode="tooltip" grouped={true} />
+        <HeadlessMode descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
+        <OverlayLayoutSettings descriptionMode="tooltip" grouped={true} />
+        <ModelKeepAliveSetting descriptionMode="tooltip" grouped={true} />
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
+        <BatterySaver descriptionMode="tooltip" grouped={true} />
         <ExperimentalToggle descriptionMode="tooltip" grouped={true} />
+        <SettingsProfile descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.advanced.groups.output")}>
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
+        <PasteFallback descriptionMode="tooltip" grouped={true} />
         <ClipboardHandlingSetting descriptionMode="tooltip" grouped={true} />
+        <SelectionTargetSetting descriptionMode="tooltip" grouped={true} />
+        <CompletionNotifications descriptionMode="tooltip" grouped={true} />
+        <ConfirmBeforePaste descriptionMode="tooltip" grouped={true} />
+        <OutputActions descriptionMode="tooltip" grouped={true} />
+        <AppBehaviors descriptionMode="tooltip" grouped={true} />
+        <ProfanityFilter descriptionMode="tooltip" grouped={true} />
+        <MeetingNotesFolder descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.advanced.groups.transcription")}>
         <CustomWords descriptionMode="tooltip" grouped />
+        <HallucinationFilter descriptionMode="tooltip" grouped={true} />
+        <PunctuationRestore descriptionMode="tooltip" grouped={true} />
+        <NumberNormalization descriptionMode="tooltip" grouped={true} />
         <AppendTrailingSpace descriptionMode="tooltip" grouped={true} />
+        <ContinueDictation descriptionMode="tooltip" grouped={true} />
+        <TranscriptionWorkers descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.advanced.groups.history")}>
+        <PrivateMode descriptionMode="tooltip" grouped={true} />
         <HistoryLimit descriptionMode="tooltip" grouped={true} />
         <RecordingRetentionPeriodSelector
           descriptionMode="tooltip"
           grouped={true}
         />
+        <HistoryRetention descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
 
       {experimentalEnabled && (
@@ -0,0 +1,85 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, type LatencyReport } from "@/bindings";
+import { Button } from "../../ui/Button";
+import { SettingContainer } from "../../ui/SettingContainer";
+
+interface LatencyStatsProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const DICTATION_LIMIT = 50;
+
+export const LatencyStats: React.FC<LatencyStatsProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [report, setReport] = useState<LatencyReport | null>(null);
+
+  const refresh = useCallback(async () => {
+    const result = await commands.getLatencyReport(DICTATION_LIMIT);
+    if (result.status === "ok") {
+      setReport(result.data);
+    }
+  }, []);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.latency.title")}
+      description={t("settings.debug.latency.description", {
+        count: DICTATION_LIMIT,
+      })}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="flex flex-col gap-2">
+        {!report || report.stages.length === 0 ? (
+          <div className="text-sm text-mid-gray">
+            {t("settings.debug.latency.empty")}
+          </div>
+        ) : (
+          <table className="w-full text-xs font-mono">
+            <thead>
+              <tr className="text-mid-gray text-left">
+                <th className="font-normal">
+                  {t("settings.debug.latency.stage")}
+                </th>
+                <th className="font-normal text-right">
+                  {t("settings.debug.latency.median")}
+                </th>
+                <th className="font-normal text-right">p90</th>
+                <th className="font-normal text-right">
+                  {t("settings.debug.latency.max")}
+                </th>
+                <th className="font-normal text-right">n</th>
+              </tr>
+            </thead>
+            <tbody>
+              {report.stages.map((stage) => (
+                <tr key={stage.stage}>
+                  <td>{t(`settings.debug.latency.stages.${stage.stage}`)}</td>
+                  <td className="text-right">{stage.median_ms} ms</td>
+                  <td className="text-right">{stage.p90_ms} ms</td>
+                  <td className="text-right">{stage.max_ms} ms</td>
+                  <td className="text-right">{stage.count}</td>
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        )}
+        <div className="flex justify-end">
+          <Button onClick={refresh} variant="secondary" size="sm">
+            {t("settings.debug.latency.refresh")}
+          </Button>
+        </div>
+      </div>
+    </SettingContainer>
+  );
+};
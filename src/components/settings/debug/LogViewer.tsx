@@ -0,0 +1,98 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, type LogEvent } from "@/bindings";
+import { Button } from "../../ui/Button";
+import { SettingContainer } from "../../ui/SettingContainer";
+
+interface LogViewerProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+const EVENT_LIMIT = 200;
+
+const formatEvent = (event: LogEvent) => {
+  const time = new Date(event.timestamp).toLocaleTimeString();
+  const spans = event.spans.length > 0 ? ` ${event.spans.join(":")}` : "";
+  const fields = Object.entries(event.fields)
+    .map(([key, value]) => ` ${key}=${value}`)
+    .join("");
+  return `${time} ${event.level}${spans} ${event.message}${fields}`;
+};
+
+export const LogViewer: React.FC<LogViewerProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [events, setEvents] = useState<LogEvent[]>([]);
+
+  const refresh = useCallback(async () => {
+    const result = await commands.getRecentLogEvents(EVENT_LIMIT);
+    if (result.status === "ok") {
+      setEvents(result.data);
+    }
+  }, []);
+
+  useEffect(() => {
+    refresh();
+  }, [refresh]);
+
+  const handleCopy = async () => {
+    try {
+      await navigator.clipboard.writeText(JSON.stringify(events, null, 2));
+      toast.success(t("settings.debug.logViewer.copied"));
+    } catch (error) {
+      console.error("Failed to copy log events:", error);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.logViewer.title")}
+      description={t("settings.debug.logViewer.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="flex flex-col gap-2">
+        <div className="max-h-64 overflow-y-auto rounded-lg border border-mid-gray/20 bg-mid-gray/5 p-2 font-mono text-xs select-text">
+          {events.length === 0 ? (
+            <div className="text-mid-gray">
+              {t("settings.debug.logViewer.empty")}
+            </div>
+          ) : (
+            events.map((event, index) => (
+              <div
+                key={index}
+                className={
+                  event.level === "ERROR"
+                    ? "text-red-400"
+                    : event.level === "WARN"
+                      ? "text-yellow-500"
+                      : undefined
+                }
+              >
+                {formatEvent(event)}
+              </div>
+            ))
+          )}
+        </div>
+        <div className="flex gap-2 justify-end">
+          <Button onClick={refresh} variant="secondary" size="sm">
+            {t("settings.debug.logViewer.refresh")}
+          </Button>
+          <Button
+            onClick={handleCopy}
+            disabled={events.length === 0}
+            variant="secondary"
+            size="sm"
+          >
+            {t("settings.debug.logViewer.copy")}
+          </Button>
+        </div>
+      </div>
+    </SettingContainer>
+  );
+};
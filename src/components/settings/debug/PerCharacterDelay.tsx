@@ -0,0 +1,36 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Slider } from "../../ui/Slider";
+import { useSettings } from "../../../hooks/useSettings";
+
+interface PerCharacterDelayProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const PerCharacterDelay: React.FC<PerCharacterDelayProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { settings, updateSetting } = useSettings();
+
+  const handleDelayChange = (value: number) => {
+    updateSetting("per_character_delay_ms", value);
+  };
+
+  return (
+    <Slider
+      value={settings?.per_character_delay_ms ?? 15}
+      onChange={handleDelayChange}
+      min={0}
+      max={100}
+      step={5}
+      label={t("settings.debug.perCharacterDelay.title")}
+      description={t("settings.debug.perCharacterDelay.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      formatValue={(v) => `${v}ms`}
+    />
+  );
+};
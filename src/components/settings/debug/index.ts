@@ -1,3 +1,6 @@
 export { WordCorrectionThreshold } from "./WordCorrectionThreshold";
 export { LogDirectory } from "./LogDirectory";
 export { LogLevelSelector } from "./LogLevelSelector";
+export { LogTranscripts } from "./LogTranscripts";
+export { LogViewer } from "./LogViewer";
+export { LatencyStats } from "./LatencyStats";
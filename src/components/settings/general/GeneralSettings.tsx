@@ -1,14 +1,19 @@
 import React from "react";
 import { useTranslation } from "react-i18next";
 import { MicrophoneSelector } from "../MicrophoneSelector";
+import { MicrophoneTest } from "../MicrophoneTest";
+import { BindingAudio } from "../BindingAudio";
 import { ShortcutInput } from "../ShortcutInput";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { OutputDeviceSelector } from "../OutputDeviceSelector";
 import { PushToTalk } from "../PushToTalk";
+import { HotkeyGestures } from "../HotkeyGestures";
+import { BusyBehaviorSetting } from "../BusyBehavior";
 import { AudioFeedback } from "../AudioFeedback";
 import { useSettings } from "../../../hooks/useSettings";
 import { VolumeSlider } from "../VolumeSlider";
 import { MuteWhileRecording } from "../MuteWhileRecording";
+import { SpokenFeedback } from "../SpokenFeedback";
 import { ModelSettingsCard } from "./ModelSettingsCard";
 
 export const GeneralSettings: React.FC = () => {
@@ -18,12 +23,17 @@ export const GeneralSettings: React.FC = () => {
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title={t("settings.general.title")}>
         <ShortcutInput shortcutId="transcribe" grouped={true} />
+        <ShortcutInput shortcutId="meeting" grouped={true} />
         <ShortcutInput shortcutId="toggle_settings" grouped={true} />
         <PushToTalk descriptionMode="tooltip" grouped={true} />
+        <HotkeyGestures descriptionMode="tooltip" grouped={true} />
+        <BusyBehaviorSetting descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
       <ModelSettingsCard />
       <SettingsGroup title={t("settings.sound.title")}>
         <MicrophoneSelector descriptionMode="tooltip" grouped={true} />
+        <MicrophoneTest descriptionMode="tooltip" grouped={true} />
+        <BindingAudio descriptionMode="tooltip" grouped={true} />
         <MuteWhileRecording descriptionMode="tooltip" grouped={true} />
         <AudioFeedback descriptionMode="tooltip" grouped={true} />
         <OutputDeviceSelector
@@ -32,6 +42,7 @@ export const GeneralSettings: React.FC = () => {
           disabled={!audioFeedbackEnabled}
         />
         <VolumeSlider disabled={!audioFeedbackEnabled} />
+        <SpokenFeedback descriptionMode="tooltip" grouped={true} />
       </SettingsGroup>
     </div>
   );
@@ -9,6 +9,7 @@ import { readFile } from "@tauri-apps/plugin-fs";
 import { commands, type HistoryEntry } from "@/bindings";
 import { formatDateTime } from "@/utils/dateFormat";
 import { useOsType } from "@/hooks/useOsType";
+import { TranscribeFileButton } from "./TranscribeFileButton";
 
 interface OpenRecordingsButtonProps {
   onClick: () => void;
@@ -142,10 +143,13 @@ export const HistorySettings: React.FC = () => {
                 {t("settings.history.title")}
               </h2>
             </div>
-            <OpenRecordingsButton
-              onClick={openRecordingsFolder}
-              label={t("settings.history.openFolder")}
-            />
+            <div className="flex items-center gap-2">
+              <TranscribeFileButton />
+              <OpenRecordingsButton
+                onClick={openRecordingsFolder}
+                label={t("settings.history.openFolder")}
+              />
+            </div>
           </div>
           <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
             <div className="px-4 py-3 text-center text-text/60">
@@ -167,10 +171,13 @@ export const HistorySettings: React.FC = () => {
                 {t("settings.history.title")}
               </h2>
             </div>
-            <OpenRecordingsButton
-              onClick={openRecordingsFolder}
-              label={t("settings.history.openFolder")}
-            />
+            <div className="flex items-center gap-2">
+              <TranscribeFileButton />
+              <OpenRecordingsButton
+                onClick={openRecordingsFolder}
+                label={t("settings.history.openFolder")}
+              />
+            </div>
           </div>
           <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
             <div className="px-4 py-3 text-center text-text/60">
@@ -191,10 +198,13 @@ export const HistorySettings: React.FC = () => {
               {t("settings.history.title")}
             </h2>
           </div>
-          <OpenRecordingsButton
-            onClick={openRecordingsFolder}
-            label={t("settings.history.openFolder")}
-          />
+          <div className="flex items-center gap-2">
+            <TranscribeFileButton />
+            <OpenRecordingsButton
+              onClick={openRecordingsFolder}
+              label={t("settings.history.openFolder")}
+            />
+          </div>
         </div>
         <div className="bg-background border border-mid-gray/20 rounded-lg overflow-visible">
           <div className="divide-y divide-mid-gray/20">
@@ -302,7 +312,19 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
       <p className="italic text-text/90 text-sm pb-2 select-text cursor-text">
         {entry.transcription_text}
       </p>
-      <AudioPlayer onLoadRequest={handleLoadAudio} className="w-full" />
+      {entry.continued_from != null && (
+        <p className="text-xs text-text/50 -mt-2">
+          {t("settings.history.continued")}
+        </p>
+      )}
+      {entry.timings?.total_ms != null && (
+        <p className="text-xs text-text/50 -mt-2">
+          {t("settings.history.latency", { ms: entry.timings.total_ms })}
+        </p>
+      )}
+      {!entry.audio_pruned && (
+        <AudioPlayer onLoadRequest={handleLoadAudio} className="w-full" />
+      )}
     </div>
   );
 };
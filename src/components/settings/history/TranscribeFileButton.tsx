@@ -0,0 +1,131 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { FileAudio } from "lucide-react";
+import { listen } from "@tauri-apps/api/event";
+import { getCurrentWebview } from "@tauri-apps/api/webview";
+import { open } from "@tauri-apps/plugin-dialog";
+import { commands } from "@/bindings";
+import { useSettings } from "../../../hooks/useSettings";
+import { Button } from "../../ui/Button";
+
+const AUDIO_EXTENSIONS = ["wav", "mp3", "m4a", "ogg"];
+
+type FileTranscriptionStage = "decoding" | "transcribing" | "post_processing";
+
+interface FileTranscriptionProgress {
+  file: string;
+  stage: FileTranscriptionStage;
+  progress: number;
+}
+
+const isAudioFile = (path: string) =>
+  AUDIO_EXTENSIONS.includes(path.split(".").pop()?.toLowerCase() ?? "");
+
+/**
+ * Transcribes a picked or dropped audio file; the result lands in the
+ * history like a dictation.
+ */
+export const TranscribeFileButton: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting } = useSettings();
+  const postProcess = getSetting("post_process_enabled") ?? false;
+  const [busy, setBusy] = useState(false);
+  const [dragging, setDragging] = useState(false);
+  const [progress, setProgress] = useState<FileTranscriptionProgress | null>(
+    null,
+  );
+
+  const transcribe = useCallback(
+    async (path: string) => {
+      if (!isAudioFile(path)) {
+        toast.error(t("settings.history.transcribeFile.unsupported"));
+        return;
+      }
+      setBusy(true);
+      try {
+        const result = await commands.transcribeAudioFile(path, postProcess);
+        if (result.status === "ok") {
+          const { text } = result.data;
+          toast.success(t("settings.history.transcribeFile.done"), {
+            action: {
+              label: t("settings.history.transcribeFile.copy"),
+              onClick: () => void navigator.clipboard.writeText(text),
+            },
+          });
+        } else {
+          toast.error(result.error);
+        }
+      } finally {
+        setBusy(false);
+        setProgress(null);
+      }
+    },
+    [postProcess, t],
+  );
+
+  useEffect(() => {
+    const unlistenProgress = listen<FileTranscriptionProgress>(
+      "file-transcription-progress",
+      (event) => setProgress(event.payload),
+    );
+    const unlistenDrop = getCurrentWebview().onDragDropEvent((event) => {
+      if (event.payload.type === "enter" || event.payload.type === "over") {
+        setDragging(true);
+      } else if (event.payload.type === "leave") {
+        setDragging(false);
+      } else if (event.payload.type === "drop") {
+        setDragging(false);
+        const path = event.payload.paths[0];
+        if (path && !busy) {
+          void transcribe(path);
+        }
+      }
+    });
+
+    return () => {
+      unlistenProgress.then((unlisten) => unlisten());
+      unlistenDrop.then((unlisten) => unlisten());
+    };
+  }, [busy, transcribe]);
+
+  const pickFile = async () => {
+    const path = await open({
+      multiple: false,
+      filters: [
+        {
+          name: t("settings.history.transcribeFile.filter"),
+          extensions: AUDIO_EXTENSIONS,
+        },
+      ],
+    });
+    if (path) {
+      await transcribe(path);
+    }
+  };
+
+  let label = t("settings.history.transcribeFile.button");
+  if (busy) {
+    label = progress
+      ? t(`settings.history.transcribeFile.stage.${progress.stage}`, {
+          percent: Math.round(progress.progress * 100),
+        })
+      : t("settings.history.transcribeFile.stage.decoding", { percent: 0 });
+  } else if (dragging) {
+    label = t("settings.history.transcribeFile.drop");
+  }
+
+  return (
+    <Button
+      onClick={pickFile}
+      variant="secondary"
+      size="sm"
+      className={`flex items-center gap-2 ${dragging ? "ring-2 ring-logo-primary" : ""}`}
+      title={t("settings.history.transcribeFile.tooltip")}
+      disabled={busy}
+    >
+      <FileAudio className="w-4 h-4" />
+      <span>{label}</span>
+    </Button>
+  );
+};
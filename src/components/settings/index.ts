@@ -9,24 +9,51 @@ export { ModelsSettings } from "./models/ModelsSettings";
 
 // Individual setting components
 export { MicrophoneSelector } from "./MicrophoneSelector";
+export { MicrophoneTest } from "./MicrophoneTest";
+export { BindingAudio } from "./BindingAudio";
 export { ClamshellMicrophoneSelector } from "./ClamshellMicrophoneSelector";
 export { OutputDeviceSelector } from "./OutputDeviceSelector";
 export { AlwaysOnMicrophone } from "./AlwaysOnMicrophone";
 export { PushToTalk } from "./PushToTalk";
+export { HotkeyGestures } from "./HotkeyGestures";
+export { BusyBehaviorSetting } from "./BusyBehavior";
 export { AudioFeedback } from "./AudioFeedback";
 export { ShowOverlay } from "./ShowOverlay";
+export { OverlayLayoutSettings } from "./OverlayLayoutSettings";
 export { GlobalShortcutInput } from "./GlobalShortcutInput";
 export { HandyKeysShortcutInput } from "./HandyKeysShortcutInput";
 export { ShortcutInput } from "./ShortcutInput";
 export { TranslateToEnglish } from "./TranslateToEnglish";
 export { CustomWords } from "./CustomWords";
+export { HallucinationFilter } from "./HallucinationFilter";
+export { PunctuationRestore } from "./PunctuationRestore";
+export { NumberNormalization } from "./NumberNormalization";
 export { PostProcessingToggle } from "./PostProcessingToggle";
 export { PostProcessingSettingsApi } from "./PostProcessingSettingsApi";
 export { PostProcessingSettingsPrompts } from "./PostProcessingSettingsPrompts";
 export { AppDataDirectory } from "./AppDataDirectory";
 export { ModelUnloadTimeoutSetting } from "./ModelUnloadTimeout";
+export { ModelKeepAliveSetting } from "./ModelKeepAlive";
+export { TranscriptionWorkers } from "./TranscriptionWorkers";
+export { BatterySaver } from "./BatterySaver";
+export { CompletionNotifications } from "./CompletionNotifications";
+export { PasteFallback } from "./PasteFallback";
+export { SelectionTargetSetting } from "./SelectionTarget";
+export { ConfirmBeforePaste } from "./ConfirmBeforePaste";
+export { OutputActions } from "./OutputActions";
+export { AppBehaviors } from "./AppBehaviors";
+export { ProfanityFilter } from "./ProfanityFilter";
+export { ContinueDictation } from "./ContinueDictation";
+export { SpokenFeedback } from "./SpokenFeedback";
+export { MeetingNotesFolder } from "./MeetingNotesFolder";
+export { SettingsProfile } from "./SettingsProfile";
 export { StartHidden } from "./StartHidden";
+export { HeadlessMode } from "./HeadlessMode";
 export { HistoryLimit } from "./HistoryLimit";
 export { RecordingRetentionPeriodSelector } from "./RecordingRetentionPeriod";
+export { HistoryRetention } from "./HistoryRetention";
+export { PrivateMode } from "./PrivateMode";
 export { AutostartToggle } from "./AutostartToggle";
 export { UpdateChecksToggle } from "./UpdateChecksToggle";
+export { UpdateChannelSelector } from "./UpdateChannelSelector";
+export { UpdateCheckInterval } from "./UpdateCheckInterval";
@@ -1,7 +1,8 @@
 import React, { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
-import { RefreshCcw } from "lucide-react";
-import { commands } from "@/bindings";
+import { ArrowDown, ArrowUp, RefreshCcw, X } from "lucide-react";
+import { toast } from "sonner";
+import { commands, type LLMPrompt } from "@/bindings";
 
 import { Alert } from "../../ui/Alert";
 import {
@@ -18,10 +19,14 @@ import { ProviderSelect } from "../PostProcessingSettingsApi/ProviderSelect";
 import { BaseUrlField } from "../PostProcessingSettingsApi/BaseUrlField";
 import { ApiKeyField } from "../PostProcessingSettingsApi/ApiKeyField";
 import { ModelSelect } from "../PostProcessingSettingsApi/ModelSelect";
+import { ProviderHealthCheck } from "../PostProcessingSettingsApi/ProviderHealthCheck";
 import { usePostProcessProviderState } from "../PostProcessingSettingsApi/usePostProcessProviderState";
 import { ShortcutInput } from "../ShortcutInput";
 import { useSettings } from "../../../hooks/useSettings";
 
+// The offline grammar checker has no endpoint to check
+const LOCAL_GRAMMAR_PROVIDER_ID = "local_grammar";
+
 const PostProcessingSettingsApiComponent: React.FC = () => {
   const { t } = useTranslation();
   const state = usePostProcessProviderState();
@@ -52,7 +57,7 @@ const PostProcessingSettingsApiComponent: React.FC = () => {
         ) : null
       ) : (
         <>
-          {state.selectedProvider?.id === "custom" && (
+          {state.selectedProvider?.allow_base_url_edit && (
             <SettingContainer
               title={t("settings.postProcessing.api.baseUrl.title")}
               description={t("settings.postProcessing.api.baseUrl.description")}
@@ -74,6 +79,30 @@ const PostProcessingSettingsApiComponent: React.FC = () => {
             </SettingContainer>
           )}
 
+          {state.isAzureProvider && (
+            <SettingContainer
+              title={t("settings.postProcessing.api.apiVersion.title")}
+              description={t(
+                "settings.postProcessing.api.apiVersion.description",
+              )}
+              descriptionMode="tooltip"
+              layout="horizontal"
+              grouped={true}
+            >
+              <div className="flex items-center gap-2">
+                <BaseUrlField
+                  value={state.apiVersion}
+                  onBlur={state.handleApiVersionChange}
+                  placeholder={t(
+                    "settings.postProcessing.api.apiVersion.placeholder",
+                  )}
+                  disabled={state.isApiVersionUpdating}
+                  className="min-w-[200px]"
+                />
+              </div>
+            </SettingContainer>
+          )}
+
           <SettingContainer
             title={t("settings.postProcessing.api.apiKey.title")}
             description={t("settings.postProcessing.api.apiKey.description")}
@@ -139,10 +168,42 @@ const PostProcessingSettingsApiComponent: React.FC = () => {
           </div>
         </SettingContainer>
       )}
+
+      {!state.isAppleProvider &&
+        state.selectedProviderId !== LOCAL_GRAMMAR_PROVIDER_ID && (
+          <SettingContainer
+            title={t("settings.postProcessing.api.healthCheck.title")}
+            description={t(
+              "settings.postProcessing.api.healthCheck.description",
+            )}
+            descriptionMode="tooltip"
+            layout="stacked"
+            grouped={true}
+          >
+            <ProviderHealthCheck
+              providerId={state.selectedProviderId}
+              model={state.model}
+            />
+          </SettingContainer>
+        )}
     </>
   );
 };
 
+interface PromptOptionsDraft {
+  model: string;
+  temperature: string;
+  maxTokens: string;
+  systemMessage: string;
+}
+
+const optionsDraftOf = (prompt: LLMPrompt | null): PromptOptionsDraft => ({
+  model: prompt?.model ?? "",
+  temperature: prompt?.temperature?.toString() ?? "",
+  maxTokens: prompt?.max_tokens?.toString() ?? "",
+  systemMessage: prompt?.system_message ?? "",
+});
+
 const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const { t } = useTranslation();
   const { getSetting, updateSetting, isUpdating, refreshSettings } =
@@ -150,6 +211,9 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const [isCreating, setIsCreating] = useState(false);
   const [draftName, setDraftName] = useState("");
   const [draftText, setDraftText] = useState("");
+  const [draftOptions, setDraftOptions] = useState<PromptOptionsDraft>(
+    optionsDraftOf(null),
+  );
 
   const prompts = getSetting("post_process_prompts") || [];
   const selectedPromptId = getSetting("post_process_selected_prompt_id") || "";
@@ -166,11 +230,16 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
       setDraftName("");
       setDraftText("");
     }
+    setDraftOptions(optionsDraftOf(selectedPrompt));
   }, [
     isCreating,
     selectedPromptId,
     selectedPrompt?.name,
     selectedPrompt?.prompt,
+    selectedPrompt?.model,
+    selectedPrompt?.temperature,
+    selectedPrompt?.max_tokens,
+    selectedPrompt?.system_message,
   ]);
 
   const handlePromptSelect = (promptId: string | null) => {
@@ -200,12 +269,24 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   const handleUpdatePrompt = async () => {
     if (!selectedPromptId || !draftName.trim() || !draftText.trim()) return;
 
+    const temperature = draftOptions.temperature.trim();
+    const maxTokens = draftOptions.maxTokens.trim();
     try {
       await commands.updatePostProcessPrompt(
         selectedPromptId,
         draftName.trim(),
         draftText.trim(),
       );
+      const result = await commands.updatePostProcessPromptOptions(
+        selectedPromptId,
+        draftOptions.model.trim() || null,
+        temperature ? Number(temperature) : null,
+        maxTokens ? Number(maxTokens) : null,
+        draftOptions.systemMessage.trim() || null,
+      );
+      if (result.status === "error") {
+        toast.error(result.error);
+      }
       await refreshSettings();
     } catch (error) {
       console.error("Failed to update prompt:", error);
@@ -242,10 +323,17 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   };
 
   const hasPrompts = prompts.length > 0;
+  const savedOptions = optionsDraftOf(selectedPrompt);
   const isDirty =
     !!selectedPrompt &&
     (draftName.trim() !== selectedPrompt.name ||
-      draftText.trim() !== selectedPrompt.prompt.trim());
+      draftText.trim() !== selectedPrompt.prompt.trim() ||
+      draftOptions.model.trim() !== savedOptions.model ||
+      draftOptions.temperature.trim() !== savedOptions.temperature ||
+      draftOptions.maxTokens.trim() !== savedOptions.maxTokens ||
+      draftOptions.systemMessage.trim() !== savedOptions.systemMessage);
+  const setOption = (key: keyof PromptOptionsDraft, value: string) =>
+    setDraftOptions((options) => ({ ...options, [key]: value }));
 
   return (
     <SettingContainer
@@ -322,6 +410,60 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
               />
             </div>
 
+            <div className="space-y-2 flex flex-col">
+              <label className="text-sm font-semibold">
+                {t("settings.postProcessing.prompts.options.title")}
+              </label>
+              <p className="text-xs text-mid-gray/70">
+                {t("settings.postProcessing.prompts.options.description")}
+              </p>
+              <div className="flex gap-2">
+                <Input
+                  type="text"
+                  value={draftOptions.model}
+                  onChange={(e) => setOption("model", e.target.value)}
+                  placeholder={t(
+                    "settings.postProcessing.prompts.options.modelPlaceholder",
+                  )}
+                  variant="compact"
+                  className="flex-1"
+                />
+                <Input
+                  type="number"
+                  min={0}
+                  max={2}
+                  step={0.1}
+                  value={draftOptions.temperature}
+                  onChange={(e) => setOption("temperature", e.target.value)}
+                  placeholder={t(
+                    "settings.postProcessing.prompts.options.temperaturePlaceholder",
+                  )}
+                  variant="compact"
+                  className="w-32"
+                />
+                <Input
+                  type="number"
+                  min={1}
+                  step={1}
+                  value={draftOptions.maxTokens}
+                  onChange={(e) => setOption("maxTokens", e.target.value)}
+                  placeholder={t(
+                    "settings.postProcessing.prompts.options.maxTokensPlaceholder",
+                  )}
+                  variant="compact"
+                  className="w-32"
+                />
+              </div>
+              <Textarea
+                value={draftOptions.systemMessage}
+                onChange={(e) => setOption("systemMessage", e.target.value)}
+                placeholder={t(
+                  "settings.postProcessing.prompts.options.systemMessagePlaceholder",
+                )}
+                variant="compact"
+              />
+            </div>
+
             <div className="flex gap-2 pt-2">
               <Button
                 onClick={handleUpdatePrompt}
@@ -413,6 +555,97 @@ const PostProcessingSettingsPromptsComponent: React.FC = () => {
   );
 };
 
+const PostProcessingSettingsChainComponent: React.FC = () => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+
+  const prompts = getSetting("post_process_prompts") || [];
+  const chain = getSetting("post_process_chain") || [];
+  const promptName = (id: string) =>
+    prompts.find((prompt) => prompt.id === id)?.name ?? id;
+  const disabled = isUpdating("post_process_chain");
+
+  const handleAdd = (promptId: string) => {
+    updateSetting("post_process_chain", [...chain, promptId]);
+  };
+
+  const handleRemove = (index: number) => {
+    updateSetting("post_process_chain", chain.filter((_, i) => i !== index));
+  };
+
+  const handleMove = (index: number, offset: number) => {
+    const target = index + offset;
+    if (target < 0 || target >= chain.length) return;
+    const next = [...chain];
+    [next[index], next[target]] = [next[target], next[index]];
+    updateSetting("post_process_chain", next);
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.postProcessing.prompts.chain.title")}
+      description={t("settings.postProcessing.prompts.chain.description")}
+      descriptionMode="tooltip"
+      layout="stacked"
+      grouped={true}
+    >
+      <div className="space-y-2">
+        {chain.length === 0 ? (
+          <p className="text-sm text-mid-gray">
+            {t("settings.postProcessing.prompts.chain.empty")}
+          </p>
+        ) : (
+          <ol className="space-y-1">
+            {chain.map((promptId, index) => (
+              <li
+                key={`${promptId}-${index}`}
+                className="flex items-center gap-2 text-sm"
+              >
+                <span className="text-mid-gray w-5">{index + 1}.</span>
+                <span className="flex-1">{promptName(promptId)}</span>
+                <button
+                  onClick={() => handleMove(index, -1)}
+                  disabled={disabled || index === 0}
+                  className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer disabled:opacity-30"
+                  title={t("settings.postProcessing.prompts.chain.moveUp")}
+                >
+                  <ArrowUp width={16} height={16} />
+                </button>
+                <button
+                  onClick={() => handleMove(index, 1)}
+                  disabled={disabled || index === chain.length - 1}
+                  className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer disabled:opacity-30"
+                  title={t("settings.postProcessing.prompts.chain.moveDown")}
+                >
+                  <ArrowDown width={16} height={16} />
+                </button>
+                <button
+                  onClick={() => handleRemove(index)}
+                  disabled={disabled}
+                  className="text-text/50 hover:text-logo-primary transition-colors cursor-pointer disabled:opacity-30"
+                  title={t("settings.postProcessing.prompts.chain.remove")}
+                >
+                  <X width={16} height={16} />
+                </button>
+              </li>
+            ))}
+          </ol>
+        )}
+        <Dropdown
+          selectedValue={null}
+          options={prompts.map((p) => ({
+            value: p.id,
+            label: p.name,
+          }))}
+          onSelect={handleAdd}
+          placeholder={t("settings.postProcessing.prompts.chain.add")}
+          disabled={disabled || prompts.length === 0}
+        />
+      </div>
+    </SettingContainer>
+  );
+};
+
 export const PostProcessingSettingsApi = React.memo(
   PostProcessingSettingsApiComponent,
 );
@@ -423,6 +656,11 @@ export const PostProcessingSettingsPrompts = React.memo(
 );
 PostProcessingSettingsPrompts.displayName = "PostProcessingSettingsPrompts";
 
+export const PostProcessingSettingsChain = React.memo(
+  PostProcessingSettingsChainComponent,
+);
+PostProcessingSettingsChain.displayName = "PostProcessingSettingsChain";
+
 export const PostProcessingSettings: React.FC = () => {
   const { t } = useTranslation();
 
@@ -442,6 +680,7 @@ export const PostProcessingSettings: React.FC = () => {
 
       <SettingsGroup title={t("settings.postProcessing.prompts.title")}>
         <PostProcessingSettingsPrompts />
+        <PostProcessingSettingsChain />
       </SettingsGroup>
     </div>
   );
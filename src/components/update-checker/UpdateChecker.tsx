@@ -1,10 +1,10 @@
 import React, { useState, useEffect, useRef } from "react";
 import { useTranslation } from "react-i18next";
-import { check } from "@tauri-apps/plugin-updater";
-import { relaunch } from "@tauri-apps/plugin-process";
-import { listen } from "@tauri-apps/api/event";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
 import { ProgressBar } from "../shared";
 import { useSettings } from "../../hooks/useSettings";
+import { useUpdateStatus } from "../../hooks/useUpdateStatus";
 
 interface UpdateCheckerProps {
   className?: string;
@@ -12,173 +12,114 @@ interface UpdateCheckerProps {
 
 const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
   const { t } = useTranslation();
-  // Update checking state
-  const [isChecking, setIsChecking] = useState(false);
-  const [updateAvailable, setUpdateAvailable] = useState(false);
-  const [isInstalling, setIsInstalling] = useState(false);
-  const [downloadProgress, setDownloadProgress] = useState(0);
+  // Checking, downloading and installing happen in the backend; this only
+  // reflects its status
+  const status = useUpdateStatus();
   const [showUpToDate, setShowUpToDate] = useState(false);
 
-  const { settings, isLoading } = useSettings();
-  const settingsLoaded = !isLoading && settings !== null;
+  const { settings } = useSettings();
   const updateChecksEnabled = settings?.update_checks_enabled ?? false;
 
   const upToDateTimeoutRef = useRef<ReturnType<typeof setTimeout>>();
-  const isManualCheckRef = useRef(false);
-  const downloadedBytesRef = useRef(0);
-  const contentLengthRef = useRef(0);
 
   useEffect(() => {
-    // Wait for settings to load before doing anything
-    if (!settingsLoaded) return;
+    if (status.state !== "up_to_date") return;
 
-    if (!updateChecksEnabled) {
-      if (upToDateTimeoutRef.current) {
-        clearTimeout(upToDateTimeoutRef.current);
-      }
-      setIsChecking(false);
-      setUpdateAvailable(false);
+    setShowUpToDate(true);
+    upToDateTimeoutRef.current = setTimeout(() => {
       setShowUpToDate(false);
-      return;
-    }
-
-    checkForUpdates();
-
-    // Listen for update check events
-    const updateUnlisten = listen("check-for-updates", () => {
-      handleManualUpdateCheck();
-    });
+    }, 3000);
 
     return () => {
       if (upToDateTimeoutRef.current) {
         clearTimeout(upToDateTimeoutRef.current);
       }
-      updateUnlisten.then((fn) => fn());
     };
-  }, [settingsLoaded, updateChecksEnabled]);
+  }, [status]);
 
-  // Update checking functions
   const checkForUpdates = async () => {
-    if (!updateChecksEnabled || isChecking) return;
-
-    try {
-      setIsChecking(true);
-      const update = await check();
-
-      if (update) {
-        setUpdateAvailable(true);
-        setShowUpToDate(false);
-      } else {
-        setUpdateAvailable(false);
-
-        if (isManualCheckRef.current) {
-          setShowUpToDate(true);
-          if (upToDateTimeoutRef.current) {
-            clearTimeout(upToDateTimeoutRef.current);
-          }
-          upToDateTimeoutRef.current = setTimeout(() => {
-            setShowUpToDate(false);
-          }, 3000);
-        }
-      }
-    } catch (error) {
-      console.error("Failed to check for updates:", error);
-    } finally {
-      setIsChecking(false);
-      isManualCheckRef.current = false;
+    const result = await commands.checkForUpdates();
+    if (result.status === "error") {
+      toast.error(result.error);
     }
   };
 
-  const handleManualUpdateCheck = () => {
-    if (!updateChecksEnabled) return;
-    isManualCheckRef.current = true;
-    checkForUpdates();
+  const downloadUpdate = async () => {
+    const result = await commands.downloadUpdate();
+    if (result.status === "error") {
+      toast.error(result.error);
+    }
   };
 
-  const installUpdate = async () => {
-    if (!updateChecksEnabled) return;
-    try {
-      setIsInstalling(true);
-      setDownloadProgress(0);
-      downloadedBytesRef.current = 0;
-      contentLengthRef.current = 0;
-      const update = await check();
-
-      if (!update) {
-        console.log("No update available during install attempt");
-        return;
-      }
-
-      await update.downloadAndInstall((event) => {
-        switch (event.event) {
-          case "Started":
-            downloadedBytesRef.current = 0;
-            contentLengthRef.current = event.data.contentLength ?? 0;
-            break;
-          case "Progress":
-            downloadedBytesRef.current += event.data.chunkLength;
-            const progress =
-              contentLengthRef.current > 0
-                ? Math.round(
-                    (downloadedBytesRef.current / contentLengthRef.current) *
-                      100,
-                  )
-                : 0;
-            setDownloadProgress(Math.min(progress, 100));
-            break;
-        }
-      });
-      await relaunch();
-    } catch (error) {
-      console.error("Failed to install update:", error);
-    } finally {
-      setIsInstalling(false);
-      setDownloadProgress(0);
-      downloadedBytesRef.current = 0;
-      contentLengthRef.current = 0;
+  const applyUpdate = async () => {
+    const result = await commands.applyUpdate();
+    if (result.status === "error") {
+      toast.error(result.error);
     }
   };
 
+  const downloadProgress =
+    status.state === "downloading" && status.total
+      ? Math.min(Math.round((status.downloaded / status.total) * 100), 100)
+      : 0;
+
   // Update status functions
   const getUpdateStatusText = () => {
     if (!updateChecksEnabled) {
       return t("footer.updateCheckingDisabled");
     }
-    if (isInstalling) {
-      return downloadProgress > 0 && downloadProgress < 100
-        ? t("footer.downloading", {
-            progress: downloadProgress.toString().padStart(3),
-          })
-        : downloadProgress === 100
-          ? t("footer.installing")
+    switch (status.state) {
+      case "checking":
+        return t("footer.checkingUpdates");
+      case "available":
+        return t("footer.updateAvailable", { version: status.update.version });
+      case "downloading":
+        return downloadProgress > 0
+          ? t("footer.downloading", {
+              progress: downloadProgress.toString().padStart(3),
+            })
           : t("footer.preparing");
+      case "ready":
+        return t("footer.restartToUpdate", { version: status.update.version });
+      default:
+        return showUpToDate
+          ? t("footer.upToDate")
+          : t("footer.checkForUpdates");
     }
-    if (isChecking) return t("footer.checkingUpdates");
-    if (showUpToDate) return t("footer.upToDate");
-    if (updateAvailable) return t("footer.updateAvailableShort");
-    return t("footer.checkForUpdates");
   };
 
   const getUpdateStatusAction = () => {
     if (!updateChecksEnabled) return undefined;
-    if (updateAvailable && !isInstalling) return installUpdate;
-    if (!isChecking && !isInstalling && !updateAvailable)
-      return handleManualUpdateCheck;
-    return undefined;
+    switch (status.state) {
+      case "available":
+        return downloadUpdate;
+      case "ready":
+        return applyUpdate;
+      case "checking":
+      case "downloading":
+        return undefined;
+      default:
+        return showUpToDate ? undefined : checkForUpdates;
+    }
   };
 
-  const isUpdateDisabled = !updateChecksEnabled || isChecking || isInstalling;
-  const isUpdateClickable =
-    !isUpdateDisabled && (updateAvailable || (!isChecking && !showUpToDate));
+  const action = getUpdateStatusAction();
+  const highlighted = status.state === "available" || status.state === "ready";
+  const notes =
+    status.state === "available" || status.state === "ready"
+      ? (status.update.notes ?? undefined)
+      : status.state === "failed"
+        ? status.message
+        : undefined;
 
   return (
     <div className={`flex items-center gap-3 ${className}`}>
-      {isUpdateClickable ? (
+      {action ? (
         <button
-          onClick={getUpdateStatusAction()}
-          disabled={isUpdateDisabled}
+          onClick={action}
+          title={notes}
           className={`transition-colors disabled:opacity-50 tabular-nums ${
-            updateAvailable
+            highlighted
               ? "text-logo-primary hover:text-logo-primary/80 font-medium"
               : "text-text/60 hover:text-text/80"
           }`}
@@ -191,7 +132,7 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
         </span>
       )}
 
-      {isInstalling && downloadProgress > 0 && downloadProgress < 100 && (
+      {status.state === "downloading" && downloadProgress > 0 && (
         <ProgressBar
           progress={[
             {
@@ -35,6 +35,10 @@ interface UseSettingsReturn {
     providerId: string,
     baseUrl: string,
   ) => Promise<void>;
+  updatePostProcessApiVersion: (
+    providerId: string,
+    apiVersion: string,
+  ) => Promise<void>;
   updatePostProcessApiKey: (
     providerId: string,
     apiKey: string,
@@ -71,6 +75,7 @@ export const useSettings = (): UseSettingsReturn => {
     getSetting: store.getSetting,
     setPostProcessProvider: store.setPostProcessProvider,
     updatePostProcessBaseUrl: store.updatePostProcessBaseUrl,
+    updatePostProcessApiVersion: store.updatePostProcessApiVersion,
     updatePostProcessApiKey: store.updatePostProcessApiKey,
     updatePostProcessModel: store.updatePostProcessModel,
     fetchPostProcessModels: store.fetchPostProcessModels,
@@ -0,0 +1,21 @@
+import { useEffect, useState } from "react";
+import { listen } from "@tauri-apps/api/event";
+import { commands, type UpdateStatus } from "@/bindings";
+
+/** The updater's status, kept current from `update-status` events. */
+export const useUpdateStatus = (): UpdateStatus => {
+  const [status, setStatus] = useState<UpdateStatus>({ state: "idle" });
+
+  useEffect(() => {
+    commands.getUpdateStatus().then(setStatus);
+    const unlisten = listen<UpdateStatus>("update-status", (event) =>
+      setStatus(event.payload),
+    );
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, []);
+
+  return status;
+};
@@ -117,6 +117,21 @@ export const getKeyName = (
       return punctuationMap[code];
     }
 
+    // Media keys are bound on their own by name (see the backend's special_input)
+    const mediaMap: Record<string, string> = {
+      MediaPlayPause: "media_play_pause",
+      MediaTrackNext: "media_next",
+      MediaTrackPrevious: "media_previous",
+      MediaStop: "media_stop",
+      AudioVolumeMute: "volume_mute",
+      AudioVolumeDown: "volume_down",
+      AudioVolumeUp: "volume_up",
+    };
+
+    if (mediaMap[code]) {
+      return mediaMap[code];
+    }
+
     // For any other codes, try to convert to a reasonable format
     return code.toLowerCase().replace(/([a-z])([A-Z])/g, "$1 $2");
   }
@@ -154,6 +169,35 @@ export const getKeyName = (
   return `unknown-${e.keyCode || e.which || 0}`;
 };
 
+/**
+ * Binding names that stand on their own: extra mouse buttons and media keys
+ */
+export const SPECIAL_BINDINGS = [
+  "mouse4",
+  "mouse5",
+  "media_play_pause",
+  "media_next",
+  "media_previous",
+  "media_stop",
+  "volume_mute",
+  "volume_down",
+  "volume_up",
+];
+
+/**
+ * Map the back/forward side buttons of a MouseEvent to "mouse4"/"mouse5"
+ */
+export const getMouseButtonName = (e: MouseEvent): string | null => {
+  switch (e.button) {
+    case 3:
+      return "mouse4";
+    case 4:
+      return "mouse5";
+    default:
+      return null;
+  }
+};
+
 /**
  * Get display-friendly key combination string for the current OS
  * Returns basic plus-separated format with correct platform key names
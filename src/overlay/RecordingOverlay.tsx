@@ -13,7 +13,36 @@ import { commands } from "@/bindings";
 import i18n, { syncLanguageFromSettings } from "@/i18n";
 import { getLanguageDirection } from "@/lib/utils/rtl";
 
-type OverlayState = "recording" | "transcribing" | "processing" | "done";
+type OverlayState =
+  | "recording"
+  | "transcribing"
+  | "processing"
+  | "done"
+  | "error"
+  | "meeting"
+  | "confirm"
+  | "no-speech";
+
+interface InputLevel {
+  rms: number;
+  peak: number;
+  rms_db: number;
+  clipping: boolean;
+}
+
+interface MeetingProgress {
+  words: number;
+  paused: boolean;
+}
+
+interface OverlayStyle {
+  scale: number;
+  opacity: number;
+}
+
+// Map -60..0 dBFS onto the meter width
+const meterPercent = (db: number) =>
+  Math.min(100, Math.max(0, ((db + 60) / 60) * 100));
 
 const RecordingOverlay: React.FC = () => {
   const { t } = useTranslation();
@@ -21,7 +50,20 @@ const RecordingOverlay: React.FC = () => {
   const [state, setState] = useState<OverlayState>("recording");
   const [levels, setLevels] = useState<number[]>(Array(16).fill(0));
   const [streamingText, setStreamingText] = useState<string>("");
+  const [errorMessage, setErrorMessage] = useState<string>("");
+  const [needsAccessibility, setNeedsAccessibility] = useState(false);
   const [copied, setCopied] = useState(false);
+  const [editing, setEditing] = useState(false);
+  const [editText, setEditText] = useState("");
+  const [inputLevel, setInputLevel] = useState<InputLevel | null>(null);
+  const [meeting, setMeeting] = useState<MeetingProgress>({
+    words: 0,
+    paused: false,
+  });
+  const [overlayStyle, setOverlayStyle] = useState<OverlayStyle>({
+    scale: 1,
+    opacity: 1,
+  });
   const smoothedLevelsRef = useRef<number[]>(Array(16).fill(0));
   const copiedTimerRef = useRef<ReturnType<typeof setTimeout> | null>(null);
   const direction = getLanguageDirection(i18n.language);
@@ -36,6 +78,17 @@ const RecordingOverlay: React.FC = () => {
           setLevels(Array(9).fill(0));
           setStreamingText("");
           setCopied(false);
+          setInputLevel(null);
+        }
+        if (overlayState !== "error") {
+          setErrorMessage("");
+          setNeedsAccessibility(false);
+        }
+        if (overlayState !== "confirm") {
+          setEditing(false);
+        }
+        if (overlayState === "no-speech") {
+          setStreamingText("");
         }
         setState(overlayState);
         setIsVisible(true);
@@ -47,9 +100,26 @@ const RecordingOverlay: React.FC = () => {
         smoothedLevelsRef.current = Array(16).fill(0);
         setLevels(Array(9).fill(0));
         setStreamingText("");
+        setErrorMessage("");
+        setNeedsAccessibility(false);
         setCopied(false);
+        setEditing(false);
+      });
+
+      const unlistenError = await listen<string>("overlay-error", (event) => {
+        setStreamingText("");
+        setErrorMessage(event.payload);
       });
 
+      const unlistenAppError = await listen<{ kind: string }>(
+        "app-error",
+        (event) => {
+          setNeedsAccessibility(
+            event.payload.kind === "accessibility_permission_missing",
+          );
+        },
+      );
+
       const unlistenLevel = await listen<number[]>("mic-level", (event) => {
         const newLevels = event.payload as number[];
         const smoothed = smoothedLevelsRef.current.map((prev, i) => {
@@ -84,9 +154,42 @@ const RecordingOverlay: React.FC = () => {
         setState("done");
       });
 
+      const unlistenConfirm = await listen<string>("confirm-text", (event) => {
+        setStreamingText(event.payload);
+        setEditText(event.payload);
+        setEditing(false);
+      });
+
+      const unlistenMeeting = await listen<MeetingProgress>(
+        "meeting-progress",
+        (event) => {
+          setMeeting(event.payload);
+        },
+      );
+
+      const unlistenInputLevel = await listen<InputLevel>(
+        "input-level",
+        (event) => {
+          setInputLevel(event.payload);
+        },
+      );
+
+      const unlistenStyle = await listen<OverlayStyle>(
+        "overlay-style",
+        (event) => {
+          setOverlayStyle(event.payload);
+        },
+      );
+
       return () => {
+        unlistenStyle();
+        unlistenConfirm();
+        unlistenMeeting();
+        unlistenInputLevel();
         unlistenShow();
         unlistenHide();
+        unlistenError();
+        unlistenAppError();
         unlistenLevel();
         unlistenStreaming();
         unlistenDone();
@@ -130,10 +233,24 @@ const RecordingOverlay: React.FC = () => {
     commands.cancelOperation();
   };
 
+  const handleEdit = () => {
+    setEditText(streamingText);
+    setEditing(true);
+    commands.editPendingText();
+  };
+
+  const handleAccept = () => {
+    commands.sendPendingText(editing ? editText : null);
+  };
+
+  const handleDiscard = () => {
+    commands.discardPendingText();
+  };
+
   const getIcon = () => {
     if (isDone && copied) {
       return <CheckIcon width={18} height={18} />;
-    } else if (state === "recording") {
+    } else if (state === "recording" || state === "meeting") {
       return <MicrophoneIcon />;
     } else {
       return <TranscriptionIcon />;
@@ -144,14 +261,31 @@ const RecordingOverlay: React.FC = () => {
   const isTranscribing = state === "transcribing";
   const isProcessing = state === "processing";
   const isDone = state === "done";
+  const isError = state === "error" && errorMessage.length > 0;
+  const isMeeting = state === "meeting";
+  const isConfirm = state === "confirm";
+  const isNoSpeech = state === "no-speech";
 
   return (
     <div
       dir={direction}
-      className={`recording-overlay ${isVisible ? "fade-in" : ""} ${hasStreamingText ? "has-text" : ""} ${isDone ? "done" : ""}`}
+      className={`recording-overlay ${isVisible ? "fade-in" : ""} ${hasStreamingText ? "has-text" : ""} ${isDone ? "done" : ""} ${isError ? "has-text error" : ""}`}
+      style={{
+        zoom: overlayStyle.scale,
+        ...(isVisible ? { opacity: overlayStyle.opacity } : {}),
+      }}
     >
       <div className="overlay-left">{getIcon()}</div>
 
+      {state === "recording" && inputLevel && (
+        <div className="input-level-meter">
+          <div
+            className={`input-level-fill ${inputLevel.clipping ? "clipping" : ""}`}
+            style={{ width: `${meterPercent(inputLevel.rms_db)}%` }}
+          />
+        </div>
+      )}
+
       <div className="overlay-middle">
         {state === "recording" && !hasStreamingText && (
           <div className="bars-container">
@@ -168,13 +302,45 @@ const RecordingOverlay: React.FC = () => {
             ))}
           </div>
         )}
-        {hasStreamingText && (
-          <div
-            className={`streaming-text ${isTranscribing || isProcessing ? "processing" : ""}`}
-          >
-            {streamingText}
+        {isError && <div className="streaming-text error-text">{errorMessage}</div>}
+        {isConfirm && editing ? (
+          <textarea
+            className="streaming-text confirm-edit"
+            value={editText}
+            autoFocus
+            onChange={(event) => setEditText(event.target.value)}
+            onKeyDown={(event) => {
+              if (
+                event.key === "Enter" &&
+                (event.metaKey || event.ctrlKey)
+              ) {
+                event.preventDefault();
+                handleAccept();
+              } else if (event.key === "Escape") {
+                event.preventDefault();
+                handleDiscard();
+              }
+            }}
+          />
+        ) : (
+          hasStreamingText && (
+            <div
+              className={`streaming-text ${isTranscribing || isProcessing ? "processing" : ""}`}
+            >
+              {streamingText}
+            </div>
+          )
+        )}
+        {isMeeting && (
+          <div className="transcribing-text">
+            {meeting.paused
+              ? t("overlay.meetingPaused")
+              : t("overlay.meetingWords", { count: meeting.words })}
           </div>
         )}
+        {isNoSpeech && (
+          <div className="transcribing-text">{t("overlay.noSpeech")}</div>
+        )}
         {(isTranscribing || isProcessing) && !hasStreamingText && (
           <div className="transcribing-text">
             {isProcessing ? t("overlay.processing") : t("overlay.transcribing")}
@@ -183,7 +349,69 @@ const RecordingOverlay: React.FC = () => {
       </div>
 
       <div className="overlay-right">
-        {hasStreamingText ? (
+        {isConfirm ? (
+          <div className="done-buttons">
+            {!editing && (
+              <div className="overlay-link-btn" onClick={handleEdit}>
+                {t("overlay.edit")}
+              </div>
+            )}
+            <div
+              className="overlay-btn accept-button"
+              onClick={handleAccept}
+              title={t("overlay.accept")}
+            >
+              <CheckIcon width={16} height={16} />
+            </div>
+            <div
+              className="overlay-btn close-button"
+              onClick={handleDiscard}
+              title={t("overlay.discard")}
+            >
+              <CancelIcon width={18} height={18} />
+            </div>
+          </div>
+        ) : isMeeting ? (
+          <div className="done-buttons">
+            <div
+              className="overlay-link-btn"
+              onClick={() => {
+                commands.setMeetingPaused(!meeting.paused);
+              }}
+            >
+              {meeting.paused ? t("overlay.resume") : t("overlay.pause")}
+            </div>
+            <div
+              className="overlay-btn close-button"
+              onClick={() => {
+                commands.stopMeeting();
+              }}
+              title={t("overlay.stopMeeting")}
+            >
+              <CancelIcon width={18} height={18} />
+            </div>
+          </div>
+        ) : isError ? (
+          <div className="done-buttons">
+            {needsAccessibility && (
+              <div
+                className="overlay-link-btn"
+                onClick={() => {
+                  commands.openAccessibilitySettings();
+                }}
+              >
+                {t("overlay.openSettings")}
+              </div>
+            )}
+            <div
+              className="overlay-btn close-button"
+              onClick={handleClose}
+              title="Close"
+            >
+              <CancelIcon width={18} height={18} />
+            </div>
+          </div>
+        ) : hasStreamingText ? (
           <div className="done-buttons">
             {(isTranscribing || isProcessing) && (
               <div className="processing-indicator" />
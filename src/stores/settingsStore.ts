@@ -1,6 +1,14 @@
 import { create } from "zustand";
 import { subscribeWithSelector } from "zustand/middleware";
-import type { AppSettings as Settings, AudioDevice } from "@/bindings";
+import { listen } from "@tauri-apps/api/event";
+import type {
+  AppBehaviorRule,
+  AppSettings as Settings,
+  AudioDevice,
+  HistoryRetentionPolicy,
+  OverlayLayout,
+  UpdateChannel,
+} from "@/bindings";
 import { commands } from "@/bindings";
 
 interface SettingsStore {
@@ -32,7 +40,7 @@ interface SettingsStore {
   checkCustomSounds: () => Promise<void>;
   setPostProcessProvider: (providerId: string) => Promise<void>;
   updatePostProcessSetting: (
-    settingType: "base_url" | "api_key" | "model",
+    settingType: "base_url" | "api_version" | "api_key" | "model",
     providerId: string,
     value: string,
   ) => Promise<void>;
@@ -40,6 +48,10 @@ interface SettingsStore {
     providerId: string,
     baseUrl: string,
   ) => Promise<void>;
+  updatePostProcessApiVersion: (
+    providerId: string,
+    apiVersion: string,
+  ) => Promise<void>;
   updatePostProcessApiKey: (
     providerId: string,
     apiKey: string,
@@ -78,11 +90,20 @@ const settingUpdaters: {
     commands.changeAudioFeedbackVolumeSetting(value as number),
   sound_theme: (value) => commands.changeSoundThemeSetting(value as string),
   start_hidden: (value) => commands.changeStartHiddenSetting(value as boolean),
+  headless: (value) => commands.changeHeadlessSetting(value as boolean),
   autostart_enabled: (value) =>
     commands.changeAutostartSetting(value as boolean),
   update_checks_enabled: (value) =>
     commands.changeUpdateChecksSetting(value as boolean),
+  update_channel: (value) =>
+    commands.changeUpdateChannelSetting(value as UpdateChannel),
+  update_check_interval_hours: (value) =>
+    commands.changeUpdateCheckIntervalSetting(value as number),
   push_to_talk: (value) => commands.changePttSetting(value as boolean),
+  hotkey_gestures: (value) =>
+    commands.changeHotkeyGesturesSetting(value as boolean),
+  busy_behavior: (value) =>
+    commands.changeBusyBehaviorSetting(value as string),
   selected_microphone: (value) =>
     commands.setSelectedMicrophone(
       (value as string) === "Default" || value === null
@@ -107,23 +128,69 @@ const settingUpdaters: {
     commands.changeSelectedLanguageSetting(value as string),
   overlay_position: (value) =>
     commands.changeOverlayPositionSetting(value as string),
+  overlay_layout: (value) =>
+    commands.changeOverlayLayoutSetting(value as OverlayLayout),
   debug_mode: (value) => commands.changeDebugModeSetting(value as boolean),
   custom_words: (value) => commands.updateCustomWords(value as string[]),
+  transcription_workers: (value) =>
+    commands.setTranscriptionWorkers(value as number),
   word_correction_threshold: (value) =>
     commands.changeWordCorrectionThresholdSetting(value as number),
   paste_method: (value) => commands.changePasteMethodSetting(value as string),
+  per_character_delay_ms: (value) =>
+    commands.changePerCharacterDelaySetting(value as number),
+  paste_fallback_enabled: (value) =>
+    commands.changePasteFallbackSetting(value as boolean),
   clipboard_handling: (value) =>
     commands.changeClipboardHandlingSetting(value as string),
+  selection_target: (value) =>
+    commands.changeSelectionTargetSetting(value as string),
+  middle_click_paste: (value) =>
+    commands.changeMiddleClickPasteSetting(value as boolean),
   history_limit: (value) => commands.updateHistoryLimit(value as number),
+  history_retention: (value) =>
+    commands.updateHistoryRetentionPolicy(value as HistoryRetentionPolicy),
   post_process_enabled: (value) =>
     commands.changePostProcessEnabledSetting(value as boolean),
   post_process_selected_prompt_id: (value) =>
     commands.setPostProcessSelectedPrompt(value as string),
+  post_process_chain: (value) =>
+    commands.setPostProcessChain(value as string[]),
   mute_while_recording: (value) =>
     commands.changeMuteWhileRecordingSetting(value as boolean),
   append_trailing_space: (value) =>
     commands.changeAppendTrailingSpaceSetting(value as boolean),
+  completion_notifications: (value) =>
+    commands.changeCompletionNotificationsSetting(value as boolean),
+  continue_dictation: (value) =>
+    commands.changeContinueDictationSetting(value as boolean),
+  continue_dictation_window_secs: (value) =>
+    commands.changeContinueDictationWindowSetting(value as number),
+  tts_read_transcripts: (value) =>
+    commands.changeTtsReadTranscriptsSetting(value as boolean),
+  tts_read_errors: (value) =>
+    commands.changeTtsReadErrorsSetting(value as boolean),
+  tts_voice: (value) => commands.changeTtsVoiceSetting(value as string | null),
+  tts_rate: (value) => commands.changeTtsRateSetting(value as number),
+  confirm_before_paste: (value) =>
+    commands.changeConfirmBeforePasteSetting(value as boolean),
+  hallucination_filter: (value) =>
+    commands.changeHallucinationFilterSetting(value as boolean),
+  hallucination_energy_floor_db: (value) =>
+    commands.changeHallucinationEnergyFloorSetting(value as number),
+  hallucination_no_speech_threshold: (value) =>
+    commands.changeHallucinationNoSpeechThresholdSetting(value as number),
+  punctuation_restore: (value) =>
+    commands.changePunctuationRestoreSetting(value as boolean),
+  punctuation_restore_casing_model: (value) =>
+    commands.changePunctuationRestoreCasingModelSetting(value as boolean),
+  number_normalization: (value) =>
+    commands.changeNumberNormalizationSetting(value as boolean),
+  private_mode: (value) => commands.changePrivateModeSetting(value as boolean),
+  app_behaviors: (value) =>
+    commands.changeAppBehaviorsSetting(value as AppBehaviorRule[]),
   log_level: (value) => commands.setLogLevel(value as any),
+  log_transcripts: (value) => commands.setLogTranscripts(value as boolean),
   app_language: (value) => commands.changeAppLanguageSetting(value as string),
   experimental_enabled: (value) =>
     commands.changeExperimentalEnabledSetting(value as boolean),
@@ -399,7 +466,7 @@ export const useSettingsStore = create<SettingsStore>()(
 
     // Generic updater for post-processing provider settings
     updatePostProcessSetting: async (
-      settingType: "base_url" | "api_key" | "model",
+      settingType: "base_url" | "api_version" | "api_key" | "model",
       providerId: string,
       value: string,
     ) => {
@@ -411,6 +478,8 @@ export const useSettingsStore = create<SettingsStore>()(
       try {
         if (settingType === "base_url") {
           await commands.changePostProcessBaseUrlSetting(providerId, value);
+        } else if (settingType === "api_version") {
+          await commands.changePostProcessApiVersionSetting(providerId, value);
         } else if (settingType === "api_key") {
           await commands.changePostProcessApiKeySetting(providerId, value);
         } else if (settingType === "model") {
@@ -431,6 +500,14 @@ export const useSettingsStore = create<SettingsStore>()(
       return get().updatePostProcessSetting("base_url", providerId, baseUrl);
     },
 
+    updatePostProcessApiVersion: async (providerId, apiVersion) => {
+      return get().updatePostProcessSetting(
+        "api_version",
+        providerId,
+        apiVersion,
+      );
+    },
+
     updatePostProcessApiKey: async (providerId, apiKey) => {
       // Clear cached models when API key changes - user should click refresh after
       set((state) => ({
@@ -506,6 +583,11 @@ export const useSettingsStore = create<SettingsStore>()(
         refreshSettings(),
         checkCustomSounds(),
       ]);
+
+      // Settings changed outside this window, e.g. from the tray menu
+      listen("settings-changed", () => {
+        get().refreshSettings();
+      });
     },
   })),
 );